@@ -275,6 +275,14 @@ impl DialogueRunner {
         self.dialogue.library_mut()
     }
 
+    /// Returns the source of randomness used by functions such as `random`, `random_range` and `dice`.
+    /// Clone it to let your own functions draw from the same random sequence, and seed it with [`YarnRng::seed`]
+    /// or [`DialogueRunnerBuilder::with_random_seed`] for deterministic dialogue.
+    #[must_use]
+    pub fn rng(&self) -> &YarnRng {
+        self.dialogue.rng()
+    }
+
     /// Returns the command registrations that can be called from Yarn files.
     #[must_use]
     pub fn commands(&self) -> &YarnCommands {
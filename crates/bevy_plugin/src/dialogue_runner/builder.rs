@@ -2,9 +2,9 @@ use crate::default_impl::{MemoryVariableStorage, StringsFileTextProvider};
 use crate::fmt_utils::SkipDebug;
 use crate::line_provider::SharedTextProvider;
 use crate::prelude::*;
+use crate::utils::add_source_positions;
 use bevy::prelude::*;
 use bevy::utils::HashMap;
-use rand::{rngs::SmallRng, Rng, SeedableRng};
 use std::any::{Any, TypeId};
 use std::fmt::Debug;
 
@@ -17,6 +17,7 @@ pub struct DialogueRunnerBuilder {
     text_provider: SharedTextProvider,
     asset_providers: HashMap<TypeId, Box<dyn AssetProvider>>,
     library: YarnLibrary,
+    random_seed: Option<u64>,
     commands: YarnCommands,
     pub compilation: Compilation,
     localizations: Option<Localizations>,
@@ -32,7 +33,8 @@ impl DialogueRunnerBuilder {
                 yarn_project,
             )),
             asset_providers: HashMap::new(),
-            library: create_extended_standard_library(),
+            library: YarnLibrary::new(),
+            random_seed: None,
             commands: YarnCommands::builtin_commands(),
             compilation: yarn_project.compilation().clone(),
             localizations: yarn_project.localizations().cloned(),
@@ -62,6 +64,15 @@ impl DialogueRunnerBuilder {
         self
     }
 
+    /// Seeds the randomness used by functions such as `random`, `random_range` and `dice`.
+    /// Dialogue runners built with the same seed will make the same random choices. By default, the seed is taken from entropy.
+    /// See [`DialogueRunner::rng`] for accessing the randomness after building.
+    #[must_use]
+    pub fn with_random_seed(mut self, seed: u64) -> Self {
+        self.random_seed = Some(seed);
+        self
+    }
+
     /// Builds the [`DialogueRunner`]. See [`DialogueRunnerBuilder::try_build`] for the fallible version.
     pub fn build(self) -> DialogueRunner {
         self.try_build().unwrap_or_else(|error| {
@@ -77,7 +88,11 @@ impl DialogueRunnerBuilder {
         dialogue
             .set_line_hints_enabled(true)
             .library_mut()
-            .extend(self.library);
+            .try_extend(self.library)?;
+        if let Some(seed) = self.random_seed {
+            dialogue.set_random_seed(seed);
+        }
+        add_source_positions(&mut dialogue, &self.compilation);
         dialogue.add_program(self.compilation.program.unwrap());
 
         for asset_provider in self.asset_providers.values_mut() {
@@ -119,83 +134,3 @@ impl DialogueRunnerBuilder {
         Ok(dialogue_runner)
     }
 }
-
-fn create_extended_standard_library() -> YarnLibrary {
-    let mut library = YarnLibrary::standard_library();
-    library
-        .add_function("random", || SmallRng::from_entropy().gen_range(0.0..1.0))
-        .add_function("random_range", |min: f32, max: f32| {
-            if let Some(min) = min.as_int() {
-                if let Some(max_inclusive) = max.as_int() {
-                    return SmallRng::from_entropy().gen_range(min..=max_inclusive) as f32;
-                }
-            }
-            SmallRng::from_entropy().gen_range(min..max)
-        })
-        .add_function("dice", |sides: u32| {
-            if sides == 0 {
-                return 1;
-            }
-            SmallRng::from_entropy().gen_range(1..=sides)
-        })
-        .add_function("round", |num: f32| num.round() as i32)
-        .add_function("round_places", |num: f32, places: u32| {
-            num.round_places(places)
-        })
-        .add_function("floor", |num: f32| num.floor() as i32)
-        .add_function("ceil", |num: f32| num.ceil() as i32)
-        .add_function("inc", |num: f32| {
-            if let Some(num) = num.as_int() {
-                num + 1
-            } else {
-                num.ceil() as i32
-            }
-        })
-        .add_function("dec", |num: f32| {
-            if let Some(num) = num.as_int() {
-                num - 1
-            } else {
-                num.floor() as i32
-            }
-        })
-        .add_function("decimal", |num: f32| num.fract())
-        .add_function("int", |num: f32| num.trunc() as i32);
-    library
-}
-
-trait FloatExt: Copy {
-    fn as_int(self) -> Option<i32>;
-    fn round_places(self, places: u32) -> Self;
-}
-
-impl FloatExt for f32 {
-    fn as_int(self) -> Option<i32> {
-        (self.fract() <= f32::EPSILON).then_some(self as i32)
-    }
-
-    fn round_places(self, places: u32) -> Self {
-        let factor = 10_u32.pow(places) as f32;
-        (self * factor).round() / factor
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn rounds_places() {
-        for (num, places, expected) in [
-            (1.0, 0, 1.0),
-            (1.2, 1, 1.2),
-            (0.4, 0, 0.0),
-            (43.132, 0, 43.0),
-            (1.1, 2, 1.1),
-            (123.123, 3, 123.123),
-            (-10.3, 1, -10.3),
-            (-11.99, 1, -12.0),
-        ] {
-            assert_eq!(expected, num.round_places(places));
-        }
-    }
-}
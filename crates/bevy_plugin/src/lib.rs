@@ -149,7 +149,8 @@ pub mod prelude {
     pub(crate) use yarnspinner::prelude::*;
     pub use yarnspinner::prelude::{
         IntoYarnValueFromNonYarnValue, Language, LineId, MarkupAttribute, MarkupValue, OptionId,
-        VariableStorage, YarnFn, YarnLibrary, YarnValue,
+        VariableStorage, Variadic, YarnFn, YarnFnContext, YarnFnReturn, YarnLibrary, YarnRng,
+        YarnValue,
     };
     pub(crate) type SystemResult = Result<()>;
 }
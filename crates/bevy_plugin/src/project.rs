@@ -34,6 +34,7 @@ pub(crate) struct CompilationSystemSet;
 pub struct YarnProject {
     pub(crate) yarn_files: HashSet<Handle<YarnFile>>,
     pub(crate) compilation: Compilation,
+    pub(crate) compiler_session: YarnCompilerSession,
     pub(crate) localizations: Option<Localizations>,
     pub(crate) asset_server: SkipDebug<AssetServer>,
     pub(crate) metadata: HashMap<LineId, Vec<String>>,
@@ -3,6 +3,7 @@ use crate::localization::{LineIdUpdateSystemSet, UpdateAllStringsFilesForStringT
 use crate::plugin::AssetRoot;
 use crate::prelude::*;
 use crate::project::{CompilationSystemSet, LoadYarnProjectEvent, WatchingForChanges};
+use crate::utils::add_source_positions;
 use anyhow::bail;
 use bevy::prelude::*;
 use bevy::utils::{error, HashSet};
@@ -117,15 +118,30 @@ fn recompile_loaded_yarn_files(
     let Some(mut yarn_project) = yarn_project else {
         return Ok(());
     };
-    let Some(compilation) = compile_yarn_files(
+    let yarn_project = &mut *yarn_project;
+    // A typo in a file being edited should not take the whole dialogue down while the game is running.
+    yarn_project
+        .compiler_session
+        .compiler
+        .with_partial_compilation(true);
+    let Some(mut compilation) = compile_yarn_files(
         &yarn_project.yarn_files,
         &yarn_files,
         yarn_project.localizations.as_ref(),
         yarn_project.development_file_generation,
+        &mut yarn_project.compiler_session,
     )?
     else {
         return Ok(());
     };
+    let is_partial = !compilation.errors.is_empty();
+    if is_partial {
+        error!(
+            "Failed to recompile some nodes of the Yarn project, keeping their previous versions:\n{}",
+            CompilerError(compilation.errors.clone())
+        );
+        keep_previous_versions_of_missing_nodes(&mut compilation, &yarn_project.compilation);
+    }
     let metadata = compilation
         .string_table
         .iter()
@@ -137,6 +153,7 @@ fn recompile_loaded_yarn_files(
     for mut dialogue_runner in dialogue_runners.iter_mut() {
         let current_node = dialogue_runner.current_node();
         dialogue_runner.dialogue.replace_program(program.clone());
+        add_source_positions(&mut dialogue_runner.dialogue, &yarn_project.compilation);
         dialogue_runner
             .text_provider
             .set_base_string_table(yarn_project.compilation.string_table.clone());
@@ -152,10 +169,70 @@ fn recompile_loaded_yarn_files(
         }
     }
     events.clear();
-    info!("Successfully recompiled Yarn project because of changes in Yarn files.");
+    if !is_partial {
+        info!("Successfully recompiled Yarn project because of changes in Yarn files.");
+    }
     Ok(())
 }
 
+/// Adds the nodes that are missing from a partial `compilation` back from the `previous` one,
+/// together with their lines, variables and debug info.
+fn keep_previous_versions_of_missing_nodes(compilation: &mut Compilation, previous: &Compilation) {
+    let (Some(program), Some(previous_program)) =
+        (compilation.program.as_mut(), previous.program.as_ref())
+    else {
+        return;
+    };
+    let missing_nodes: HashSet<_> = previous_program
+        .nodes
+        .keys()
+        .filter(|name| !program.nodes.contains_key(*name))
+        .cloned()
+        .collect();
+    for name in &missing_nodes {
+        program
+            .nodes
+            .insert(name.clone(), previous_program.nodes[name].clone());
+        if let Some(debug_info) = previous.debug_info.get(name) {
+            compilation
+                .debug_info
+                .insert(name.clone(), debug_info.clone());
+        }
+    }
+    for (line_id, string_info) in &previous.string_table {
+        if missing_nodes.contains(&string_info.node_name) {
+            compilation
+                .string_table
+                .entry(line_id.clone())
+                .or_insert_with(|| string_info.clone());
+        }
+    }
+    let missing_declarations: Vec<_> = previous
+        .declarations
+        .iter()
+        .filter(|declaration| {
+            declaration
+                .source_node_name
+                .as_ref()
+                .is_some_and(|node_name| missing_nodes.contains(node_name))
+                && !compilation
+                    .declarations
+                    .iter()
+                    .any(|other| other.name == declaration.name)
+        })
+        .cloned()
+        .collect();
+    for declaration in &missing_declarations {
+        if let Some(value) = previous_program.initial_values.get(&declaration.name) {
+            program
+                .initial_values
+                .entry(declaration.name.clone())
+                .or_insert_with(|| value.clone());
+        }
+    }
+    compilation.declarations.extend(missing_declarations);
+}
+
 fn compile_loaded_yarn_files(
     mut commands: Commands,
     mut yarn_files_being_loaded: ResMut<YarnFilesBeingLoaded>,
@@ -190,11 +267,13 @@ fn compile_loaded_yarn_files(
         .unwrap()
         .as_ref();
     let development_file_generation = yarn_project_config_to_load.development_file_generation;
+    let mut compiler_session = YarnCompilerSession::default();
     let Some(compilation) = compile_yarn_files(
         &yarn_files_being_loaded.0,
         &yarn_files,
         localizations,
         development_file_generation,
+        &mut compiler_session,
     )?
     else {
         return Ok(());
@@ -237,6 +316,7 @@ fn compile_loaded_yarn_files(
     commands.insert_resource(YarnProject {
         yarn_files: std::mem::take(&mut yarn_files_being_loaded.0),
         compilation,
+        compiler_session,
         localizations: yarn_project_config_to_load.localizations.clone().unwrap(),
         asset_server: SkipDebug(asset_server.clone()),
         watching_for_changes: yarn_project_config_to_load.watching_for_changes,
@@ -261,6 +341,7 @@ fn compile_yarn_files(
     yarn_files: &Res<Assets<YarnFile>>,
     localizations: Option<&Localizations>,
     development_file_generation: DevelopmentFileGeneration,
+    compiler_session: &mut YarnCompilerSession,
 ) -> Result<Option<Compilation>> {
     let yarn_files = yarn_file_handles
         .iter()
@@ -283,7 +364,8 @@ fn compile_yarn_files(
             }
         }
     }
-    let inner_yarn_files = yarn_files.map(|file| file.file.clone());
-    let compilation = YarnCompiler::new().add_files(inner_yarn_files).compile()?;
+    // Only the files that changed since the last compilation are parsed again.
+    compiler_session.compiler.files = yarn_files.map(|file| file.file.clone()).collect();
+    let compilation = compiler_session.compile()?;
     Ok(Some(compilation))
 }
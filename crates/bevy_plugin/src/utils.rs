@@ -8,6 +8,20 @@ pub(crate) fn panic_on_err(In(result): In<SystemResult>) {
     }
 }
 
+/// Lets errors raised by the dialogue point at the Yarn source the failing instruction was compiled from.
+pub(crate) fn add_source_positions(dialogue: &mut Dialogue, compilation: &Compilation) {
+    for (node_name, debug_info) in &compilation.debug_info {
+        dialogue.add_source_positions(
+            node_name,
+            &debug_info.file_name,
+            debug_info
+                .line_positions
+                .iter()
+                .filter_map(|(&index, &position)| Some((index, position?))),
+        );
+    }
+}
+
 pub(crate) fn in_development(
     project: Option<Res<YarnProject>>,
     project_to_load: Option<Res<YarnProjectConfigToLoad>>,
@@ -0,0 +1,126 @@
+//! The command line arguments of `ysc`. The doc comments double as the `--help` text.
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+
+/// Compiles Yarn Spinner dialogue and inspects `.yarn` files.
+#[derive(Debug, Clone, Parser)]
+#[command(name = "ysc", version)]
+pub struct Cli {
+    /// Print a single JSON object to stdout instead of text.
+    #[arg(long, global = true)]
+    pub json: bool,
+
+    /// The command to run.
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+/// The commands of `ysc`.
+#[derive(Debug, Clone, Subcommand)]
+pub enum Command {
+    /// Compile the files into `<name>.yarnc`, `<name>-Lines.csv` and `<name>-Metadata.csv`.
+    Compile(CompileArgs),
+
+    /// Report the problems in the files.
+    Check(CheckArgs),
+
+    /// Add a `#line:` tag to every line, option and shortcut option that has none, rewriting the files in place.
+    Tag(TagArgs),
+
+    /// Format the files in a canonical style, rewriting them in place.
+    Format(FormatArgs),
+
+    /// Print the string table in the format of `<name>-Lines.csv`.
+    DumpStrings(Inputs),
+
+    /// Print the titles of the nodes, sorted by file.
+    ListNodes(Inputs),
+
+    /// Print the declared and inferred variables. The output is always JSON.
+    Declarations(Inputs),
+}
+
+/// The files a command works on.
+#[derive(Debug, Clone, Args)]
+pub struct Inputs {
+    /// The `.yarn` files to use. Directories are searched for `.yarn` files recursively.
+    #[arg(required = true)]
+    pub inputs: Vec<PathBuf>,
+}
+
+/// The arguments of [`Command::Compile`].
+#[derive(Debug, Clone, Args)]
+pub struct CompileArgs {
+    /// The files to compile.
+    #[command(flatten)]
+    pub inputs: Inputs,
+
+    /// The directory to write the files to.
+    #[arg(short, long, default_value = ".")]
+    pub output_directory: PathBuf,
+
+    /// The name of the written files. Defaults to the name of the first input without its extension.
+    #[arg(short = 'n', long)]
+    pub output_name: Option<String>,
+
+    /// Write the program without a header, the way the original implementation does, instead of a `.yarnc` file
+    /// that can be loaded with `ProgramFile::from_bytes`.
+    #[arg(long)]
+    pub upstream: bool,
+}
+
+/// The arguments of [`Command::Check`].
+#[derive(Debug, Clone, Args)]
+pub struct CheckArgs {
+    /// The files to check.
+    #[command(flatten)]
+    pub inputs: Inputs,
+
+    /// Fail if there are warnings.
+    #[arg(long)]
+    pub deny_warnings: bool,
+
+    /// Print the diagnostics to stdout as a SARIF log, which CI services can use to annotate pull requests.
+    #[arg(long)]
+    pub sarif: bool,
+}
+
+/// The arguments of [`Command::Tag`].
+#[derive(Debug, Clone, Args)]
+pub struct TagArgs {
+    /// The files to tag.
+    #[command(flatten)]
+    pub inputs: Inputs,
+
+    /// Do not change any file, but fail if a file has lines without a `#line:` tag.
+    #[arg(long)]
+    pub check: bool,
+
+    /// How the IDs of the new tags are generated.
+    #[arg(long, value_enum, default_value_t = LineIds::Random)]
+    pub ids: LineIds,
+}
+
+/// The strategies for generating line IDs, see [`yarnspinner::compiler::line_tagging`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum LineIds {
+    /// Random IDs like `line:1234567`.
+    Random,
+    /// IDs derived from the node name and the text of the line, like `line:3f2a9c1e`.
+    Hash,
+    /// IDs made of the node name and a number, like `line:Start-1`.
+    Sequential,
+}
+
+/// The arguments of [`Command::Format`].
+#[derive(Debug, Clone, Args)]
+pub struct FormatArgs {
+    /// The files to format.
+    #[command(flatten)]
+    pub inputs: Inputs,
+
+    /// Do not change any file, but fail if a file is not formatted.
+    #[arg(long)]
+    pub check: bool,
+}
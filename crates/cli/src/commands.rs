@@ -0,0 +1,275 @@
+//! The implementation of each command.
+
+use crate::cli::*;
+use crate::input::read_files;
+use crate::json;
+use crate::output::{Output, Status};
+use serde_json::{Map, Value};
+use std::error::Error;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use yarnspinner::compiler::line_tagging::*;
+use yarnspinner::compiler::*;
+use yarnspinner::core::{LineEntry, LineMetadataEntry};
+
+type Result<T, E = Box<dyn Error>> = std::result::Result<T, E>;
+
+/// Runs the command given on the command line, writing its output to `stdout` and `stderr`.
+/// Returns the [`Status`] to exit with.
+pub fn run(cli: &Cli, stdout: &mut dyn Write, stderr: &mut dyn Write) -> Status {
+    let mut output = Output::new(cli.json, stdout, stderr);
+    let result = match &cli.command {
+        Command::Compile(args) => compile(args, &mut output),
+        Command::Check(args) => check(args, &mut output),
+        Command::Tag(args) => tag(args, &mut output),
+        Command::Format(args) => format(args, &mut output),
+        Command::DumpStrings(inputs) => dump_strings(inputs, &mut output),
+        Command::ListNodes(inputs) => list_nodes(inputs, &mut output),
+        Command::Declarations(inputs) => {
+            output.json = true;
+            declarations(inputs, &mut output)
+        }
+    };
+    result.unwrap_or_else(|error| output.error(error))
+}
+
+fn compile(args: &CompileArgs, output: &mut Output) -> Result<Status> {
+    let files = read_files(&args.inputs.inputs)?;
+    let name = match &args.output_name {
+        Some(name) => name.clone(),
+        None => file_stem(&files[0].file_name),
+    };
+    let compilation = match compile_files(files, CompilationType::FullCompilation) {
+        Ok(compilation) => compilation,
+        Err(error) => return Ok(output.finish(Status::Failure, &error.0, Map::new())?),
+    };
+    // A compilation without errors always has a program.
+    let program = if args.upstream {
+        compilation.program.as_ref().unwrap().to_upstream_bytes()
+    } else {
+        compilation.to_bytes().unwrap()
+    };
+    let outputs = [
+        (format!("{name}.yarnc"), program),
+        (
+            format!("{name}-Lines.csv"),
+            LineEntry::write_csv(&compilation.line_entries()).into_bytes(),
+        ),
+        (
+            format!("{name}-Metadata.csv"),
+            LineMetadataEntry::write_csv(&compilation.line_metadata_entries()).into_bytes(),
+        ),
+    ];
+
+    fs::create_dir_all(&args.output_directory)
+        .map_err(|error| format!("{}: {error}", args.output_directory.display()))?;
+    let mut written = Vec::new();
+    for (file_name, contents) in outputs {
+        let path = args.output_directory.join(file_name);
+        fs::write(&path, contents).map_err(|error| format!("{}: {error}", path.display()))?;
+        output.print(path.display())?;
+        written.push(Value::from(path.to_string_lossy()));
+    }
+    let fields = Map::from_iter([("files".to_owned(), Value::from(written))]);
+    Ok(output.finish(Status::Success, &compilation.warnings, fields)?)
+}
+
+fn check(args: &CheckArgs, output: &mut Output) -> Result<Status> {
+    let files = read_files(&args.inputs.inputs)?;
+    let (status, diagnostics) = match compile_files(files, CompilationType::FullCompilation) {
+        Ok(compilation) if args.deny_warnings && !compilation.warnings.is_empty() => {
+            (Status::Failure, compilation.warnings)
+        }
+        Ok(compilation) => (Status::Success, compilation.warnings),
+        Err(error) => (Status::Failure, error.0),
+    };
+    if args.sarif {
+        return Ok(output.finish_sarif(status, &diagnostics)?);
+    }
+    Ok(output.finish(status, &diagnostics, Map::new())?)
+}
+
+/// Tags the files one after another, so that the tags added to one file are known when tagging the next.
+fn tag(args: &TagArgs, output: &mut Output) -> Result<Status> {
+    let files = read_files(&args.inputs.inputs)?;
+    let tagger = LineTagger::new();
+    let mut tagger = match args.ids {
+        LineIds::Random => tagger,
+        LineIds::Hash => tagger.with_id_generator(ContentHashLineIds),
+        LineIds::Sequential => tagger.with_id_generator(SequentialLineIds::default()),
+    };
+
+    let mut diagnostics = Vec::new();
+    let mut tagged = Vec::new();
+    for tagged_file in tagger.tag_files(&files) {
+        diagnostics.extend(tagged_file.diagnostics);
+        let Some(source) = tagged_file.source else {
+            continue;
+        };
+        if !args.check {
+            fs::write(&tagged_file.file_name, &source)
+                .map_err(|error| format!("{}: {error}", tagged_file.file_name))?;
+        }
+        output.print(&tagged_file.file_name)?;
+        tagged.push(json::tagged_file(
+            &tagged_file.file_name,
+            &tagged_file.added_line_ids,
+        ));
+    }
+
+    let status = if diagnostics.has_errors() {
+        Status::Failure
+    } else if args.check && !tagged.is_empty() {
+        output.note(format!(
+            "{} file(s) have lines without a #line: tag. Run `ysc tag` to add them.",
+            tagged.len()
+        ))?;
+        Status::Failure
+    } else {
+        Status::Success
+    };
+    let fields = Map::from_iter([("files".to_owned(), Value::from(tagged))]);
+    Ok(output.finish(status, &diagnostics, fields)?)
+}
+
+/// Formats each file on its own, since formatting does not depend on the other files.
+fn format(args: &FormatArgs, output: &mut Output) -> Result<Status> {
+    let files = read_files(&args.inputs.inputs)?;
+    let mut diagnostics = Vec::new();
+    let mut changed = Vec::new();
+    for file in &files {
+        let source = match yarnspinner::compiler::format::format_file(file) {
+            Ok(source) => source,
+            Err(error) => {
+                diagnostics.extend(error.0);
+                continue;
+            }
+        };
+        if source == file.source {
+            continue;
+        }
+        if !args.check {
+            fs::write(&file.file_name, &source)
+                .map_err(|error| format!("{}: {error}", file.file_name))?;
+        }
+        output.print(&file.file_name)?;
+        changed.push(Value::from(file.file_name.as_str()));
+    }
+
+    let status = if !diagnostics.is_empty() {
+        Status::Failure
+    } else if args.check && !changed.is_empty() {
+        output.note(format!(
+            "{} file(s) are not formatted. Run `ysc format` to format them.",
+            changed.len()
+        ))?;
+        Status::Failure
+    } else {
+        Status::Success
+    };
+    let fields = Map::from_iter([("files".to_owned(), Value::from(changed))]);
+    Ok(output.finish(status, &diagnostics, fields)?)
+}
+
+fn dump_strings(inputs: &Inputs, output: &mut Output) -> Result<Status> {
+    let files = read_files(&inputs.inputs)?;
+    let compilation = match compile_files(files, CompilationType::StringsOnly) {
+        Ok(compilation) => compilation,
+        Err(error) => return Ok(output.finish(Status::Failure, &error.0, Map::new())?),
+    };
+    let entries = compilation.line_entries();
+    output.write(LineEntry::write_csv(&entries))?;
+
+    let lines: Vec<_> = entries
+        .iter()
+        .map(|entry| {
+            let tags: Vec<_> = compilation.string_table[&entry.id]
+                .metadata
+                .iter()
+                .filter(|tag| !tag.starts_with("line:"))
+                .cloned()
+                .collect();
+            json::line(entry, &tags)
+        })
+        .collect();
+    let fields = Map::from_iter([("lines".to_owned(), Value::from(lines))]);
+    Ok(output.finish(Status::Success, &compilation.warnings, fields)?)
+}
+
+fn list_nodes(inputs: &Inputs, output: &mut Output) -> Result<Status> {
+    let files = read_files(&inputs.inputs)?;
+    let compilation = match compile_files(files, CompilationType::FullCompilation) {
+        Ok(compilation) => compilation,
+        Err(error) => return Ok(output.finish(Status::Failure, &error.0, Map::new())?),
+    };
+    let program = compilation.program.as_ref().unwrap();
+    // Nodes do not know where they were declared, but their debug info knows where their first statement is,
+    // which keeps the nodes of a file in the order they were written in.
+    let mut nodes: Vec<_> = program
+        .nodes
+        .values()
+        .map(|node| {
+            let debug_info = compilation.debug_info.get(&node.name);
+            let file = debug_info.map(|debug_info| debug_info.file_name.clone());
+            let first_line = debug_info.and_then(|debug_info| {
+                debug_info
+                    .line_positions
+                    .values()
+                    .flatten()
+                    .map(|position| position.line)
+                    .min()
+            });
+            (file, first_line, node)
+        })
+        .collect();
+    nodes.sort_by(|(a_file, a_line, a), (b_file, b_line, b)| {
+        (a_file, a_line, &a.name).cmp(&(b_file, b_line, &b.name))
+    });
+
+    let mut json_nodes = Vec::new();
+    for (file, _, node) in nodes {
+        output.print(&node.name)?;
+        json_nodes.push(serde_json::json!({
+            "title": node.name,
+            "file": file,
+            "tags": node.tags,
+        }));
+    }
+    let fields = Map::from_iter([("nodes".to_owned(), Value::from(json_nodes))]);
+    Ok(output.finish(Status::Success, &compilation.warnings, fields)?)
+}
+
+fn declarations(inputs: &Inputs, output: &mut Output) -> Result<Status> {
+    let files = read_files(&inputs.inputs)?;
+    let compilation = match compile_files(files, CompilationType::DeclarationsOnly) {
+        Ok(compilation) => compilation,
+        Err(error) => return Ok(output.finish(Status::Failure, &error.0, Map::new())?),
+    };
+    let declarations: Vec<_> = compilation
+        .declarations
+        .iter()
+        // The variables the compiler generates for tracking visits are not meant to be used in scripts.
+        .filter(|declaration| !declaration.name.starts_with("$Yarn.Internal."))
+        .map(json::declaration)
+        .collect();
+    let fields = Map::from_iter([("declarations".to_owned(), Value::from(declarations))]);
+    Ok(output.finish(Status::Success, &compilation.warnings, fields)?)
+}
+
+fn compile_files(
+    files: Vec<File>,
+    compilation_type: CompilationType,
+) -> yarnspinner::compiler::Result<Compilation> {
+    Compiler::new()
+        .add_files(files)
+        .with_compilation_type(compilation_type)
+        .compile()
+}
+
+fn file_stem(file_name: &str) -> String {
+    Path::new(file_name).file_stem().map_or_else(
+        || "Program".into(),
+        |stem| stem.to_string_lossy().into_owned(),
+    )
+}
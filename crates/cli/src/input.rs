@@ -0,0 +1,56 @@
+//! Finding and reading the `.yarn` files given on the command line.
+
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use yarnspinner::compiler::File;
+
+/// Reads the given files and the `.yarn` files in the given directories.
+/// Files in a directory are read in the order of their paths, so that the output does not depend on the file system.
+pub(crate) fn read_files(inputs: &[PathBuf]) -> Result<Vec<File>, Box<dyn Error>> {
+    let mut paths = Vec::new();
+    for input in inputs {
+        if input.is_dir() {
+            let mut found = Vec::new();
+            find_yarn_files(input, &mut found)
+                .map_err(|error| format!("{}: {error}", input.display()))?;
+            found.sort();
+            paths.extend(found);
+        } else {
+            paths.push(input.clone());
+        }
+    }
+    if paths.is_empty() {
+        return Err("No .yarn files found".into());
+    }
+    paths
+        .into_iter()
+        .map(|path| {
+            let source = fs::read_to_string(&path)
+                .map_err(|error| format!("{}: {error}", path.display()))?;
+            Ok(File {
+                file_name: path.to_string_lossy().into_owned(),
+                source,
+            })
+        })
+        .collect()
+}
+
+/// Skips hidden directories like `.git`.
+fn find_yarn_files(directory: &Path, found: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in fs::read_dir(directory)? {
+        let entry = entry?;
+        let path = entry.path();
+        if path.is_dir() {
+            if !entry.file_name().to_string_lossy().starts_with('.') {
+                find_yarn_files(&path, found)?;
+            }
+        } else if path
+            .extension()
+            .is_some_and(|extension| extension == "yarn")
+        {
+            found.push(path);
+        }
+    }
+    Ok(())
+}
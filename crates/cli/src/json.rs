@@ -0,0 +1,91 @@
+//! The JSON representations printed with `--json`. Lines and characters are 1-indexed, like in the text output.
+
+use serde_json::{json, Value};
+use std::ops::Range;
+use yarnspinner::compiler::line_tagging::AddedLineId;
+use yarnspinner::compiler::{Declaration, DeclarationSource, Diagnostic, DiagnosticSeverity};
+use yarnspinner::core::{LineEntry, Position, YarnValue};
+
+pub(crate) fn diagnostic(diagnostic: &Diagnostic) -> Value {
+    let severity = match diagnostic.severity {
+        DiagnosticSeverity::Error => "error",
+        DiagnosticSeverity::Warning => "warning",
+    };
+    let suggestions: Vec<_> = diagnostic
+        .suggestions
+        .iter()
+        .map(|suggestion| {
+            json!({
+                "message": suggestion.message,
+                "range": range(&suggestion.range),
+                "replacement": suggestion.replacement,
+            })
+        })
+        .collect();
+    json!({
+        "code": diagnostic.code.as_str(),
+        "severity": severity,
+        "message": diagnostic.message,
+        "file": diagnostic.file_name,
+        "range": diagnostic.range.as_ref().map(range),
+        "suggestions": suggestions,
+    })
+}
+
+pub(crate) fn declaration(declaration: &Declaration) -> Value {
+    let file = match &declaration.source_file_name {
+        DeclarationSource::File(file_name) => Some(file_name),
+        DeclarationSource::External => None,
+    };
+    json!({
+        "name": declaration.name,
+        "type": declaration.r#type.to_string(),
+        "default_value": declaration.default_value.as_ref().map(value),
+        "description": declaration.description,
+        "file": file,
+        "node": declaration.source_node_name,
+        "range": declaration.range.as_ref().map(range),
+        "implicit": declaration.is_implicit,
+    })
+}
+
+pub(crate) fn line(entry: &LineEntry, tags: &[String]) -> Value {
+    json!({
+        "id": entry.id.0,
+        "text": entry.text,
+        "file": entry.file,
+        "node": entry.node,
+        "line": entry.line_number,
+        "tags": tags,
+    })
+}
+
+pub(crate) fn tagged_file(file_name: &str, added_line_ids: &[AddedLineId]) -> Value {
+    let lines: Vec<_> = added_line_ids
+        .iter()
+        .map(|added| {
+            json!({
+                "id": added.line_id.0,
+                "node": added.node_name,
+                "line": added.line_number,
+            })
+        })
+        .collect();
+    json!({ "file": file_name, "lines": lines })
+}
+
+fn value(value: &YarnValue) -> Value {
+    match value {
+        YarnValue::Number(number) => json!(number),
+        YarnValue::String(string) => json!(string),
+        YarnValue::Boolean(boolean) => json!(boolean),
+    }
+}
+
+fn range(range: &Range<Position>) -> Value {
+    json!({ "start": position(range.start), "end": position(range.end) })
+}
+
+fn position(position: Position) -> Value {
+    json!({ "line": position.line + 1, "character": position.character + 1 })
+}
@@ -0,0 +1,69 @@
+//! `ysc`, the command line compiler for Yarn Spinner, along with a few tools for working with `.yarn` files.
+//!
+//! Modeled after the `ysc` tool of the original Yarn Spinner:
+//! `ysc compile` writes the same `<name>.yarnc`, `<name>-Lines.csv` and `<name>-Metadata.csv` files.
+//! The other commands are
+//! - `ysc check`, which reports the problems in the files.
+//! - `ysc tag`, which adds `#line:` tags to the lines that have none, see [`yarnspinner::compiler::line_tagging`].
+//! - `ysc format`, which formats the files in the style described in [`yarnspinner::compiler::format`].
+//! - `ysc dump-strings`, which prints the string table as CSV.
+//! - `ysc list-nodes`, which prints the titles of the nodes.
+//! - `ysc declarations`, which prints the variable declarations as JSON.
+//!
+//! All commands take `.yarn` files or directories containing them.
+//!
+//! ## Usage in CI
+//!
+//! The exit code is 0 on success, 1 if the files have errors (see [`Status`]), and 2 if `ysc` could not do its job,
+//! e.g. because of invalid arguments or a file that could not be read.
+//!
+//! With `--json`, every command prints a single JSON object to stdout instead of text, for example
+//!
+//! ```json
+//! {
+//!   "success": false,
+//!   "diagnostics": [
+//!     {
+//!       "code": "YS0001",
+//!       "severity": "error",
+//!       "message": "…",
+//!       "file": "dialogue/start.yarn",
+//!       "range": { "start": { "line": 4, "character": 8 }, "end": { "line": 4, "character": 13 } },
+//!       "suggestions": [
+//!         {
+//!           "message": "did you mean `$gold`?",
+//!           "range": { "start": { "line": 4, "character": 8 }, "end": { "line": 4, "character": 13 } },
+//!           "replacement": "$gold"
+//!         }
+//!       ]
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! Lines and characters are 1-indexed. Besides `success` and `diagnostics`, the object contains what the command produced.
+//! The `code` of a diagnostic never changes between versions, see [`yarnspinner::compiler::DiagnosticCode`].
+//!
+//! `ysc check --sarif` prints the diagnostics as a SARIF log instead, see [`yarnspinner::compiler::sarif`].
+//!
+//! The commands can also be run without spawning a process:
+//!
+//! ```no_run
+//! use clap::Parser;
+//! use yarnspinner_cli::{Cli, Status};
+//!
+//! let cli = Cli::parse_from(["ysc", "check", "--json", "dialogue"]);
+//! let status = yarnspinner_cli::run(&cli, &mut std::io::stdout(), &mut std::io::stderr());
+//! assert_eq!(Status::Success, status);
+//! ```
+#![warn(missing_docs, missing_debug_implementations)]
+
+mod cli;
+mod commands;
+mod input;
+mod json;
+mod output;
+
+pub use cli::*;
+pub use commands::run;
+pub use output::Status;
@@ -0,0 +1,12 @@
+//! The `ysc` binary.
+
+use clap::Parser;
+use std::io;
+use std::process::ExitCode;
+use yarnspinner_cli::Cli;
+
+fn main() -> ExitCode {
+    // Exits with 2 on invalid arguments, like `Status::Error`.
+    let cli = Cli::parse();
+    yarnspinner_cli::run(&cli, &mut io::stdout().lock(), &mut io::stderr().lock()).into()
+}
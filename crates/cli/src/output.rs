@@ -0,0 +1,142 @@
+//! How the commands report their results: as text split between stdout and stderr, or as a single JSON object on stdout.
+
+use crate::json;
+use serde_json::{Map, Value};
+use std::fmt::Display;
+use std::io::{self, Write};
+use std::process::ExitCode;
+use yarnspinner::compiler::sarif::SarifLog;
+use yarnspinner::compiler::{Diagnostic, DiagnosticSeverity};
+
+/// How a command ended, reported as the exit code of `ysc`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// Exit code 0: the command did its job.
+    Success,
+    /// Exit code 1: the files have errors, or failed a check like `ysc check --deny-warnings` or `ysc tag --check`.
+    Failure,
+    /// Exit code 2: the command could not run, e.g. because a file could not be read or written.
+    /// Invalid arguments are reported with this code as well.
+    Error,
+}
+
+impl Status {
+    /// The exit code `ysc` exits with.
+    #[must_use]
+    pub fn code(self) -> u8 {
+        match self {
+            Status::Success => 0,
+            Status::Failure => 1,
+            Status::Error => 2,
+        }
+    }
+}
+
+impl From<Status> for ExitCode {
+    fn from(status: Status) -> Self {
+        ExitCode::from(status.code())
+    }
+}
+
+pub(crate) struct Output<'a> {
+    pub(crate) json: bool,
+    stdout: &'a mut dyn Write,
+    stderr: &'a mut dyn Write,
+}
+
+impl<'a> Output<'a> {
+    pub(crate) fn new(json: bool, stdout: &'a mut dyn Write, stderr: &'a mut dyn Write) -> Self {
+        Self {
+            json,
+            stdout,
+            stderr,
+        }
+    }
+
+    /// Prints a line to stdout. Does nothing in JSON mode.
+    pub(crate) fn print(&mut self, text: impl Display) -> io::Result<()> {
+        self.write(format_args!("{text}\n"))
+    }
+
+    /// Prints text to stdout as it is. Does nothing in JSON mode.
+    pub(crate) fn write(&mut self, text: impl Display) -> io::Result<()> {
+        if self.json {
+            return Ok(());
+        }
+        write!(self.stdout, "{text}")
+    }
+
+    /// Prints a line to stderr. Does nothing in JSON mode.
+    pub(crate) fn note(&mut self, text: impl Display) -> io::Result<()> {
+        if self.json {
+            return Ok(());
+        }
+        writeln!(self.stderr, "{text}")
+    }
+
+    /// Ends the command. In JSON mode, prints an object with `success`, `diagnostics` and the given `fields`.
+    /// Otherwise, prints the diagnostics to stderr.
+    pub(crate) fn finish(
+        &mut self,
+        status: Status,
+        diagnostics: &[Diagnostic],
+        fields: Map<String, Value>,
+    ) -> io::Result<Status> {
+        if self.json {
+            let mut object = Map::new();
+            object.insert("success".to_owned(), (status == Status::Success).into());
+            object.insert(
+                "diagnostics".to_owned(),
+                diagnostics.iter().map(json::diagnostic).collect(),
+            );
+            object.extend(fields);
+            serde_json::to_writer_pretty(&mut *self.stdout, &object)?;
+            writeln!(self.stdout)?;
+        } else {
+            for diagnostic in diagnostics {
+                write!(self.stderr, "{diagnostic}")?;
+            }
+            let errors = diagnostics
+                .iter()
+                .filter(|diagnostic| diagnostic.severity == DiagnosticSeverity::Error)
+                .count();
+            let warnings = diagnostics.len() - errors;
+            if !diagnostics.is_empty() {
+                writeln!(
+                    self.stderr,
+                    "{errors} error{}, {warnings} warning{}",
+                    plural(errors),
+                    plural(warnings)
+                )?;
+            }
+        }
+        Ok(status)
+    }
+
+    /// Ends the command by printing the diagnostics to stdout as a SARIF log, in JSON mode or not.
+    pub(crate) fn finish_sarif(
+        &mut self,
+        status: Status,
+        diagnostics: &[Diagnostic],
+    ) -> io::Result<Status> {
+        let log = SarifLog::from_diagnostics(diagnostics);
+        serde_json::to_writer_pretty(&mut *self.stdout, &log)?;
+        writeln!(self.stdout)?;
+        Ok(status)
+    }
+
+    /// Reports that the command could not run. Always printed to stderr, so that stdout only ever contains complete output.
+    pub(crate) fn error(&mut self, error: impl Display) -> Status {
+        // There is nowhere left to report a failure to write to stderr.
+        let _ = writeln!(self.stderr, "error: {error}");
+        Status::Error
+    }
+}
+
+fn plural(count: usize) -> &'static str {
+    if count == 1 {
+        ""
+    } else {
+        "s"
+    }
+}
@@ -0,0 +1,350 @@
+//! Runs the commands of `ysc` in-process on files in a temporary directory.
+
+use clap::Parser;
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use yarnspinner::core::ProgramFile;
+use yarnspinner_cli::{Cli, Status};
+
+const START: &str = "title: Start
+tags: intro
+---
+<<declare $gold = 5>>
+Hello! You have {$gold} gold. #line:0a1b2c #greeting
+<<jump Other>>
+===
+title: Other
+---
+Goodbye.
+-> Stay
+-> Leave #line:leave
+===
+";
+
+struct Project {
+    directory: PathBuf,
+}
+
+impl Project {
+    fn new(name: &str) -> Self {
+        let directory = std::env::temp_dir().join(format!(
+            "yarnspinner_cli_test_{name}_{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        Self { directory }
+    }
+
+    fn write(&self, file_name: &str, source: &str) -> PathBuf {
+        let path = self.directory.join(file_name);
+        fs::write(&path, source).unwrap();
+        path
+    }
+
+    fn path(&self, file_name: &str) -> PathBuf {
+        self.directory.join(file_name)
+    }
+}
+
+impl Drop for Project {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.directory);
+    }
+}
+
+struct Run {
+    status: Status,
+    stdout: String,
+    stderr: String,
+}
+
+impl Run {
+    fn json(&self) -> Value {
+        serde_json::from_str(&self.stdout).unwrap()
+    }
+}
+
+fn ysc(args: &[&dyn AsRef<std::ffi::OsStr>]) -> Run {
+    let args = [std::ffi::OsStr::new("ysc")]
+        .into_iter()
+        .chain(args.iter().map(|arg| arg.as_ref()));
+    let cli = Cli::try_parse_from(args).unwrap();
+    let (mut stdout, mut stderr) = (Vec::new(), Vec::new());
+    let status = yarnspinner_cli::run(&cli, &mut stdout, &mut stderr);
+    Run {
+        status,
+        stdout: String::from_utf8(stdout).unwrap(),
+        stderr: String::from_utf8(stderr).unwrap(),
+    }
+}
+
+fn count_line_tags(source: &str) -> usize {
+    source.matches("#line:").count()
+}
+
+#[test]
+fn compiles_to_a_program_and_csv_files() {
+    let project = Project::new("compile");
+    let start = project.write("start.yarn", START);
+    let output = project.path("out");
+
+    let run = ysc(&[&"compile", &start, &"--output-directory", &output]);
+
+    assert_eq!(Status::Success, run.status, "{}", run.stderr);
+    let program_file =
+        ProgramFile::from_bytes(&fs::read(output.join("start.yarnc")).unwrap()).unwrap();
+    assert!(program_file.program.nodes.contains_key("Other"));
+    let lines = fs::read_to_string(output.join("start-Lines.csv")).unwrap();
+    assert!(lines.starts_with("id,text,file,node,lineNumber"));
+    assert!(lines.contains("line:0a1b2c,Hello! You have {0} gold."));
+    let metadata = fs::read_to_string(output.join("start-Metadata.csv")).unwrap();
+    assert!(metadata.contains("line:0a1b2c,Start,5,greeting"));
+}
+
+#[test]
+fn names_the_output_files() {
+    let project = Project::new("compile_name");
+    let start = project.write("start.yarn", START);
+
+    let run = ysc(&[
+        &"--json",
+        &"compile",
+        &start,
+        &"-o",
+        &project.directory,
+        &"-n",
+        &"Game",
+    ]);
+
+    assert_eq!(Status::Success, run.status);
+    let files: Vec<_> = run.json()["files"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|file| PathBuf::from(file.as_str().unwrap()))
+        .collect();
+    assert_eq!(
+        vec![
+            project.path("Game.yarnc"),
+            project.path("Game-Lines.csv"),
+            project.path("Game-Metadata.csv")
+        ],
+        files
+    );
+    assert!(files.iter().all(|file| file.exists()));
+}
+
+#[test]
+fn checks_files_for_errors() {
+    let project = Project::new("check");
+    project.write("start.yarn", START);
+    project.write(
+        "broken.yarn",
+        "title: Broken\n---\n<<if true>>\nOops\n===\n",
+    );
+
+    let run = ysc(&[&"check", &project.directory]);
+    assert_eq!(Status::Failure, run.status);
+    assert_eq!(1, run.status.code());
+    assert!(run.stderr.contains("broken.yarn"), "{}", run.stderr);
+
+    let run = ysc(&[&"check", &"--json", &project.directory]);
+    assert_eq!(Status::Failure, run.status);
+    let json = run.json();
+    assert_eq!(Value::Bool(false), json["success"]);
+    let diagnostic = &json["diagnostics"][0];
+    assert_eq!("error", diagnostic["severity"]);
+    assert!(diagnostic["file"]
+        .as_str()
+        .unwrap()
+        .ends_with("broken.yarn"));
+
+    let run = ysc(&[&"check", &"--json", &project.path("start.yarn")]);
+    assert_eq!(Status::Success, run.status);
+    assert_eq!(Value::Bool(true), run.json()["success"]);
+}
+
+#[test]
+fn reports_diagnostics_as_sarif() {
+    let project = Project::new("sarif");
+    project.write(
+        "typo.yarn",
+        "title: Start\n---\n<<declare $gold = 0>>\nYou have {$glod} gold.\n===\n",
+    );
+
+    let run = ysc(&[&"check", &"--sarif", &project.directory]);
+
+    assert_eq!(Status::Failure, run.status);
+    let sarif = run.json();
+    assert_eq!("2.1.0", sarif["version"]);
+    let result = &sarif["runs"][0]["results"][0];
+    assert_eq!("YS0001", result["ruleId"]);
+    assert_eq!("error", result["level"]);
+    let location = &result["locations"][0]["physicalLocation"];
+    assert!(location["artifactLocation"]["uri"]
+        .as_str()
+        .unwrap()
+        .ends_with("typo.yarn"));
+    assert_eq!(4, location["region"]["startLine"]);
+    assert_eq!(11, location["region"]["startColumn"]);
+    let replacement = &result["fixes"][0]["artifactChanges"][0]["replacements"][0];
+    assert_eq!("$gold", replacement["insertedContent"]["text"]);
+}
+
+#[test]
+fn tags_lines_in_place() {
+    let project = Project::new("tag");
+    let start = project.write("start.yarn", START);
+
+    let run = ysc(&[&"tag", &"--check", &start]);
+    assert_eq!(Status::Failure, run.status);
+    assert_eq!(START, fs::read_to_string(&start).unwrap());
+
+    let run = ysc(&[&"tag", &start]);
+    assert_eq!(Status::Success, run.status);
+    assert_eq!(start.to_string_lossy(), run.stdout.trim());
+    let tagged = fs::read_to_string(&start).unwrap();
+    assert_eq!(4, count_line_tags(&tagged), "{tagged}");
+    assert!(tagged.contains("#line:0a1b2c #greeting"));
+
+    let run = ysc(&[&"--json", &"tag", &"--check", &start]);
+    assert_eq!(Status::Success, run.status);
+    assert_eq!(Value::Array(vec![]), run.json()["files"]);
+}
+
+#[test]
+fn tags_lines_with_sequential_ids_across_files() {
+    let project = Project::new("tag_sequential");
+    let start = project.write("start.yarn", START);
+    let other = project.write(
+        "other.yarn",
+        "title: Third\n---\nHi #line:Other-1\nHo\n===\n",
+    );
+
+    let run = ysc(&[
+        &"--json",
+        &"tag",
+        &"--ids",
+        &"sequential",
+        &project.directory,
+    ]);
+    assert_eq!(Status::Success, run.status);
+
+    let tagged = fs::read_to_string(&start).unwrap();
+    assert!(tagged.contains("Goodbye. #line:Other-2 "), "{tagged}");
+    assert!(tagged.contains("-> Stay #line:Other-3 "), "{tagged}");
+    assert_eq!(
+        "title: Third\n---\nHi #line:Other-1\nHo #line:Third-1 \n===\n",
+        fs::read_to_string(&other).unwrap()
+    );
+
+    let files = run.json()["files"].as_array().unwrap().clone();
+    let start_report = files
+        .iter()
+        .find(|file| file["file"].as_str().unwrap().ends_with("start.yarn"))
+        .unwrap();
+    assert_eq!(
+        serde_json::json!([
+            { "id": "line:Other-2", "node": "Other", "line": 10 },
+            { "id": "line:Other-3", "node": "Other", "line": 11 },
+        ]),
+        start_report["lines"]
+    );
+}
+
+#[test]
+fn formats_files_in_place() {
+    let project = Project::new("format");
+    let unformatted = "title:Start\n---\n-> Yes\n  <<set $gold=1>>\n===\n";
+    let start = project.write("start.yarn", unformatted);
+    let other = project.write("other.yarn", "title: Other\n---\nBye\n===\n");
+
+    let run = ysc(&[&"format", &"--check", &project.directory]);
+    assert_eq!(Status::Failure, run.status);
+    assert_eq!(start.to_string_lossy(), run.stdout.trim());
+    assert_eq!(unformatted, fs::read_to_string(&start).unwrap());
+
+    let run = ysc(&[&"format", &project.directory]);
+    assert_eq!(Status::Success, run.status, "{}", run.stderr);
+    assert_eq!(
+        "title: Start\n---\n-> Yes\n    <<set $gold = 1>>\n===\n",
+        fs::read_to_string(&start).unwrap()
+    );
+    assert_eq!(
+        "title: Other\n---\nBye\n===\n",
+        fs::read_to_string(&other).unwrap()
+    );
+
+    let run = ysc(&[&"--json", &"format", &"--check", &project.directory]);
+    assert_eq!(Status::Success, run.status);
+    assert_eq!(Value::Array(vec![]), run.json()["files"]);
+}
+
+#[test]
+fn dumps_the_string_table() {
+    let project = Project::new("dump_strings");
+    let start = project.write("start.yarn", START);
+
+    let run = ysc(&[&"dump-strings", &start]);
+    assert_eq!(Status::Success, run.status);
+    assert!(run.stdout.starts_with("id,text,file,node,lineNumber"));
+    assert_eq!(5, run.stdout.lines().count(), "{}", run.stdout);
+
+    let run = ysc(&[&"dump-strings", &"--json", &start]);
+    let line = &run.json()["lines"][0];
+    assert_eq!("line:0a1b2c", line["id"]);
+    assert_eq!("Start", line["node"]);
+    assert_eq!(5, line["line"]);
+    assert_eq!(serde_json::json!(["greeting"]), line["tags"]);
+}
+
+#[test]
+fn lists_nodes_in_the_order_they_were_written() {
+    let project = Project::new("list_nodes");
+    let start = project.write("start.yarn", START);
+
+    let run = ysc(&[&"list-nodes", &start]);
+    assert_eq!(Status::Success, run.status);
+    assert_eq!("Start\nOther\n", run.stdout);
+
+    let run = ysc(&[&"list-nodes", &"--json", &start]);
+    let node = &run.json()["nodes"][0];
+    assert_eq!("Start", node["title"]);
+    assert_eq!(serde_json::json!(["intro"]), node["tags"]);
+}
+
+#[test]
+fn prints_declarations_as_json() {
+    let project = Project::new("declarations");
+    let start = project.write("start.yarn", START);
+
+    let run = ysc(&[&"declarations", &start]);
+
+    assert_eq!(Status::Success, run.status);
+    let declarations = run.json()["declarations"].as_array().unwrap().clone();
+    assert_eq!(1, declarations.len(), "{declarations:?}");
+    let gold = &declarations[0];
+    assert_eq!("$gold", gold["name"]);
+    assert_eq!("Number", gold["type"]);
+    assert_eq!(5.0, gold["default_value"]);
+    assert_eq!("Start", gold["node"]);
+    assert_eq!(4, gold["range"]["start"]["line"]);
+}
+
+#[test]
+fn fails_with_exit_code_2_if_a_file_cannot_be_read() {
+    let missing = Path::new("does/not/exist.yarn");
+
+    let run = ysc(&[&"--json", &"check", &missing]);
+
+    assert_eq!(Status::Error, run.status);
+    assert_eq!(2, run.status.code());
+    assert!(run.stdout.is_empty());
+    assert!(
+        run.stderr.starts_with("error: does/not/exist.yarn"),
+        "{}",
+        run.stderr
+    );
+}
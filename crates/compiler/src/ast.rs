@@ -0,0 +1,447 @@
+//! An owned syntax tree of Yarn files, for tools like linters, formatters and exporters that need to inspect Yarn code
+//! without compiling it. Create one with [`parse`] and walk it with a [`Visitor`].
+//!
+//! Every element has the [`Range`] of the source code it was parsed from, with [`Position`]s as used in
+//! [`Diagnostic::range`]. The tree represents the code as it was written: lines without a `#line:` tag do not get one,
+//! and no types are inferred. Use a [`Compiler`] for everything that needs the semantics of a file.
+//!
+//! ## Implementation notes
+//!
+//! The tree is converted from the parse tree, whose types are internal. Syntax errors are not represented in the
+//! tree, which is why [`parse`] only returns one for files without them.
+
+use crate::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+mod convert;
+mod visitor;
+
+pub use self::visitor::*;
+
+/// Parses the source code of a Yarn file into its syntax tree.
+///
+/// ## Errors
+///
+/// Returns the diagnostics of the parser if the file has syntax errors. The diagnostics refer to the [`File::file_name`].
+pub fn parse(file: &File) -> crate::Result<Dialogue> {
+    let chars: Vec<_> = file.source.chars().collect();
+    let mut diagnostics = Vec::new();
+    let parse_result = parse_syntax_tree(file, &chars, &mut diagnostics);
+    if diagnostics.has_errors() {
+        return Err(CompilerError(diagnostics));
+    }
+    Ok(convert::convert_dialogue(&parse_result))
+}
+
+/// The contents of a single Yarn file.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Dialogue {
+    /// The [`File::file_name`] of the parsed file.
+    pub file_name: String,
+    /// The hashtags at the start of the file, before the first node, e.g. `#intro`.
+    pub file_tags: Vec<Hashtag>,
+    /// The nodes of the file, in the order they appear in.
+    pub nodes: Vec<Node>,
+}
+
+/// A node, i.e. its headers followed by its body between `---` and `===`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Node {
+    /// The headers of the node, in the order they appear in.
+    pub headers: Vec<Header>,
+    /// The statements of the node's body.
+    pub body: Vec<Statement>,
+    /// The range of the whole node, from its first header to the `===`.
+    pub range: Range<Position>,
+}
+
+impl Node {
+    /// The value of the `title` header, if the node has one.
+    pub fn title(&self) -> Option<&str> {
+        self.header("title")
+    }
+
+    /// The value of the first header with the given key.
+    pub fn header(&self, key: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|header| header.key == key)
+            .map(|header| header.value.as_str())
+    }
+
+    /// The tags of the `tags` header, which are separated by spaces.
+    pub fn tags(&self) -> Vec<&str> {
+        self.header("tags")
+            .map(|tags| tags.split_whitespace().collect())
+            .unwrap_or_default()
+    }
+}
+
+/// A header of a node, e.g. `title: Start`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Header {
+    /// The text before the `:`.
+    pub key: String,
+    /// The text after the `:`, which is empty for a header without a value.
+    pub value: String,
+    /// The range of the whole header.
+    pub range: Range<Position>,
+}
+
+/// A hashtag, e.g. `#line:abc123` or `#happy`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Hashtag {
+    /// The text of the hashtag without the leading `#`.
+    pub text: String,
+    /// The range of the hashtag, including the `#`.
+    pub range: Range<Position>,
+}
+
+/// A statement in the body of a node or in one of the blocks in it.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Statement {
+    /// A line of dialogue.
+    Line(Line),
+    /// A group of options following each other, e.g. `-> Yes` and `-> No`.
+    Options(OptionGroup),
+    /// An `<<if>>` block with its `<<elseif>>` and `<<else>>` clauses.
+    If(IfStatement),
+    /// A `<<set>>` command.
+    Set(SetStatement),
+    /// A `<<call>>` command.
+    Call(CallStatement),
+    /// A `<<declare>>` command.
+    Declare(DeclareStatement),
+    /// A `<<jump>>` command.
+    Jump(JumpStatement),
+    /// Any other command, e.g. `<<wait 2>>`, which is passed to the game.
+    Command(Command),
+    /// Indented statements that do not belong to an option.
+    Block(Block),
+}
+
+impl Statement {
+    /// The range of the statement.
+    pub fn range(&self) -> Range<Position> {
+        match self {
+            Self::Line(line) => line.range.clone(),
+            Self::Options(options) => options.range.clone(),
+            Self::If(if_statement) => if_statement.range.clone(),
+            Self::Set(set) => set.range.clone(),
+            Self::Call(call) => call.range.clone(),
+            Self::Declare(declare) => declare.range.clone(),
+            Self::Jump(jump) => jump.range.clone(),
+            Self::Command(command) => command.range.clone(),
+            Self::Block(block) => block.range.clone(),
+        }
+    }
+}
+
+/// A line of dialogue, e.g. `Sally: Hi, {$name}! <<if $met_before>> #line:abc123`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Line {
+    /// The text of the line, with the whitespace around it removed.
+    pub text: Vec<TextSegment>,
+    /// The condition in the `<<if>>` after the text, if there is one.
+    pub condition: Option<Expression>,
+    /// The hashtags of the line.
+    pub hashtags: Vec<Hashtag>,
+    /// The range of the whole line.
+    pub range: Range<Position>,
+}
+
+impl Line {
+    /// The ID of the line, i.e. the value of its `#line:` tag, if it has one.
+    pub fn line_id(&self) -> Option<LineId> {
+        self.hashtags
+            .iter()
+            .find(|hashtag| hashtag.text.starts_with("line:"))
+            .map(|hashtag| LineId(hashtag.text.clone()))
+    }
+}
+
+/// A part of the text of a [`Line`] or [`Command`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TextSegment {
+    /// Text as it was written, including escape sequences like `\{` and markup like `[b]`.
+    Text(String),
+    /// An expression in braces, e.g. `{$name}`.
+    Expression(Expression),
+}
+
+/// A group of options following each other.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OptionGroup {
+    /// The options in the order they are offered in.
+    pub options: Vec<ShortcutOption>,
+    /// The range of all options.
+    pub range: Range<Position>,
+}
+
+/// An option, e.g. `-> Sure. #line:abc123`, with the statements indented below it.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ShortcutOption {
+    /// The line offered to the player.
+    pub line: Line,
+    /// The statements run when the player chooses the option.
+    pub statements: Vec<Statement>,
+    /// The range of the option and its statements.
+    pub range: Range<Position>,
+}
+
+/// An `<<if>>` block.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IfStatement {
+    /// The `<<if>>` clause followed by the `<<elseif>>` clauses and the `<<else>>` clause, if there is one.
+    /// Only the `<<else>>` clause has no [`Clause::condition`].
+    pub clauses: Vec<Clause>,
+    /// The range from the `<<if>>` to the `<<endif>>`.
+    pub range: Range<Position>,
+}
+
+/// A clause of an [`IfStatement`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Clause {
+    /// The condition of an `<<if>>` or `<<elseif>>`. [`None`] for an `<<else>>`.
+    pub condition: Option<Expression>,
+    /// The statements run when the clause is chosen.
+    pub statements: Vec<Statement>,
+    /// The range of the clause and its statements.
+    pub range: Range<Position>,
+}
+
+/// A `<<set>>` command, e.g. `<<set $gold += 10>>`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SetStatement {
+    /// The variable being set.
+    pub variable: Variable,
+    /// The operator of a compound assignment like `+=`. [`None`] for plain assignments with `=` or `to`.
+    pub operator: Option<Operator>,
+    /// The expression on the right side.
+    pub value: Expression,
+    /// The range of the whole command.
+    pub range: Range<Position>,
+}
+
+/// A `<<call>>` command, e.g. `<<call wave("Sally")>>`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CallStatement {
+    /// The function being called.
+    pub function_call: FunctionCall,
+    /// The range of the whole command.
+    pub range: Range<Position>,
+}
+
+/// A `<<declare>>` command, e.g. `<<declare $gold = 0 as number>>`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DeclareStatement {
+    /// The variable being declared.
+    pub variable: Variable,
+    /// The default value of the variable.
+    pub default_value: Expression,
+    /// The name of the type after `as`, if there is one.
+    pub type_name: Option<String>,
+    /// The range of the whole command.
+    pub range: Range<Position>,
+}
+
+/// A `<<jump>>` command.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct JumpStatement {
+    /// Where to jump to.
+    pub destination: JumpDestination,
+    /// The range of the whole command.
+    pub range: Range<Position>,
+}
+
+/// The destination of a [`JumpStatement`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JumpDestination {
+    /// The name of a node, e.g. `<<jump Start>>`.
+    Node {
+        /// The name of the node.
+        name: String,
+        /// The range of the name.
+        range: Range<Position>,
+    },
+    /// An expression evaluating to the name of a node, e.g. `<<jump {$next_node}>>`.
+    Expression(Expression),
+}
+
+/// A command that is passed to the game, e.g. `<<wait {$duration}>>`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Command {
+    /// The text between `<<` and `>>`, with the whitespace around it removed.
+    pub text: Vec<TextSegment>,
+    /// The hashtags after the command.
+    pub hashtags: Vec<Hashtag>,
+    /// The range of the whole command.
+    pub range: Range<Position>,
+}
+
+impl Command {
+    /// The first word of the command, e.g. `wait` for `<<wait 2>>`.
+    pub fn name(&self) -> Option<&str> {
+        match self.text.first() {
+            Some(TextSegment::Text(text)) => text.split_whitespace().next(),
+            _ => None,
+        }
+    }
+}
+
+/// Indented statements that do not belong to an option.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Block {
+    /// The indented statements.
+    pub statements: Vec<Statement>,
+    /// The range of the statements.
+    pub range: Range<Position>,
+}
+
+/// A variable, e.g. `$gold`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Variable {
+    /// The name of the variable, including the `$`.
+    pub name: String,
+    /// The range of the name.
+    pub range: Range<Position>,
+}
+
+/// A call of a function in an expression or a `<<call>>` command, e.g. `visited("Start")`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FunctionCall {
+    /// The name of the function.
+    pub name: String,
+    /// The arguments in the order they were passed in.
+    pub arguments: Vec<Expression>,
+    /// The range of the whole call.
+    pub range: Range<Position>,
+}
+
+/// An expression, e.g. `$gold >= 10 and visited("Shop")`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Expression {
+    /// What kind of expression this is.
+    pub kind: ExpressionKind,
+    /// The range of the whole expression.
+    pub range: Range<Position>,
+}
+
+/// The kinds of [`Expression`]s.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ExpressionKind {
+    /// A number literal, e.g. `1.5`.
+    Number(f32),
+    /// A string literal, e.g. `"Sally"`, without its quotes.
+    String(String),
+    /// `true` or `false`.
+    Bool(bool),
+    /// `null`.
+    Null,
+    /// A variable.
+    Variable(Variable),
+    /// A function call.
+    FunctionCall(FunctionCall),
+    /// An expression in parentheses.
+    Parenthesized(Box<Expression>),
+    /// An operator applied to a single operand, i.e. [`Operator::Not`] or [`Operator::UnarySubtract`].
+    Unary {
+        /// The operator.
+        operator: Operator,
+        /// The operand.
+        operand: Box<Expression>,
+    },
+    /// An operator applied to two operands, e.g. [`Operator::Add`].
+    Binary {
+        /// The operator.
+        operator: Operator,
+        /// The operand on the left side of the operator.
+        left: Box<Expression>,
+        /// The operand on the right side of the operator.
+        right: Box<Expression>,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_source(source: &str) -> Dialogue {
+        parse(&File {
+            file_name: "test.yarn".to_owned(),
+            source: source.to_owned(),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn parses_nodes_and_headers() {
+        let dialogue = parse_source("#intro\ntitle: Start\ntags: a b\n---\nHello\n===\n");
+
+        assert_eq!("intro", dialogue.file_tags[0].text);
+        let node = &dialogue.nodes[0];
+        assert_eq!(Some("Start"), node.title());
+        assert_eq!(vec!["a", "b"], node.tags());
+        assert_eq!(1, node.range.start.line);
+        assert_eq!(5, node.range.end.line);
+    }
+
+    #[test]
+    fn parses_expressions_with_precedence() {
+        let dialogue = parse_source("title: Start\n---\n<<set $x to 1 + 2 * 3>>\n===\n");
+
+        let Statement::Set(set) = &dialogue.nodes[0].body[0] else {
+            panic!("expected a set statement");
+        };
+        assert_eq!("$x", set.variable.name);
+        assert_eq!(None, set.operator);
+        let ExpressionKind::Binary {
+            operator, right, ..
+        } = &set.value.kind
+        else {
+            panic!("expected a binary expression");
+        };
+        assert_eq!(Operator::Add, *operator);
+        assert!(matches!(
+            right.kind,
+            ExpressionKind::Binary {
+                operator: Operator::Multiply,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn fails_on_syntax_errors() {
+        let result = parse(&File {
+            file_name: "test.yarn".to_owned(),
+            source: "title: Start\n---\n<<set $x to >>\n===\n".to_owned(),
+        });
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,317 @@
+//! Converts the parse tree of a file without syntax errors into an [`ast`](crate::ast).
+
+use super::*;
+use crate::parser::{ExpressionKind as Alternative, JumpKind, ValueKind};
+use crate::visitors::CodeGenerationVisitor;
+use std::rc::Rc;
+
+pub(crate) fn convert_dialogue(file: &FileParseResult) -> Dialogue {
+    Dialogue {
+        file_name: file.name.clone(),
+        file_tags: file
+            .tree
+            .file_hashtag_all()
+            .iter()
+            .map(|hashtag| Hashtag {
+                text: hashtag.text.as_ref().unwrap().text.clone(),
+                range: hashtag.range(),
+            })
+            .collect(),
+        nodes: file
+            .tree
+            .node_all()
+            .iter()
+            .map(|node| convert_node(node))
+            .collect(),
+    }
+}
+
+fn convert_node(node: &NodeContext) -> Node {
+    let headers = node
+        .header_all()
+        .iter()
+        .map(|header| Header {
+            key: header.header_key.as_ref().unwrap().text.clone(),
+            value: header
+                .header_value
+                .as_ref()
+                .map(|value| value.text.clone())
+                .unwrap_or_default(),
+            range: header.range(),
+        })
+        .collect();
+    let body = node
+        .body()
+        .map(|body| convert_statements(&body.statement_all()))
+        .unwrap_or_default();
+    Node {
+        headers,
+        body,
+        range: node.range(),
+    }
+}
+
+fn convert_statements(statements: &[Rc<StatementContext>]) -> Vec<Statement> {
+    statements
+        .iter()
+        .map(|statement| convert_statement(statement))
+        .collect()
+}
+
+fn convert_statement(ctx: &StatementContext) -> Statement {
+    if let Some(line) = ctx.line_statement() {
+        Statement::Line(convert_line(&line))
+    } else if let Some(options) = ctx.shortcut_option_statement() {
+        Statement::Options(OptionGroup {
+            options: options
+                .shortcut_option_all()
+                .iter()
+                .map(|option| ShortcutOption {
+                    line: convert_line(&option.line_statement().unwrap()),
+                    statements: convert_statements(&option.statement_all()),
+                    range: option.range(),
+                })
+                .collect(),
+            range: options.range(),
+        })
+    } else if let Some(if_statement) = ctx.if_statement() {
+        Statement::If(convert_if_statement(&if_statement))
+    } else if let Some(set) = ctx.set_statement() {
+        let operator = match set.op.as_ref().unwrap().token_type {
+            OPERATOR_MATHS_ADDITION_EQUALS => Some(Operator::Add),
+            OPERATOR_MATHS_SUBTRACTION_EQUALS => Some(Operator::Subtract),
+            OPERATOR_MATHS_MULTIPLICATION_EQUALS => Some(Operator::Multiply),
+            OPERATOR_MATHS_DIVISION_EQUALS => Some(Operator::Divide),
+            OPERATOR_MATHS_MODULUS_EQUALS => Some(Operator::Modulo),
+            _ => None,
+        };
+        Statement::Set(SetStatement {
+            variable: convert_variable(&set.variable().unwrap()),
+            operator,
+            value: convert_expression(&set.expression().unwrap()),
+            range: set.range(),
+        })
+    } else if let Some(call) = ctx.call_statement() {
+        Statement::Call(CallStatement {
+            function_call: convert_function_call(&call.function_call().unwrap()),
+            range: call.range(),
+        })
+    } else if let Some(declare) = ctx.declare_statement() {
+        Statement::Declare(DeclareStatement {
+            variable: convert_variable(&declare.variable().unwrap()),
+            default_value: convert_value(&declare.value().unwrap()),
+            type_name: declare
+                .declaration_type
+                .as_ref()
+                .map(|type_name| type_name.text.clone()),
+            range: declare.range(),
+        })
+    } else if let Some(jump) = ctx.jump_statement() {
+        let destination = match jump.kind {
+            JumpKind::JumpToNodeName => {
+                let destination = jump.destination.as_ref().unwrap();
+                JumpDestination::Node {
+                    name: destination.text.clone(),
+                    range: destination.range(),
+                }
+            }
+            JumpKind::JumpToExpression => {
+                JumpDestination::Expression(convert_expression(&jump.expression().unwrap()))
+            }
+            JumpKind::Error => unreachable!("{ONLY_VALID_TREES}"),
+        };
+        Statement::Jump(JumpStatement {
+            destination,
+            range: jump.range(),
+        })
+    } else if let Some(command) = ctx.command_statement() {
+        Statement::Command(Command {
+            text: convert_formatted_text(command.command_formatted_text().unwrap().as_ref()),
+            hashtags: convert_hashtags(&command.hashtag_all()),
+            range: command.range(),
+        })
+    } else {
+        // INDENT statement* DEDENT
+        Statement::Block(Block {
+            statements: convert_statements(&ctx.statement_all()),
+            range: ctx.range(),
+        })
+    }
+}
+
+fn convert_line(ctx: &LineStatementContext) -> Line {
+    Line {
+        text: convert_formatted_text(ctx.line_formatted_text().unwrap().as_ref()),
+        condition: ctx
+            .line_condition()
+            .and_then(|condition| condition.expression())
+            .map(|expression| convert_expression(&expression)),
+        hashtags: convert_hashtags(&ctx.hashtag_all()),
+        range: ctx.range(),
+    }
+}
+
+fn convert_if_statement(ctx: &IfStatementContext) -> IfStatement {
+    let if_clause = ctx.if_clause().unwrap();
+    let clauses = std::iter::once(Clause {
+        condition: Some(convert_expression(&if_clause.expression().unwrap())),
+        statements: convert_statements(&if_clause.statement_all()),
+        range: if_clause.range(),
+    })
+    .chain(ctx.else_if_clause_all().iter().map(|clause| Clause {
+        condition: Some(convert_expression(&clause.expression().unwrap())),
+        statements: convert_statements(&clause.statement_all()),
+        range: clause.range(),
+    }))
+    .chain(ctx.else_clause().map(|clause| Clause {
+        condition: None,
+        statements: convert_statements(&clause.statement_all()),
+        range: clause.range(),
+    }))
+    .collect();
+    IfStatement {
+        clauses,
+        range: ctx.range(),
+    }
+}
+
+fn convert_hashtags(hashtags: &[Rc<HashtagContext>]) -> Vec<Hashtag> {
+    hashtags
+        .iter()
+        .map(|hashtag| Hashtag {
+            text: hashtag.text.as_ref().unwrap().text.clone(),
+            range: hashtag.range(),
+        })
+        .collect()
+}
+
+/// The children of formatted text are text, and expressions surrounded by the terminals `{` and `}`.
+fn convert_formatted_text(ctx: &impl ParserRuleContext) -> Vec<TextSegment> {
+    let children = ctx.get_children();
+    let expressions: Vec<_> = children
+        .iter()
+        .map(|child| match child {
+            ParseTree::Expression(expression) => Some(expression),
+            _ => None,
+        })
+        .collect();
+    let mut segments = Vec::new();
+    for (index, child) in children.iter().enumerate() {
+        if let Some(expression) = &expressions[index] {
+            segments.push(TextSegment::Expression(convert_expression(expression)));
+            continue;
+        }
+        let is_brace = expressions.get(index + 1).is_some_and(Option::is_some)
+            || (index > 0 && expressions[index - 1].is_some());
+        if is_brace {
+            continue;
+        }
+        match segments.last_mut() {
+            Some(TextSegment::Text(text)) => text.push_str(&child.get_text()),
+            _ => segments.push(TextSegment::Text(child.get_text())),
+        }
+    }
+    if let Some(TextSegment::Text(text)) = segments.first_mut() {
+        *text = text.trim_start().to_owned();
+    }
+    if let Some(TextSegment::Text(text)) = segments.last_mut() {
+        *text = text.trim_end().to_owned();
+    }
+    segments.retain(|segment| !matches!(segment, TextSegment::Text(text) if text.is_empty()));
+    segments
+}
+
+fn convert_expression(ctx: &ExpressionContext) -> Expression {
+    let kind = match ctx.kind {
+        Alternative::Parens => {
+            ExpressionKind::Parenthesized(Box::new(convert_expression(&ctx.expression(0).unwrap())))
+        }
+        Alternative::MultDivMod
+        | Alternative::AddSub
+        | Alternative::Comparison
+        | Alternative::Equality
+        | Alternative::AndOrXor => binary(
+            ctx.op.as_ref().unwrap().token_type,
+            &ctx.expression(0).unwrap(),
+            &ctx.expression(1).unwrap(),
+        ),
+        Alternative::Negative => ExpressionKind::Unary {
+            operator: Operator::UnarySubtract,
+            operand: Box::new(convert_expression(&ctx.expression(0).unwrap())),
+        },
+        Alternative::Not => ExpressionKind::Unary {
+            operator: Operator::Not,
+            operand: Box::new(convert_expression(&ctx.expression(0).unwrap())),
+        },
+        Alternative::Value => {
+            return convert_value(&ctx.value().unwrap());
+        }
+        Alternative::Error => unreachable!("{ONLY_VALID_TREES}"),
+    };
+    Expression {
+        kind,
+        range: ctx.range(),
+    }
+}
+
+fn binary(
+    operator_token_type: isize,
+    left: &ExpressionContext,
+    right: &ExpressionContext,
+) -> ExpressionKind {
+    let operator = CodeGenerationVisitor::token_to_operator(operator_token_type)
+        .expect("Internal error: unknown operator. This is a bug. Please report it at https://github.com/YarnSpinnerTool/YarnSpinner-Rust/issues/new");
+    ExpressionKind::Binary {
+        operator,
+        left: Box::new(convert_expression(left)),
+        right: Box::new(convert_expression(right)),
+    }
+}
+
+fn convert_value(ctx: &ValueContext) -> Expression {
+    let kind = match ctx.kind {
+        ValueKind::Number => {
+            ExpressionKind::Number(ctx.get_token(NUMBER, 0).unwrap().text.parse().unwrap())
+        }
+        ValueKind::True => ExpressionKind::Bool(true),
+        ValueKind::False => ExpressionKind::Bool(false),
+        ValueKind::Null => ExpressionKind::Null,
+        ValueKind::String => ExpressionKind::String(
+            ctx.get_token(STRING, 0)
+                .unwrap()
+                .text
+                .trim_matches('"')
+                .to_owned(),
+        ),
+        ValueKind::Var => ExpressionKind::Variable(convert_variable(&ctx.variable().unwrap())),
+        ValueKind::Func => {
+            ExpressionKind::FunctionCall(convert_function_call(&ctx.function_call().unwrap()))
+        }
+        ValueKind::Error => unreachable!("{ONLY_VALID_TREES}"),
+    };
+    Expression {
+        kind,
+        range: ctx.range(),
+    }
+}
+
+fn convert_variable(ctx: &VariableContext) -> Variable {
+    Variable {
+        name: ctx.var_id().unwrap().text.clone(),
+        range: ctx.range(),
+    }
+}
+
+fn convert_function_call(ctx: &FunctionCallContext) -> FunctionCall {
+    FunctionCall {
+        name: ctx.func_id().unwrap().text.clone(),
+        arguments: ctx
+            .expression_all()
+            .iter()
+            .map(|argument| convert_expression(argument))
+            .collect(),
+        range: ctx.range(),
+    }
+}
+
+const ONLY_VALID_TREES: &str = "Internal error: tried to convert a parse tree with syntax errors. This is a bug. Please report it at https://github.com/YarnSpinnerTool/YarnSpinner-Rust/issues/new";
@@ -0,0 +1,240 @@
+//! The [`Visitor`] trait and the functions that walk the children of each element of the syntax tree.
+
+use super::*;
+
+/// Walks a syntax tree created by [`parse`].
+///
+/// Every method has a default implementation that visits the children of the element through the matching
+/// `walk_*` function, so implementors only override the methods for the elements they care about.
+/// To still visit the children of an element in an overridden method, call its `walk_*` function.
+///
+/// ## Example
+///
+/// ```rust
+/// use yarnspinner_compiler::ast::{self, Variable, Visitor};
+/// use yarnspinner_compiler::prelude::File;
+///
+/// #[derive(Default)]
+/// struct VariableCollector(Vec<String>);
+///
+/// impl Visitor for VariableCollector {
+///     fn visit_variable(&mut self, variable: &Variable) {
+///         self.0.push(variable.name.clone());
+///     }
+/// }
+///
+/// let file = File {
+///     file_name: "example.yarn".to_owned(),
+///     source: "title: Start\n---\n<<set $gold to $gold + 1>>\n===\n".to_owned(),
+/// };
+/// let dialogue = ast::parse(&file).unwrap();
+/// let mut collector = VariableCollector::default();
+/// collector.visit_dialogue(&dialogue);
+/// assert_eq!(vec!["$gold", "$gold"], collector.0);
+/// ```
+pub trait Visitor {
+    /// Visits a whole file.
+    fn visit_dialogue(&mut self, dialogue: &Dialogue) {
+        walk_dialogue(self, dialogue);
+    }
+
+    /// Visits a node.
+    fn visit_node(&mut self, node: &Node) {
+        walk_node(self, node);
+    }
+
+    /// Visits a header of a node.
+    fn visit_header(&mut self, _header: &Header) {}
+
+    /// Visits a hashtag of the file, a line or a command.
+    fn visit_hashtag(&mut self, _hashtag: &Hashtag) {}
+
+    /// Visits any statement. The default implementation calls the method for the specific kind of statement.
+    fn visit_statement(&mut self, statement: &Statement) {
+        walk_statement(self, statement);
+    }
+
+    /// Visits a line, including the lines of options.
+    fn visit_line(&mut self, line: &Line) {
+        walk_line(self, line);
+    }
+
+    /// Visits a single option of an [`OptionGroup`].
+    fn visit_option(&mut self, option: &ShortcutOption) {
+        walk_option(self, option);
+    }
+
+    /// Visits an `<<if>>` block.
+    fn visit_if(&mut self, if_statement: &IfStatement) {
+        walk_if(self, if_statement);
+    }
+
+    /// Visits a `<<set>>` command.
+    fn visit_set(&mut self, set: &SetStatement) {
+        walk_set(self, set);
+    }
+
+    /// Visits a `<<call>>` command.
+    fn visit_call(&mut self, call: &CallStatement) {
+        self.visit_function_call(&call.function_call);
+    }
+
+    /// Visits a `<<declare>>` command.
+    fn visit_declare(&mut self, declare: &DeclareStatement) {
+        walk_declare(self, declare);
+    }
+
+    /// Visits a `<<jump>>` command.
+    fn visit_jump(&mut self, jump: &JumpStatement) {
+        walk_jump(self, jump);
+    }
+
+    /// Visits a command that is passed to the game.
+    fn visit_command(&mut self, command: &Command) {
+        walk_command(self, command);
+    }
+
+    /// Visits any expression, including the ones nested in other expressions.
+    fn visit_expression(&mut self, expression: &Expression) {
+        walk_expression(self, expression);
+    }
+
+    /// Visits a variable, both in expressions and as the target of `<<set>>` and `<<declare>>`.
+    fn visit_variable(&mut self, _variable: &Variable) {}
+
+    /// Visits a function call, both in expressions and in `<<call>>`.
+    fn visit_function_call(&mut self, function_call: &FunctionCall) {
+        walk_function_call(self, function_call);
+    }
+}
+
+/// Visits the file tags and nodes of `dialogue`.
+pub fn walk_dialogue<V: Visitor + ?Sized>(visitor: &mut V, dialogue: &Dialogue) {
+    for hashtag in &dialogue.file_tags {
+        visitor.visit_hashtag(hashtag);
+    }
+    for node in &dialogue.nodes {
+        visitor.visit_node(node);
+    }
+}
+
+/// Visits the headers and statements of `node`.
+pub fn walk_node<V: Visitor + ?Sized>(visitor: &mut V, node: &Node) {
+    for header in &node.headers {
+        visitor.visit_header(header);
+    }
+    walk_statements(visitor, &node.body);
+}
+
+/// Calls the [`Visitor`] method for the kind of `statement`. Option groups and blocks visit the options or statements in them.
+pub fn walk_statement<V: Visitor + ?Sized>(visitor: &mut V, statement: &Statement) {
+    match statement {
+        Statement::Line(line) => visitor.visit_line(line),
+        Statement::Options(options) => {
+            for option in &options.options {
+                visitor.visit_option(option);
+            }
+        }
+        Statement::If(if_statement) => visitor.visit_if(if_statement),
+        Statement::Set(set) => visitor.visit_set(set),
+        Statement::Call(call) => visitor.visit_call(call),
+        Statement::Declare(declare) => visitor.visit_declare(declare),
+        Statement::Jump(jump) => visitor.visit_jump(jump),
+        Statement::Command(command) => visitor.visit_command(command),
+        Statement::Block(block) => walk_statements(visitor, &block.statements),
+    }
+}
+
+/// Visits the expressions in the text, the condition and the hashtags of `line`.
+pub fn walk_line<V: Visitor + ?Sized>(visitor: &mut V, line: &Line) {
+    walk_text(visitor, &line.text);
+    if let Some(condition) = &line.condition {
+        visitor.visit_expression(condition);
+    }
+    for hashtag in &line.hashtags {
+        visitor.visit_hashtag(hashtag);
+    }
+}
+
+/// Visits the line and statements of `option`.
+pub fn walk_option<V: Visitor + ?Sized>(visitor: &mut V, option: &ShortcutOption) {
+    visitor.visit_line(&option.line);
+    walk_statements(visitor, &option.statements);
+}
+
+/// Visits the conditions and statements of all clauses of `if_statement`.
+pub fn walk_if<V: Visitor + ?Sized>(visitor: &mut V, if_statement: &IfStatement) {
+    for clause in &if_statement.clauses {
+        if let Some(condition) = &clause.condition {
+            visitor.visit_expression(condition);
+        }
+        walk_statements(visitor, &clause.statements);
+    }
+}
+
+/// Visits the variable and the value of `set`.
+pub fn walk_set<V: Visitor + ?Sized>(visitor: &mut V, set: &SetStatement) {
+    visitor.visit_variable(&set.variable);
+    visitor.visit_expression(&set.value);
+}
+
+/// Visits the variable and the default value of `declare`.
+pub fn walk_declare<V: Visitor + ?Sized>(visitor: &mut V, declare: &DeclareStatement) {
+    visitor.visit_variable(&declare.variable);
+    visitor.visit_expression(&declare.default_value);
+}
+
+/// Visits the expression of `jump`, if it jumps to one.
+pub fn walk_jump<V: Visitor + ?Sized>(visitor: &mut V, jump: &JumpStatement) {
+    if let JumpDestination::Expression(expression) = &jump.destination {
+        visitor.visit_expression(expression);
+    }
+}
+
+/// Visits the expressions in the text and the hashtags of `command`.
+pub fn walk_command<V: Visitor + ?Sized>(visitor: &mut V, command: &Command) {
+    walk_text(visitor, &command.text);
+    for hashtag in &command.hashtags {
+        visitor.visit_hashtag(hashtag);
+    }
+}
+
+/// Visits the operands, variable or function call of `expression`.
+pub fn walk_expression<V: Visitor + ?Sized>(visitor: &mut V, expression: &Expression) {
+    match &expression.kind {
+        ExpressionKind::Number(_)
+        | ExpressionKind::String(_)
+        | ExpressionKind::Bool(_)
+        | ExpressionKind::Null => {}
+        ExpressionKind::Variable(variable) => visitor.visit_variable(variable),
+        ExpressionKind::FunctionCall(function_call) => visitor.visit_function_call(function_call),
+        ExpressionKind::Parenthesized(operand) | ExpressionKind::Unary { operand, .. } => {
+            visitor.visit_expression(operand);
+        }
+        ExpressionKind::Binary { left, right, .. } => {
+            visitor.visit_expression(left);
+            visitor.visit_expression(right);
+        }
+    }
+}
+
+/// Visits the arguments of `function_call`.
+pub fn walk_function_call<V: Visitor + ?Sized>(visitor: &mut V, function_call: &FunctionCall) {
+    for argument in &function_call.arguments {
+        visitor.visit_expression(argument);
+    }
+}
+
+fn walk_statements<V: Visitor + ?Sized>(visitor: &mut V, statements: &[Statement]) {
+    for statement in statements {
+        visitor.visit_statement(statement);
+    }
+}
+
+fn walk_text<V: Visitor + ?Sized>(visitor: &mut V, text: &[TextSegment]) {
+    for segment in text {
+        if let TextSegment::Expression(expression) = segment {
+            visitor.visit_expression(expression);
+        }
+    }
+}
@@ -2,21 +2,26 @@ mod add_initial_value_registrations;
 mod add_tracking_declarations;
 mod check_types;
 mod clean_up_diagnostics;
+mod collect_node_infos;
 mod create_declarations_for_tracking_nodes;
 mod early_breaks;
 mod find_tracking_nodes;
+mod finish_partial_compilation;
 mod generate_code;
 mod get_declarations;
+mod optimize_code;
 mod parse_files;
 mod register_initial_variables;
 mod register_strings;
 mod resolve_deferred_type_diagnostic;
+mod validate_jump_targets;
 mod validate_unique_node_names;
 
 pub(crate) use self::{
     add_initial_value_registrations::*, add_tracking_declarations::*, check_types::*,
-    clean_up_diagnostics::*, create_declarations_for_tracking_nodes::*, early_breaks::*,
-    find_tracking_nodes::*, generate_code::*, get_declarations::*, parse_files::*,
-    register_initial_variables::*, register_strings::*, resolve_deferred_type_diagnostic::*,
+    clean_up_diagnostics::*, collect_node_infos::*, create_declarations_for_tracking_nodes::*,
+    early_breaks::*, find_tracking_nodes::*, finish_partial_compilation::*, generate_code::*,
+    get_declarations::*, optimize_code::*, parse_files::*, register_initial_variables::*,
+    register_strings::*, resolve_deferred_type_diagnostic::*, validate_jump_targets::*,
     validate_unique_node_names::*,
 };
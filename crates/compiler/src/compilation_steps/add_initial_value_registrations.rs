@@ -19,11 +19,14 @@ pub(crate) fn add_initial_value_registrations(
 
     for declaration in declarations {
         let Some(default_value) = declaration.default_value.clone() else {
-            state.diagnostics.push(Diagnostic::from_message(format!(
+            state.diagnostics.push(Diagnostic::new(
+                DiagnosticCode::NullValue,
+                format!(
                 "Variable declaration {} (type {}) has a null default value. This is not allowed.",
                 declaration.name,
                 declaration.r#type.format()
-            )));
+            ),
+            ));
             continue;
         };
         if let Some(ref mut program) = compilation.program {
@@ -5,9 +5,11 @@ use yarnspinner_core::types::Type;
 pub(crate) fn add_tracking_declarations(
     mut state: CompilationIntermediate,
 ) -> CompilationIntermediate {
-    let tracking_declarations: Vec<_> = state
-        .tracking_nodes
-        .iter()
+    let mut tracking_nodes: Vec<_> = state.tracking_nodes.iter().collect();
+    // The set has no stable order, but the declarations should.
+    tracking_nodes.sort();
+    let tracking_declarations: Vec<_> = tracking_nodes
+        .into_iter()
         .map(|node| {
             let name = Library::generate_unique_visited_variable_for_node(node);
             Declaration::new(name, Type::Number)
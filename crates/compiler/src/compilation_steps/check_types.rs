@@ -1,21 +1,55 @@
+use crate::compiler::session::TypesOutput;
 use crate::prelude::*;
 use crate::visitors::TypeCheckVisitor;
-use antlr_rust::tree::ParseTreeVisitorCompat;
 
 pub(crate) fn check_types(mut state: CompilationIntermediate) -> CompilationIntermediate {
-    for (file, known_types) in &mut state.parsed_files {
-        let mut visitor =
-            TypeCheckVisitor::new(state.known_variable_declarations.clone(), file.clone());
-        visitor.visit(file.tree.as_ref());
+    // The types of a file depend on the declarations of all files and the ones inferred in the files before it.
+    let mut previous_declarations_unchanged = state.declarations_unchanged;
+    for index in 0..state.job.files.len() {
+        let output =
+            match state
+                .incremental
+                .reusable(index, previous_declarations_unchanged, |file| {
+                    file.types.as_ref()
+                }) {
+                Some(output) => output,
+                None => {
+                    let known_variable_declarations = state.known_variable_declarations.clone();
+                    state
+                        .with_parsed_file(index, move |file| {
+                            let mut visitor =
+                                TypeCheckVisitor::new(known_variable_declarations, file.clone());
+                            visitor.visit(file.tree.as_ref());
+                            TypesOutput {
+                                new_declarations: visitor.new_declarations,
+                                deferred_types: visitor.deferred_types,
+                                known_types: visitor.known_types,
+                                diagnostics: visitor.diagnostics,
+                            }
+                        })
+                        .wait()
+                }
+            };
+        previous_declarations_unchanged &= state
+            .incremental
+            .previous(index)
+            .and_then(|file| file.types.as_ref())
+            .is_some_and(|previous| previous.new_declarations == output.new_declarations);
+
         state
             .known_variable_declarations
-            .extend(visitor.new_declarations.clone());
+            .extend_from_slice(&output.new_declarations);
         state
             .derived_variable_declarations
-            .extend(visitor.new_declarations);
-        state.diagnostics.extend(visitor.diagnostics);
-        state.potential_issues.extend(visitor.deferred_types);
-        known_types.extend(visitor.known_types);
+            .extend_from_slice(&output.new_declarations);
+        state.diagnostics.extend_from_slice(&output.diagnostics);
+        state
+            .potential_issues
+            .extend_from_slice(&output.deferred_types);
+        state.known_types[index].extend(output.known_types.clone());
+        state
+            .incremental
+            .record(index, |file| &mut file.types, output);
     }
     state
 }
@@ -24,10 +24,18 @@ pub(crate) fn clean_up_diagnostics(mut state: CompilationIntermediate) -> Compil
         }
     }
     state.diagnostics = ordered_unique_diagnostics;
-    if state.diagnostics.has_errors() {
+    let is_partial = state.job.partial_compilation && matches!(state.result, Some(Ok(_)));
+    if state.diagnostics.has_errors() && !is_partial {
         state.result = Some(Err(CompilerError(state.diagnostics.clone())));
     } else if let Some(Ok(compilation)) = state.result.as_mut() {
-        compilation.warnings.clone_from(&state.diagnostics);
+        // A partial compilation keeps its errors apart from the warnings.
+        let (errors, warnings) = state
+            .diagnostics
+            .iter()
+            .cloned()
+            .partition(|diagnostic| diagnostic.severity == DiagnosticSeverity::Error);
+        compilation.warnings = warnings;
+        compilation.errors = errors;
     }
     state
 }
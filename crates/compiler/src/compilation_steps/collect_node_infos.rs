@@ -0,0 +1,113 @@
+use crate::compilation_steps::get_node_title;
+use crate::compiler::parsed_files::Pending;
+use crate::prelude::*;
+use crate::visitors::JumpTargetVisitor;
+use std::collections::HashMap;
+
+/// Collects the [`NodeInfo`] of every node for [`Compilation::nodes`].
+pub(crate) fn collect_node_infos(mut state: CompilationIntermediate) -> CompilationIntermediate {
+    let pending: Vec<_> = (0..state.job.files.len())
+        .map(|index| {
+            match state
+                .incremental
+                .reusable(index, true, |file| file.node_infos.as_ref())
+            {
+                Some(node_infos) => Pending::Ready(node_infos),
+                None => state.with_parsed_file(index, get_node_infos),
+            }
+        })
+        .collect();
+
+    // The line IDs are taken from the string table, as the implicit ones depend on the files before.
+    let mut line_ids_by_node: HashMap<(String, String), Vec<(usize, LineId)>> = HashMap::new();
+    for (line_id, string_info) in state.string_table.iter() {
+        line_ids_by_node
+            .entry((string_info.file_name.clone(), string_info.node_name.clone()))
+            .or_default()
+            .push((string_info.line_number, line_id.clone()));
+    }
+    let mut nodes = Vec::new();
+    for (index, pending) in pending.into_iter().enumerate() {
+        let node_infos = pending.wait();
+        for node_info in &node_infos {
+            let mut line_ids = line_ids_by_node
+                .remove(&(node_info.file_name.clone(), node_info.title.clone()))
+                .unwrap_or_default();
+            line_ids.sort_by(|(a_line, a_id), (b_line, b_id)| {
+                (a_line, &a_id.0).cmp(&(b_line, &b_id.0))
+            });
+            nodes.push(NodeInfo {
+                line_ids: line_ids.into_iter().map(|(_, line_id)| line_id).collect(),
+                ..node_info.clone()
+            });
+        }
+        state
+            .incremental
+            .record(index, |file| &mut file.node_infos, node_infos);
+    }
+    state.nodes = nodes;
+    state
+}
+
+/// The [`NodeInfo`] of every node in `file` that has a title, without its line IDs.
+fn get_node_infos(file: &FileParseResult) -> Vec<NodeInfo> {
+    file.tree
+        .node_all()
+        .iter()
+        .filter_map(|node| {
+            let title = get_node_title(node)?;
+            let headers: Vec<_> = node
+                .header_all()
+                .iter()
+                .filter_map(|header| {
+                    Some(Header {
+                        key: header.header_key.as_ref()?.text.clone(),
+                        value: header
+                            .header_value
+                            .as_ref()
+                            .map(|value| value.text.clone())
+                            .unwrap_or_default(),
+                    })
+                })
+                .collect();
+            // Split the same way as the tags of the compiled node
+            let tags = headers
+                .iter()
+                .filter(|header| header.key == "tags")
+                .flat_map(|header| header.value.split(' ').map(|tag| tag.to_owned()))
+                .collect();
+
+            let range = node.range();
+            let body_start = node
+                .get_token(BODY_START, 0)
+                .map_or(range.end, |token| Position {
+                    line: token.line,
+                    character: 0,
+                });
+            let body_end = node
+                .get_token(BODY_END, 0)
+                .map_or(range.end, |token| token.range().start);
+
+            let mut jump_target_visitor = JumpTargetVisitor::new(file.clone());
+            if let Some(body) = node.body() {
+                jump_target_visitor.visit(body.as_ref());
+            }
+            let jumps = jump_target_visitor
+                .jump_targets
+                .into_iter()
+                .map(|jump_target| jump_target.node_name)
+                .collect();
+
+            Some(NodeInfo {
+                title,
+                file_name: file.name.clone(),
+                range,
+                body_range: body_start..body_end.max(body_start),
+                headers,
+                tags,
+                jumps,
+                line_ids: Vec::new(),
+            })
+        })
+        .collect()
+}
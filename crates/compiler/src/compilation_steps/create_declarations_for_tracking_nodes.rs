@@ -5,7 +5,10 @@ use yarnspinner_core::types::Type;
 pub(crate) fn create_declarations_for_tracking_nodes(
     mut state: CompilationIntermediate,
 ) -> CompilationIntermediate {
-    let tracking_declarations = state.tracking_nodes.iter().map(|node| {
+    // Sorted so that the order of the declarations does not depend on the order of the set.
+    let mut tracking_nodes: Vec<_> = state.tracking_nodes.iter().collect();
+    tracking_nodes.sort();
+    let tracking_declarations = tracking_nodes.into_iter().map(|node| {
         let name = Library::generate_unique_visited_variable_for_node(node);
         Declaration::new(name, Type::Number)
             .with_default_value(0.0)
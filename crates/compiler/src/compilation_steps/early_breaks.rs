@@ -8,6 +8,7 @@ pub(crate) fn break_on_job_with_only_strings(
             string_table: state.string_table.clone().into(),
             contains_implicit_string_tags: state.string_table.contains_implicit_string_tags(),
             warnings: state.diagnostics.clone(),
+            nodes: state.nodes.clone(),
             ..Default::default()
         }));
         state.early_break = true;
@@ -23,6 +24,7 @@ pub(crate) fn break_on_job_with_only_declarations(
             declarations: state.derived_variable_declarations.clone(),
             warnings: state.diagnostics.clone(),
             file_tags: state.file_tags.clone(),
+            nodes: state.nodes.clone(),
             ..Default::default()
         }));
         state.early_break = true;
@@ -1,6 +1,7 @@
+use crate::compiler::parsed_files::Pending;
+use crate::compiler::session::TrackingOutput;
 use crate::prelude::*;
 use crate::visitors::NodeTrackingVisitor;
-use antlr_rust::tree::ParseTreeVisitorCompat;
 use std::collections::HashSet;
 
 pub(crate) fn find_tracking_nodes(mut state: CompilationIntermediate) -> CompilationIntermediate {
@@ -9,11 +10,31 @@ pub(crate) fn find_tracking_nodes(mut state: CompilationIntermediate) -> Compila
     // so that any tracking variables are included in the compiled declarations
     let mut tracking_nodes = HashSet::new();
     let mut ignore_nodes = HashSet::new();
-    for (file, _) in &state.parsed_files {
-        let mut visitor = NodeTrackingVisitor::new();
-        visitor.visit(file.tree.as_ref());
-        tracking_nodes.extend(visitor.tracking_nodes);
-        ignore_nodes.extend(visitor.ignoring_nodes);
+    let pending: Vec<_> = (0..state.job.files.len())
+        .map(|index| {
+            match state
+                .incremental
+                .reusable(index, true, |file| file.tracking.as_ref())
+            {
+                Some(output) => Pending::Ready(output),
+                None => state.with_parsed_file(index, |file| {
+                    let mut visitor = NodeTrackingVisitor::new();
+                    visitor.visit(file.tree.as_ref());
+                    TrackingOutput {
+                        tracking_nodes: visitor.tracking_nodes,
+                        ignoring_nodes: visitor.ignoring_nodes,
+                    }
+                }),
+            }
+        })
+        .collect();
+    for (index, pending) in pending.into_iter().enumerate() {
+        let output = pending.wait();
+        tracking_nodes.extend(output.tracking_nodes.iter().cloned());
+        ignore_nodes.extend(output.ignoring_nodes.iter().cloned());
+        state
+            .incremental
+            .record(index, |file| &mut file.tracking, output);
     }
     state.tracking_nodes = tracking_nodes.difference(&ignore_nodes).cloned().collect();
     state
@@ -0,0 +1,89 @@
+use crate::prelude::*;
+use std::collections::HashSet;
+
+/// Removes the lines, declarations and node infos of the nodes containing errors
+/// from a partial compilation, see [`Compiler::with_partial_compilation`].
+/// Runs after [`clean_up_diagnostics`], which moves the errors to [`Compilation::errors`].
+pub(crate) fn finish_partial_compilation(
+    mut state: CompilationIntermediate,
+) -> CompilationIntermediate {
+    let is_partial =
+        matches!(&state.result, Some(Ok(compilation)) if !compilation.errors.is_empty());
+    if !is_partial {
+        return state;
+    }
+    let broken_nodes = find_broken_nodes(&mut state);
+    let Some(Ok(compilation)) = state.result.as_mut() else {
+        return state;
+    };
+    compilation
+        .string_table
+        .retain(|_, string_info| !broken_nodes.contains(&string_info.node_name));
+    compilation.declarations.retain(|declaration| {
+        declaration
+            .source_node_name
+            .as_ref()
+            .is_none_or(|node_name| !broken_nodes.contains(node_name))
+    });
+    compilation
+        .nodes
+        .retain(|node| !broken_nodes.contains(&node.title));
+    compilation.contains_implicit_string_tags = compilation
+        .string_table
+        .values()
+        .any(|string_info| string_info.is_implicit_tag);
+    state
+}
+
+/// The titles of the nodes that contain an error, i.e. whose lines overlap with the range of an error diagnostic.
+pub(crate) fn find_broken_nodes(state: &mut CompilationIntermediate) -> HashSet<String> {
+    let job = state.job;
+    let pending: Vec<_> = (0..job.files.len())
+        .filter_map(|index| {
+            let file_name = &job.files[index].file_name;
+            let error_lines: Vec<_> = state
+                .diagnostics
+                .iter()
+                .filter(|diagnostic| {
+                    diagnostic.severity == DiagnosticSeverity::Error
+                        && diagnostic.file_name.as_ref() == Some(file_name)
+                })
+                .filter_map(|diagnostic| diagnostic.range.as_ref())
+                .map(|range| (range.start.line, range.end.line))
+                .collect();
+            (!error_lines.is_empty()).then(|| {
+                state.with_parsed_file(index, move |file| {
+                    file.tree
+                        .node_all()
+                        .iter()
+                        .filter(|node| {
+                            let range = node.range();
+                            error_lines.iter().any(|&(start, end)| {
+                                start <= range.end.line && end >= range.start.line
+                            })
+                        })
+                        .filter_map(|node| get_node_title(node))
+                        .collect::<Vec<_>>()
+                })
+            })
+        })
+        .collect();
+    pending
+        .into_iter()
+        .flat_map(|pending| pending.wait())
+        .collect()
+}
+
+/// The value of the `title` header of a node, if it has one.
+pub(crate) fn get_node_title(node: &NodeContext) -> Option<String> {
+    node.header_all()
+        .iter()
+        .find(|header| {
+            header
+                .header_key
+                .as_ref()
+                .is_some_and(|key| key.text == "title")
+        })
+        .and_then(|header| header.header_value.as_ref())
+        .map(|value| value.text.clone())
+}
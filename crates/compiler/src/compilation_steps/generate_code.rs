@@ -1,5 +1,6 @@
+use crate::compilation_steps::{find_broken_nodes, get_node_title};
+use crate::compiler::session::CodeOutput;
 use crate::listeners::{CompilerListener, DiagnosticVec};
-use crate::prelude::generated::yarnspinnerparser::YarnSpinnerParserTreeWalker;
 use crate::prelude::*;
 use crate::visitors::KnownTypes;
 use crate::Result;
@@ -7,10 +8,11 @@ use std::collections::{HashMap, HashSet};
 
 pub(crate) fn generate_code(mut state: CompilationIntermediate) -> CompilationIntermediate {
     let has_errors = state.diagnostics.has_errors();
-    let results: Vec<_> = if has_errors {
-        // We have errors, so we can't safely generate code.
-        vec![]
-    } else {
+    let is_partial = has_errors && state.job.partial_compilation;
+    let mut results = Vec::new();
+    if is_partial {
+        results = generate_partial_code(&mut state);
+    } else if !has_errors {
         // No errors! Go ahead and generate the code for all parsed files.
         let template = Compilation {
             string_table: state.string_table.0.clone(),
@@ -18,21 +20,80 @@ pub(crate) fn generate_code(mut state: CompilationIntermediate) -> CompilationIn
             file_tags: state.file_tags.clone(),
             ..Default::default()
         };
+        // The code of a file depends on the nodes tracked by the files before it.
+        let mut previous_tracking_nodes_unchanged =
+            *state.incremental.previous_tracking_nodes() == state.tracking_nodes;
         state
-            .parsed_files
-            .iter()
-            .map(|(file, known_types)| {
-                generate_code_for_file(
-                    &mut state.tracking_nodes,
-                    known_types.clone(),
-                    template.clone(),
-                    file,
-                )
+            .incremental
+            .next
+            .tracking_nodes
+            .clone_from(&state.tracking_nodes);
+        // Generate the code of all files in parallel, assuming that no file starts tracking nodes the others do not know about.
+        // This is almost always the case. If not, the code of the files after the first one that does is generated again.
+        let start_tracking_nodes = state.tracking_nodes.clone();
+        let speculative_outputs: Vec<_> = (0..state.job.files.len())
+            .map(|index| {
+                let reusable = state.incremental.is_reusable(
+                    index,
+                    previous_tracking_nodes_unchanged,
+                    |file| file.code.is_some(),
+                );
+                (!reusable).then(|| {
+                    let tracking_nodes = start_tracking_nodes.clone();
+                    let known_types = state.known_types[index].clone();
+                    state.with_parsed_file(index, move |file| {
+                        generate_code_for_file(&tracking_nodes, known_types, file, None)
+                    })
+                })
             })
-            .collect()
-    };
+            .collect();
+        let mut tracking_nodes_extended = false;
+        for (index, speculative_output) in speculative_outputs.into_iter().enumerate() {
+            let output =
+                match state
+                    .incremental
+                    .reusable(index, previous_tracking_nodes_unchanged, |file| {
+                        file.code.as_ref()
+                    }) {
+                    Some(output) => output,
+                    None => match speculative_output {
+                        Some(output) if !tracking_nodes_extended => output.wait(),
+                        _ => {
+                            let tracking_nodes = state.tracking_nodes.clone();
+                            let known_types = state.known_types[index].clone();
+                            state
+                                .with_parsed_file(index, move |file| {
+                                    generate_code_for_file(&tracking_nodes, known_types, file, None)
+                                })
+                                .wait()
+                        }
+                    },
+                };
+            tracking_nodes_extended |= !output.new_tracking_nodes.is_empty();
+            previous_tracking_nodes_unchanged &= state
+                .incremental
+                .previous(index)
+                .and_then(|file| file.code.as_ref())
+                .is_some_and(|previous| previous.new_tracking_nodes == output.new_tracking_nodes);
+
+            state
+                .tracking_nodes
+                .extend(output.new_tracking_nodes.iter().cloned());
+            results.push(output.result.clone().map(|compilation| Compilation {
+                program: compilation.program,
+                warnings: compilation.warnings,
+                debug_info: compilation.debug_info,
+                ..template.clone()
+            }));
+            state
+                .incremental
+                .record(index, |file| &mut file.code, output);
+        }
+    }
+    // Otherwise we have errors, so we can't safely generate code.
+
     let has_code_generation_errors = results.iter().any(|r| r.is_err());
-    let result = if has_errors || has_code_generation_errors {
+    let result = if (has_errors && !is_partial) || has_code_generation_errors {
         let total_diagnostics: Vec<_> = results
             .iter()
             .filter_map(|result| result.as_ref().err())
@@ -43,38 +104,90 @@ pub(crate) fn generate_code(mut state: CompilationIntermediate) -> CompilationIn
         Err(CompilerError(total_diagnostics))
     } else {
         let compilations = results.into_iter().map(|r| r.unwrap());
-        Ok(Compilation::combine(
-            compilations,
-            state.string_table.clone(),
-        ))
+        Ok(Compilation {
+            nodes: state.nodes.clone(),
+            ..Compilation::combine(compilations, state.string_table.clone())
+        })
     };
 
     state.result = Some(result);
     state
 }
 
-fn generate_code_for_file<'a, 'b: 'a, 'input: 'a + 'b>(
-    tracking_nodes: &mut HashSet<String>,
+/// Generates the code of the nodes without errors, see [`Compiler::with_partial_compilation`].
+/// The errors found while doing so are added to the diagnostics, and the files they were found in are left out.
+///
+/// The files are processed one after another and the results are not cached, since partial compilations are rare
+/// and the nodes that are left out depend on the errors in all files.
+fn generate_partial_code(state: &mut CompilationIntermediate) -> Vec<Result<Compilation>> {
+    let broken_nodes = find_broken_nodes(state);
+    let template = Compilation {
+        string_table: state.string_table.0.clone(),
+        contains_implicit_string_tags: state.string_table.contains_implicit_string_tags(),
+        file_tags: state.file_tags.clone(),
+        ..Default::default()
+    };
+    let mut results = Vec::new();
+    for index in 0..state.job.files.len() {
+        let tracking_nodes = state.tracking_nodes.clone();
+        let known_types = state.known_types[index].clone();
+        let broken_nodes = broken_nodes.clone();
+        let output = state
+            .with_parsed_file(index, move |file| {
+                generate_code_for_file(&tracking_nodes, known_types, file, Some(&broken_nodes))
+            })
+            .wait();
+        state.tracking_nodes.extend(output.new_tracking_nodes);
+        match output.result {
+            Ok(compilation) => results.push(Ok(Compilation {
+                program: compilation.program,
+                warnings: compilation.warnings,
+                debug_info: compilation.debug_info,
+                ..template.clone()
+            })),
+            Err(error) => state.diagnostics.extend(error.0),
+        }
+    }
+    results
+}
+
+/// Generates the code of all nodes in `file`. For a partial compilation, `broken_nodes` are the titles of the nodes
+/// containing errors, which are left out together with the nodes without a title.
+fn generate_code_for_file(
+    tracking_nodes: &HashSet<String>,
     known_types: KnownTypes,
-    result_template: Compilation,
-    file: &'a FileParseResult<'input>,
-) -> Result<Compilation> {
-    let compiler_listener = Box::new(CompilerListener::new(
-        tracking_nodes.clone(),
-        known_types,
-        file.clone(),
-    ));
+    file: &FileParseResult,
+    broken_nodes: Option<&HashSet<String>>,
+) -> CodeOutput {
+    let mut compiler_listener =
+        CompilerListener::new(tracking_nodes.clone(), known_types, file.clone());
     let compiler_tracking_nodes = compiler_listener.tracking_nodes.clone();
     let compiler_diagnostics = compiler_listener.diagnostics.clone();
     let compiler_program = compiler_listener.program.clone();
     let compiler_debug_infos = compiler_listener.debug_infos.clone();
 
-    YarnSpinnerParserTreeWalker::walk(compiler_listener, file.tree.as_ref());
+    match broken_nodes {
+        None => walk(&mut compiler_listener, &file.tree.clone().into()),
+        Some(broken_nodes) => {
+            // Walk the nodes one by one, as the parse trees of the skipped ones may be incomplete.
+            // Nodes without a title are left out as well, since a missing title is an error.
+            for node in file.tree.node_all() {
+                if get_node_title(&node).is_none_or(|title| broken_nodes.contains(&title)) {
+                    continue;
+                }
+                walk(&mut compiler_listener, &node.into());
+            }
+        }
+    }
 
-    tracking_nodes.extend(compiler_tracking_nodes.borrow().iter().cloned());
+    let new_tracking_nodes = compiler_tracking_nodes
+        .borrow()
+        .difference(tracking_nodes)
+        .cloned()
+        .collect();
 
     // Don't attempt to generate debug information if compilation produced errors
-    if compiler_diagnostics.borrow().has_errors() {
+    let result = if compiler_diagnostics.borrow().has_errors() {
         Err(CompilerError(compiler_diagnostics.borrow().clone()))
     } else {
         let debug_infos: HashMap<_, _> = compiler_debug_infos
@@ -87,7 +200,11 @@ fn generate_code_for_file<'a, 'b: 'a, 'input: 'a + 'b>(
             program: Some(compiler_program.borrow().clone()),
             warnings: compiler_diagnostics.borrow().clone(),
             debug_info: debug_infos,
-            ..result_template
+            ..Default::default()
         })
+    };
+    CodeOutput {
+        result,
+        new_tracking_nodes,
     }
 }
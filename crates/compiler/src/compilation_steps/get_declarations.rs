@@ -1,29 +1,65 @@
+use crate::compiler::session::DeclarationsOutput;
 use crate::prelude::*;
 use crate::visitors::DeclarationVisitor;
-use antlr_rust::tree::ParseTreeVisitorCompat;
 
 pub(crate) fn get_declarations(mut state: CompilationIntermediate) -> CompilationIntermediate {
-    // Find the variable declarations in these files.
-    for (file, _) in &state.parsed_files {
-        let mut variable_declaration_visitor =
-            DeclarationVisitor::new(state.known_variable_declarations.clone(), file.clone());
+    // The declarations of a file depend on all declarations found before it.
+    let mut previous_declarations_unchanged =
+        state.incremental.previous_initial_declarations() == state.known_variable_declarations;
+    state
+        .incremental
+        .next
+        .initial_declarations
+        .clone_from(&state.known_variable_declarations);
 
-        variable_declaration_visitor.visit(file.tree.as_ref());
+    // Find the variable declarations in these files.
+    for index in 0..state.job.files.len() {
+        let output =
+            match state
+                .incremental
+                .reusable(index, previous_declarations_unchanged, |file| {
+                    file.declarations.as_ref()
+                }) {
+                Some(output) => output,
+                None => {
+                    let known_variable_declarations = state.known_variable_declarations.clone();
+                    state
+                        .with_parsed_file(index, move |file| {
+                            let mut variable_declaration_visitor =
+                                DeclarationVisitor::new(known_variable_declarations, file.clone());
+                            variable_declaration_visitor.visit(file.tree.as_ref());
+                            DeclarationsOutput {
+                                new_declarations: variable_declaration_visitor.new_declarations,
+                                file_tags: variable_declaration_visitor.file_tags,
+                                diagnostics: variable_declaration_visitor.diagnostics,
+                            }
+                        })
+                        .wait()
+                }
+            };
+        previous_declarations_unchanged &= state
+            .incremental
+            .previous(index)
+            .and_then(|file| file.declarations.as_ref())
+            .is_some_and(|previous| previous.new_declarations == output.new_declarations);
 
         state
             .known_variable_declarations
-            .extend(variable_declaration_visitor.new_declarations.clone());
+            .extend_from_slice(&output.new_declarations);
         state
             .derived_variable_declarations
-            .extend(variable_declaration_visitor.new_declarations);
+            .extend_from_slice(&output.new_declarations);
 
-        state
-            .diagnostics
-            .extend_from_slice(&variable_declaration_visitor.diagnostics);
+        state.diagnostics.extend_from_slice(&output.diagnostics);
 
+        state.file_tags.insert(
+            state.job.files[index].file_name.clone(),
+            output.file_tags.clone(),
+        );
         state
-            .file_tags
-            .insert(file.name.clone(), variable_declaration_visitor.file_tags);
+            .incremental
+            .record(index, |file| &mut file.declarations, output);
     }
+    state.declarations_unchanged = previous_declarations_unchanged;
     state
 }
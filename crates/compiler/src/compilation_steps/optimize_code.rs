@@ -0,0 +1,16 @@
+use crate::compiler::optimizer::optimize_program;
+use crate::prelude::*;
+
+pub(crate) fn optimize_code(mut state: CompilationIntermediate) -> CompilationIntermediate {
+    if !state.job.optimize {
+        return state;
+    }
+    let Some(Ok(compilation)) = state.result.as_mut() else {
+        return state;
+    };
+    let Some(program) = compilation.program.as_mut() else {
+        return state;
+    };
+    optimize_program(program, &mut compilation.debug_info, &state.job.library);
+    state
+}
@@ -1,9 +1,25 @@
+use crate::compiler::parsed_files::Pending;
 use crate::prelude::*;
 
 pub(crate) fn parse_files(mut state: CompilationIntermediate) -> CompilationIntermediate {
-    for (file, chars) in state.job.files.iter().zip(state.file_chars.iter()) {
-        let parse_result = parse_syntax_tree(file, chars, &mut state.diagnostics);
-        state.parsed_files.push((parse_result, Default::default()));
+    // Start parsing all files before collecting the results, so that they can be parsed in parallel.
+    let pending: Vec<_> = (0..state.job.files.len())
+        .map(|index| {
+            match state
+                .incremental
+                .reusable(index, true, |file| file.parse_diagnostics.as_ref())
+            {
+                Some(diagnostics) => Pending::Ready(diagnostics),
+                None => state.parse(index),
+            }
+        })
+        .collect();
+    for (index, pending) in pending.into_iter().enumerate() {
+        let diagnostics = pending.wait();
+        state.diagnostics.extend(diagnostics.iter().cloned());
+        state
+            .incremental
+            .record(index, |file| &mut file.parse_diagnostics, diagnostics);
     }
     state
 }
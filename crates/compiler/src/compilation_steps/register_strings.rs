@@ -1,23 +1,69 @@
+use crate::compiler::session::StringsOutput;
 use crate::prelude::*;
 use crate::visitors::{LastLineBeforeOptionsVisitor, StringTableGeneratorVisitor};
-use antlr_rust::tree::ParseTreeVisitorCompat;
 
 pub(crate) fn register_strings(mut state: CompilationIntermediate) -> CompilationIntermediate {
-    // First pass: parse all files, generate their syntax trees,
-    // and figure out what variables they've declared
-    for (file, _) in &state.parsed_files {
-        // ok now we will add in our lastline tags
-        // we do this BEFORE we build our strings table otherwise the tags will get missed
-        // this should probably be a flag instead of every time though
-        let mut last_line_tagger = LastLineBeforeOptionsVisitor::default();
-        last_line_tagger.visit(file.tree.as_ref());
+    // The strings of a file depend on the line IDs registered by the files before it.
+    let mut previous_line_ids_unchanged = true;
+    for index in 0..state.job.files.len() {
+        let output = match state
+            .incremental
+            .reusable(index, previous_line_ids_unchanged, |file| {
+                file.strings.as_ref()
+            }) {
+            Some(output) => output,
+            None => {
+                let string_table = state.string_table.clone();
+                let (string_table, diagnostics) = state
+                    .with_parsed_file(index, move |file| {
+                        let visitor = generate_string_table(file, string_table);
+                        (visitor.string_table_manager, visitor.diagnostics)
+                    })
+                    .wait();
+                let new_strings = string_table
+                    .0
+                    .into_iter()
+                    .filter(|(line_id, string_info)| {
+                        state.string_table.get(line_id) != Some(string_info)
+                    })
+                    .collect();
+                StringsOutput {
+                    new_strings,
+                    diagnostics,
+                }
+            }
+        };
+        previous_line_ids_unchanged &= state
+            .incremental
+            .previous(index)
+            .and_then(|file| file.strings.as_ref())
+            .is_some_and(|previous| previous.has_same_line_ids(&output));
 
-        let mut visitor =
-            StringTableGeneratorVisitor::new(state.string_table.clone(), file.clone());
-        visitor.visit(file.tree.as_ref());
-        state.diagnostics.extend(visitor.diagnostics);
-        state.string_table.extend(visitor.string_table_manager);
+        state.diagnostics.extend(output.diagnostics.iter().cloned());
+        state.string_table.extend(output.new_strings.clone().into());
+        state
+            .line_ids_by_file
+            .push(output.new_strings.keys().cloned().collect());
+        state
+            .incremental
+            .record(index, |file| &mut file.strings, output);
     }
 
     state
 }
+
+/// Registers the strings of `file` in a copy of `string_table` and adds the tags the later compilation steps expect to its parse tree.
+pub(crate) fn generate_string_table(
+    file: &FileParseResult,
+    string_table: StringTableManager,
+) -> StringTableGeneratorVisitor {
+    // ok now we will add in our lastline tags
+    // we do this BEFORE we build our strings table otherwise the tags will get missed
+    // this should probably be a flag instead of every time though
+    let mut last_line_tagger = LastLineBeforeOptionsVisitor;
+    last_line_tagger.visit(file.tree.as_ref());
+
+    let mut visitor = StringTableGeneratorVisitor::new(string_table, file.clone());
+    visitor.visit(file.tree.as_ref());
+    visitor
+}
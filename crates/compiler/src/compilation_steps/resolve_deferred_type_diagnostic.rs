@@ -1,3 +1,4 @@
+use crate::listeners::closest_match;
 use crate::prelude::*;
 use std::collections::HashSet;
 
@@ -13,9 +14,23 @@ pub(crate) fn resolve_deferred_type_diagnostic(
     for deferred_type_diagnostic in &state.potential_issues {
         let resolved = known_declarations.contains(&deferred_type_diagnostic.name);
         if !resolved {
-            state
-                .diagnostics
-                .push(deferred_type_diagnostic.diagnostic.clone())
+            let mut diagnostic = deferred_type_diagnostic.diagnostic.clone();
+            // Suggest the closest known variable, which is most likely what was meant.
+            let variable_names = known_declarations
+                .iter()
+                .map(|name| name.as_str())
+                .filter(|name| name.starts_with('$'));
+            if let (Some(closest_match), Some(range)) = (
+                closest_match(&deferred_type_diagnostic.name, variable_names),
+                diagnostic.range.clone(),
+            ) {
+                diagnostic = diagnostic.with_suggestion(Suggestion {
+                    message: format!("did you mean `{closest_match}`?"),
+                    range,
+                    replacement: closest_match.to_owned(),
+                });
+            }
+            state.diagnostics.push(diagnostic)
         }
     }
     state
@@ -0,0 +1,56 @@
+use crate::compiler::parsed_files::Pending;
+use crate::listeners::closest_match;
+use crate::prelude::*;
+use crate::visitors::*;
+
+/// Warns about jumps to nodes that don't exist, which would otherwise only be noticed when the jump runs.
+/// These are warnings, since the node may be part of a program the result is combined with.
+pub(crate) fn validate_jump_targets(mut state: CompilationIntermediate) -> CompilationIntermediate {
+    let pending: Vec<_> = (0..state.job.files.len())
+        .map(|index| {
+            match state
+                .incremental
+                .reusable(index, true, |file| file.jump_targets.as_ref())
+            {
+                Some(jump_targets) => Pending::Ready(jump_targets),
+                None => state.with_parsed_file(index, get_jump_targets),
+            }
+        })
+        .collect();
+    for (index, pending) in pending.into_iter().enumerate() {
+        let jump_targets = pending.wait();
+        for jump_target in &jump_targets {
+            if state.node_titles.contains(&jump_target.node_name) {
+                continue;
+            }
+            let mut diagnostic = jump_target.diagnostic.clone();
+            let node_titles = state.node_titles.iter().map(|title| title.as_str());
+            if let (Some(closest_match), Some(range)) = (
+                closest_match(&jump_target.node_name, node_titles),
+                diagnostic.range.clone(),
+            ) {
+                let replacement = if jump_target.is_string {
+                    format!("\"{closest_match}\"")
+                } else {
+                    closest_match.to_owned()
+                };
+                diagnostic = diagnostic.with_suggestion(Suggestion {
+                    message: format!("did you mean `{closest_match}`?"),
+                    range,
+                    replacement,
+                });
+            }
+            state.diagnostics.push(diagnostic);
+        }
+        state
+            .incremental
+            .record(index, |file| &mut file.jump_targets, jump_targets);
+    }
+    state
+}
+
+fn get_jump_targets(file: &FileParseResult) -> Vec<JumpTarget> {
+    let mut visitor = JumpTargetVisitor::new(file.clone());
+    visitor.visit(file.tree.as_ref());
+    visitor.jump_targets
+}
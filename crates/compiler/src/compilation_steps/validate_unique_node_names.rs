@@ -1,6 +1,5 @@
-use crate::prelude::generated::yarnspinnerparser::{DialogueContextAttrs, NodeContextAttrs};
+use crate::compiler::parsed_files::Pending;
 use crate::prelude::*;
-use antlr_rust::token::Token;
 use std::collections::HashMap;
 
 pub(crate) fn validate_unique_node_names(
@@ -9,53 +8,69 @@ pub(crate) fn validate_unique_node_names(
     // Ensure that all nodes names in this compilation are unique. Node
     // name uniqueness is important for several processes, so we do this
     // check here.
-    let all_nodes = state.parsed_files.iter().flat_map(|(file, _)| {
-        file.tree
-            .node_all()
-            .iter()
-            .map(|node| (node.clone(), file))
-            .collect::<Vec<_>>()
-    });
-
-    // Pair up every node with its name, and filter out any that don't
-    // have a name
-    let nodes_with_names = all_nodes.filter_map(|(node, file)| {
-        node.header_all()
-            .iter()
-            .find(|header| header.header_key.as_ref().unwrap().get_text() == "title")
-            .map(|title_header| {
-                let title = title_header
-                    .header_value
-                    .as_ref()
-                    .unwrap()
-                    .get_text()
-                    .to_owned();
-                (title, title_header.clone(), file)
-            })
-    });
+    let mut nodes_by_name: HashMap<_, Vec<_>> = HashMap::new();
+    let pending: Vec<_> = (0..state.job.files.len())
+        .map(|index| {
+            match state
+                .incremental
+                .reusable(index, true, |file| file.node_titles.as_ref())
+            {
+                Some(node_titles) => Pending::Ready(node_titles),
+                None => state.with_parsed_file(index, get_node_titles),
+            }
+        })
+        .collect();
+    for (index, pending) in pending.into_iter().enumerate() {
+        let node_titles = pending.wait();
+        for (name, diagnostic) in &node_titles {
+            nodes_by_name
+                .entry(name.clone())
+                .or_default()
+                .push(diagnostic.clone());
+        }
+        state
+            .incremental
+            .record(index, |file| &mut file.node_titles, node_titles);
+    }
 
-    let nodes_by_name = nodes_with_names.fold(
-        HashMap::new(),
-        |mut map: HashMap<_, Vec<_>>, (name, header_context, file)| {
-            map.entry(name).or_default().push((header_context, file));
-            map
-        },
-    );
+    state.node_titles = nodes_by_name.keys().cloned().collect();
 
     // Find groups of nodes with the same name and generate diagnostics
     // for each
-    for (name, nodes) in nodes_by_name
-        .into_iter()
-        .filter(|(_, nodes)| nodes.len() > 1)
+    for diagnostics in nodes_by_name
+        .into_values()
+        .filter(|diagnostics| diagnostics.len() > 1)
     {
         // More than one node has this name! Report an error on both.
-        for (header_context, file) in nodes {
-            state.diagnostics.push(
-                Diagnostic::from_message(format!("More than one node is named {name}",))
-                    .with_file_name(file.name.clone())
-                    .with_parser_context(header_context.as_ref(), file.tokens()),
-            );
-        }
+        state.diagnostics.extend(diagnostics);
     }
     state
 }
+
+/// Pairs up every node in `file` with its name, filtering out any that don't have a name,
+/// together with the diagnostic to report if another node has the same name.
+fn get_node_titles(file: &FileParseResult) -> Vec<(String, Diagnostic)> {
+    file.tree
+        .node_all()
+        .iter()
+        .filter_map(|node| {
+            node.header_all()
+                .iter()
+                .find(|header| header.header_key.as_ref().unwrap().text == "title")
+                .map(|title_header| {
+                    let title = title_header
+                        .header_value
+                        .as_ref()
+                        .map(|value| value.text.clone())
+                        .unwrap_or_default();
+                    let diagnostic = Diagnostic::new(
+                        DiagnosticCode::DuplicateNodeName,
+                        format!("More than one node is named {title}"),
+                    )
+                    .with_file_name(file.name.clone())
+                    .with_parser_context(title_header.as_ref(), file.tokens());
+                    (title, diagnostic)
+                })
+        })
+        .collect()
+}
@@ -5,9 +5,13 @@ use crate::prelude::*;
 use std::path::Path;
 use yarnspinner_core::prelude::*;
 
+pub use session::CompilerSession;
+
 mod add_tags_to_lines;
-pub(crate) mod antlr_rust_ext;
+pub(crate) mod optimizer;
+pub(crate) mod parsed_files;
 pub(crate) mod run_compilation;
+pub(crate) mod session;
 pub(crate) mod utils;
 
 #[allow(missing_docs)]
@@ -43,6 +47,14 @@ pub struct Compiler {
 
     /// The declarations for variables.
     pub variable_declarations: Vec<Declaration>,
+
+    /// Whether the generated [`Program`] is optimized. See [`Compiler::with_optimization`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub optimize: bool,
+
+    /// Whether [`Compiler::compile`] returns the parts of the compilation that are free of errors. See [`Compiler::with_partial_compilation`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub partial_compilation: bool,
 }
 
 impl Compiler {
@@ -91,13 +103,52 @@ impl Compiler {
         self
     }
 
+    /// Sets whether the generated [`Program`] is optimized. By default, it is not.
+    ///
+    /// An optimized program behaves the same when run, but is smaller and executes fewer instructions:
+    /// constant expressions are evaluated at compile time, jumps are shortened and unreachable code is removed.
+    /// The [`DebugInfo`] of the [`Compilation`] is updated to match the optimized program.
+    ///
+    /// Calls to the operators and to the deterministic functions of [`Library::standard_library`] are evaluated at compile time,
+    /// unless [`Compiler::library`] replaces them with implementations of its own.
+    pub fn with_optimization(&mut self, optimize: bool) -> &mut Self {
+        self.optimize = optimize;
+        self
+    }
+
+    /// Sets whether a compilation with errors still produces a [`Compilation`]. By default, it does not, and [`Compiler::compile`]
+    /// returns a [`CompilerError`] instead.
+    ///
+    /// With partial compilation, [`Compiler::compile`] returns a [`Compilation`] for the nodes without errors,
+    /// and lists the errors in [`Compilation::errors`]. A node containing an error is left out of [`Compilation::program`],
+    /// and its lines and variable declarations are left out of [`Compilation::string_table`] and [`Compilation::declarations`].
+    /// This is useful for editors and for hot reloading, where the files are often only halfway written.
+    ///
+    /// Errors that cannot be attributed to a node, such as ones outside of any node, leave out nothing.
+    pub fn with_partial_compilation(&mut self, partial_compilation: bool) -> &mut Self {
+        self.partial_compilation = partial_compilation;
+        self
+    }
+
     /// Adds a variable declaration to the compilation.
     pub fn declare_variable(&mut self, declaration: Declaration) -> &mut Self {
         self.variable_declarations.push(declaration);
         self
     }
 
+    /// The declarations of the functions that the compiled Yarn files can call,
+    /// i.e. the ones of [`Library::standard_library`] followed by the ones of [`Compiler::library`].
+    /// Operators are not included.
+    #[must_use]
+    pub fn function_declarations(&self) -> Vec<Declaration> {
+        let mut declarations = get_declarations_from_library(&Library::standard_library());
+        declarations.extend(get_declarations_from_library(&self.library));
+        declarations
+    }
+
     /// Compiles the Yarn files previously added into a [`Compilation`].
+    ///
+    /// When compiling the same files again after changing some of them, [`CompilerSession`] avoids redoing most of the work.
     pub fn compile(&self) -> Result<Compilation> {
         run_compilation::compile(self)
     }
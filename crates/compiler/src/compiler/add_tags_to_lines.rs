@@ -1,9 +1,7 @@
 //! Adapted from <https://github.com/YarnSpinnerTool/YarnSpinner/blob/da39c7195107d8211f21c263e4084f773b84eaff/YarnSpinner.Compiler/Utils.cs>
 
-use crate::listeners::{DiagnosticVec, UntaggedLineListener};
-use crate::prelude::generated::yarnspinnerparser::YarnSpinnerParserTreeWalker;
+use crate::line_tagging::LineTagger;
 use crate::prelude::*;
-use std::sync::atomic::Ordering;
 
 impl Compiler {
     /// Given Yarn source code, adds line tags to the ends of all lines
@@ -16,6 +14,8 @@ impl Compiler {
     /// Line tags are added to any line of source code that contains
     /// user-visible text: lines, options, and shortcut options.
     ///
+    /// To tag many files at once or to choose how the IDs look, use a [`LineTagger`](crate::line_tagging::LineTagger) instead.
+    ///
     /// ## Parameters
     ///
     /// `contents`: The source code to add line tags
@@ -32,51 +32,19 @@ impl Compiler {
         contents: impl Into<String>,
         existing_line_tags: Vec<LineId>,
     ) -> crate::Result<Option<String>> {
-        let contents = contents.into();
-        let chars: Vec<_> = contents.chars().map(|c| c as u32).collect();
-        // First, get the parse tree for this source code.
         let file = File {
             file_name: "<input>".to_string(),
-            source: contents,
+            source: contents.into(),
         };
-        let (parse_source, diagnostics) = parse_source(&file, &chars);
-        let tree = parse_source.tree.clone();
+        let tagged_file = LineTagger::new()
+            .with_existing_line_tags(existing_line_tags)
+            .tag_files(std::slice::from_ref(&file))
+            .remove(0);
         // Were there any error-level diagnostics?
-        if diagnostics.has_errors() {
+        if tagged_file.diagnostics.has_errors() {
             // We encountered a parse error. Bail here; we aren't confident in our ability to correctly insert a line tag.
-            return Err(CompilerError(diagnostics));
-        }
-
-        // Create the line listener, which will produce TextReplacements for each new line tag.
-        let untagged_line_listener =
-            Box::new(UntaggedLineListener::new(existing_line_tags, parse_source));
-        let rewritten_nodes = untagged_line_listener.rewritten_lines.clone();
-        let rewrote_anything = untagged_line_listener.rewrote_anything.clone();
-
-        // Walk the tree with this listener, and generate text replacements containing line tags.
-        YarnSpinnerParserTreeWalker::walk(untagged_line_listener, tree.as_ref());
-        // Apply these text replacements to the original source and return it.
-
-        if rewrote_anything.load(Ordering::Relaxed) {
-            let result = rewritten_nodes.take();
-            let mut string = result.join("\n");
-            string.push('\n');
-            Ok(Some(string))
-        } else {
-            Ok(None)
+            return Err(CompilerError(tagged_file.diagnostics));
         }
+        Ok(tagged_file.source)
     }
 }
-
-/// Parses a string of Yarn source code, and produces a [`FileParseResult`]
-/// and (if there were any problems) a collection of [`Diagnostic`]s.
-fn parse_source<'a, 'b: 'a>(
-    file: &'b File,
-    chars: &'a [u32],
-) -> (FileParseResult<'a>, Vec<Diagnostic>) {
-    let mut diagnostics = Vec::new();
-
-    let result = parse_syntax_tree(file, chars, &mut diagnostics);
-
-    (result, diagnostics)
-}
@@ -0,0 +1,799 @@
+//! The code generator favours simplicity over efficiency: constant expressions are evaluated at runtime,
+//! jumps land on other jumps, and branches on constant conditions are kept around.
+//! The passes in this module clean up after it without changing what a program does when it is run.
+
+use crate::prelude::*;
+use std::collections::{HashMap, HashSet};
+use yarnspinner_core::types::{Type, TypedValue};
+
+/// The functions of [`Library::standard_library`] whose result only depends on their arguments.
+/// The methods of the built-in types, i.e. the operators, are always pure and not listed here.
+const PURE_STANDARD_FUNCTIONS: &[&str] = &[
+    "string",
+    "number",
+    "bool",
+    "round",
+    "round_places",
+    "floor",
+    "ceil",
+    "inc",
+    "dec",
+    "decimal",
+    "int",
+    "min",
+    "max",
+    "clamp",
+    "abs",
+    "format_invariant",
+    "length",
+    "upper",
+    "lower",
+    "contains",
+    "substring",
+    "format",
+];
+
+/// Optimizes every node of `program` in place and updates its entry in `debug_info` to match.
+///
+/// The following passes are run until none of them finds anything left to do:
+/// - Constant folding: calls to pure standard functions with only constant arguments of the expected types
+///   are evaluated and replaced by their result. Functions that `user_library` replaces with an implementation of its own
+///   are not folded, since the program will call that implementation at runtime.
+/// - Constant branches: a [`OpCode::JumpIfFalse`] on a constant condition is removed or turned into an [`OpCode::JumpTo`].
+/// - Jump threading: jumps to [`OpCode::JumpTo`] instructions go straight to their final destination,
+///   jumps to the next instruction are removed and jumps to [`OpCode::Stop`] are replaced by it.
+/// - Dead code removal: instructions that cannot be reached from the start of the node are removed.
+/// - Label compaction: labels nothing refers to are removed, and labels pointing to the same instruction are merged.
+///
+/// ## Implementation notes
+///
+/// Nodes containing invalid instructions or jumps to missing labels are left untouched.
+pub(crate) fn optimize_program(
+    program: &mut Program,
+    debug_info: &mut HashMap<String, DebugInfo>,
+    user_library: &Library,
+) {
+    let library = Library::standard_library();
+    let mut pure_functions = pure_function_names();
+    pure_functions.retain(|name| {
+        let standard_function = library.get(name);
+        user_library.get(name).is_none_or(|function| {
+            standard_function.is_some_and(|standard_function| {
+                function.function_type_id() == standard_function.function_type_id()
+            })
+        })
+    });
+    for node in program.nodes.values_mut() {
+        let debug_info = debug_info.get_mut(&node.name);
+        NodeOptimizer::new(node, debug_info, &library, &pure_functions).optimize();
+    }
+}
+
+fn pure_function_names() -> HashSet<String> {
+    let operators = [Type::Number, Type::String, Type::Boolean]
+        .into_iter()
+        .flat_map(|r#type| {
+            r#type
+                .methods()
+                .names()
+                .map(|name| r#type.get_canonical_name_for_method(name))
+                .collect::<Vec<_>>()
+        });
+    PURE_STANDARD_FUNCTIONS
+        .iter()
+        .map(|name| name.to_string())
+        .chain(operators)
+        .collect()
+}
+
+struct NodeOptimizer<'a> {
+    node: &'a mut Node,
+    debug_info: Option<&'a mut DebugInfo>,
+    /// The debug position of each instruction, kept in lockstep with `node.instructions`.
+    positions: Vec<Option<Option<Position>>>,
+    library: &'a Library,
+    pure_functions: &'a HashSet<String>,
+}
+
+impl<'a> NodeOptimizer<'a> {
+    fn new(
+        node: &'a mut Node,
+        debug_info: Option<&'a mut DebugInfo>,
+        library: &'a Library,
+        pure_functions: &'a HashSet<String>,
+    ) -> Self {
+        let positions = (0..node.instructions.len())
+            .map(|index| {
+                debug_info
+                    .as_ref()
+                    .and_then(|debug_info| debug_info.line_positions.get(&index).copied())
+            })
+            .collect();
+        Self {
+            node,
+            debug_info,
+            positions,
+            library,
+            pure_functions,
+        }
+    }
+
+    fn optimize(mut self) {
+        if !self.is_well_formed() {
+            return;
+        }
+        // Every pass can open up opportunities for the others,
+        // e.g. a folded condition turns a branch into dead code.
+        while self.fold_constants()
+            | self.fold_constant_branches()
+            | self.thread_jumps()
+            | self.remove_unreachable_code()
+            | self.compact_labels()
+        {}
+
+        if let Some(debug_info) = self.debug_info {
+            debug_info.line_positions = self
+                .positions
+                .into_iter()
+                .enumerate()
+                .filter_map(|(index, position)| position.map(|position| (index, position)))
+                .collect();
+        }
+    }
+
+    fn is_well_formed(&self) -> bool {
+        let instruction_count = self.node.instructions.len();
+        let labels_in_range = self.node.labels.values().all(|&position| {
+            usize::try_from(position).is_ok_and(|position| position <= instruction_count)
+        });
+        labels_in_range
+            && self
+                .node
+                .instructions
+                .iter()
+                .all(|instruction| match opcode(instruction) {
+                    None => false,
+                    Some(OpCode::JumpTo | OpCode::JumpIfFalse) => string_operand(instruction, 0)
+                        .is_some_and(|label| self.node.labels.contains_key(label)),
+                    Some(_) => true,
+                })
+    }
+
+    fn fold_constants(&mut self) -> bool {
+        let label_targets = self.label_targets();
+        let mut removed = vec![false; self.node.instructions.len()];
+        for index in 0..self.node.instructions.len() {
+            if let Some((start, value)) = self.evaluate_call(index, &label_targets) {
+                self.node.instructions[start] = push_instruction(value);
+                removed[start + 1..=index].fill(true);
+            }
+        }
+        self.remove_instructions(&removed)
+    }
+
+    /// Evaluates the function called by the instruction at `index` if it is pure and all its arguments are constants
+    /// of the types it expects. Returns the index of the first argument and the result.
+    fn evaluate_call(
+        &self,
+        index: usize,
+        label_targets: &HashSet<usize>,
+    ) -> Option<(usize, YarnValue)> {
+        let instructions = &self.node.instructions;
+        let instruction = &instructions[index];
+        if opcode(instruction) != Some(OpCode::CallFunc) {
+            return None;
+        }
+        let function_name = string_operand(instruction, 0)?;
+        if !self.pure_functions.contains(function_name) {
+            return None;
+        }
+        let function = self.library.get(function_name)?;
+
+        let count_index = index.checked_sub(1)?;
+        let Some(YarnValue::Number(argument_count)) = constant(&instructions[count_index]) else {
+            return None;
+        };
+        let argument_count = as_count(argument_count)?;
+        let start = count_index.checked_sub(argument_count)?;
+        // Something jumping into the middle of the call relies on it staying intact.
+        if (start + 1..=index).any(|position| label_targets.contains(&position)) {
+            return None;
+        }
+        let arguments = instructions[start..count_index]
+            .iter()
+            .map(constant)
+            .collect::<Option<Vec<_>>>()?;
+
+        // Converting an argument to a parameter of another type panics instead of failing the call.
+        let mut parameter_types = function
+            .parameter_types()
+            .into_iter()
+            .chain(function.variadic_parameter_type().into_iter().cycle());
+        let arguments_match = arguments.iter().all(|argument| {
+            parameter_types
+                .next()
+                .and_then(|type_id| Type::try_from(type_id).ok())
+                .is_some_and(|r#type| r#type == Type::Any || r#type == argument.r#type())
+        });
+        if !arguments_match {
+            return None;
+        }
+        // A failing call is left in place so that it fails at runtime, as it would have without optimization.
+        let value = function.call(arguments, &()).ok()?;
+        Some((start, value))
+    }
+
+    fn fold_constant_branches(&mut self) -> bool {
+        let label_targets = self.label_targets();
+        let mut removed = vec![false; self.node.instructions.len()];
+        let mut changed = false;
+        for (index, is_removed) in removed.iter_mut().enumerate().skip(1) {
+            if opcode(&self.node.instructions[index]) != Some(OpCode::JumpIfFalse)
+                || label_targets.contains(&index)
+            {
+                continue;
+            }
+            // The condition is only peeked, so the push has to stay either way.
+            match constant(&self.node.instructions[index - 1]) {
+                Some(YarnValue::Boolean(true)) => *is_removed = true,
+                Some(YarnValue::Boolean(false)) => {
+                    self.node.instructions[index].opcode = OpCode::JumpTo.into();
+                    changed = true;
+                }
+                _ => {}
+            }
+        }
+        self.remove_instructions(&removed) || changed
+    }
+
+    fn thread_jumps(&mut self) -> bool {
+        let mut removed = vec![false; self.node.instructions.len()];
+        let mut changed = false;
+        for (index, is_removed) in removed.iter_mut().enumerate() {
+            let instruction = &self.node.instructions[index];
+            let jump_opcode = opcode(instruction);
+            if !matches!(jump_opcode, Some(OpCode::JumpTo | OpCode::JumpIfFalse)) {
+                continue;
+            }
+            let label = string_operand(instruction, 0).unwrap().to_owned();
+            let Some(destination) = self.final_destination(&label) else {
+                continue;
+            };
+            if destination != label {
+                self.node.instructions[index].operands[0] = Operand::from(destination.clone());
+                changed = true;
+            }
+            if jump_opcode != Some(OpCode::JumpTo) {
+                continue;
+            }
+            let target = self.label_target(&destination);
+            if target == index + 1 {
+                *is_removed = true;
+            } else if self
+                .node
+                .instructions
+                .get(target)
+                .is_some_and(|target| opcode_is(target, OpCode::Stop))
+            {
+                self.node.instructions[index] = Instruction {
+                    opcode: OpCode::Stop.into(),
+                    operands: vec![],
+                };
+                changed = true;
+            }
+        }
+        self.remove_instructions(&removed) || changed
+    }
+
+    /// Follows the chain of [`OpCode::JumpTo`] instructions starting at `label`.
+    /// Returns [`None`] if the chain loops.
+    fn final_destination(&self, label: &str) -> Option<String> {
+        let mut visited = HashSet::new();
+        let mut label = label.to_owned();
+        loop {
+            if !visited.insert(label.clone()) {
+                return None;
+            }
+            match self.node.instructions.get(self.label_target(&label)) {
+                Some(instruction) if opcode_is(instruction, OpCode::JumpTo) => {
+                    label = string_operand(instruction, 0).unwrap().to_owned();
+                }
+                _ => return Some(label),
+            }
+        }
+    }
+
+    fn remove_unreachable_code(&mut self) -> bool {
+        let instruction_count = self.node.instructions.len();
+        let dynamic_targets: Vec<_> = self
+            .dynamic_jump_labels()
+            .iter()
+            .map(|label| self.label_target(label))
+            .collect();
+        let mut reachable = vec![false; instruction_count];
+        let mut worklist = vec![0];
+        while let Some(index) = worklist.pop() {
+            if index >= instruction_count || reachable[index] {
+                continue;
+            }
+            reachable[index] = true;
+            let instruction = &self.node.instructions[index];
+            match opcode(instruction) {
+                Some(OpCode::JumpTo) => {
+                    worklist.push(self.label_target(string_operand(instruction, 0).unwrap()));
+                }
+                Some(OpCode::JumpIfFalse) => {
+                    worklist.push(index + 1);
+                    worklist.push(self.label_target(string_operand(instruction, 0).unwrap()));
+                }
+                Some(OpCode::Jump) => worklist.extend(dynamic_targets.iter().copied()),
+                Some(OpCode::Stop | OpCode::RunNode) => {}
+                _ => worklist.push(index + 1),
+            }
+        }
+        let removed: Vec<_> = reachable.into_iter().map(|reachable| !reachable).collect();
+        self.remove_instructions(&removed)
+    }
+
+    fn compact_labels(&mut self) -> bool {
+        let dynamic_jump_labels = self.dynamic_jump_labels();
+        let mut canonical_labels: HashMap<i32, &String> = HashMap::new();
+        for (label, position) in &self.node.labels {
+            canonical_labels
+                .entry(*position)
+                .and_modify(|canonical| *canonical = (*canonical).min(label))
+                .or_insert(label);
+        }
+        let canonical_labels: HashMap<_, _> = canonical_labels
+            .into_iter()
+            .map(|(position, label)| (position, label.clone()))
+            .collect();
+
+        let mut changed = false;
+        let mut static_jump_labels = HashSet::new();
+        for instruction in &mut self.node.instructions {
+            if !matches!(
+                opcode(instruction),
+                Some(OpCode::JumpTo | OpCode::JumpIfFalse)
+            ) {
+                continue;
+            }
+            let label = string_operand(instruction, 0).unwrap();
+            let canonical_label = &canonical_labels[&self.node.labels[label]];
+            if label != canonical_label {
+                instruction.operands[0] = Operand::from(canonical_label.clone());
+                changed = true;
+            }
+            static_jump_labels.insert(canonical_label.clone());
+        }
+
+        let label_count = self.node.labels.len();
+        self.node.labels.retain(|label, _| {
+            static_jump_labels.contains(label) || dynamic_jump_labels.contains(label)
+        });
+        changed || self.node.labels.len() != label_count
+    }
+
+    /// The labels that [`OpCode::Jump`] may jump to, i.e. the destinations of options and labels pushed as strings.
+    fn dynamic_jump_labels(&self) -> HashSet<String> {
+        self.node
+            .instructions
+            .iter()
+            .filter_map(|instruction| match opcode(instruction) {
+                Some(OpCode::AddOption) => string_operand(instruction, 1),
+                Some(OpCode::PushString) => string_operand(instruction, 0),
+                _ => None,
+            })
+            .filter(|label| self.node.labels.contains_key(*label))
+            .map(ToOwned::to_owned)
+            .collect()
+    }
+
+    fn label_target(&self, label: &str) -> usize {
+        self.node.labels[label] as usize
+    }
+
+    fn label_targets(&self) -> HashSet<usize> {
+        self.node
+            .labels
+            .values()
+            .map(|&position| position as usize)
+            .collect()
+    }
+
+    /// Removes the marked instructions. Labels pointing to a removed instruction are moved to the next one that is kept.
+    fn remove_instructions(&mut self, removed: &[bool]) -> bool {
+        if !removed.contains(&true) {
+            return false;
+        }
+        let mut new_indices = Vec::with_capacity(removed.len() + 1);
+        let mut kept_count = 0;
+        for &is_removed in removed {
+            new_indices.push(kept_count);
+            if !is_removed {
+                kept_count += 1;
+            }
+        }
+        new_indices.push(kept_count);
+
+        let instructions = std::mem::take(&mut self.node.instructions);
+        self.node.instructions = keep(instructions, removed);
+        let positions = std::mem::take(&mut self.positions);
+        self.positions = keep(positions, removed);
+        for position in self.node.labels.values_mut() {
+            *position = new_indices[*position as usize];
+        }
+        true
+    }
+}
+
+fn keep<T>(items: Vec<T>, removed: &[bool]) -> Vec<T> {
+    items
+        .into_iter()
+        .zip(removed)
+        .filter(|(_, &is_removed)| !is_removed)
+        .map(|(item, _)| item)
+        .collect()
+}
+
+fn opcode(instruction: &Instruction) -> Option<OpCode> {
+    OpCode::try_from(instruction.opcode).ok()
+}
+
+fn opcode_is(instruction: &Instruction, expected: OpCode) -> bool {
+    opcode(instruction) == Some(expected)
+}
+
+fn string_operand(instruction: &Instruction, index: usize) -> Option<&str> {
+    match instruction.operands.get(index)?.value.as_ref()? {
+        OperandValue::StringValue(value) => Some(value),
+        _ => None,
+    }
+}
+
+/// The value pushed by the instruction, if it pushes a constant.
+fn constant(instruction: &Instruction) -> Option<YarnValue> {
+    let value = instruction.operands.first()?.value.clone()?;
+    match (opcode(instruction)?, value) {
+        (OpCode::PushString, OperandValue::StringValue(value)) => Some(value.into()),
+        (OpCode::PushFloat, OperandValue::FloatValue(value)) => Some(value.into()),
+        (OpCode::PushBool, OperandValue::BoolValue(value)) => Some(value.into()),
+        _ => None,
+    }
+}
+
+fn push_instruction(value: YarnValue) -> Instruction {
+    let (opcode, operand) = match value {
+        YarnValue::String(value) => (OpCode::PushString, Operand::from(value)),
+        YarnValue::Number(value) => (OpCode::PushFloat, Operand::from(value)),
+        YarnValue::Boolean(value) => (OpCode::PushBool, Operand::from(value)),
+    };
+    Instruction {
+        opcode: opcode.into(),
+        operands: vec![operand],
+    }
+}
+
+fn as_count(value: f32) -> Option<usize> {
+    (value >= 0.0 && value.fract() == 0.0).then_some(value as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instruction(opcode: OpCode, operands: impl IntoIterator<Item = Operand>) -> Instruction {
+        Instruction {
+            opcode: opcode.into(),
+            operands: operands.into_iter().collect(),
+        }
+    }
+
+    fn push_float(value: f32) -> Instruction {
+        instruction(OpCode::PushFloat, [value.into()])
+    }
+
+    fn push_bool(value: bool) -> Instruction {
+        instruction(OpCode::PushBool, [value.into()])
+    }
+
+    fn push_string(value: &str) -> Instruction {
+        instruction(OpCode::PushString, [value.to_owned().into()])
+    }
+
+    fn call(function_name: &str) -> Instruction {
+        instruction(OpCode::CallFunc, [function_name.to_owned().into()])
+    }
+
+    fn jump_to(label: &str) -> Instruction {
+        instruction(OpCode::JumpTo, [label.to_owned().into()])
+    }
+
+    fn jump_if_false(label: &str) -> Instruction {
+        instruction(OpCode::JumpIfFalse, [label.to_owned().into()])
+    }
+
+    fn run_line(line_id: &str) -> Instruction {
+        instruction(OpCode::RunLine, [line_id.to_owned().into(), 0_usize.into()])
+    }
+
+    fn stop() -> Instruction {
+        instruction(OpCode::Stop, [])
+    }
+
+    fn pop() -> Instruction {
+        instruction(OpCode::Pop, [])
+    }
+
+    fn node(
+        instructions: impl IntoIterator<Item = Instruction>,
+        labels: impl IntoIterator<Item = (&'static str, i32)>,
+    ) -> Node {
+        Node {
+            name: "Start".to_owned(),
+            instructions: instructions.into_iter().collect(),
+            labels: labels
+                .into_iter()
+                .map(|(label, position)| (label.to_owned(), position))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn optimize(node: Node) -> (Node, Vec<usize>) {
+        optimize_with_library(node, &Library::new())
+    }
+
+    /// Optimizes the node with a debug position on every instruction, whose line is the instruction's original index.
+    fn optimize_with_library(node: Node, user_library: &Library) -> (Node, Vec<usize>) {
+        let mut program = Program {
+            nodes: HashMap::from([(node.name.clone(), node)]),
+            ..Default::default()
+        };
+        let line_positions = (0..program.nodes["Start"].instructions.len())
+            .map(|line| (line, Some(Position { line, character: 0 })))
+            .collect();
+        let mut debug_info = HashMap::from([(
+            "Start".to_owned(),
+            DebugInfo {
+                node_name: "Start".to_owned(),
+                line_positions,
+                ..Default::default()
+            },
+        )]);
+
+        optimize_program(&mut program, &mut debug_info, user_library);
+
+        let node = program.nodes.remove("Start").unwrap();
+        let original_lines = (0..node.instructions.len())
+            .map(|index| {
+                debug_info["Start"]
+                    .get_line_info(index)
+                    .position
+                    .unwrap()
+                    .line
+            })
+            .collect();
+        (node, original_lines)
+    }
+
+    #[test]
+    fn folds_constant_expressions() {
+        // <<set $x to 1 + 2 * round(2.6)>>
+        let (node, original_lines) = optimize(node(
+            [
+                push_float(1.0),
+                push_float(2.0),
+                push_float(2.6),
+                push_float(1.0),
+                call("round"),
+                push_float(2.0),
+                call("Number.Multiply"),
+                push_float(2.0),
+                call("Number.Add"),
+                instruction(OpCode::StoreVariable, ["$x".to_owned().into()]),
+                pop(),
+                stop(),
+            ],
+            [],
+        ));
+
+        assert_eq!(
+            node.instructions,
+            vec![
+                push_float(7.0),
+                instruction(OpCode::StoreVariable, ["$x".to_owned().into()]),
+                pop(),
+                stop(),
+            ]
+        );
+        assert_eq!(original_lines, vec![0, 9, 10, 11]);
+    }
+
+    #[test]
+    fn does_not_fold_impure_or_failing_calls() {
+        let instructions = vec![
+            push_float(6.0),
+            push_float(1.0),
+            call("dice"),
+            push_string("not a number"),
+            push_float(1.0),
+            call("number"),
+            push_float(1.0),
+            push_float(1.0),
+            call("user_function"),
+            stop(),
+        ];
+
+        let (node, _) = optimize(node(instructions.clone(), []));
+
+        assert_eq!(node.instructions, instructions);
+    }
+
+    #[test]
+    fn does_not_fold_calls_with_unexpected_arguments() {
+        let instructions = vec![
+            push_string("2.6"),
+            push_float(1.0),
+            call("round"),
+            push_string("a"),
+            push_string("b"),
+            push_float(2.0),
+            call("upper"),
+            push_bool(true),
+            push_float(1.0),
+            call("Number.Add"),
+            stop(),
+        ];
+
+        let (node, _) = optimize(node(instructions.clone(), []));
+
+        assert_eq!(node.instructions, instructions);
+    }
+
+    #[test]
+    fn does_not_fold_replaced_standard_functions() {
+        let instructions = vec![push_string("a"), push_float(1.0), call("upper"), stop()];
+        let mut user_library = Library::standard_library();
+        user_library.add_function("upper", |string: &str| format!("{string}!"));
+
+        let (replaced, _) = optimize_with_library(node(instructions.clone(), []), &user_library);
+        let (standard, _) =
+            optimize_with_library(node(instructions, []), &Library::standard_library());
+
+        assert_eq!(replaced.instructions[2], call("upper"));
+        assert_eq!(standard.instructions, vec![push_string("A"), stop()]);
+    }
+
+    #[test]
+    fn folds_rounding_to_many_places() {
+        let (node, _) = optimize(node(
+            [
+                push_float(1.5),
+                push_float(12.0),
+                push_float(2.0),
+                call("round_places"),
+                stop(),
+            ],
+            [],
+        ));
+
+        assert_eq!(node.instructions, vec![push_float(1.5), stop()]);
+    }
+
+    #[test]
+    fn removes_branches_on_constant_conditions() {
+        // <<if true>> A <<endif>> <<if false>> B <<endif>>
+        let (node, original_lines) = optimize(node(
+            [
+                push_bool(true),
+                jump_if_false("skip_a"),
+                run_line("line:a"),
+                jump_to("end_a"),
+                pop(),
+                push_bool(false),
+                jump_if_false("skip_b"),
+                run_line("line:b"),
+                jump_to("end_b"),
+                pop(),
+                stop(),
+            ],
+            [("skip_a", 4), ("end_a", 5), ("skip_b", 9), ("end_b", 10)],
+        ));
+
+        assert_eq!(
+            node.instructions,
+            vec![
+                push_bool(true),
+                run_line("line:a"),
+                push_bool(false),
+                pop(),
+                stop(),
+            ]
+        );
+        assert!(node.labels.is_empty());
+        assert_eq!(original_lines, vec![0, 2, 5, 9, 10]);
+    }
+
+    #[test]
+    fn threads_jumps_and_merges_labels() {
+        let (node, original_lines) = optimize(node(
+            [
+                push_string("$flag"),
+                jump_if_false("first"),
+                jump_to("second"),
+                run_line("line:a"),
+                jump_to("third"),
+                run_line("line:b"),
+                stop(),
+            ],
+            [("first", 2), ("second", 4), ("third", 5), ("also_third", 5)],
+        ));
+
+        assert_eq!(
+            node.instructions,
+            vec![
+                push_string("$flag"),
+                jump_if_false("also_third"),
+                run_line("line:b"),
+                stop(),
+            ]
+        );
+        assert_eq!(node.labels, HashMap::from([("also_third".to_owned(), 2)]));
+        assert_eq!(original_lines, vec![0, 1, 5, 6]);
+    }
+
+    #[test]
+    fn keeps_option_destinations() {
+        let add_option = |line_id: &str, destination: &str| {
+            instruction(
+                OpCode::AddOption,
+                [
+                    line_id.to_owned().into(),
+                    destination.to_owned().into(),
+                    0_usize.into(),
+                    false.into(),
+                ],
+            )
+        };
+        let mut instructions = vec![
+            add_option("line:a", "option_a"),
+            add_option("line:b", "option_b"),
+            instruction(OpCode::ShowOptions, []),
+            instruction(OpCode::Jump, []),
+            run_line("line:a"),
+            jump_to("end"),
+            run_line("line:b"),
+            jump_to("end"),
+            pop(),
+            stop(),
+        ];
+        let (node, _) = optimize(node(
+            instructions.clone(),
+            [("option_a", 4), ("option_b", 6), ("end", 8)],
+        ));
+
+        // Only the jump from the last option to the end of the options is redundant.
+        instructions.remove(7);
+        assert_eq!(node.instructions, instructions);
+        assert_eq!(
+            node.labels,
+            HashMap::from([
+                ("option_a".to_owned(), 4),
+                ("option_b".to_owned(), 6),
+                ("end".to_owned(), 7),
+            ])
+        );
+    }
+
+    #[test]
+    fn removes_code_after_stop() {
+        let (node, original_lines) = optimize(node(
+            [run_line("line:a"), stop(), run_line("line:b"), stop()],
+            [],
+        ));
+
+        assert_eq!(node.instructions, vec![run_line("line:a"), stop()]);
+        assert_eq!(original_lines, vec![0, 1]);
+    }
+}
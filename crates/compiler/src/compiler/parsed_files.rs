@@ -0,0 +1,207 @@
+//! The parse trees are built on [`Rc`](std::rc::Rc) and can therefore not be sent to other threads.
+//! With the `parallel` feature, each file is instead parsed on one of several worker threads, where its parse tree stays for the rest of the compilation.
+//! The compilation steps send the work they want to do on a file to the thread owning it and receive the results, which are plain data.
+//! Since the results are always collected in the order of the files, the output of a compilation does not depend on the number of threads.
+
+use crate::prelude::*;
+#[cfg(not(feature = "parallel"))]
+use std::marker::PhantomData;
+#[cfg(feature = "parallel")]
+use std::sync::mpsc::{self, Receiver, Sender};
+
+/// The parse results of the files of a compilation, indexed like [`Compiler::files`]. [`None`] for files that were not parsed yet.
+pub(crate) type FileParseResults = Vec<Option<FileParseResult>>;
+
+#[cfg(feature = "parallel")]
+type Job<'input> = Box<dyn FnOnce(&mut FileParseResults) + Send + 'input>;
+
+/// Owns the parse results of the files of a compilation, either directly or through worker threads.
+pub(crate) struct ParsedFiles<'input> {
+    local: FileParseResults,
+    /// The worker threads owning the parse results. The file at index `i` belongs to the worker at `i % workers.len()`.
+    #[cfg(feature = "parallel")]
+    workers: Vec<Sender<Job<'input>>>,
+    /// The jobs may borrow from the compilation for `'input`, even if they run on the current thread.
+    #[cfg(not(feature = "parallel"))]
+    input: PhantomData<&'input ()>,
+}
+
+impl<'input> ParsedFiles<'input> {
+    fn local(file_count: usize) -> Self {
+        Self {
+            local: vec![None; file_count],
+            #[cfg(feature = "parallel")]
+            workers: Vec::new(),
+            #[cfg(not(feature = "parallel"))]
+            input: PhantomData,
+        }
+    }
+
+    /// Calls `f` with storage for the parse results of `file_count` files.
+    #[cfg(not(feature = "parallel"))]
+    pub(crate) fn scope<R>(file_count: usize, f: impl FnOnce(ParsedFiles<'input>) -> R) -> R {
+        f(Self::local(file_count))
+    }
+
+    /// Calls `f` with storage for the parse results of `file_count` files,
+    /// which is spread over as many worker threads as there are cores available.
+    #[cfg(feature = "parallel")]
+    pub(crate) fn scope<R>(file_count: usize, f: impl FnOnce(ParsedFiles<'input>) -> R) -> R {
+        let worker_count = std::thread::available_parallelism()
+            .map_or(1, std::num::NonZeroUsize::get)
+            .min(file_count);
+        Self::scope_with_workers(file_count, worker_count, f)
+    }
+
+    /// Calls `f` with storage for the parse results of `file_count` files, which is spread over `worker_count` worker threads.
+    /// The workers are stopped once `f` returns. With fewer than two workers, everything runs on the current thread.
+    #[cfg(feature = "parallel")]
+    pub(crate) fn scope_with_workers<R>(
+        file_count: usize,
+        worker_count: usize,
+        f: impl FnOnce(ParsedFiles<'input>) -> R,
+    ) -> R {
+        if worker_count < 2 {
+            return f(Self::local(file_count));
+        }
+        std::thread::scope(|scope| {
+            let workers = (0..worker_count)
+                .map(|_| {
+                    let (sender, receiver) = mpsc::channel::<Job<'input>>();
+                    scope.spawn(move || {
+                        let mut files: FileParseResults = vec![None; file_count];
+                        for job in receiver {
+                            job(&mut files);
+                        }
+                    });
+                    sender
+                })
+                .collect();
+            f(Self {
+                local: Vec::new(),
+                workers,
+            })
+        })
+    }
+
+    /// Runs `job` with the parse results on the thread owning the file at `index`.
+    #[cfg(not(feature = "parallel"))]
+    pub(crate) fn run<T: Send + 'input>(
+        &mut self,
+        _index: usize,
+        job: impl FnOnce(&mut FileParseResults) -> T + Send + 'input,
+    ) -> Pending<T> {
+        Pending::Ready(job(&mut self.local))
+    }
+
+    /// Runs `job` with the parse results on the thread owning the file at `index`.
+    /// Returns immediately if that is a worker thread, so that jobs for files owned by different workers run in parallel.
+    #[cfg(feature = "parallel")]
+    pub(crate) fn run<T: Send + 'input>(
+        &mut self,
+        index: usize,
+        job: impl FnOnce(&mut FileParseResults) -> T + Send + 'input,
+    ) -> Pending<T> {
+        if self.workers.is_empty() {
+            return Pending::Ready(job(&mut self.local));
+        }
+        let (sender, receiver) = mpsc::channel();
+        let job: Job<'input> = Box::new(move |files| {
+            // The receiver is gone if the result is not needed anymore.
+            let _ = sender.send(job(files));
+        });
+        self.workers[index % self.workers.len()]
+            .send(job)
+            .expect("A compiler worker thread stopped unexpectedly");
+        Pending::Running(receiver)
+    }
+}
+
+/// The result of [`ParsedFiles::run`], which may still be computed on another thread.
+pub(crate) enum Pending<T> {
+    Ready(T),
+    #[cfg(feature = "parallel")]
+    Running(Receiver<T>),
+}
+
+impl<T> Pending<T> {
+    /// Blocks until the result is available.
+    pub(crate) fn wait(self) -> T {
+        match self {
+            Pending::Ready(value) => value,
+            #[cfg(feature = "parallel")]
+            Pending::Running(receiver) => {
+                receiver.recv().expect("A compiler worker thread panicked")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(index: usize) -> File {
+        File {
+            file_name: format!("file{index}.yarn"),
+            source: format!(
+                "title: Node{index}
+---
+<<declare $var{index} = {index}>>
+<<if visited(\"Node{previous}\")>>
+    Line {{$var{previous}}} of {index}
+<<endif>>
+Another line #tag
+===",
+                previous = index.saturating_sub(1)
+            ),
+        }
+    }
+
+    #[test]
+    fn compiles_many_files_deterministically() {
+        let mut compiler = Compiler::new();
+        for index in 0..12 {
+            compiler.add_file(file(index));
+        }
+        let compilation = compiler.compile().unwrap();
+        for _ in 0..3 {
+            assert_eq!(compiler.compile(), Ok(compilation.clone()));
+        }
+        assert!(compilation
+            .string_table
+            .contains_key(&"line:file11.yarn-Node11-23".into()));
+        assert_eq!(12, compilation.file_tags.len());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn runs_jobs_on_the_thread_owning_the_file() {
+        let thread_ids = ParsedFiles::scope_with_workers(4, 2, |mut parsed_files| {
+            let pending: Vec<_> = (0..4)
+                .map(|index| parsed_files.run(index, |_| std::thread::current().id()))
+                .collect();
+            pending.into_iter().map(Pending::wait).collect::<Vec<_>>()
+        });
+        assert_eq!(thread_ids[0], thread_ids[2]);
+        assert_eq!(thread_ids[1], thread_ids[3]);
+        assert_ne!(thread_ids[0], thread_ids[1]);
+        assert!(!thread_ids.contains(&std::thread::current().id()));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn compiles_the_same_on_any_number_of_threads() {
+        let mut compiler = Compiler::new();
+        for index in 0..7 {
+            compiler.add_file(file(index));
+        }
+        let compile = |worker_count| {
+            crate::compiler::run_compilation::compile_with_workers(&compiler, worker_count)
+        };
+        let expected = compile(1);
+        for worker_count in 2..=8 {
+            assert_eq!(compile(worker_count), expected);
+        }
+    }
+}
@@ -1,4 +1,6 @@
 use crate::compilation_steps::*;
+use crate::compiler::parsed_files::{ParsedFiles, Pending};
+use crate::compiler::session::{CompilationCache, IncrementalCompilation};
 use crate::output::*;
 use crate::prelude::*;
 use crate::string_table_manager::StringTableManager;
@@ -8,11 +10,70 @@ use std::collections::{HashMap, HashSet};
 
 /// Compile Yarn code, as specified by a compilation job.
 pub(crate) fn compile(compiler: &Compiler) -> Result<Compilation> {
+    compile_incrementally(compiler, &mut CompilationCache::default())
+}
+
+/// Compile Yarn code, reusing the results of the compilation that filled the `cache` where possible.
+/// Afterwards, `cache` holds the results of this compilation.
+pub(crate) fn compile_incrementally(
+    compiler: &Compiler,
+    cache: &mut CompilationCache,
+) -> Result<Compilation> {
+    let chars = file_chars(compiler);
+    let chars: Vec<_> = chars.iter().map(|c| c.as_slice()).collect();
+    ParsedFiles::scope(compiler.files.len(), |parsed_files| {
+        run_steps(compiler, chars, cache, parsed_files)
+    })
+}
+
+/// Compile Yarn code on exactly `worker_count` worker threads.
+#[cfg(all(test, feature = "parallel"))]
+pub(crate) fn compile_with_workers(
+    compiler: &Compiler,
+    worker_count: usize,
+) -> Result<Compilation> {
+    let chars = file_chars(compiler);
+    let chars: Vec<_> = chars.iter().map(|c| c.as_slice()).collect();
+    ParsedFiles::scope_with_workers(compiler.files.len(), worker_count, |parsed_files| {
+        run_steps(
+            compiler,
+            chars,
+            &mut CompilationCache::default(),
+            parsed_files,
+        )
+    })
+}
+
+fn file_chars(compiler: &Compiler) -> Vec<Vec<char>> {
+    compiler
+        .files
+        .iter()
+        .map(|file| {
+            // Strip the BOM from the source string if it is present before compiling.
+            // Rust does not do this by default
+            // https://github.com/rust-lang/rfcs/issues/2428
+            let source = match file.source.strip_prefix('\u{feff}') {
+                None => file.source.as_str(),
+                Some(sanitized_string) => sanitized_string,
+            };
+            source.chars().collect()
+        })
+        .collect()
+}
+
+fn run_steps<'input>(
+    compiler: &'input Compiler,
+    chars: Vec<&'input [char]>,
+    cache: &mut CompilationCache,
+    parsed_files: ParsedFiles<'input>,
+) -> Result<Compilation> {
     let compiler_steps: Vec<&CompilationStep> = vec![
         &register_initial_variables,
         &parse_files,
         &register_strings,
         &validate_unique_node_names,
+        &validate_jump_targets,
+        &collect_node_infos,
         &break_on_job_with_only_strings,
         &get_declarations,
         &check_types,
@@ -22,25 +83,12 @@ pub(crate) fn compile(compiler: &Compiler) -> Result<Compilation> {
         &resolve_deferred_type_diagnostic,
         &break_on_job_with_only_declarations,
         &generate_code,
+        &optimize_code,
         &add_initial_value_registrations,
     ];
 
-    let chars: Vec<Vec<u32>> = compiler
-        .files
-        .iter()
-        .map(|file| {
-            // Strip the BOM from the source string if it is present before compiling.
-            // Rust does not do this by default
-            // https://github.com/rust-lang/rfcs/issues/2428
-            let source = match file.source.strip_prefix('\u{feff}') {
-                None => file.source.as_str(),
-                Some(sanitized_string) => sanitized_string,
-            };
-            source.chars().map(|c| c as u32).collect()
-        })
-        .collect();
-    let chars: Vec<_> = chars.iter().map(|c| c.as_slice()).collect();
-    let initial = CompilationIntermediate::from_job(compiler, chars);
+    let incremental = IncrementalCompilation::new(std::mem::take(cache), &compiler.files);
+    let initial = CompilationIntermediate::from_job(compiler, chars, incremental, parsed_files);
     let intermediate = compiler_steps.into_iter().fold(initial, |state, step| {
         if state.early_break {
             state
@@ -51,31 +99,52 @@ pub(crate) fn compile(compiler: &Compiler) -> Result<Compilation> {
     // Cleaning up diagnostics doesn't change the state but makes sure
     // that diagnostics are unique, there are no errors in the warnings, etc.
     // So we execute it even if we've had early breaks.
-    let result = clean_up_diagnostics(intermediate).result.unwrap();
-    result
+    let intermediate = clean_up_diagnostics(intermediate);
+    let intermediate = finish_partial_compilation(intermediate);
+    *cache = intermediate.incremental.next;
+    intermediate.result.unwrap()
 }
 
 type CompilationStep = dyn Fn(CompilationIntermediate) -> CompilationIntermediate;
 
 pub(crate) struct CompilationIntermediate<'input> {
     pub(crate) job: &'input Compiler,
-    pub(crate) file_chars: Vec<&'input [u32]>,
+    pub(crate) file_chars: Vec<&'input [char]>,
     pub(crate) result: Option<Result<Compilation>>,
     /// All variable declarations that we've encountered, PLUS the ones we knew about before
     pub(crate) known_variable_declarations: Vec<Declaration>,
     /// All variable declarations that we've encountered during this compilation job
     pub(crate) derived_variable_declarations: Vec<Declaration>,
     pub(crate) potential_issues: Vec<DeferredTypeDiagnostic>,
-    pub(crate) parsed_files: Vec<(FileParseResult<'input>, KnownTypes)>,
+    /// The parse result of each file. See [`CompilationIntermediate::with_parsed_file`].
+    pub(crate) parsed_files: ParsedFiles<'input>,
+    /// Whether each file was parsed yet.
+    pub(crate) is_parsed: Vec<bool>,
+    pub(crate) known_types: Vec<KnownTypes>,
+    /// The line IDs each file added to the string table.
+    pub(crate) line_ids_by_file: Vec<Vec<LineId>>,
+    /// Whether the declarations found in every file are the same as in the previous compilation.
+    pub(crate) declarations_unchanged: bool,
     pub(crate) tracking_nodes: HashSet<String>,
+    /// The titles of all nodes in the job. Filled in by [`validate_unique_node_names`].
+    pub(crate) node_titles: HashSet<String>,
+    /// The nodes for [`Compilation::nodes`]. Filled in by [`collect_node_infos`].
+    pub(crate) nodes: Vec<NodeInfo>,
     pub(crate) string_table: StringTableManager,
     pub(crate) diagnostics: Vec<Diagnostic>,
     pub(crate) file_tags: HashMap<String, Vec<String>>,
     pub(crate) early_break: bool,
+    pub(crate) incremental: IncrementalCompilation,
 }
 
 impl<'input> CompilationIntermediate<'input> {
-    pub(crate) fn from_job(compiler: &'input Compiler, chars: Vec<&'input [u32]>) -> Self {
+    pub(crate) fn from_job(
+        compiler: &'input Compiler,
+        chars: Vec<&'input [char]>,
+        incremental: IncrementalCompilation,
+        parsed_files: ParsedFiles<'input>,
+    ) -> Self {
+        let file_count = compiler.files.len();
         Self {
             job: compiler,
             file_chars: chars,
@@ -83,12 +152,82 @@ impl<'input> CompilationIntermediate<'input> {
             known_variable_declarations: Default::default(),
             derived_variable_declarations: Default::default(),
             potential_issues: Default::default(),
-            parsed_files: Default::default(),
+            parsed_files,
+            is_parsed: vec![false; file_count],
+            known_types: vec![KnownTypes::default(); file_count],
+            line_ids_by_file: Default::default(),
+            declarations_unchanged: Default::default(),
             tracking_nodes: Default::default(),
+            node_titles: Default::default(),
+            nodes: Default::default(),
             string_table: Default::default(),
             diagnostics: Default::default(),
             file_tags: Default::default(),
             early_break: Default::default(),
+            incremental,
         }
     }
+
+    /// Runs `f` with the parse result of the file at `index` on the thread owning it, parsing the file first if no compilation step needed it so far.
+    /// Wait for the result with [`Pending::wait`].
+    ///
+    /// Since [`register_strings`] adds tags to the parse tree, a file parsed after it ran for the file is tagged the same way.
+    /// Diagnostics found while parsing are not reported again; [`parse_files`] already did that.
+    pub(crate) fn with_parsed_file<T: Send + 'input>(
+        &mut self,
+        index: usize,
+        f: impl FnOnce(&FileParseResult) -> T + Send + 'input,
+    ) -> Pending<T> {
+        let mut string_table_before_file = None;
+        if !self.is_parsed[index] {
+            self.mark_parsed(index);
+            if self.line_ids_by_file.len() > index {
+                // Recreate the string table as it was before the file's strings were registered.
+                // Only its line IDs matter, as they determine both duplicate IDs and the generated IDs.
+                let previous_line_ids: HashSet<_> =
+                    self.line_ids_by_file[..index].iter().flatten().collect();
+                let string_table: HashMap<_, _> = self
+                    .string_table
+                    .iter()
+                    .filter(|(line_id, _)| previous_line_ids.contains(line_id))
+                    .map(|(line_id, string_info)| (line_id.clone(), string_info.clone()))
+                    .collect();
+                string_table_before_file = Some(StringTableManager::from(string_table));
+            }
+        }
+        let job = self.job;
+        let file = &job.files[index];
+        let chars = self.file_chars[index];
+        self.parsed_files.run(index, move |files| {
+            let parse_result = files[index].get_or_insert_with(|| {
+                let parse_result = parse_syntax_tree(file, chars, &mut Vec::new());
+                if let Some(string_table) = string_table_before_file {
+                    generate_string_table(&parse_result, string_table);
+                }
+                parse_result
+            });
+            f(parse_result)
+        })
+    }
+
+    /// Parses the file at `index` on the thread owning it and returns the problems found.
+    pub(crate) fn parse(&mut self, index: usize) -> Pending<Vec<Diagnostic>> {
+        self.mark_parsed(index);
+        let job = self.job;
+        let file = &job.files[index];
+        let chars = self.file_chars[index];
+        self.parsed_files.run(index, move |files| {
+            let mut diagnostics = Vec::new();
+            files[index] = Some(parse_syntax_tree(file, chars, &mut diagnostics));
+            diagnostics
+        })
+    }
+
+    fn mark_parsed(&mut self, index: usize) {
+        self.is_parsed[index] = true;
+        self.incremental
+            .next
+            .parsed_files
+            .push(self.job.files[index].file_name.clone());
+    }
 }
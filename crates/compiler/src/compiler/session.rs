@@ -0,0 +1,370 @@
+//! [`Compiler::compile`] lexes, parses and analyses every file on each call. When only a few files of a large project change between compilations,
+//! as is the case when hot reloading, most of that work produces the same results as before.
+//! A [`CompilerSession`] remembers the results of each compilation step per file and only recomputes the ones whose inputs changed.
+
+use crate::prelude::*;
+use crate::visitors::{JumpTarget, KnownTypes};
+use crate::Result;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+/// A [`Compiler`] that keeps the intermediate results of its last compilation around, so that compiling again after changing some of its files
+/// only reprocesses the files that changed and the files depending on them.
+///
+/// The result of [`CompilerSession::compile`] is always the same as calling [`Compiler::compile`] on [`CompilerSession::compiler`].
+///
+/// ## Implementation notes
+///
+/// Files are identified by their position in [`Compiler::files`], their name and a hash of their content.
+/// For each compilation step, a file is only processed again if it changed or if something it depends on changed,
+/// e.g. a variable declared in another file or the line IDs of the files before it. Whether the latter is the case is determined
+/// by comparing the outputs of the step for the other files with the ones of the last compilation.
+/// Reordering the files invalidates everything after the first file that moved.
+#[derive(Debug, Clone, Default)]
+pub struct CompilerSession {
+    /// The compiler whose files and settings are compiled by [`CompilerSession::compile`].
+    /// It can be changed freely between compilations.
+    pub compiler: Compiler,
+    cache: CompilationCache,
+}
+
+impl CompilerSession {
+    /// Creates a new [`CompilerSession`] for the given [`Compiler`]. The first compilation will process all files.
+    pub fn new(compiler: Compiler) -> Self {
+        Self {
+            compiler,
+            cache: Default::default(),
+        }
+    }
+
+    /// Replaces the file with the same [`File::file_name`] in [`Compiler::files`], or adds it if there is none.
+    pub fn set_file(&mut self, file: File) -> &mut Self {
+        match self
+            .compiler
+            .files
+            .iter_mut()
+            .find(|existing| existing.file_name == file.file_name)
+        {
+            Some(existing) => *existing = file,
+            None => {
+                self.compiler.files.push(file);
+            }
+        }
+        self
+    }
+
+    /// Removes the file with the given name from [`Compiler::files`] and returns it, if there is one.
+    pub fn remove_file(&mut self, file_name: &str) -> Option<File> {
+        let index = self
+            .compiler
+            .files
+            .iter()
+            .position(|file| file.file_name == file_name)?;
+        Some(self.compiler.files.remove(index))
+    }
+
+    /// Compiles the files of [`CompilerSession::compiler`], reusing what can be reused from the last compilation.
+    pub fn compile(&mut self) -> Result<Compilation> {
+        super::run_compilation::compile_incrementally(&self.compiler, &mut self.cache)
+    }
+
+    /// The names of the files that had to be parsed again during the last call to [`CompilerSession::compile`],
+    /// in the order they were parsed in.
+    pub fn last_parsed_files(&self) -> &[String] {
+        &self.cache.parsed_files
+    }
+
+    /// Forgets the results of the last compilation, so that the next one processes all files.
+    pub fn clear_cache(&mut self) {
+        self.cache = Default::default();
+    }
+}
+
+/// Everything a compilation remembers for the next one.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CompilationCache {
+    /// The variable declarations known before any file was analysed.
+    pub(crate) initial_declarations: Vec<Declaration>,
+    /// The nodes that were tracked when code generation started.
+    pub(crate) tracking_nodes: HashSet<String>,
+    pub(crate) files: Vec<FileCache>,
+    pub(crate) parsed_files: Vec<String>,
+}
+
+/// The outputs of the compilation steps for a single file. A step's output is [`None`] if the step did not run.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FileCache {
+    pub(crate) file_name: String,
+    pub(crate) content_hash: u64,
+    pub(crate) parse_diagnostics: Option<Vec<Diagnostic>>,
+    pub(crate) strings: Option<StringsOutput>,
+    pub(crate) node_titles: Option<Vec<(String, Diagnostic)>>,
+    pub(crate) jump_targets: Option<Vec<JumpTarget>>,
+    pub(crate) node_infos: Option<Vec<NodeInfo>>,
+    pub(crate) declarations: Option<DeclarationsOutput>,
+    pub(crate) types: Option<TypesOutput>,
+    pub(crate) tracking: Option<TrackingOutput>,
+    pub(crate) code: Option<CodeOutput>,
+}
+
+impl FileCache {
+    fn new(file: &File) -> Self {
+        let mut hasher = DefaultHasher::new();
+        file.source.hash(&mut hasher);
+        Self {
+            file_name: file.file_name.clone(),
+            content_hash: hasher.finish(),
+            ..Default::default()
+        }
+    }
+
+    fn is_same_file(&self, other: &Self) -> bool {
+        self.file_name == other.file_name && self.content_hash == other.content_hash
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct StringsOutput {
+    /// The entries the file added to the string table or changed in it.
+    pub(crate) new_strings: HashMap<LineId, StringInfo>,
+    pub(crate) diagnostics: Vec<Diagnostic>,
+}
+
+impl StringsOutput {
+    /// Whether both outputs added the same line IDs. Only these influence how the strings of later files are registered.
+    pub(crate) fn has_same_line_ids(&self, other: &Self) -> bool {
+        self.new_strings.len() == other.new_strings.len()
+            && self
+                .new_strings
+                .keys()
+                .all(|line_id| other.new_strings.contains_key(line_id))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct DeclarationsOutput {
+    pub(crate) new_declarations: Vec<Declaration>,
+    pub(crate) file_tags: Vec<String>,
+    pub(crate) diagnostics: Vec<Diagnostic>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TypesOutput {
+    pub(crate) new_declarations: Vec<Declaration>,
+    pub(crate) deferred_types: Vec<DeferredTypeDiagnostic>,
+    pub(crate) known_types: KnownTypes,
+    pub(crate) diagnostics: Vec<Diagnostic>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TrackingOutput {
+    pub(crate) tracking_nodes: HashSet<String>,
+    pub(crate) ignoring_nodes: HashSet<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct CodeOutput {
+    /// The compiled file, containing only its program, warnings and debug info.
+    pub(crate) result: Result<Compilation>,
+    /// The nodes code generation started tracking while compiling the file.
+    pub(crate) new_tracking_nodes: HashSet<String>,
+}
+
+/// The caches of the previous and the current compilation while compiling.
+#[derive(Debug, Default)]
+pub(crate) struct IncrementalCompilation {
+    previous: CompilationCache,
+    pub(crate) next: CompilationCache,
+    /// Whether each file is the same as in the previous compilation, and so were its outputs in all steps so far.
+    unchanged_files: Vec<bool>,
+}
+
+impl IncrementalCompilation {
+    pub(crate) fn new(previous: CompilationCache, files: &[File]) -> Self {
+        let next_files: Vec<_> = files.iter().map(FileCache::new).collect();
+        let unchanged_files = next_files
+            .iter()
+            .enumerate()
+            .map(|(index, file)| {
+                previous
+                    .files
+                    .get(index)
+                    .is_some_and(|previous| previous.is_same_file(file))
+            })
+            .collect();
+        Self {
+            previous,
+            next: CompilationCache {
+                files: next_files,
+                ..Default::default()
+            },
+            unchanged_files,
+        }
+    }
+
+    /// The cache of the file at the same position in the previous compilation, which may have been a different file.
+    pub(crate) fn previous(&self, index: usize) -> Option<&FileCache> {
+        self.previous.files.get(index)
+    }
+
+    pub(crate) fn previous_initial_declarations(&self) -> &[Declaration] {
+        &self.previous.initial_declarations
+    }
+
+    pub(crate) fn previous_tracking_nodes(&self) -> &HashSet<String> {
+        &self.previous.tracking_nodes
+    }
+
+    /// Returns the output of a step for the file at `index` from the previous compilation,
+    /// if neither the file nor the inputs of the step coming from other files changed since.
+    pub(crate) fn reusable<T: Clone>(
+        &self,
+        index: usize,
+        inputs_unchanged: bool,
+        output: impl Fn(&FileCache) -> Option<&T>,
+    ) -> Option<T> {
+        if !inputs_unchanged || !self.unchanged_files[index] {
+            return None;
+        }
+        output(&self.previous.files[index]).cloned()
+    }
+
+    /// Whether [`IncrementalCompilation::reusable`] would return an output, without cloning it.
+    pub(crate) fn is_reusable(
+        &self,
+        index: usize,
+        inputs_unchanged: bool,
+        has_output: impl Fn(&FileCache) -> bool,
+    ) -> bool {
+        inputs_unchanged && self.unchanged_files[index] && has_output(&self.previous.files[index])
+    }
+
+    /// Stores the output of a step for the file at `index`. If it differs from the previous compilation,
+    /// later steps will process the file again.
+    pub(crate) fn record<T: PartialEq>(
+        &mut self,
+        index: usize,
+        output: impl Fn(&mut FileCache) -> &mut Option<T>,
+        value: T,
+    ) {
+        let unchanged = self
+            .previous
+            .files
+            .get_mut(index)
+            .is_some_and(|previous| output(previous).as_ref() == Some(&value));
+        if !unchanged {
+            self.unchanged_files[index] = false;
+        }
+        *output(&mut self.next.files[index]) = Some(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const START: &str = "title: Start
+---
+<<declare $gold = 5>>
+Hello! #line:hello
+<<jump Other>>
+===";
+
+    const OTHER: &str = "title: Other
+---
+You have {$gold} gold.
+<<if visited(\"Start\")>>
+    Been there.
+<<endif>>
+===";
+
+    fn file(file_name: &str, source: &str) -> File {
+        File {
+            file_name: file_name.to_owned(),
+            source: source.to_owned(),
+        }
+    }
+
+    fn session() -> CompilerSession {
+        let mut compiler = Compiler::new();
+        compiler
+            .add_file(file("start.yarn", START))
+            .add_file(file("other.yarn", OTHER));
+        let mut session = CompilerSession::new(compiler);
+        session.compile().unwrap();
+        session
+    }
+
+    fn assert_same_as_cold_compilation(session: &mut CompilerSession) {
+        let result = session.compile();
+        assert_eq!(session.compiler.compile(), result);
+    }
+
+    #[test]
+    fn parses_all_files_on_first_compilation() {
+        let session = session();
+        assert_eq!(["start.yarn", "other.yarn"], session.last_parsed_files());
+    }
+
+    #[test]
+    fn parses_nothing_if_nothing_changed() {
+        let mut session = session();
+        assert_same_as_cold_compilation(&mut session);
+        assert!(session.last_parsed_files().is_empty());
+    }
+
+    #[test]
+    fn only_parses_files_with_changed_text() {
+        let mut session = session();
+        session.set_file(file("start.yarn", &START.replace("Hello!", "Hi!")));
+
+        assert_same_as_cold_compilation(&mut session);
+        assert_eq!(["start.yarn"], session.last_parsed_files());
+    }
+
+    #[test]
+    fn parses_files_depending_on_changed_declarations() {
+        let mut session = session();
+        session.set_file(file("start.yarn", &START.replace("= 5", "= 10")));
+
+        assert_same_as_cold_compilation(&mut session);
+        assert_eq!(["start.yarn", "other.yarn"], session.last_parsed_files());
+    }
+
+    #[test]
+    fn parses_files_whose_generated_line_ids_change() {
+        let mut session = session();
+        session.set_file(file(
+            "start.yarn",
+            &START.replace("Hello! #line:hello", "Hello! #line:hello\nNo ID here."),
+        ));
+
+        assert_same_as_cold_compilation(&mut session);
+        assert_eq!(["start.yarn", "other.yarn"], session.last_parsed_files());
+    }
+
+    #[test]
+    fn recovers_from_errors() {
+        let mut session = session();
+        session.set_file(file("other.yarn", &OTHER.replace("<<endif>>", "")));
+        assert_same_as_cold_compilation(&mut session);
+        assert!(session.compile().is_err());
+
+        session.set_file(file("other.yarn", OTHER));
+        assert_same_as_cold_compilation(&mut session);
+        assert!(session.compile().is_ok());
+    }
+
+    #[test]
+    fn handles_added_and_removed_files() {
+        let mut session = session();
+        let removed = session.remove_file("start.yarn").unwrap();
+        assert_eq!(START, removed.source);
+        assert_same_as_cold_compilation(&mut session);
+
+        session.set_file(removed);
+        assert_same_as_cold_compilation(&mut session);
+        assert_eq!(2, session.compiler.files.len());
+    }
+}
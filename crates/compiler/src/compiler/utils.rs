@@ -1,76 +1,43 @@
 //! Contains functions that were originally part of `compiler.rs` according to the original implementation,
 //! but were moved to their own file for better organization.
 
-use crate::error_strategy::ErrorStrategy;
-use crate::listeners::*;
-use crate::prelude::generated::yarnspinnerparser::*;
-use crate::prelude::generated::{yarnspinnerlexer, yarnspinnerparser};
 use crate::prelude::*;
-use antlr_rust::common_token_stream::CommonTokenStream;
-use antlr_rust::input_stream::CodePoint32BitCharStream;
-use antlr_rust::token::{Token, TOKEN_DEFAULT_CHANNEL};
-use antlr_rust::Parser;
 use std::collections::HashSet;
 use std::rc::Rc;
 use yarnspinner_core::prelude::*;
 use yarnspinner_core::types::FunctionType;
 
-pub(crate) fn get_line_id_tag<'a>(
-    hashtag_contexts: &[Rc<HashtagContextAll<'a>>],
-) -> Option<Rc<HashtagContextAll<'a>>> {
+pub(crate) fn get_line_id_tag(
+    hashtag_contexts: &[Rc<HashtagContext>],
+) -> Option<Rc<HashtagContext>> {
     hashtag_contexts
         .iter()
         .find(|hashtag| {
-            let hashtag_text = hashtag
+            // A hashtag without text is a syntax error, which has already been reported.
+            hashtag
                 .text
                 .as_ref()
-                .expect("Hashtag held no text")
-                .get_text();
-            hashtag_text.starts_with("line:")
+                .is_some_and(|hashtag_text| hashtag_text.text.starts_with("line:"))
         })
         .cloned()
 }
 
-pub(crate) fn parse_syntax_tree<'a, 'b: 'a>(
-    file: &'b File,
-    file_chars: &'a [u32],
+pub(crate) fn parse_syntax_tree(
+    file: &File,
+    file_chars: &[char],
     diagnostics: &mut Vec<Diagnostic>,
-) -> FileParseResult<'a> {
-    // Using 32 bit codepoints because that's how big a Rust `char` is: 4 bytes.
-    let input = CodePoint32BitCharStream::new(file_chars);
-    let mut lexer = YarnSpinnerLexer::new(input, file.file_name.clone());
+) -> FileParseResult {
+    let lexer = YarnSpinnerLexer::new(file_chars, file.file_name.clone());
+    let (tokens, lexer_diagnostics) = lexer.into_token_stream();
+    let tokens = Rc::new(tokens);
 
-    // turning off the normal error listener and using ours
-    let file_name = file.file_name.clone();
-    let lexer_error_listener = LexerErrorListener::new(file_name.clone());
-    let lexer_error_listener_diagnostics = lexer_error_listener.diagnostics.clone();
-    let lexer_diagnostics = lexer.diagnostics.clone();
-    lexer.remove_error_listeners();
-    lexer.add_error_listener(Box::new(lexer_error_listener));
+    let mut parser = YarnSpinnerParser::new(tokens.clone(), file);
+    let tree = parser.dialogue();
 
-    let tokens = CommonTokenStream::new(lexer);
-    let mut parser = YarnSpinnerParser::with_strategy(tokens, ErrorStrategy::new());
-    let parser_error_listener = ParserErrorListener::new(file.clone());
-    let parser_error_listener_diagnostics = parser_error_listener.diagnostics.clone();
+    diagnostics.extend(lexer_diagnostics);
+    diagnostics.extend(parser.diagnostics);
 
-    parser.remove_error_listeners();
-    parser.add_error_listener(Box::new(parser_error_listener));
-
-    // Must be read exactly here, because the error listeners running during the parse borrow the diagnostics mutably,
-    // and we want to read them after.
-    let tree = parser.dialogue().unwrap();
-
-    let lexer_diagnostics_borrowed = lexer_diagnostics.borrow();
-    let lexer_error_listener_diagnostics_borrowed = lexer_error_listener_diagnostics.borrow();
-    let parser_error_listener_diagnostics_borrowed = parser_error_listener_diagnostics.borrow();
-    let new_diagnostics = lexer_error_listener_diagnostics_borrowed
-        .iter()
-        .chain(lexer_diagnostics_borrowed.iter())
-        .chain(parser_error_listener_diagnostics_borrowed.iter())
-        .cloned();
-    diagnostics.extend(new_diagnostics);
-
-    FileParseResult::new(file_name, tree, Rc::new(parser))
+    FileParseResult::new(file.file_name.clone(), tree, tokens)
 }
 
 pub(crate) fn get_line_id_for_node_name(name: &str) -> LineId {
@@ -92,28 +59,22 @@ pub(crate) fn get_line_id_for_node_name(name: &str) -> LineId {
 /// ## Implementation notes
 ///
 /// The flag `allowCommentsAfter` was not ported because it was always set to `true` anyway.
-pub(crate) fn get_document_comments<'input>(
-    tokens: &ActualTokenStream<'input>,
-    context: &impl YarnSpinnerParserContext<
-        'input,
-        TF = LocalTokenFactory<'input>,
-        Ctx = YarnSpinnerParserContextType,
-    >,
+pub(crate) fn get_document_comments(
+    tokens: &TokenStream,
+    context: &(impl ParserRuleContext + ?Sized),
 ) -> String {
-    let subsequent_comments = tokens.get_hidden_tokens_to_right(
-        context.stop().get_token_index(),
-        yarnspinnerlexer::COMMENTS as isize,
-    );
+    let subsequent_comments =
+        tokens.get_hidden_tokens_to_right(context.stop().token_index, COMMENTS);
 
     let subsequent_doc_comment = subsequent_comments
         .iter()
         // This comment is on the same line as the end of
         // the declaration
-        .filter(|t| t.get_line() == context.stop().get_line())
+        .filter(|t| t.line == context.stop().line)
         // The comment starts with a triple-slash
-        .filter(|t| t.get_text().starts_with("///"))
+        .filter(|t| t.text.starts_with("///"))
         // Get its text
-        .map(|t| t.get_text().replace("///", "").trim().to_owned())
+        .map(|t| t.text.replace("///", "").trim().to_owned())
         // Get the first one, or null
         .next();
 
@@ -121,10 +82,8 @@ pub(crate) fn get_document_comments<'input>(
         return subsequent_doc_comment;
     }
 
-    let preceding_comments = tokens.get_hidden_tokens_to_left(
-        context.start().get_token_index(),
-        yarnspinnerlexer::COMMENTS as isize,
-    );
+    let preceding_comments =
+        tokens.get_hidden_tokens_to_left(context.start().token_index, COMMENTS);
 
     let preceding_doc_comments: Vec<_> = preceding_comments
         .iter()
@@ -134,59 +93,28 @@ pub(crate) fn get_document_comments<'input>(
             !tokens
                 .get_tokens()
                 .iter()
-                .filter(|ot| ot.get_line() == t.get_line())
-                .filter(|ot| {
-                    ot.get_token_type() != yarnspinnerlexer::INDENT
-                        && ot.get_token_type() != yarnspinnerlexer::DEDENT
-                })
-                .any(|ot| ot.get_channel() == TOKEN_DEFAULT_CHANNEL)
+                .filter(|ot| ot.line == t.line)
+                .filter(|ot| ot.token_type != INDENT && ot.token_type != DEDENT)
+                .any(|ot| ot.channel == DEFAULT_CHANNEL)
         })
-        .filter(|t| t.get_text().starts_with("///"))
+        .filter(|t| t.text.starts_with("///"))
         // Get its text
-        .map(|t| t.get_text().replace("///", "").trim().to_owned())
+        .map(|t| t.text.replace("///", "").trim().to_owned())
         .collect();
     preceding_doc_comments.join(" ")
 }
 
-/// Not part of original implementation, but needed because we lack some convenience methods
-/// that the C# implementation of ANTLR would provide but antlr4rust does not.
-pub(crate) fn add_hashtag_child<'input>(
-    parent: &impl YarnSpinnerParserContext<'input>,
-    text: impl Into<String>,
-) {
-    let parent = parent.ref_to_rc();
-    let string_id_token = create_common_token(yarnspinnerparser::HASHTAG_TEXT, text);
-    let invoking_state_according_to_original_implementation = 0;
-    // `new_with_text` was hacked into the generated parser. Also, `FooContextExt::new` is usually private...
-    let hashtag = HashtagContextExt::new_with_text(
-        Some(parent.clone()),
-        invoking_state_according_to_original_implementation,
-        string_id_token,
-    );
-    parent.add_child(hashtag);
-}
-
-pub(crate) trait ContextRefExt<'input> {
-    fn ref_to_rc(self) -> Rc<ActualParserContext<'input>>;
-}
-
-impl<'input, T> ContextRefExt<'input> for &T
-where
-    T: YarnSpinnerParserContext<'input> + ?Sized,
-{
-    fn ref_to_rc(self) -> Rc<ActualParserContext<'input>> {
-        self.get_children()
-            .next()
-            .map(|child| child.get_parent().unwrap())
-            .or_else(|| {
-                let interval = self.get_source_interval();
-                self.get_parent()
-                    .unwrap()
-                    .get_children()
-                    .find(|child| child.get_source_interval() == interval)
-            })
-            .unwrap()
-    }
+/// Not part of original implementation, but needed because the compiler adds hashtags to lines
+/// that were not present in the source, which the C# implementation does by adding to the parse tree.
+pub(crate) fn add_hashtag_child(parent: &impl ParserRuleContext, text: impl Into<String>) {
+    let string_id_token = Rc::new(Token::synthetic(HASHTAG_TEXT, text));
+    let mut hashtag = HashtagContext::new(string_id_token.clone());
+    hashtag.text = Some(string_id_token.clone());
+    hashtag
+        .base
+        .add_child(ParseTree::Token(string_id_token.clone()));
+    hashtag.base.set_stop(Some(string_id_token));
+    parent.base().add_child(hashtag.into());
 }
 
 /// Returns a collection of [`Declaration`] structs that
@@ -208,7 +136,7 @@ pub(crate) fn get_declarations_from_library(library: &Library) -> Vec<Declaratio
                 .collect::<Vec<_>>()
         })
         .collect();
-    library
+    let mut declarations: Vec<_> = library
         .iter()
         // Operators are type checked by visitors instead
         .filter(|(name, _function)| !operators.contains(*name))
@@ -221,11 +149,20 @@ pub(crate) fn get_declarations_from_library(library: &Library) -> Vec<Declaratio
                 .map(Some)
                 .collect();
             function_type.parameters = parameters;
+            function_type.optional_parameter_count = function.optional_parameter_count();
+            function_type.set_variadic_parameter_type(
+                function
+                    .variadic_parameter_type()
+                    .map(|t| Type::try_from(t).unwrap()),
+            );
             let return_type = Type::try_from(function.return_type()).unwrap();
             function_type.set_return_type(return_type);
             Declaration::new(name, function_type).with_source_file_name(DeclarationSource::External)
         })
-        .collect()
+        .collect();
+    // The library has no stable order, but compiler sessions compare the declarations of consecutive compilations.
+    declarations.sort_by(|a, b| a.name.cmp(&b.name));
+    declarations
 }
 
 #[cfg(test)]
@@ -244,28 +181,27 @@ mod tests {
 ==="
             .to_owned(),
         };
-        let chars: Vec<_> = mixed_indentation_input
-            .source
-            .chars()
-            .map(|c| c as u32)
-            .collect();
+        let chars: Vec<_> = mixed_indentation_input.source.chars().collect();
         let _parsed_file = parse_syntax_tree(&mixed_indentation_input, &chars, &mut diagnostics);
         assert_eq!(1, diagnostics.len());
         assert_eq!(
-            Diagnostic::from_message("Indentation contains tabs and spaces")
-                .with_context("\t   ")
-                .with_start_line(3)
-                .with_file_name("test.yarn")
-                .with_range(
-                    Position {
-                        line: 3,
-                        character: 0
-                    }..Position {
-                        line: 3,
-                        character: 5
-                    }
-                )
-                .with_severity(DiagnosticSeverity::Warning),
+            Diagnostic::new(
+                DiagnosticCode::MixedIndentation,
+                "Indentation contains tabs and spaces"
+            )
+            .with_context("\t   ")
+            .with_start_line(3)
+            .with_file_name("test.yarn")
+            .with_range(
+                Position {
+                    line: 3,
+                    character: 0
+                }..Position {
+                    line: 3,
+                    character: 5
+                }
+            )
+            .with_severity(DiagnosticSeverity::Warning),
             diagnostics[0]
         );
     }
@@ -1,6 +1,6 @@
 //! Adapted from <https://github.com/YarnSpinnerTool/YarnSpinner/blob/da39c7195107d8211f21c263e4084f773b84eaff/YarnSpinner.Compiler/FileParseResult.cs>
 
-use crate::prelude::{generated::yarnspinnerparser::*, *};
+use crate::prelude::*;
 use std::rc::Rc;
 
 /// Contains the result of parsing a single file of source code.
@@ -8,29 +8,21 @@ use std::rc::Rc;
 /// This class provides only syntactic information about a parse - that is,
 /// it provides access to the parse tree, and the stream of tokens used to
 /// produce that parse tree.
-#[derive(Clone)]
-pub(crate) struct FileParseResult<'input> {
+#[derive(Debug, Clone)]
+pub(crate) struct FileParseResult {
     pub name: String,
 
-    pub tree: Rc<DialogueContextAll<'input>>,
+    pub tree: Rc<DialogueContext>,
 
-    /// This was not in the original, but in Rust we need to actually store
-    /// the parser itself somewhere, which is why we store it here.
-    /// We also end up leading the `ErrorStrategy` into the public interface, but using generics here makes
-    /// the code a lot more complicated without actually providing much benefit.
-    pub parser: Rc<ActualYarnSpinnerParser<'input>>,
+    pub tokens: Rc<TokenStream>,
 }
 
-impl<'input> FileParseResult<'input> {
-    pub(crate) fn new(
-        name: String,
-        tree: Rc<DialogueContextAll<'input>>,
-        parser: Rc<ActualYarnSpinnerParser<'input>>,
-    ) -> Self {
-        Self { name, tree, parser }
+impl FileParseResult {
+    pub(crate) fn new(name: String, tree: Rc<DialogueContext>, tokens: Rc<TokenStream>) -> Self {
+        Self { name, tree, tokens }
     }
 
-    pub(crate) fn tokens(&self) -> &ActualTokenStream<'input> {
-        &self.parser.input
+    pub(crate) fn tokens(&self) -> &TokenStream {
+        &self.tokens
     }
 }
@@ -0,0 +1,39 @@
+//! Formats Yarn source code in a canonical style, so that diffs of dialogue only show changes to the dialogue
+//! and not the personal style of whoever wrote it. The formatting is:
+//! - Nodes are separated by a single blank line, and the tags of the file by a blank line from the first node.
+//! - The headers and comments of a node keep their order. Headers are written as `key: value`.
+//! - The contents of options, `<<if>>` and `<<enum>>` blocks are indented by four spaces per level.
+//! - Blank lines at the start and the end of a node are removed, as are repeated blank lines that do not end an option body.
+//! - Expressions, both in `{braces}` and in commands like `<<set>>` and `<<if>>`, have single spaces between their tokens.
+//! - The condition, hashtags and comment of a line are separated from its text and from each other by single spaces.
+//!
+//! Everything the game sees stays as it is, which includes the text of lines and of generic commands
+//! like `<<walk Sally  left>>`. Comments and `#line:` tags are kept. Formatting an already formatted file does not change it.
+//!
+//! ## Implementation notes
+//!
+//! Formatting compiles to the same program, including the order of the headers of each node.
+//! The file is parsed first to only format files without errors, since the indentation of a file with errors
+//! might not mean what its author intended. The formatting itself works on the lines of the file
+//! rather than the parse tree, because the parse tree does not contain comments and whitespace.
+
+use crate::prelude::*;
+
+mod expression;
+mod layout;
+mod statement;
+
+/// Formats the source code of a Yarn file. See the [module documentation](self) for the rules.
+///
+/// ## Errors
+///
+/// Returns the diagnostics of the parser if the file has syntax errors. The diagnostics refer to the [`File::file_name`].
+pub fn format_file(file: &File) -> crate::Result<String> {
+    let chars: Vec<_> = file.source.chars().collect();
+    let mut diagnostics = Vec::new();
+    parse_syntax_tree(file, &chars, &mut diagnostics);
+    if diagnostics.has_errors() {
+        return Err(CompilerError(diagnostics));
+    }
+    Ok(layout::format_lines(&file.source))
+}
@@ -0,0 +1,186 @@
+//! Spacing of the expressions inside `{braces}` and `<<commands>>`.
+
+/// Words that act as operators, so an opening parenthesis after them is not a function call.
+const WORD_OPERATORS: &[&str] = &[
+    "and", "or", "xor", "not", "is", "eq", "neq", "lt", "lte", "gt", "gte", "to", "as",
+];
+
+/// Operators made of two characters, which must be matched before the single character ones.
+const LONG_OPERATORS: &[&str] = &[
+    "==", "!=", "<=", ">=", "&&", "||", "+=", "-=", "*=", "/=", "%=",
+];
+
+const SHORT_OPERATORS: &str = "<>=!+-*/%^";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token<'a> {
+    /// Identifiers, variables, numbers and keywords. Dots directly next to them are part of the word,
+    /// because the lexer only joins names like `Math.floor` when there is no whitespace around the dot.
+    Word(&'a str),
+    String(&'a str),
+    Operator(&'a str),
+    /// A dot with whitespace around it.
+    Dot,
+    OpenParenthesis,
+    CloseParenthesis,
+    Comma,
+}
+
+/// Formats an expression with single spaces between its tokens, except inside parentheses, before commas,
+/// between a function name and its arguments and after unary operators.
+///
+/// Returns the trimmed expression as it is if it contains anything that is not understood,
+/// so that formatting never changes what an expression means.
+pub(super) fn format_expression(expression: &str) -> String {
+    let Some(tokens) = tokenize(expression) else {
+        return expression.trim_matches([' ', '\t']).to_owned();
+    };
+    let mut formatted = String::new();
+    let mut previous: Option<Token> = None;
+    let mut previous_is_unary = false;
+    for token in tokens {
+        if let Some(previous) = previous {
+            if needs_space(previous, previous_is_unary, token) {
+                formatted.push(' ');
+            }
+        }
+        previous_is_unary = matches!(token, Token::Operator("-" | "!")) && starts_operand(previous);
+        formatted.push_str(match token {
+            Token::Word(text) | Token::String(text) | Token::Operator(text) => text,
+            Token::Dot => ".",
+            Token::OpenParenthesis => "(",
+            Token::CloseParenthesis => ")",
+            Token::Comma => ",",
+        });
+        previous = Some(token);
+    }
+    formatted
+}
+
+fn tokenize(expression: &str) -> Option<Vec<Token<'_>>> {
+    let mut tokens = Vec::new();
+    let mut rest = expression;
+    loop {
+        rest = rest.trim_start_matches([' ', '\t']);
+        let Some(first) = rest.chars().next() else {
+            return Some(tokens);
+        };
+        let length = match first {
+            '"' => {
+                let length = string_length(rest)?;
+                tokens.push(Token::String(&rest[..length]));
+                length
+            }
+            '(' => {
+                tokens.push(Token::OpenParenthesis);
+                1
+            }
+            ')' => {
+                tokens.push(Token::CloseParenthesis);
+                1
+            }
+            ',' => {
+                tokens.push(Token::Comma);
+                1
+            }
+            '.' if !rest[1..].starts_with(is_word_character) => {
+                tokens.push(Token::Dot);
+                1
+            }
+            _ if first == '$' || first == '.' || is_word_character(first) => {
+                let length = first.len_utf8()
+                    + rest[first.len_utf8()..]
+                        .find(|c: char| !is_word_character(c) && c != '.')
+                        .unwrap_or(rest.len() - first.len_utf8());
+                tokens.push(Token::Word(&rest[..length]));
+                length
+            }
+            _ => {
+                let operator = LONG_OPERATORS
+                    .iter()
+                    .copied()
+                    .find(|operator| rest.starts_with(operator))
+                    .or_else(|| SHORT_OPERATORS.contains(first).then(|| &rest[..1]))?;
+                tokens.push(Token::Operator(operator));
+                operator.len()
+            }
+        };
+        rest = &rest[length..];
+    }
+}
+
+/// The length of the string literal at the start of `text`, including its quotes.
+fn string_length(text: &str) -> Option<usize> {
+    let mut is_escaped = false;
+    for (index, character) in text.char_indices().skip(1) {
+        match character {
+            _ if is_escaped => is_escaped = false,
+            '\\' => is_escaped = true,
+            '"' => return Some(index + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn is_word_character(character: char) -> bool {
+    character.is_alphanumeric() || character == '_'
+}
+
+/// Whether an operator following `previous` is a unary operator, i.e. starts an operand instead of combining two.
+fn starts_operand(previous: Option<Token>) -> bool {
+    match previous {
+        None | Some(Token::Operator(_) | Token::OpenParenthesis | Token::Comma) => true,
+        Some(Token::Word(word)) => WORD_OPERATORS.contains(&word),
+        Some(_) => false,
+    }
+}
+
+fn needs_space(previous: Token, previous_is_unary: bool, next: Token) -> bool {
+    match (previous, next) {
+        // Keeps operators like `! =` from being joined into a different operator.
+        (Token::Operator(_), Token::Operator(operator)) if operator.starts_with('=') => true,
+        _ if previous_is_unary => false,
+        (Token::OpenParenthesis, _) | (_, Token::CloseParenthesis | Token::Comma) => false,
+        (Token::Word(word), Token::OpenParenthesis) => WORD_OPERATORS.contains(&word),
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spaces_operators() {
+        assert_eq!("$gold + 1 >= 10", format_expression("$gold+1>=10"));
+        assert_eq!("$a and not $b", format_expression("  $a  and not   $b "));
+        assert_eq!("$name == \"a  b\"", format_expression("$name==\"a  b\""));
+    }
+
+    #[test]
+    fn keeps_function_calls_and_unary_operators_together() {
+        assert_eq!(
+            "max(-1, visited(\"Start\")) * -$x",
+            format_expression("max ( - 1 ,visited( \"Start\" ) )*- $x")
+        );
+        assert_eq!(
+            "!$done or ($a - -1)",
+            format_expression("! $done or( $a - - 1 )")
+        );
+        assert_eq!("$x to (1 + 2)", format_expression("$x to(1+2)"));
+    }
+
+    #[test]
+    fn keeps_whitespace_around_dots() {
+        assert_eq!("Math.floor(1.5)", format_expression("Math.floor (1.5)"));
+        assert_eq!("Food . Apple", format_expression("Food  .  Apple"));
+        assert_eq!("Food .Apple", format_expression("Food .Apple"));
+    }
+
+    #[test]
+    fn keeps_unknown_expressions_as_they_are() {
+        assert_eq!("$a ? b : c", format_expression(" $a ? b : c "));
+        assert_eq!("\"unterminated", format_expression("\"unterminated"));
+    }
+}
@@ -0,0 +1,371 @@
+//! The layout of a Yarn file: the headers, the blank lines between nodes and the indentation of node bodies.
+
+use super::statement::{format_command_line, format_line_statement, Keyword};
+
+const INDENTATION: &str = "    ";
+const WHITESPACE: [char; 2] = [' ', '\t'];
+
+/// Formats the lines of a Yarn file. Expects a file that parses without errors,
+/// but never fails: lines that are not understood are kept as they are, without surrounding whitespace.
+pub(super) fn format_lines(source: &str) -> String {
+    let mut output: Vec<String> = Vec::new();
+    let mut lines = source.lines();
+    let mut is_first_node = true;
+    loop {
+        let mut header_lines = Vec::new();
+        let mut body_start = None;
+        for line in lines.by_ref() {
+            let content = line.trim_matches(WHITESPACE);
+            if content.starts_with("---") {
+                body_start = Some(content);
+                break;
+            }
+            if !content.is_empty() {
+                header_lines.push(HeaderLine::parse(line));
+            }
+        }
+        let Some(body_start) = body_start else {
+            // Comments after the last node.
+            if !header_lines.is_empty() && !output.is_empty() {
+                output.push(String::new());
+            }
+            output.extend(header_lines.iter().map(HeaderLine::to_string));
+            break;
+        };
+
+        write_headers(&mut output, header_lines, is_first_node);
+        is_first_node = false;
+        output.push(body_start.to_owned());
+
+        let mut body = Body::default();
+        let mut body_end = None;
+        for line in lines.by_ref() {
+            let content = line.trim_matches(WHITESPACE);
+            if content.starts_with("===") {
+                body_end = Some(content);
+                break;
+            }
+            body.push_line(line);
+        }
+        output.extend(body.finish());
+        match body_end {
+            Some(body_end) => output.push(body_end.to_owned()),
+            None => break,
+        }
+    }
+
+    let mut formatted = output.join("\n");
+    formatted.push('\n');
+    formatted
+}
+
+/// Writes the headers and comments of a node in their original order.
+///
+/// The tags of the file, i.e. the lines starting with `#` before the first node, are separated from it by a blank line.
+fn write_headers(output: &mut Vec<String>, header_lines: Vec<HeaderLine>, is_first_node: bool) {
+    let file_lines = if is_first_node {
+        let first_header = header_lines
+            .iter()
+            .position(|line| matches!(line, HeaderLine::Header { .. }))
+            .unwrap_or(header_lines.len());
+        header_lines[..first_header]
+            .iter()
+            .rposition(|line| matches!(line, HeaderLine::Other(text) if text.starts_with('#')))
+            .map_or(0, |last_file_tag| last_file_tag + 1)
+    } else {
+        0
+    };
+    let mut header_lines = header_lines.iter().map(HeaderLine::to_string);
+    output.extend(header_lines.by_ref().take(file_lines));
+    if !output.is_empty() {
+        output.push(String::new());
+    }
+    output.extend(header_lines);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum HeaderLine {
+    /// A header is written as `key: value`. The value is kept as it is, including trailing whitespace,
+    /// since everything after the `:` and the spaces following it is part of the value.
+    Header { key: String, value: String },
+    /// Comments, the tags of the file and anything else that is not a header.
+    Other(String),
+}
+
+impl HeaderLine {
+    fn parse(line: &str) -> Self {
+        let content = line.trim_start_matches(WHITESPACE);
+        if !content.starts_with("//") && !content.starts_with('#') {
+            if let Some((key, value)) = content.split_once(':') {
+                let key = key.trim_end_matches(WHITESPACE);
+                if !key.is_empty()
+                    && key
+                        .chars()
+                        .all(|character| character.is_alphanumeric() || "_.".contains(character))
+                {
+                    return HeaderLine::Header {
+                        key: key.to_owned(),
+                        value: value.trim_start_matches(' ').to_owned(),
+                    };
+                }
+            }
+        }
+        if content.starts_with('#') {
+            // Trailing whitespace is part of a file tag.
+            HeaderLine::Other(content.to_owned())
+        } else {
+            HeaderLine::Other(content.trim_end_matches(WHITESPACE).to_owned())
+        }
+    }
+}
+
+impl std::fmt::Display for HeaderLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HeaderLine::Header { key, value } if value.is_empty() => write!(f, "{key}:"),
+            HeaderLine::Header { key, value } => write!(f, "{key}: {value}"),
+            HeaderLine::Other(text) => f.write_str(text),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Block {
+    Option,
+    If,
+    Enum,
+}
+
+/// Formats the body of a node line by line.
+///
+/// ## Implementation notes
+///
+/// Which lines belong to an option is decided by the indentation the lines were written with,
+/// so the body is run through the same rules as the `IndentAwareYarnSpinnerLexer`: after an option,
+/// a more indented line starts the option's body, and a line less indented than the body ends it.
+/// Every line is then written with four spaces per block it is in, so the lexer finds the same
+/// option bodies in the formatted body as in the original one.
+///
+/// Blank lines are significant as well, since one directly after an option ends the group of options.
+/// They are kept, but runs of them are collapsed if they do not change the lexer's state.
+#[derive(Debug, Default)]
+struct Body {
+    lines: Vec<String>,
+    /// The indentation of the option bodies the lexer is in, innermost last.
+    option_indentation: Vec<usize>,
+    last_indentation: usize,
+    previous_line_was_option: bool,
+    blocks: Vec<Block>,
+    /// The number of option bodies the lexer was in after the last line, if that line was blank.
+    last_blank_line: Option<usize>,
+}
+
+impl Body {
+    fn push_line(&mut self, line: &str) {
+        self.track_indentation(indentation(line));
+        let content = line.trim_matches(WHITESPACE);
+        if content.is_empty() {
+            self.push_blank_line();
+            return;
+        }
+
+        let depth = self.blocks.len();
+        let (depth, text) = if content.starts_with("//") {
+            (depth, content.to_owned())
+        } else if let Some(option) = content.strip_prefix("->") {
+            self.previous_line_was_option = true;
+            let text = format_line_statement(option);
+            (depth, format!("-> {text}").trim_end().to_owned())
+        } else if content.starts_with("<<") {
+            let (text, keywords) = format_command_line(content);
+            (self.track_blocks(&keywords), text)
+        } else {
+            (depth, format_line_statement(content))
+        };
+        self.lines
+            .push(format!("{}{text}", INDENTATION.repeat(depth)));
+        self.last_blank_line = None;
+    }
+
+    /// Updates the option bodies the lexer is in, as it does at the start of each line.
+    fn track_indentation(&mut self, indentation: usize) {
+        if std::mem::take(&mut self.previous_line_was_option) && indentation > self.last_indentation
+        {
+            self.option_indentation.push(indentation);
+            self.blocks.push(Block::Option);
+        }
+        while self
+            .option_indentation
+            .last()
+            .is_some_and(|&top| indentation < top)
+        {
+            self.option_indentation.pop();
+            while let Some(block) = self.blocks.pop() {
+                if block == Block::Option {
+                    break;
+                }
+            }
+        }
+        self.last_indentation = indentation;
+    }
+
+    /// Opens and closes the blocks of the commands on a line. Returns how deep the line itself is indented,
+    /// which is one level less than its contents for `<<else>>`, `<<elseif>>`, `<<endif>>` and `<<endenum>>`.
+    fn track_blocks(&mut self, keywords: &[Keyword]) -> usize {
+        let mut depth = self.blocks.len();
+        let closed_block = match keywords.first() {
+            Some(Keyword::ElseIf | Keyword::Else | Keyword::EndIf) => Some(Block::If),
+            Some(Keyword::EndEnum) => Some(Block::Enum),
+            _ => None,
+        };
+        if closed_block.is_some() && self.blocks.last() == closed_block.as_ref() {
+            depth -= 1;
+        }
+        for keyword in keywords {
+            match keyword {
+                Keyword::If => self.blocks.push(Block::If),
+                Keyword::Enum => self.blocks.push(Block::Enum),
+                Keyword::EndIf if self.blocks.last() == Some(&Block::If) => {
+                    self.blocks.pop();
+                }
+                Keyword::EndEnum if self.blocks.last() == Some(&Block::Enum) => {
+                    self.blocks.pop();
+                }
+                _ => {}
+            }
+        }
+        depth
+    }
+
+    /// Blank lines are indented like the body of the innermost option they are in, which keeps that body open.
+    fn push_blank_line(&mut self) {
+        let option_depth = self.option_indentation.len();
+        if self.lines.is_empty() || self.last_blank_line == Some(option_depth) {
+            return;
+        }
+        let depth = self
+            .blocks
+            .iter()
+            .rposition(|block| *block == Block::Option)
+            .map_or(0, |index| index + 1);
+        self.lines.push(INDENTATION.repeat(depth));
+        self.last_blank_line = Some(option_depth);
+    }
+
+    fn finish(mut self) -> Vec<String> {
+        while self
+            .lines
+            .last()
+            .is_some_and(|line| line.trim_matches(WHITESPACE).is_empty())
+        {
+            self.lines.pop();
+        }
+        self.lines
+    }
+}
+
+/// The indentation of a line as the lexer counts it, where a tab counts as 8 spaces.
+fn indentation(line: &str) -> usize {
+    line.chars()
+        .map_while(|character| match character {
+            ' ' => Some(1),
+            '\t' => Some(8),
+            _ => None,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_header_order_and_separates_nodes() {
+        let source = "#file_tag\n// about Start\ntags:  a b\ntitle:Start\n---\nHi\n===\n\n\n\ntitle: Other\n// comment\nposition: 1,2\n---\nBye\n===\n// the end\n";
+        let expected = "#file_tag\n\n// about Start\ntags: a b\ntitle: Start\n---\nHi\n===\n\ntitle: Other\n// comment\nposition: 1,2\n---\nBye\n===\n\n// the end\n";
+
+        assert_eq!(expected, format_lines(source));
+    }
+
+    #[test]
+    fn indents_options_and_blocks() {
+        let source = "title: Start
+---
+<<if $a>>
+Hello {$name}!   #line:1
+  -> Yes<<if $b>>#line:2
+   Nice.
+  -> No
+<<else>>
+       <<set $a to true>>
+<<endif>>
+===
+";
+        let expected = "title: Start
+---
+<<if $a>>
+    Hello {$name}! #line:1
+    -> Yes <<if $b>> #line:2
+        Nice.
+    -> No
+<<else>>
+    <<set $a to true>>
+<<endif>>
+===
+";
+
+        assert_eq!(expected, format_lines(source));
+    }
+
+    #[test]
+    fn keeps_blank_lines_that_end_option_bodies() {
+        let source = "title: Start
+---
+
+-> Option 1
+\tNice.
+-> Option 2
+\tNicer
+\t
+\tStill part of option 2
+
+
+  Not part of option 2
+  \t
+
+
+===
+";
+        let expected = "title: Start
+---
+-> Option 1
+    Nice.
+-> Option 2
+    Nicer
+    
+    Still part of option 2
+
+Not part of option 2
+===
+";
+
+        assert_eq!(expected, format_lines(source));
+    }
+
+    #[test]
+    fn keeps_text_and_generic_commands_as_they_are() {
+        let source = "title: Start\n---\n  Walk  \\{to} the   [b]door[/b]. // comment  \n<<walk  Sally {$x+1} \"to  there\">>#tag\n<<jump {$next}>>\n===\n";
+        let expected = "title: Start\n---\nWalk  \\{to} the   [b]door[/b]. // comment\n<<walk  Sally {$x + 1} \"to  there\">> #tag\n<<jump {$next}>>\n===\n";
+
+        assert_eq!(expected, format_lines(source));
+    }
+
+    #[test]
+    fn is_idempotent() {
+        let source = "title: Start\n---\n-> A\n        -> B\n                Deep\n\n    Back in A\n<<if true>> <<set $x=1>>\n===\n";
+
+        let formatted = format_lines(source);
+
+        assert_eq!(formatted, format_lines(&formatted));
+    }
+}
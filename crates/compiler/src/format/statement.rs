@@ -0,0 +1,219 @@
+//! Formatting of a single line of a node's body: lines of dialogue, options and commands.
+
+use super::expression::format_expression;
+
+const WHITESPACE: [char; 2] = [' ', '\t'];
+
+/// A command that opens or closes a block, which decides how the lines around it are indented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Keyword {
+    If,
+    ElseIf,
+    Else,
+    EndIf,
+    Enum,
+    EndEnum,
+    Other,
+}
+
+/// Formats a line of dialogue: its text with formatted `{expressions}`, followed by its condition,
+/// hashtags and comment, separated by single spaces.
+///
+/// The text itself is kept as it is, since its whitespace is part of what the player sees.
+pub(super) fn format_line_statement(line: &str) -> String {
+    let (text, rest) = split_text(line.trim_start_matches(WHITESPACE));
+    let mut parts = Vec::new();
+    if !text.is_empty() {
+        parts.push(text);
+    }
+    let mut rest = rest;
+    loop {
+        rest = rest.trim_start_matches(WHITESPACE);
+        if rest.is_empty() {
+            break;
+        }
+        if rest.starts_with("<<") {
+            if let Some((command, _, remaining)) = format_command(rest) {
+                parts.push(command);
+                rest = remaining;
+                continue;
+            }
+        } else if rest.starts_with('#') {
+            let end = rest.find(WHITESPACE).unwrap_or(rest.len());
+            parts.push(rest[..end].to_owned());
+            rest = &rest[end..];
+            continue;
+        }
+        // Comments, and anything that is not understood, run until the end of the line.
+        parts.push(rest.trim_end_matches(WHITESPACE).to_owned());
+        break;
+    }
+    parts.join(" ")
+}
+
+/// Formats a line starting with one or more commands. Returns the formatted line and the keywords of its commands.
+pub(super) fn format_command_line(line: &str) -> (String, Vec<Keyword>) {
+    let mut parts = Vec::new();
+    let mut keywords = Vec::new();
+    let mut rest = line;
+    while let Some((command, keyword, remaining)) = format_command(rest) {
+        parts.push(command);
+        keywords.push(keyword);
+        rest = remaining.trim_start_matches(WHITESPACE);
+    }
+    if !rest.is_empty() {
+        parts.push(format_line_statement(rest));
+    }
+    (parts.join(" "), keywords)
+}
+
+/// Splits a line into its text and whatever follows it, i.e. a condition, hashtags or a comment.
+fn split_text(line: &str) -> (String, &str) {
+    let mut text = String::new();
+    let mut rest = line;
+    while let Some(character) = rest.chars().next() {
+        let length = match character {
+            '\\' => character.len_utf8() + rest[1..].chars().next().map_or(0, char::len_utf8),
+            '{' => match expression_end(&rest[1..]) {
+                Some(end) => {
+                    text.push('{');
+                    text.push_str(&format_expression(&rest[1..end + 1]));
+                    text.push('}');
+                    rest = &rest[end + 2..];
+                    continue;
+                }
+                None => rest.len(),
+            },
+            '#' => break,
+            '<' if rest.starts_with("<<") => break,
+            '/' if rest.starts_with("//") && (text.is_empty() || text.ends_with(WHITESPACE)) => {
+                break
+            }
+            _ => character.len_utf8(),
+        };
+        text.push_str(&rest[..length]);
+        rest = &rest[length..];
+    }
+    (text.trim_end_matches(WHITESPACE).to_owned(), rest)
+}
+
+/// Formats the command at the start of `text`. Returns the formatted command, its keyword and the text after it,
+/// or `None` if `text` does not start with a complete command.
+fn format_command(text: &str) -> Option<(String, Keyword, &str)> {
+    let inner = text.strip_prefix("<<")?;
+    let body = inner.trim_start_matches(WHITESPACE);
+    let word_length = body
+        .find(|character: char| !(character.is_alphanumeric() || character == '_'))
+        .unwrap_or(body.len());
+    let (word, after_word) = body.split_at(word_length);
+    let keyword = match word {
+        "if" => Keyword::If,
+        "elseif" => Keyword::ElseIf,
+        "else" => Keyword::Else,
+        "endif" => Keyword::EndIf,
+        "enum" => Keyword::Enum,
+        "endenum" => Keyword::EndEnum,
+        _ => Keyword::Other,
+    };
+    match word {
+        "else" | "endif" | "endenum" => {
+            if let Some(rest) = after_word.trim_start_matches(WHITESPACE).strip_prefix(">>") {
+                return Some((format!("<<{word}>>"), keyword, rest));
+            }
+        }
+        // These keywords are only recognized when followed by whitespace, e.g. `<<if(true)>>` is a generic command.
+        "if" | "elseif" | "set" | "call" | "declare" | "case" | "local" | "jump" | "enum"
+            if after_word.starts_with(WHITESPACE) =>
+        {
+            let end = expression_command_end(after_word)?;
+            let expression = &after_word[..end];
+            let formatted = match word {
+                "jump" => format_jump_target(expression),
+                "enum" => expression.trim_matches(WHITESPACE).to_owned(),
+                _ => format_expression(expression),
+            };
+            return Some((
+                format!("<<{word} {formatted}>>"),
+                keyword,
+                &after_word[end + 2..],
+            ));
+        }
+        _ => {}
+    }
+
+    // The text of other commands is passed to the game as it is, so only the expressions in it are formatted.
+    let end = text_command_end(inner)?;
+    let mut formatted = String::from("<<");
+    let mut rest = &inner[..end];
+    while let Some(start) = rest.find('{') {
+        let Some(expression_end) = expression_end(&rest[start + 1..]) else {
+            break;
+        };
+        let expression_end = start + 1 + expression_end;
+        formatted.push_str(&rest[..=start]);
+        formatted.push_str(&format_expression(&rest[start + 1..expression_end]));
+        formatted.push('}');
+        rest = &rest[expression_end + 1..];
+    }
+    formatted.push_str(rest);
+    formatted.push_str(">>");
+    Some((formatted, Keyword::Other, &inner[end + 2..]))
+}
+
+/// A `<<jump>>` goes either to a node name or to the node named by an `{expression}`.
+fn format_jump_target(target: &str) -> String {
+    let target = target.trim_matches(WHITESPACE);
+    match target
+        .strip_prefix('{')
+        .and_then(|target| target.strip_suffix('}'))
+    {
+        Some(expression) => format!("{{{}}}", format_expression(expression)),
+        None => target.to_owned(),
+    }
+}
+
+/// The index of the `}` closing an expression, skipping over string literals.
+fn expression_end(text: &str) -> Option<usize> {
+    find_outside_of_strings(text, "}")
+}
+
+/// The index of the `>>` closing a command made of an expression, skipping over string literals.
+fn expression_command_end(text: &str) -> Option<usize> {
+    find_outside_of_strings(text, ">>")
+}
+
+/// The index of the `>>` closing a command made of text, skipping over the `{expressions}` in it.
+fn text_command_end(text: &str) -> Option<usize> {
+    let mut index = 0;
+    while index < text.len() {
+        let rest = &text[index..];
+        if rest.starts_with(">>") {
+            return Some(index);
+        }
+        if let Some(expression) = rest.strip_prefix('{') {
+            index += 1 + expression_end(expression)?;
+        }
+        index += rest.chars().next().map_or(1, char::len_utf8);
+    }
+    None
+}
+
+fn find_outside_of_strings(text: &str, pattern: &str) -> Option<usize> {
+    let mut is_in_string = false;
+    let mut is_escaped = false;
+    for (index, character) in text.char_indices() {
+        if is_in_string {
+            match character {
+                _ if is_escaped => is_escaped = false,
+                '\\' => is_escaped = true,
+                '"' => is_in_string = false,
+                _ => {}
+            }
+        } else if character == '"' {
+            is_in_string = true;
+        } else if text[index..].starts_with(pattern) {
+            return Some(index);
+        }
+    }
+    None
+}
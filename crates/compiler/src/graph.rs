@@ -0,0 +1,441 @@
+//! Builds a map of a story: which nodes lead to which, through which options, and which variables each node uses.
+//! A [`DialogueGraph`] is built from a [`Compilation`] or a [`Program`] and can be exported as
+//! - [Graphviz](https://graphviz.org/) with [`DialogueGraph::to_dot`],
+//! - [Mermaid](https://mermaid.js.org/) with [`DialogueGraph::to_mermaid`],
+//! - JSON with `DialogueGraph::to_json`, which requires the `serde` feature.
+//!
+//! ```rust
+//! # use yarnspinner_compiler::prelude::*;
+//! # use yarnspinner_compiler::graph::*;
+//! let compilation = Compiler::new()
+//!     .add_file(File {
+//!         file_name: "intro.yarn".to_owned(),
+//!         source: "title: Start\n---\n-> Go shopping\n    <<jump Shop>>\n===\ntitle: Shop\n---\n<<set $gold to 10>>\n===\n"
+//!             .to_owned(),
+//!     })
+//!     .compile()
+//!     .unwrap();
+//! let graph = DialogueGraph::from_compilation(&compilation).unwrap();
+//!
+//! assert_eq!(
+//!     vec![GraphEdge {
+//!         from: "Start".to_owned(),
+//!         to: "Shop".to_owned(),
+//!         kind: EdgeKind::Option,
+//!         label: Some("Go shopping".to_owned()),
+//!     }],
+//!     graph.edges
+//! );
+//! assert_eq!(vec!["$gold"], graph.nodes[1].writes);
+//! assert!(graph.to_dot().contains("\"Start\" -> \"Shop\" [label=\"Go shopping\"];"));
+//! ```
+//!
+//! ## Implementation notes
+//!
+//! The edges are the `<<jump>>` statements of each node, read from its compiled instructions.
+//! Jumps to a node name computed at runtime, like `<<jump {$destination}>>`, are left out since their destination isn't known.
+//! Yarn Spinner 2 has no detours: running another node always ends the current one, so every edge is a jump.
+//!
+//! A jump written in the body of an option belongs to that option, or to the innermost one if options are nested,
+//! and its edge is labelled with the option. Jumps after an option group do not belong to any of its options.
+
+use crate::prelude::*;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::ops::Range;
+
+/// The nodes of a story and the jumps between them. See the [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bevy", reflect(Debug, PartialEq, Default))]
+#[cfg_attr(
+    all(feature = "bevy", feature = "serde"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct DialogueGraph {
+    /// The nodes of the program.
+    pub nodes: Vec<GraphNode>,
+
+    /// The jumps between the nodes, grouped by the node they start in.
+    /// An edge may point to a node that is not in [`DialogueGraph::nodes`] if the program jumps to a node that doesn't exist.
+    pub edges: Vec<GraphEdge>,
+}
+
+/// A node of a [`DialogueGraph`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bevy", reflect(Debug, PartialEq, Default))]
+#[cfg_attr(
+    all(feature = "bevy", feature = "serde"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct GraphNode {
+    /// The name of the node.
+    pub name: String,
+
+    /// The tags of the node.
+    pub tags: Vec<String>,
+
+    /// The variables the node reads, sorted by name. Variables used internally by Yarn Spinner, e.g. for `visited`, are left out.
+    pub reads: Vec<String>,
+
+    /// The variables the node writes, sorted by name. Variables used internally by Yarn Spinner, e.g. for `visited`, are left out.
+    pub writes: Vec<String>,
+}
+
+/// A jump from one node of a [`DialogueGraph`] to another.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bevy", reflect(Debug, PartialEq, Default))]
+#[cfg_attr(
+    all(feature = "bevy", feature = "serde"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct GraphEdge {
+    /// The name of the node the jump is in.
+    pub from: String,
+
+    /// The name of the node the jump goes to.
+    pub to: String,
+
+    /// Whether the jump happens after choosing an option.
+    pub kind: EdgeKind,
+
+    /// For an [`EdgeKind::Option`], the text of the option, or its line ID if the text is not known.
+    pub label: Option<String>,
+}
+
+/// The kind of a [`GraphEdge`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "bevy", reflect(Debug, PartialEq, Hash, Default))]
+#[cfg_attr(
+    all(feature = "bevy", feature = "serde"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum EdgeKind {
+    /// A jump that happens regardless of the options the player chooses.
+    #[default]
+    Jump,
+
+    /// A jump in the body of an option, which only happens after the player chooses it.
+    Option,
+}
+
+impl DialogueGraph {
+    /// Builds the graph of [`Compilation::program`], labelling options with their text from [`Compilation::string_table`].
+    /// The nodes and their edges are in the order of [`Compilation::nodes`], i.e. the order they appear in the source.
+    ///
+    /// Returns `None` if the compilation has no program, e.g. for a [`CompilationType::StringsOnly`] compilation.
+    pub fn from_compilation(compilation: &Compilation) -> Option<Self> {
+        let program = compilation.program.as_ref()?;
+        let mut graph = Self::build(program, |line_id| {
+            compilation
+                .string_table
+                .get(line_id)
+                .map(|string_info| string_info.text.clone())
+        });
+        let source_order: HashMap<_, _> = compilation
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (node.title.as_str(), index))
+            .collect();
+        let position = |name: &str| source_order.get(name).copied().unwrap_or(usize::MAX);
+        graph.nodes.sort_by_key(|node| position(&node.name));
+        graph.edges.sort_by_key(|edge| position(&edge.from));
+        Some(graph)
+    }
+
+    /// Builds the graph of `program`. The nodes are sorted by name.
+    /// Since a program does not contain the text of its lines, options are labelled with their line IDs.
+    pub fn from_program(program: &Program) -> Self {
+        Self::build(program, |_| None)
+    }
+
+    fn build(program: &Program, option_text: impl Fn(&LineId) -> Option<String>) -> Self {
+        let mut nodes: Vec<_> = program.nodes.values().collect();
+        nodes.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut graph = Self::default();
+        for node in nodes {
+            let code = NodeCode::new(node);
+            graph.nodes.push(code.graph_node());
+            for (destination, option) in code.jumps() {
+                let edge = match option {
+                    Some(line_id) => GraphEdge {
+                        from: node.name.clone(),
+                        to: destination,
+                        kind: EdgeKind::Option,
+                        label: Some(option_text(&line_id).unwrap_or(line_id.0)),
+                    },
+                    None => GraphEdge {
+                        from: node.name.clone(),
+                        to: destination,
+                        kind: EdgeKind::Jump,
+                        label: None,
+                    },
+                };
+                if !graph.edges.contains(&edge) {
+                    graph.edges.push(edge);
+                }
+            }
+        }
+        graph
+    }
+
+    /// Exports the graph in the DOT language of [Graphviz](https://graphviz.org/).
+    /// Nodes are labelled with their name, tags and variables, and edges of options with the option.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph dialogue {\n");
+        for node in &self.nodes {
+            let label = node_label(node).join("\n");
+            writeln!(
+                dot,
+                "    {} [label={}];",
+                dot_string(&node.name),
+                dot_string(&label)
+            )
+            .unwrap();
+        }
+        for edge in &self.edges {
+            let (from, to) = (dot_string(&edge.from), dot_string(&edge.to));
+            match &edge.label {
+                Some(label) => writeln!(dot, "    {from} -> {to} [label={}];", dot_string(label)),
+                None => writeln!(dot, "    {from} -> {to};"),
+            }
+            .unwrap();
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Exports the graph as a [Mermaid](https://mermaid.js.org/) flowchart.
+    /// Since Mermaid IDs are restricted, the nodes get the IDs `n0`, `n1`, etc. and are labelled with their name, tags and variables.
+    pub fn to_mermaid(&self) -> String {
+        let mut boxes: Vec<_> = self
+            .nodes
+            .iter()
+            .map(|node| (node.name.as_str(), node_label(node)))
+            .collect();
+        // Jumps to nodes that don't exist still need a box to point to.
+        for edge in &self.edges {
+            for name in [&edge.from, &edge.to] {
+                if !boxes.iter().any(|(existing, _)| existing == name) {
+                    boxes.push((name, vec![name.clone()]));
+                }
+            }
+        }
+
+        let mut ids = HashMap::new();
+        let mut mermaid = String::from("flowchart TD\n");
+        for (index, (name, label)) in boxes.into_iter().enumerate() {
+            let id = format!("n{index}");
+            let label: Vec<_> = label.iter().map(|line| mermaid_string(line)).collect();
+            writeln!(mermaid, "    {id}[\"{}\"]", label.join("<br/>")).unwrap();
+            ids.insert(name, id);
+        }
+        for edge in &self.edges {
+            let (from, to) = (&ids[edge.from.as_str()], &ids[edge.to.as_str()]);
+            match &edge.label {
+                Some(label) => writeln!(
+                    mermaid,
+                    "    {from} -->|\"{}\"| {to}",
+                    mermaid_string(label)
+                ),
+                None => writeln!(mermaid, "    {from} --> {to}"),
+            }
+            .unwrap();
+        }
+        mermaid
+    }
+
+    /// Exports the graph as JSON, in the `serde` representation of [`DialogueGraph`].
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Serializing a graph cannot fail")
+    }
+}
+
+/// The lines describing `node` in the DOT and Mermaid exports.
+fn node_label(node: &GraphNode) -> Vec<String> {
+    let mut label = vec![node.name.clone()];
+    for (name, values) in [
+        ("tags", &node.tags),
+        ("reads", &node.reads),
+        ("writes", &node.writes),
+    ] {
+        if !values.is_empty() {
+            label.push(format!("{name}: {}", values.join(", ")));
+        }
+    }
+    label
+}
+
+fn dot_string(value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("\"{escaped}\"")
+}
+
+fn mermaid_string(value: &str) -> String {
+    value
+        .replace('"', "#quot;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
+}
+
+/// Variables with this prefix are generated by the compiler, e.g. to track whether a node was visited.
+const INTERNAL_VARIABLE_PREFIX: &str = "$Yarn.Internal.";
+
+/// The instructions of a node, read without assuming that they are valid.
+struct NodeCode<'a> {
+    node: &'a Node,
+    opcodes: Vec<Option<OpCode>>,
+}
+
+impl<'a> NodeCode<'a> {
+    fn new(node: &'a Node) -> Self {
+        let opcodes = node
+            .instructions
+            .iter()
+            .map(|instruction| OpCode::try_from(instruction.opcode).ok())
+            .collect();
+        Self { node, opcodes }
+    }
+
+    /// The operand at `index` of the instruction at `position` if it is a string.
+    /// Unlike [`Instruction::read_operand`], this doesn't panic on programs that weren't produced by the compiler.
+    fn string_operand(&self, position: usize, index: usize) -> Option<String> {
+        let operand = self.node.instructions[position].operands.get(index)?;
+        String::try_from(operand.clone()).ok()
+    }
+
+    fn positions_of(&self, opcode: OpCode) -> impl Iterator<Item = usize> + '_ {
+        (0..self.opcodes.len()).filter(move |&position| self.opcodes[position] == Some(opcode))
+    }
+
+    fn graph_node(&self) -> GraphNode {
+        let variables = |opcode| {
+            let mut variables: Vec<_> = self
+                .positions_of(opcode)
+                .filter_map(|position| self.string_operand(position, 0))
+                .filter(|variable| !variable.starts_with(INTERNAL_VARIABLE_PREFIX))
+                .collect();
+            variables.sort();
+            variables.dedup();
+            variables
+        };
+        GraphNode {
+            name: self.node.name.clone(),
+            tags: self
+                .node
+                .tags
+                .iter()
+                .filter(|tag| !tag.is_empty())
+                .cloned()
+                .collect(),
+            reads: variables(OpCode::PushVariable),
+            writes: variables(OpCode::StoreVariable),
+        }
+    }
+
+    fn label_position(&self, label: &str) -> Option<usize> {
+        self.node
+            .labels
+            .get(label)
+            .and_then(|&position| usize::try_from(position).ok())
+    }
+
+    /// The instructions run after choosing each option, as the range of positions and the position of the `AddOption`.
+    ///
+    /// The compiler emits the code of the options of a group one after another, followed by a label ending in `group_end`.
+    /// Groups nest like brackets, so each `ShowOptions` is matched with its end like an opening with a closing bracket.
+    fn option_bodies(&self) -> Vec<(Range<usize>, usize)> {
+        let group_ends = self
+            .node
+            .labels
+            .keys()
+            .filter(|label| label.ends_with("group_end"))
+            .filter_map(|label| self.label_position(label))
+            .map(|position| (position, false));
+        let mut brackets: Vec<_> = self
+            .positions_of(OpCode::ShowOptions)
+            .map(|position| (position, true))
+            .chain(group_ends)
+            .collect();
+        brackets.sort();
+        let mut open = Vec::new();
+        let mut ends = HashMap::new();
+        for (position, is_opening) in brackets {
+            if is_opening {
+                open.push(position);
+            } else if let Some(show_options) = open.pop() {
+                ends.insert(show_options, position);
+            }
+        }
+
+        let mut bodies = Vec::new();
+        for show_options in self.positions_of(OpCode::ShowOptions) {
+            let end = ends
+                .get(&show_options)
+                .copied()
+                .unwrap_or(self.opcodes.len());
+            let mut options: Vec<_> = self
+                .options_shown_at(show_options)
+                .into_iter()
+                .filter_map(|add_option| {
+                    let label = self.string_operand(add_option, 1)?;
+                    Some((self.label_position(&label)?, add_option))
+                })
+                .collect();
+            options.sort();
+            for (index, &(start, add_option)) in options.iter().enumerate() {
+                let body_end = options.get(index + 1).map_or(end, |(next, _)| *next);
+                bodies.push((start..body_end, add_option));
+            }
+        }
+        bodies
+    }
+
+    /// The positions of the `AddOption` instructions whose options are shown by the `ShowOptions` at `show_options`.
+    fn options_shown_at(&self, show_options: usize) -> Vec<usize> {
+        let first = self.opcodes[..show_options]
+            .iter()
+            .rposition(|opcode| *opcode == Some(OpCode::ShowOptions))
+            .map_or(0, |previous| previous + 1);
+        (first..show_options)
+            .filter(|&position| self.opcodes[position] == Some(OpCode::AddOption))
+            .collect()
+    }
+
+    /// The nodes this node jumps to, each with the line ID of the option it belongs to, if any.
+    fn jumps(&self) -> Vec<(String, Option<LineId>)> {
+        let option_bodies = self.option_bodies();
+        self.positions_of(OpCode::RunNode)
+            .filter_map(|run_node| {
+                // The destination is only known if it is pushed right before, i.e. not computed at runtime.
+                let push_string = run_node.checked_sub(1)?;
+                if self.opcodes[push_string] != Some(OpCode::PushString) {
+                    return None;
+                }
+                let destination = self.string_operand(push_string, 0)?;
+                // Bodies of nested options start after those of their parents
+                let option = option_bodies
+                    .iter()
+                    .filter(|(body, _)| body.contains(&run_node))
+                    .max_by_key(|(body, _)| body.start)
+                    .and_then(|(_, add_option)| self.string_operand(*add_option, 0))
+                    .map(LineId);
+                Some((destination, option))
+            })
+            .collect()
+    }
+}
@@ -8,17 +8,21 @@
 //!
 #![warn(missing_docs, missing_debug_implementations)]
 
+pub mod ast;
 mod collections;
 pub(crate) mod compilation_steps;
 pub(crate) mod compiler;
-pub(crate) mod error_strategy;
 mod file_parse_result;
+pub mod format;
+pub mod graph;
+pub mod line_tagging;
 pub(crate) mod listeners;
 mod output;
 mod parser;
 pub(crate) mod parser_rule_context_ext;
+#[cfg(feature = "serde")]
+pub mod sarif;
 mod string_table_manager;
-pub(crate) mod token_ext;
 pub(crate) mod visitors;
 
 pub use crate::compiler::Result;
@@ -26,13 +30,12 @@ pub use crate::compiler::Result;
 pub mod prelude {
     //! Everything you need to get started with the Yarn Spinner compiler.
     pub(crate) use crate::{
-        compiler::antlr_rust_ext::*, compiler::run_compilation::*, compiler::utils::*,
-        file_parse_result::*, parser::*, parser_rule_context_ext::*, string_table_manager::*,
-        token_ext::*,
+        compiler::run_compilation::*, compiler::utils::*, file_parse_result::*, parser::*,
+        parser_rule_context_ext::*, string_table_manager::*,
     };
     pub use crate::{
-        compiler::{CompilationType, Compiler, File},
-        listeners::{Diagnostic, DiagnosticSeverity, DiagnosticVec},
+        compiler::{CompilationType, Compiler, CompilerSession, File},
+        listeners::{Diagnostic, DiagnosticCode, DiagnosticSeverity, DiagnosticVec, Suggestion},
         output::*,
     };
     pub(crate) use yarnspinner_core::prelude::*;
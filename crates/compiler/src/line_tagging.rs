@@ -0,0 +1,430 @@
+//! Adds `#line:` tags to the lines of a whole project. [`Compiler::add_tags_to_lines`] tags a single string with random IDs,
+//! which is enough for a single file, but makes it hard to keep IDs unique across many files or to follow the naming scheme
+//! of a localization vendor. A [`LineTagger`] instead tags many [`File`]s at once, never reuses an ID that exists anywhere in them,
+//! and generates new IDs with a [`LineIdGenerator`] of your choice:
+//! - [`RandomLineIds`]: random IDs like `line:1234567`, the default and what [`Compiler::add_tags_to_lines`] uses.
+//! - [`ContentHashLineIds`]: IDs derived from the node and the text of the line, so that tagging the same content always gives the same IDs.
+//! - [`SequentialLineIds`]: readable IDs prefixed with the node name, like `line:Start-3`.
+//! - Any type implementing [`LineIdGenerator`], including closures taking an [`UntaggedLine`].
+//!
+//! ```rust
+//! # use yarnspinner_compiler::prelude::*;
+//! # use yarnspinner_compiler::line_tagging::*;
+//! let files = vec![File {
+//!     file_name: "intro.yarn".to_owned(),
+//!     source: "title: Start\n---\nHello! #line:Start-1\nHow are you?\n===\n".to_owned(),
+//! }];
+//! let tagged_files = LineTagger::new()
+//!     .with_id_generator(SequentialLineIds::default())
+//!     .tag_files(&files);
+//!
+//! assert_eq!(
+//!     Some("title: Start\n---\nHello! #line:Start-1\nHow are you? #line:Start-2 \n===\n"),
+//!     tagged_files[0].source.as_deref()
+//! );
+//! ```
+//!
+//! ## Implementation notes
+//!
+//! Every file is parsed before any ID is generated, so that the IDs of all files are known up front.
+//! In files with syntax errors, only the nodes that contain no error are tagged, since we aren't confident in our ability
+//! to insert a tag correctly in the others. The IDs in broken nodes are still reserved.
+
+use crate::listeners::{UntaggedLineListener, UntaggedLineStatement};
+use crate::prelude::*;
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Debug, Formatter};
+
+/// How often a [`LineIdGenerator`] is asked for an ID for the same line before the line is reported as an error.
+const MAX_ATTEMPTS: usize = 1000;
+
+/// A line without a `#line:` tag that needs a new ID. Passed to [`LineIdGenerator::generate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UntaggedLine<'a> {
+    /// The name of the file containing the line.
+    pub file_name: &'a str,
+    /// The title of the node containing the line. Empty if the node has no title.
+    pub node_name: &'a str,
+    /// The text of the line as it appears in the string table, i.e. with its expressions replaced by `{0}`, `{1}`, etc.
+    pub text: &'a str,
+    /// The one-indexed line number of the line in its file.
+    pub line_number: usize,
+    /// How often an ID was already generated for this line and turned out to be in use. Starts at `0`.
+    /// Deterministic generators use this to produce a different ID on the next attempt.
+    pub attempt: usize,
+}
+
+/// A strategy for generating the IDs of new line tags. See the [module documentation](self) for the built-in strategies.
+///
+/// The returned ID must start with `line:` and must not contain whitespace or `#`.
+/// It does not need to be unique: if it is already in use, [`LineIdGenerator::generate`] is called again
+/// for the same line with [`UntaggedLine::attempt`] increased by one.
+pub trait LineIdGenerator {
+    /// Generates an ID for `line`.
+    fn generate(&mut self, line: &UntaggedLine) -> LineId;
+}
+
+impl<F> LineIdGenerator for F
+where
+    F: FnMut(&UntaggedLine) -> LineId,
+{
+    fn generate(&mut self, line: &UntaggedLine) -> LineId {
+        self(line)
+    }
+}
+
+/// Generates random IDs like `line:1234567`. This is what [`Compiler::add_tags_to_lines`] uses.
+#[derive(Debug, Clone)]
+pub struct RandomLineIds {
+    rng: SmallRng,
+}
+
+impl RandomLineIds {
+    /// Creates a generator whose IDs are determined by `seed`, which is useful for tests.
+    pub fn from_seed(seed: u64) -> Self {
+        Self {
+            rng: SmallRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl Default for RandomLineIds {
+    fn default() -> Self {
+        Self {
+            rng: SmallRng::from_entropy(),
+        }
+    }
+}
+
+impl LineIdGenerator for RandomLineIds {
+    fn generate(&mut self, _line: &UntaggedLine) -> LineId {
+        let line: usize = self.rng.gen_range(0..0x1000000);
+        LineId(format!("line:{line}"))
+    }
+}
+
+/// Generates IDs like `line:3f2a9c1e` from a hash of the node name and the text of a line.
+///
+/// Tagging the same content always results in the same IDs, regardless of the order of the files or of the machine doing it.
+/// Lines with the same text in the same node get different IDs, which depend on the order of these lines.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ContentHashLineIds;
+
+impl LineIdGenerator for ContentHashLineIds {
+    fn generate(&mut self, line: &UntaggedLine) -> LineId {
+        // FNV-1a, since the hashers of the standard library are not guaranteed to be stable across Rust versions.
+        let mut hash: u64 = 0xcbf29ce484222325;
+        let bytes = line
+            .node_name
+            .bytes()
+            .chain([0])
+            .chain(line.text.bytes())
+            .chain([0])
+            .chain(line.attempt.to_le_bytes());
+        for byte in bytes {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+        LineId(format!("line:{:08x}", (hash ^ (hash >> 32)) as u32))
+    }
+}
+
+/// Generates readable IDs prefixed with the node name, like `line:Start-1`, `line:Start-2`, etc.
+/// Each node is counted separately, and numbers already in use are skipped.
+#[derive(Debug, Clone, Default)]
+pub struct SequentialLineIds {
+    counters: HashMap<String, usize>,
+}
+
+impl LineIdGenerator for SequentialLineIds {
+    fn generate(&mut self, line: &UntaggedLine) -> LineId {
+        let counter = self.counters.entry(line.node_name.to_owned()).or_default();
+        *counter += 1;
+        if line.node_name.is_empty() {
+            LineId(format!("line:{counter}"))
+        } else {
+            LineId(format!("line:{}-{counter}", line.node_name))
+        }
+    }
+}
+
+/// Adds `#line:` tags to the lines of many files at once, making sure that every ID is unique across all of them.
+/// See the [module documentation](self) for an example.
+pub struct LineTagger {
+    id_generator: Box<dyn LineIdGenerator>,
+    existing_line_tags: HashSet<LineId>,
+}
+
+impl Debug for LineTagger {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LineTagger")
+            .field("existing_line_tags", &self.existing_line_tags)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for LineTagger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LineTagger {
+    /// Creates a tagger that generates [`RandomLineIds`].
+    pub fn new() -> Self {
+        Self {
+            id_generator: Box::<RandomLineIds>::default(),
+            existing_line_tags: HashSet::new(),
+        }
+    }
+
+    /// Sets the strategy used to generate new IDs.
+    pub fn with_id_generator(mut self, id_generator: impl LineIdGenerator + 'static) -> Self {
+        self.id_generator = Box::new(id_generator);
+        self
+    }
+
+    /// Reserves line IDs that are used elsewhere, e.g. in files that are not tagged, so that no new line gets one of them.
+    /// The IDs already in the tagged files are always reserved.
+    pub fn with_existing_line_tags(mut self, line_tags: impl IntoIterator<Item = LineId>) -> Self {
+        self.existing_line_tags.extend(line_tags);
+        self
+    }
+
+    /// Adds a `#line:` tag to every line, option and shortcut option in `files` that has none.
+    /// Returns a [`TaggedFile`] for each file, in the same order.
+    ///
+    /// The new IDs are reserved by this tagger, so calling this method again with other files will not reuse them.
+    pub fn tag_files(&mut self, files: &[File]) -> Vec<TaggedFile> {
+        let chars: Vec<Vec<char>> = files
+            .iter()
+            .map(|file| file.source.chars().collect())
+            .collect();
+        let parsed_files: Vec<_> = files
+            .iter()
+            .zip(&chars)
+            .map(|(file, chars)| {
+                let mut diagnostics = Vec::new();
+                let parse_result = parse_syntax_tree(file, chars, &mut diagnostics);
+                self.existing_line_tags.extend(line_tags(&parse_result));
+                (parse_result, diagnostics)
+            })
+            .collect();
+
+        files
+            .iter()
+            .zip(parsed_files)
+            .map(|(file, (parse_result, diagnostics))| {
+                // Without a range inside the file, we can't tell which nodes an error belongs to.
+                // This includes errors at the end of the file, like a node that is never closed.
+                let line_count = file.source.lines().count();
+                let error_lines: Option<Vec<_>> = diagnostics
+                    .iter()
+                    .filter(|diagnostic| diagnostic.severity == DiagnosticSeverity::Error)
+                    .map(|diagnostic| {
+                        let range = diagnostic.range.as_ref()?;
+                        (range.start.line < line_count)
+                            .then_some((range.start.line, range.end.line))
+                    })
+                    .collect();
+                let Some(error_lines) = error_lines else {
+                    return TaggedFile {
+                        file_name: file.file_name.clone(),
+                        source: None,
+                        added_line_ids: Vec::new(),
+                        diagnostics,
+                    };
+                };
+                self.tag_file(file, parse_result, error_lines, diagnostics)
+            })
+            .collect()
+    }
+
+    fn tag_file(
+        &mut self,
+        file: &File,
+        parse_result: FileParseResult,
+        error_lines: Vec<(usize, usize)>,
+        mut diagnostics: Vec<Diagnostic>,
+    ) -> TaggedFile {
+        let tree = parse_result.tree.clone();
+        let mut listener = UntaggedLineListener::new(parse_result, error_lines);
+        walk(&mut listener, &tree.into());
+
+        let mut lines: Vec<String> = file.source.lines().map(ToOwned::to_owned).collect();
+        let mut added_line_ids = Vec::new();
+        for untagged_line in listener.untagged_lines {
+            // The parser counts columns in characters, while strings are indexed by bytes.
+            let insertion_index = lines.get(untagged_line.line_index).and_then(|line| {
+                line.char_indices()
+                    .map(|(index, _)| index)
+                    .chain([line.len()])
+                    .nth(untagged_line.insertion_column)
+            });
+            let Some(insertion_index) = insertion_index else {
+                continue;
+            };
+            let Some(line_id) = self.generate_unique_id(file, &untagged_line) else {
+                diagnostics.push(
+                    Diagnostic::new(
+                        DiagnosticCode::LineIdGenerationFailed,
+                        format!(
+                        "Could not generate a line ID that is not already in use after {MAX_ATTEMPTS} attempts"
+                    ))
+                    .with_file_name(file.file_name.clone())
+                    .with_range(untagged_line.range.clone())
+                    .with_context(lines[untagged_line.line_index].clone())
+                    .with_start_line(untagged_line.line_index),
+                );
+                continue;
+            };
+            lines[untagged_line.line_index].insert_str(insertion_index, &format!(" #{line_id} "));
+            added_line_ids.push(AddedLineId {
+                line_id,
+                node_name: untagged_line.node_name,
+                line_number: untagged_line.line_index + 1,
+            });
+        }
+
+        let source = (!added_line_ids.is_empty()).then(|| {
+            let mut source = lines.join("\n");
+            source.push('\n');
+            source
+        });
+        TaggedFile {
+            file_name: file.file_name.clone(),
+            source,
+            added_line_ids,
+            diagnostics,
+        }
+    }
+
+    fn generate_unique_id(&mut self, file: &File, line: &UntaggedLineStatement) -> Option<LineId> {
+        (0..MAX_ATTEMPTS).find_map(|attempt| {
+            let line_id = self.id_generator.generate(&UntaggedLine {
+                file_name: &file.file_name,
+                node_name: &line.node_name,
+                text: &line.text,
+                line_number: line.line_index + 1,
+                attempt,
+            });
+            self.existing_line_tags
+                .insert(line_id.clone())
+                .then_some(line_id)
+        })
+    }
+}
+
+/// The result of tagging a single file with [`LineTagger::tag_files`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaggedFile {
+    /// The [`File::file_name`] of the tagged file.
+    pub file_name: String,
+    /// The source code with the new line tags added. [`None`] if no tag was added, either because every line already had one
+    /// or because the untagged lines are all in nodes with errors.
+    pub source: Option<String>,
+    /// The tags added to the file, in the order of their lines.
+    pub added_line_ids: Vec<AddedLineId>,
+    /// The problems found in the file. The lines of nodes containing an error are not tagged.
+    /// If an error has no [`Diagnostic::range`] or is at the end of the file, nothing in the file is tagged.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// A line tag added by a [`LineTagger`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddedLineId {
+    /// The new ID.
+    pub line_id: LineId,
+    /// The title of the node containing the line.
+    pub node_name: String,
+    /// The one-indexed line number of the line in its file.
+    pub line_number: usize,
+}
+
+/// The IDs of the `#line:` tags in a file. Files with errors are still searched, which is why this works on the tokens.
+fn line_tags(file: &FileParseResult) -> Vec<LineId> {
+    file.tokens()
+        .get_tokens()
+        .iter()
+        .filter(|token| token.token_type == HASHTAG_TEXT)
+        .map(|token| token.text.trim().to_owned())
+        .filter(|text| text.starts_with("line:"))
+        .map(LineId)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn untagged_line<'a>(node_name: &'a str, text: &'a str, attempt: usize) -> UntaggedLine<'a> {
+        UntaggedLine {
+            file_name: "test.yarn",
+            node_name,
+            text,
+            line_number: 1,
+            attempt,
+        }
+    }
+
+    #[test]
+    fn content_hash_ids_are_stable() {
+        let id = ContentHashLineIds.generate(&untagged_line("Start", "Hello", 0));
+
+        assert_eq!(
+            id,
+            ContentHashLineIds.generate(&untagged_line("Start", "Hello", 0))
+        );
+        assert_ne!(
+            id,
+            ContentHashLineIds.generate(&untagged_line("Start", "Hello", 1))
+        );
+        assert_ne!(
+            id,
+            ContentHashLineIds.generate(&untagged_line("Other", "Hello", 0))
+        );
+        assert_ne!(
+            id,
+            ContentHashLineIds.generate(&untagged_line("Start", "Bye", 0))
+        );
+        assert_eq!(13, id.0.len());
+    }
+
+    #[test]
+    fn sequential_ids_are_counted_per_node() {
+        let mut generator = SequentialLineIds::default();
+
+        let ids: Vec<_> = [
+            ("Start", 0),
+            ("Start", 0),
+            ("Other", 0),
+            ("Start", 1),
+            ("", 0),
+        ]
+        .into_iter()
+        .map(|(node, attempt)| generator.generate(&untagged_line(node, "text", attempt)).0)
+        .collect();
+
+        assert_eq!(
+            vec![
+                "line:Start-1",
+                "line:Start-2",
+                "line:Other-1",
+                "line:Start-3",
+                "line:1"
+            ],
+            ids
+        );
+    }
+
+    #[test]
+    fn seeded_random_ids_are_reproducible() {
+        let line = untagged_line("Start", "Hello", 0);
+
+        assert_eq!(
+            RandomLineIds::from_seed(42).generate(&line),
+            RandomLineIds::from_seed(42).generate(&line)
+        );
+    }
+}
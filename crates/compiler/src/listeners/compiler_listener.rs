@@ -1,25 +1,17 @@
 //! Adapted from the listener part of <https://github.com/YarnSpinnerTool/YarnSpinner/blob/da39c7195107d8211f21c263e4084f773b84eaff/YarnSpinner.Compiler/Compiler.cs>
 
 use crate::prelude::*;
-use antlr_rust::parser_rule_context::ParserRuleContext;
-use antlr_rust::token::Token;
-use antlr_rust::tree::{ParseTreeListener, ParseTreeVisitorCompat};
 use std::cell::RefCell;
 use std::collections::HashSet;
 use std::rc::Rc;
 use yarnspinner_core::prelude::*;
 
 mod emit;
-use crate::parser::generated::yarnspinnerparser::{
-    BodyContext, HeaderContext, NodeContext, YarnSpinnerParserContextType,
-};
-use crate::prelude::generated::yarnspinnerparser::BodyContextAttrs;
-use crate::prelude::generated::yarnspinnerparserlistener::YarnSpinnerParserListener;
 use crate::visitors::{CodeGenerationVisitor, KnownTypes};
 pub(crate) use emit::*;
 use yarnspinner_core::prelude::OpCode;
 
-pub(crate) struct CompilerListener<'input> {
+pub(crate) struct CompilerListener {
     pub(crate) debug_infos: Rc<RefCell<Vec<DebugInfo>>>,
     /// The program being generated by the compiler.
     pub(crate) program: Rc<RefCell<Program>>,
@@ -34,15 +26,15 @@ pub(crate) struct CompilerListener<'input> {
     /// Whether we are currently parsing the
     /// current node as a 'raw text' node, or as a fully syntactic node.
     is_current_node_raw_text: bool,
-    file: FileParseResult<'input>,
+    file: FileParseResult,
     label_count: usize,
 }
 
-impl<'input> CompilerListener<'input> {
+impl CompilerListener {
     pub(crate) fn new(
         tracking_nodes: HashSet<String>,
         types: KnownTypes,
-        file: FileParseResult<'input>,
+        file: FileParseResult,
     ) -> Self {
         Self {
             file,
@@ -70,24 +62,25 @@ impl<'input> CompilerListener<'input> {
     }
 }
 
-impl<'input> ParseTreeListener<'input, YarnSpinnerParserContextType> for CompilerListener<'input> {}
-
-impl<'input> YarnSpinnerParserListener<'input> for CompilerListener<'input> {
-    fn enter_node(&mut self, _ctx: &NodeContext<'input>) {
+impl YarnSpinnerParserListener for CompilerListener {
+    fn enter_node(&mut self, _ctx: &NodeContext) {
         // we have found a new node set up the currentNode var ready to hold it and otherwise continue
         self.current_node = Some(Node::default());
         self.current_debug_info = Default::default();
         self.is_current_node_raw_text = false;
     }
 
-    fn exit_node(&mut self, ctx: &NodeContext<'input>) {
+    fn exit_node(&mut self, ctx: &NodeContext) {
         let name = &self.current_node.as_ref().unwrap().name.clone();
         if name.is_empty() {
             // We don't have a name for this node. We can't emit code for it.
             self.diagnostics.borrow_mut().push(
-                Diagnostic::from_message("Missing title header for node")
-                    .with_file_name(self.file.name.clone())
-                    .with_parser_context(ctx, self.file.tokens()),
+                Diagnostic::new(
+                    DiagnosticCode::MissingNodeTitle,
+                    "Missing title header for node",
+                )
+                .with_file_name(self.file.name.clone())
+                .with_parser_context(ctx, self.file.tokens()),
             );
         } else {
             if !self.program.borrow().nodes.contains_key(name) {
@@ -111,11 +104,11 @@ impl<'input> YarnSpinnerParserListener<'input> for CompilerListener<'input> {
         self.is_current_node_raw_text = false;
     }
 
-    fn exit_header(&mut self, ctx: &HeaderContext<'input>) {
+    fn exit_header(&mut self, ctx: &HeaderContext) {
         // have finished with the header so about to enter the node body
         // and all its statements do the initial setup required before
         // compiling that body statements eg emit a new startlabel
-        let header_key = ctx.header_key.as_ref().unwrap().get_text();
+        let header_key = ctx.header_key.as_ref().unwrap().text.as_str();
         let current_node = self.current_node.as_mut().unwrap();
 
         // Use the header value if provided, else fall back to the
@@ -126,9 +119,8 @@ impl<'input> YarnSpinnerParserListener<'input> for CompilerListener<'input> {
         let header_value = ctx
             .header_value
             .as_ref()
-            .map(|v| v.get_text())
-            .unwrap_or_default()
-            .to_owned();
+            .map(|v| v.text.clone())
+            .unwrap_or_default();
         match header_key {
             "title" => {
                 // Set the name of the node
@@ -152,7 +144,7 @@ impl<'input> YarnSpinnerParserListener<'input> for CompilerListener<'input> {
         current_node.headers.push(header);
     }
 
-    fn enter_body(&mut self, ctx: &BodyContext<'input>) {
+    fn enter_body(&mut self, ctx: &BodyContext) {
         // ok so something in here needs to be a bit different
         // also need to emit tracking code here for when we fall out of a node that needs tracking?
         // or should do I do in inside the codegenvisitor?
@@ -180,7 +172,7 @@ impl<'input> YarnSpinnerParserListener<'input> for CompilerListener<'input> {
         }
     }
 
-    fn exit_body(&mut self, ctx: &BodyContext<'input>) {
+    fn exit_body(&mut self, ctx: &BodyContext) {
         // this gives us the final increment at the end of the node
         // this is for when we visit and complete a node without a jump
         // theoretically this does mean that there might be redundant increments
@@ -198,7 +190,7 @@ impl<'input> YarnSpinnerParserListener<'input> for CompilerListener<'input> {
         }
         // We have exited the body; emit a 'stop' opcode here.
         self.emit(Emit::from_op_code(OpCode::Stop).with_source(Position {
-            line: (ctx.stop().line as usize).saturating_sub(1),
+            line: ctx.stop().line.saturating_sub(1),
             character: 0,
         }));
     }
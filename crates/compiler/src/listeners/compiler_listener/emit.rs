@@ -1,10 +1,9 @@
 use crate::listeners::CompilerListener;
 use crate::prelude::*;
-use antlr_rust::token::Token;
 use yarnspinner_core::prelude::OpCode;
 use yarnspinner_core::prelude::*;
 
-impl CompilerListener<'_> {
+impl CompilerListener {
     /// Creates a new instruction, and appends it to a node in the [`Program`].
     pub(crate) fn emit(&mut self, emit: Emit) {
         let instruction = Instruction {
@@ -46,10 +45,10 @@ impl Emit {
         self
     }
 
-    pub(crate) fn with_token(mut self, token: &(impl Token + ?Sized)) -> Self {
+    pub(crate) fn with_token(mut self, token: &Token) -> Self {
         self.source = Some(Position {
-            line: token.get_line_as_usize().saturating_sub(1),
-            character: token.get_column_as_usize(),
+            line: token.line.saturating_sub(1),
+            character: token.column,
         });
         self
     }
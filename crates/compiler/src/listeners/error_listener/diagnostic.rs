@@ -1,9 +1,6 @@
 use crate::parser_rule_context_ext::ParserRuleContextExt;
 use crate::prelude::*;
 use annotate_snippets::{Annotation, AnnotationType, Renderer, Slice, Snippet, SourceAnnotation};
-use antlr_rust::rule_context::CustomRuleContext;
-use antlr_rust::token::Token;
-use antlr_rust::token_factory::TokenFactory;
 use core::fmt;
 use std::fmt::{Display, Formatter};
 use std::ops::Range;
@@ -46,10 +43,17 @@ pub struct Diagnostic {
 
     /// The line the context starts on.
     pub start_line: usize,
+
+    /// The kind of issue.
+    pub code: DiagnosticCode,
+
+    /// Fixes for the issue that can be applied automatically, if any.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub suggestions: Vec<Suggestion>,
 }
 
 impl Diagnostic {
-    pub(crate) fn from_message(message: impl Into<String>) -> Self {
+    pub(crate) fn new(code: DiagnosticCode, message: impl Into<String>) -> Self {
         Self {
             message: message.into(),
             file_name: Default::default(),
@@ -57,19 +61,16 @@ impl Diagnostic {
             context: Default::default(),
             severity: Default::default(),
             start_line: Default::default(),
+            code,
+            suggestions: Default::default(),
         }
     }
 
-    pub(crate) fn with_parser_context<'input, T>(
+    pub(crate) fn with_parser_context(
         self,
-        ctx: &T,
-        token_stream: &ActualTokenStream<'input>,
-    ) -> Self
-    where
-        T: ParserRuleContextExt<'input>,
-    <<<<T as CustomRuleContext<'input>>::TF as TokenFactory<'input>>::Inner as Token>::Data as ToOwned>::Owned:
-        Into<String>
-    {
+        ctx: &(impl ParserRuleContext + ?Sized),
+        token_stream: &TokenStream,
+    ) -> Self {
         let lines_above_and_below_offending_line = 2;
         let lines_around = ctx.get_lines_around(token_stream, lines_above_and_below_offending_line);
         let range = ctx.range();
@@ -103,6 +104,11 @@ impl Diagnostic {
         self.severity = severity;
         self
     }
+
+    pub(crate) fn with_suggestion(mut self, suggestion: Suggestion) -> Self {
+        self.suggestions.push(suggestion);
+        self
+    }
 }
 
 impl Display for Diagnostic {
@@ -115,10 +121,18 @@ impl Display for Diagnostic {
         let snippet = Snippet {
             title: Some(Annotation {
                 label: Some(label),
-                id: None,
+                id: Some(self.code.as_str()),
                 annotation_type,
             }),
-            footer: vec![],
+            footer: self
+                .suggestions
+                .iter()
+                .map(|suggestion| Annotation {
+                    label: Some(&suggestion.message),
+                    id: None,
+                    annotation_type: AnnotationType::Help,
+                })
+                .collect(),
             slices: vec![Slice {
                 source: self.context.as_deref().unwrap_or("<unknown line>"),
                 line_start: self.start_line + 1,
@@ -147,24 +161,33 @@ fn convert_absolute_range_to_relative(diagnostic: &Diagnostic) -> (usize, usize)
         return (0, 0);
     };
 
-    let relative_start_line = range.start.line - diagnostic.start_line;
-    let annotated_lines = range.end.line - range.start.line;
+    let relative_start_line = range.start.line.saturating_sub(diagnostic.start_line);
+    let annotated_lines = range.end.line.saturating_sub(range.start.line);
     let line_lengths: Vec<_> = context
         .lines()
         .map(|line| line.chars().count() + 1)
         .collect();
     let relative_start =
         line_lengths.iter().take(relative_start_line).sum::<usize>() + range.start.character;
-    let relative_end: usize = line_lengths
+    let relative_end: usize = (line_lengths
         .iter()
         .take(relative_start_line + annotated_lines)
         .sum::<usize>()
-        + range.end.character
+        + range.end.character)
         // - 1 because the Diagnostic range is exclusive, but the annotation range is inclusive
-        - 1;
-    let mut char_indices = context.char_indices().map(|(i, _)| i);
-    let byte_start = char_indices.clone().nth(relative_start).unwrap();
-    let byte_end = char_indices.nth(relative_end).unwrap_or(byte_start);
+        .saturating_sub(1);
+    // Ranges reaching past the context, e.g. to the end of a line, are clamped to its last character.
+    let char_indices: Vec<_> = context.char_indices().map(|(i, _)| i).collect();
+    let last_index = char_indices.last().copied().unwrap_or_default();
+    let byte_start = char_indices
+        .get(relative_start)
+        .copied()
+        .unwrap_or(last_index);
+    let byte_end = char_indices
+        .get(relative_end)
+        .copied()
+        .unwrap_or(last_index)
+        .max(byte_start);
     (byte_start, byte_end)
 }
 
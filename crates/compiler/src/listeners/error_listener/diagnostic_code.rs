@@ -0,0 +1,280 @@
+use crate::prelude::*;
+use std::fmt::{self, Display, Formatter};
+use std::ops::Range;
+
+/// A stable identifier for the kind of issue a [`Diagnostic`] describes, written as e.g. `YS0001`.
+///
+/// Unlike [`Diagnostic::message`], which may be reworded between versions, the code of an issue never changes,
+/// so tools can reliably match on it. New codes are only ever added at the end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "bevy", reflect(Debug, PartialEq, Hash))]
+#[non_exhaustive]
+pub enum DiagnosticCode {
+    /// `YS0001`: A variable is used without a declaration, and its type cannot be inferred from its context.
+    UndeclaredVariable,
+    /// `YS0002`: The source code does not follow the syntax of Yarn.
+    SyntaxError,
+    /// `YS0003`: The indentation of a line mixes tabs and spaces.
+    MixedIndentation,
+    /// `YS0004`: A command spans more than one line.
+    NewlineInCommand,
+    /// `YS0005`: A node has no `title` header.
+    MissingNodeTitle,
+    /// `YS0006`: More than one node has the same title.
+    DuplicateNodeName,
+    /// `YS0007`: The title of a node contains characters that are not allowed.
+    InvalidNodeName,
+    /// `YS0008`: More than one line has the same `#line:` tag.
+    DuplicateLineId,
+    /// `YS0009`: A variable is declared more than once.
+    DuplicateDeclaration,
+    /// `YS0010`: A declaration names a type that does not exist.
+    UnknownType,
+    /// `YS0011`: The default value of a declaration does not match its declared type.
+    DeclarationTypeMismatch,
+    /// `YS0012`: The default value of a declaration is a variable or a function call instead of a constant.
+    NonConstantDeclaration,
+    /// `YS0013`: A number cannot be parsed.
+    InvalidNumber,
+    /// `YS0014`: `null` is used as a value.
+    NullValue,
+    /// `YS0015`: The types of the terms of an expression, or of a variable and the value assigned to it, do not fit together.
+    TypeMismatch,
+    /// `YS0016`: An operator is used with a type that does not support it.
+    UnsupportedOperator,
+    /// `YS0017`: The type of an expression cannot be determined.
+    IndeterminateType,
+    /// `YS0018`: A function is called with the wrong number of arguments.
+    WrongArgumentCount,
+    /// `YS0019`: An argument of a function call has the wrong type.
+    ArgumentTypeMismatch,
+    /// `YS0020`: No unused line ID could be generated while tagging lines.
+    LineIdGenerationFailed,
+    /// `YS0021`: A jump names a node that does not exist in any file of the compilation.
+    UnknownNode,
+}
+
+impl DiagnosticCode {
+    /// All codes, in the order of their numbers.
+    pub const ALL: &'static [DiagnosticCode] = &[
+        DiagnosticCode::UndeclaredVariable,
+        DiagnosticCode::SyntaxError,
+        DiagnosticCode::MixedIndentation,
+        DiagnosticCode::NewlineInCommand,
+        DiagnosticCode::MissingNodeTitle,
+        DiagnosticCode::DuplicateNodeName,
+        DiagnosticCode::InvalidNodeName,
+        DiagnosticCode::DuplicateLineId,
+        DiagnosticCode::DuplicateDeclaration,
+        DiagnosticCode::UnknownType,
+        DiagnosticCode::DeclarationTypeMismatch,
+        DiagnosticCode::NonConstantDeclaration,
+        DiagnosticCode::InvalidNumber,
+        DiagnosticCode::NullValue,
+        DiagnosticCode::TypeMismatch,
+        DiagnosticCode::UnsupportedOperator,
+        DiagnosticCode::IndeterminateType,
+        DiagnosticCode::WrongArgumentCount,
+        DiagnosticCode::ArgumentTypeMismatch,
+        DiagnosticCode::LineIdGenerationFailed,
+        DiagnosticCode::UnknownNode,
+    ];
+
+    /// The code as it is written, e.g. `YS0001`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DiagnosticCode::UndeclaredVariable => "YS0001",
+            DiagnosticCode::SyntaxError => "YS0002",
+            DiagnosticCode::MixedIndentation => "YS0003",
+            DiagnosticCode::NewlineInCommand => "YS0004",
+            DiagnosticCode::MissingNodeTitle => "YS0005",
+            DiagnosticCode::DuplicateNodeName => "YS0006",
+            DiagnosticCode::InvalidNodeName => "YS0007",
+            DiagnosticCode::DuplicateLineId => "YS0008",
+            DiagnosticCode::DuplicateDeclaration => "YS0009",
+            DiagnosticCode::UnknownType => "YS0010",
+            DiagnosticCode::DeclarationTypeMismatch => "YS0011",
+            DiagnosticCode::NonConstantDeclaration => "YS0012",
+            DiagnosticCode::InvalidNumber => "YS0013",
+            DiagnosticCode::NullValue => "YS0014",
+            DiagnosticCode::TypeMismatch => "YS0015",
+            DiagnosticCode::UnsupportedOperator => "YS0016",
+            DiagnosticCode::IndeterminateType => "YS0017",
+            DiagnosticCode::WrongArgumentCount => "YS0018",
+            DiagnosticCode::ArgumentTypeMismatch => "YS0019",
+            DiagnosticCode::LineIdGenerationFailed => "YS0020",
+            DiagnosticCode::UnknownNode => "YS0021",
+        }
+    }
+
+    /// The name of the code in `PascalCase`, e.g. `UndeclaredVariable`.
+    pub fn name(self) -> &'static str {
+        match self {
+            DiagnosticCode::UndeclaredVariable => "UndeclaredVariable",
+            DiagnosticCode::SyntaxError => "SyntaxError",
+            DiagnosticCode::MixedIndentation => "MixedIndentation",
+            DiagnosticCode::NewlineInCommand => "NewlineInCommand",
+            DiagnosticCode::MissingNodeTitle => "MissingNodeTitle",
+            DiagnosticCode::DuplicateNodeName => "DuplicateNodeName",
+            DiagnosticCode::InvalidNodeName => "InvalidNodeName",
+            DiagnosticCode::DuplicateLineId => "DuplicateLineId",
+            DiagnosticCode::DuplicateDeclaration => "DuplicateDeclaration",
+            DiagnosticCode::UnknownType => "UnknownType",
+            DiagnosticCode::DeclarationTypeMismatch => "DeclarationTypeMismatch",
+            DiagnosticCode::NonConstantDeclaration => "NonConstantDeclaration",
+            DiagnosticCode::InvalidNumber => "InvalidNumber",
+            DiagnosticCode::NullValue => "NullValue",
+            DiagnosticCode::TypeMismatch => "TypeMismatch",
+            DiagnosticCode::UnsupportedOperator => "UnsupportedOperator",
+            DiagnosticCode::IndeterminateType => "IndeterminateType",
+            DiagnosticCode::WrongArgumentCount => "WrongArgumentCount",
+            DiagnosticCode::ArgumentTypeMismatch => "ArgumentTypeMismatch",
+            DiagnosticCode::LineIdGenerationFailed => "LineIdGenerationFailed",
+            DiagnosticCode::UnknownNode => "UnknownNode",
+        }
+    }
+
+    /// A one-sentence description of the kind of issue.
+    pub fn description(self) -> &'static str {
+        match self {
+            DiagnosticCode::UndeclaredVariable => "A variable is used without a declaration, and its type cannot be inferred from its context.",
+            DiagnosticCode::SyntaxError => "The source code does not follow the syntax of Yarn.",
+            DiagnosticCode::MixedIndentation => "The indentation of a line mixes tabs and spaces.",
+            DiagnosticCode::NewlineInCommand => "A command spans more than one line.",
+            DiagnosticCode::MissingNodeTitle => "A node has no title header.",
+            DiagnosticCode::DuplicateNodeName => "More than one node has the same title.",
+            DiagnosticCode::InvalidNodeName => "The title of a node contains characters that are not allowed.",
+            DiagnosticCode::DuplicateLineId => "More than one line has the same line tag.",
+            DiagnosticCode::DuplicateDeclaration => "A variable is declared more than once.",
+            DiagnosticCode::UnknownType => "A declaration names a type that does not exist.",
+            DiagnosticCode::DeclarationTypeMismatch => "The default value of a declaration does not match its declared type.",
+            DiagnosticCode::NonConstantDeclaration => "The default value of a declaration is a variable or a function call instead of a constant.",
+            DiagnosticCode::InvalidNumber => "A number cannot be parsed.",
+            DiagnosticCode::NullValue => "Null is used as a value.",
+            DiagnosticCode::TypeMismatch => "The types of the terms of an expression, or of a variable and the value assigned to it, do not fit together.",
+            DiagnosticCode::UnsupportedOperator => "An operator is used with a type that does not support it.",
+            DiagnosticCode::IndeterminateType => "The type of an expression cannot be determined.",
+            DiagnosticCode::WrongArgumentCount => "A function is called with the wrong number of arguments.",
+            DiagnosticCode::ArgumentTypeMismatch => "An argument of a function call has the wrong type.",
+            DiagnosticCode::LineIdGenerationFailed => "No unused line ID could be generated while tagging lines.",
+            DiagnosticCode::UnknownNode => "A jump names a node that does not exist in any file of the compilation.",
+        }
+    }
+
+    /// Looks up a code by how it is written, e.g. `YS0001`.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|c| c.as_str() == code)
+    }
+}
+
+impl Display for DiagnosticCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Codes are serialized as they are written, e.g. `"YS0001"`, so that serialized diagnostics stay stable
+/// even if the variants are renamed.
+#[cfg(feature = "serde")]
+impl Serialize for DiagnosticCode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for DiagnosticCode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        Self::from_code(&code)
+            .ok_or_else(|| serde::de::Error::custom(format!("unknown diagnostic code {code}")))
+    }
+}
+
+/// A machine-applicable fix for a [`Diagnostic`]: replacing the text in [`Suggestion::range`] with [`Suggestion::replacement`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bevy", reflect(Debug, PartialEq, Hash))]
+#[cfg_attr(
+    all(feature = "bevy", feature = "serde"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct Suggestion {
+    /// A description of the fix for the user, e.g. ``did you mean `$gold`?``.
+    pub message: String,
+
+    /// The range of the file of the [`Diagnostic`] to replace.
+    pub range: Range<Position>,
+
+    /// The text to put in place of [`Suggestion::range`].
+    pub replacement: String,
+}
+
+/// Finds the candidate closest to `name` that is close enough to likely be a typo of it, e.g. `$glod` for `$gold`.
+/// Differences in case count as half an edit, so that `$Gold` is preferred over `$bold` for `$gold`.
+pub(crate) fn closest_match<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let max_distance = name.chars().count().div_ceil(3) * 2;
+    candidates
+        .into_iter()
+        .filter(|candidate| *candidate != name)
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min()
+        .map(|(_, candidate)| candidate)
+}
+
+/// The Levenshtein distance between `a` and `b` in half edits, where changing the case of a character is half an edit.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous_row: Vec<usize> = (0..=b.len()).map(|i| i * 2).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut row = vec![(i + 1) * 2];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution_cost = if a_char == *b_char {
+                0
+            } else if a_char.to_lowercase().eq(b_char.to_lowercase()) {
+                1
+            } else {
+                2
+            };
+            let cost = (previous_row[j] + substitution_cost)
+                .min(previous_row[j + 1] + 2)
+                .min(row[j] + 2);
+            row.push(cost);
+        }
+        previous_row = row;
+    }
+    previous_row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes_are_unique_and_in_order() {
+        for (index, code) in DiagnosticCode::ALL.iter().enumerate() {
+            assert_eq!(format!("YS{:04}", index + 1), code.as_str());
+            assert_eq!(Some(*code), DiagnosticCode::from_code(code.as_str()));
+        }
+    }
+
+    #[test]
+    fn finds_close_matches() {
+        let candidates = ["$gold", "$Gold", "$bold", "$name", "$player_name"];
+
+        assert_eq!(Some("$gold"), closest_match("$glod", candidates));
+        assert_eq!(Some("$Gold"), closest_match("$GOld", candidates));
+        assert_eq!(
+            Some("$player_name"),
+            closest_match("$playername", candidates)
+        );
+        assert_eq!(None, closest_match("$health", candidates));
+        assert_eq!(None, closest_match("$gold", ["$gold"]));
+    }
+}
@@ -1,65 +1,82 @@
 //! Adapted from <https://github.com/YarnSpinnerTool/YarnSpinner/blob/da39c7195107d8211f21c263e4084f773b84eaff/YarnSpinner.Compiler/Utils.cs>
 
-use crate::parser::generated::yarnspinnerparser::Line_statementContext;
-use crate::prelude::generated::yarnspinnerparser::{
-    Line_statementContextAttrs, YarnSpinnerParserContextType,
-};
-use crate::prelude::generated::yarnspinnerparserlistener::YarnSpinnerParserListener;
 use crate::prelude::*;
-use crate::visitors::get_hashtag_texts;
-use antlr_rust::int_stream::IntStream;
-use antlr_rust::parser_rule_context::ParserRuleContext;
-use antlr_rust::token::Token;
-use antlr_rust::token_stream::TokenStream;
-use antlr_rust::tree::ParseTreeListener;
-use rand::{rngs::SmallRng, Rng, SeedableRng};
-use std::cell::RefCell;
-use std::rc::Rc;
-use std::sync::atomic::AtomicBool;
-
-pub(crate) struct UntaggedLineListener<'input> {
-    existing_line_tags: Vec<LineId>,
-    file: FileParseResult<'input>,
-    pub(crate) rewritten_lines: Rc<RefCell<Vec<String>>>,
-    pub(crate) rewrote_anything: Rc<AtomicBool>,
+use crate::visitors::{generate_formatted_text, get_hashtag_texts};
+use std::ops::Range;
+
+/// A line statement without a `#line:` tag, found by the [`UntaggedLineListener`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct UntaggedLineStatement {
+    /// The title of the node containing the line.
+    pub(crate) node_name: String,
+    /// The text of the line, with its expressions replaced by `{0}`, `{1}`, etc., as it appears in the string table.
+    pub(crate) text: String,
+    /// The range of the line statement in the source.
+    pub(crate) range: Range<Position>,
+    /// The zero-indexed line of the source that the tag is inserted into.
+    pub(crate) line_index: usize,
+    /// The character index in that line after which the tag is inserted.
+    pub(crate) insertion_column: usize,
 }
 
-impl<'input> UntaggedLineListener<'input> {
-    pub fn new(existing_line_tags: Vec<LineId>, file: FileParseResult<'input>) -> Self {
-        let original_source = file
-            .tokens()
-            .get_all_text()
-            .lines()
-            .map(|s| s.to_owned())
-            .collect();
+/// Finds the line statements that need a line tag.
+///
+/// ## Implementation notes
+///
+/// The original listener generates the new line IDs and rewrites the source while walking the tree.
+/// Here, the untagged lines are only collected, so that the caller can generate IDs for a whole project
+/// with the strategy of its choice. See [`crate::line_tagging`].
+///
+/// Nodes overlapping the lines of an error are skipped, since we aren't confident in our ability to insert a tag correctly there.
+pub(crate) struct UntaggedLineListener {
+    file: FileParseResult,
+    current_node_name: String,
+    /// The zero-indexed first and last line of each error in the file.
+    error_lines: Vec<(usize, usize)>,
+    is_in_broken_node: bool,
+    pub(crate) untagged_lines: Vec<UntaggedLineStatement>,
+}
+
+impl UntaggedLineListener {
+    pub fn new(file: FileParseResult, error_lines: Vec<(usize, usize)>) -> Self {
         Self {
-            existing_line_tags,
             file,
-            rewritten_lines: Rc::new(RefCell::new(original_source)),
-            rewrote_anything: Default::default(),
+            current_node_name: Default::default(),
+            error_lines,
+            is_in_broken_node: false,
+            untagged_lines: Default::default(),
         }
     }
+}
+
+impl YarnSpinnerParserListener for UntaggedLineListener {
+    fn enter_node(&mut self, ctx: &NodeContext) {
+        self.current_node_name.clear();
+        let range = ctx.range();
+        self.is_in_broken_node = self
+            .error_lines
+            .iter()
+            .any(|&(start, end)| start <= range.end.line && end >= range.start.line);
+    }
 
-    /// Generates a new unique line tag that is not present in `existing_line_tags`.
-    fn generate_string(&self) -> LineId {
-        let mut rng = SmallRng::from_entropy();
-        loop {
-            let line: usize = rng.gen_range(0..0x1000000);
-            let tag = LineId(format!("line:{}", line));
-            if !self.existing_line_tags.contains(&tag) {
-                return tag;
-            }
+    fn exit_header(&mut self, ctx: &HeaderContext) {
+        if self.is_in_broken_node {
+            return;
+        }
+        if ctx.header_key.as_ref().unwrap().text == "title" {
+            self.current_node_name = ctx
+                .header_value
+                .as_ref()
+                .map(|value| value.text.clone())
+                .unwrap_or_default();
         }
     }
-}
 
-impl<'input> ParseTreeListener<'input, YarnSpinnerParserContextType>
-    for UntaggedLineListener<'input>
-{
-}
+    fn exit_line_statement(&mut self, ctx: &LineStatementContext) {
+        if self.is_in_broken_node {
+            return;
+        }
 
-impl<'input> YarnSpinnerParserListener<'input> for UntaggedLineListener<'input> {
-    fn exit_line_statement(&mut self, ctx: &Line_statementContext<'input>) {
         // We're looking at a complete line statement.
 
         // First, figure out if this line statement already has a line
@@ -76,43 +93,31 @@ impl<'input> YarnSpinnerParserListener<'input> for UntaggedLineListener<'input>
 
         // Find the index of the first token on the default channel to
         // the left of the newline.
-        let index = ctx.NEWLINE().unwrap().symbol.get_token_index();
+        let index = ctx.get_token(NEWLINE, 0).unwrap().token_index;
 
         let tokens = self.file.tokens();
         let previous_token_index = index_of_previous_token_on_channel(tokens, index);
-        let line_index = ctx.start().get_line_as_usize().saturating_sub(1);
 
         // Did we find one?
         let previous_token_index = previous_token_index.unwrap_or_else(|| {
             // No token was found before this newline. This is an
             // internal error - there must be at least one symbol
             // besides the terminating newline.
-            panic!("Internal error: failed to find any tokens before the newline in line statement on line {line_index}. \
-                   This is a bug. Please report it at https://github.com/YarnSpinnerTool/YarnSpinner-Rust/issues/new");
+            panic!("Internal error: failed to find any tokens before the newline in line statement on line {}. \
+                   This is a bug. Please report it at https://github.com/YarnSpinnerTool/YarnSpinner-Rust/issues/new", ctx.start().line);
         });
         // Get the token at this index. We'll put our tag after it.
-        let previous_token = tokens.get(previous_token_index);
-
-        // Generate a new, unique line ID.
-        let new_line_id = self.generate_string();
-        // Record that we've used this new line ID, so that we don't
-        // accidentally use it twice.
-        self.existing_line_tags.push(new_line_id.clone());
-
-        let mut lines = self.rewritten_lines.borrow_mut();
-        let line = lines.get_mut(line_index).unwrap();
-
-        let insertion_index = line
-            .char_indices()
-            .map(|(byte_pos, _char)| byte_pos)
-            .nth(previous_token.get_column_as_usize())
-            .unwrap_or_else(||
-                panic!("Internal error: failed to convert char pos to byte pos for insertion index on line {line_index}. \
-                        This is a bug. Please report it at https://github.com/YarnSpinnerTool/YarnSpinner-Rust/issues/new"))
-            + previous_token.get_text().len();
-        line.insert_str(insertion_index, &format!(" #{new_line_id} "));
-        self.rewrote_anything
-            .store(true, std::sync::atomic::Ordering::Relaxed);
+        let previous_token = &tokens.get_tokens()[previous_token_index as usize];
+        let line_index = previous_token.line.saturating_sub(1);
+        let insertion_column = previous_token.column + previous_token.text.chars().count();
+
+        self.untagged_lines.push(UntaggedLineStatement {
+            node_name: self.current_node_name.clone(),
+            text: generate_formatted_text(&ctx.line_formatted_text().unwrap()),
+            range: ctx.range(),
+            line_index,
+            insertion_column,
+        });
     }
 }
 
@@ -122,15 +127,12 @@ impl<'input> YarnSpinnerParserListener<'input> for UntaggedLineListener<'input>
 ///
 /// Returns the index of the first token before the token at `index` that is on the channel `0`.
 /// If none is found, returns [`None`]. If `index` is beyond the size of `token_stream`, returns the index of the last token in the stream.
-fn index_of_previous_token_on_channel(
-    token_stream: &ActualTokenStream,
-    index: isize,
-) -> Option<isize> {
-    let default_token_channel = 0;
+fn index_of_previous_token_on_channel(token_stream: &TokenStream, index: isize) -> Option<isize> {
+    let size = token_stream.get_tokens().len() as isize;
     // Are we beyond the list of tokens?
-    if index >= token_stream.size() {
+    if index >= size {
         // Return the final token in the channel, which will be an EOF.
-        return Some(token_stream.size() - 1);
+        return Some(size - 1);
     }
     // 'index' is the token we want to start searching from. We want
     // to find items before it, so start looking from the token before it.
@@ -138,5 +140,5 @@ fn index_of_previous_token_on_channel(
     // Walk backwards through the tokens list.
     (0..index)
         .rev()
-        .find(|&i| token_stream.get(i).get_channel() == default_token_channel)
+        .find(|&i| token_stream.get_tokens()[i as usize].channel == DEFAULT_CHANNEL)
 }
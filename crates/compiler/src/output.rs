@@ -1,7 +1,7 @@
 //! Adapted from <https://github.com/YarnSpinnerTool/YarnSpinner/blob/da39c7195107d8211f21c263e4084f773b84eaff/YarnSpinner.Compiler/CompilationResult.cs>
 
 use crate::listeners::*;
-pub use crate::output::{debug_info::*, declaration::*, string_info::*};
+pub use crate::output::{debug_info::*, declaration::*, node_info::*, string_info::*};
 use crate::prelude::*;
 use std::collections::HashMap;
 use std::error::Error;
@@ -10,6 +10,7 @@ use yarnspinner_core::prelude::*;
 
 mod debug_info;
 mod declaration;
+mod node_info;
 mod string_info;
 
 /// The result of a compilation.
@@ -19,7 +20,8 @@ mod string_info;
 /// ## Implementation Notes
 ///
 /// In contrast to the original implementation, where this struct was called a `CompilationResult`, we return
-/// an actual [`Result`], so this type is guaranteed to only hold warnings as opposed to all diagnostics.
+/// an actual [`Result`], so this type only holds warnings as opposed to all diagnostics.
+/// The exception is a partial compilation as described in [`Compiler::with_partial_compilation`], which lists its errors in [`Compilation::errors`].
 #[derive(Debug, Clone, PartialEq, Default)]
 #[cfg_attr(feature = "bevy", derive(Reflect))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -81,11 +83,25 @@ pub struct Compilation {
     ///
     /// All diagnostics in this collection have a severity of [`DiagnosticSeverity::Warning`].
     /// If there was an error during compilation, the compilation returns an [`Err`] variant containing
-    /// error diagnostics instead of this [`Compilation`].
+    /// error diagnostics instead of this [`Compilation`], unless [`Compiler::partial_compilation`] is set.
     pub warnings: Vec<Diagnostic>,
 
+    /// The errors found during a partial compilation, see [`Compiler::with_partial_compilation`].
+    /// The parts of the source code they were found in are not part of this [`Compilation`].
+    ///
+    /// This is always empty if [`Compiler::partial_compilation`] is not set, or if there were no errors.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub errors: Vec<Diagnostic>,
+
     /// The collection of [`DebugInfo`] objects for each node in [`Program`].
     pub debug_info: HashMap<String, DebugInfo>,
+
+    /// Information about every node found in the source code, in the order of [`Compiler::files`] and of the nodes within each file.
+    /// Look up a single node with [`Compilation::node`].
+    ///
+    /// In a partial compilation, see [`Compiler::with_partial_compilation`], the nodes containing errors are left out.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub nodes: Vec<NodeInfo>,
 }
 
 impl Compilation {
@@ -117,8 +133,124 @@ impl Compilation {
             contains_implicit_string_tags,
             file_tags: tags,
             warnings: diagnostics,
+            errors: Vec::new(),
+            nodes: Vec::new(),
         }
     }
+
+    /// The [`NodeInfo`] of the node named `title`, if there is one.
+    #[must_use]
+    pub fn node(&self, title: &str) -> Option<&NodeInfo> {
+        self.nodes.iter().find(|node| node.title == title)
+    }
+
+    /// Writes the compiled [`Program`] in the textual listing format of [`Program::disassemble`],
+    /// with the source position of each instruction from [`Compilation::debug_info`] added as a comment.
+    /// Positions are written as `file:line:character`, both 1-indexed.
+    ///
+    /// Returns [`None`] if [`Compilation::program`] is [`None`].
+    #[must_use]
+    pub fn disassemble(&self) -> Option<String> {
+        let program = self.program.as_ref()?;
+        let listing = program.disassemble_with_comments(|node, instruction_index| {
+            let line_info = self
+                .debug_info
+                .get(&node.name)?
+                .try_get_line_info(instruction_index)?;
+            let position = line_info.position?;
+            Some(format!(
+                "{}:{}:{}",
+                line_info.file_name,
+                position.line + 1,
+                position.character + 1
+            ))
+        });
+        Some(listing)
+    }
+
+    /// Bundles the compiled [`Program`] with the text and metadata of its lines from [`Compilation::string_table`],
+    /// so that it can be saved as a `.yarnc` file and run without the compiler.
+    ///
+    /// Returns [`None`] if [`Compilation::program`] is [`None`].
+    #[must_use]
+    pub fn to_program_file(&self) -> Option<ProgramFile> {
+        let program = self.program.clone()?;
+        let string_table = self
+            .string_table
+            .iter()
+            .map(|(line_id, string_info)| (line_id.clone(), string_info.text.clone()))
+            .collect();
+        let line_metadata = self
+            .string_table
+            .iter()
+            .filter(|(_, string_info)| !string_info.metadata.is_empty())
+            .map(|(line_id, string_info)| (line_id.clone(), string_info.metadata.clone()))
+            .collect();
+        Some(ProgramFile {
+            program,
+            string_table,
+            line_metadata,
+        })
+    }
+
+    /// Encodes the result of [`Compilation::to_program_file`] in the `.yarnc` format. Load it again with [`ProgramFile::from_bytes`].
+    ///
+    /// Returns [`None`] if [`Compilation::program`] is [`None`].
+    #[must_use]
+    pub fn to_bytes(&self) -> Option<Vec<u8>> {
+        self.to_program_file().map(|file| file.to_bytes())
+    }
+
+    /// The string table as the records of the `<name>-Lines.csv` file written by the original implementation.
+    /// See [`LineEntry::write_csv`]. The records are sorted by file, line number and ID.
+    #[must_use]
+    pub fn line_entries(&self) -> Vec<LineEntry> {
+        let mut entries: Vec<_> = self
+            .string_table
+            .iter()
+            .map(|(line_id, string_info)| LineEntry {
+                id: line_id.clone(),
+                text: string_info.text.clone(),
+                file: string_info.file_name.clone(),
+                node: string_info.node_name.clone(),
+                line_number: string_info.line_number,
+            })
+            .collect();
+        entries.sort_by(|a, b| {
+            (&a.file, a.line_number, &a.id.0).cmp(&(&b.file, b.line_number, &b.id.0))
+        });
+        entries
+    }
+
+    /// The line metadata as the records of the `<name>-Metadata.csv` file written by the original implementation.
+    /// See [`LineMetadataEntry::write_csv`]. Like there, line IDs are left out of the tags, and lines without other tags are skipped.
+    /// The records are sorted by file, line number and ID.
+    #[must_use]
+    pub fn line_metadata_entries(&self) -> Vec<LineMetadataEntry> {
+        let mut entries: Vec<_> = self
+            .string_table
+            .iter()
+            .filter_map(|(line_id, string_info)| {
+                let tags: Vec<_> = string_info
+                    .metadata
+                    .iter()
+                    .filter(|tag| !tag.starts_with("line:"))
+                    .cloned()
+                    .collect();
+                let entry = LineMetadataEntry {
+                    id: line_id.clone(),
+                    node: string_info.node_name.clone(),
+                    line_number: string_info.line_number,
+                    tags,
+                };
+                (!entry.tags.is_empty()).then_some((&string_info.file_name, entry))
+            })
+            .collect();
+        entries.sort_by(|(a_file, a), (b_file, b)| {
+            (a_file, a.line_number, &a.id.0).cmp(&(b_file, b.line_number, &b.id.0))
+        });
+        entries.into_iter().map(|(_, entry)| entry).collect()
+    }
 }
 
 /// A collection of [`Diagnostic`] objects that describe problems that occurred during compilation.
@@ -5,9 +5,6 @@
 //! [`Range`] has been replaced with the more idiomatic [`Range<Position>`].
 
 use crate::prelude::*;
-use antlr_rust::rule_context::CustomRuleContext;
-use antlr_rust::token::Token;
-use antlr_rust::token_factory::TokenFactory;
 use std::fmt::{Debug, Display};
 use std::ops::Range;
 use yarnspinner_core::prelude::*;
@@ -203,31 +200,21 @@ where
     }
 }
 
-pub(crate) trait ParserRuleContextExtRangeSource<'input>:
-    ParserRuleContextExt<'input>
-where
-    <<<<Self as CustomRuleContext<'input>>::TF as TokenFactory<'input>>::Inner as Token>::Data as ToOwned>::Owned:
-        Into<String>,
-{
+pub(crate) trait ParserRuleContextExtRangeSource: ParserRuleContextExt {
     fn range(&self) -> Range<Position> {
         let start = Position {
-            line: self.start().get_line_as_usize().saturating_sub(1),
-            character: self.start().get_column_as_usize(),
+            line: self.start().line.saturating_sub(1),
+            character: self.start().column,
         };
-        let text: String = self.stop().get_text().to_owned().into();
         let stop = Position {
-            line: self.stop().get_line_as_usize().saturating_sub(1),
-            character: self.stop().get_column_as_usize() + text.len(),
+            line: self.stop().line.saturating_sub(1),
+            character: self.stop().column + self.stop().text.len(),
         };
         start..stop
     }
 }
 
-impl<'input, T: ParserRuleContextExt<'input>> ParserRuleContextExtRangeSource<'input> for T where
-    <<<<T as CustomRuleContext<'input>>::TF as TokenFactory<'input>>::Inner as Token>::Data as ToOwned>::Owned:
-        Into<String>
-{
-}
+impl<T: ParserRuleContextExt + ?Sized> ParserRuleContextExtRangeSource for T {}
 
 impl Display for DeclarationSource {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -0,0 +1,53 @@
+use crate::prelude::*;
+use std::ops::Range;
+
+/// Information about a node of the compiled Yarn files, taken from its source code.
+/// This is available even if the node was not compiled, e.g. for a [`CompilationType::StringsOnly`] compilation.
+///
+/// You do not create instances of this struct yourself. They are
+/// generated by the [`Compiler`], see [`Compilation::nodes`].
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "bevy", derive(Reflect))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bevy", reflect(Debug, PartialEq, Default))]
+#[cfg_attr(
+    all(feature = "bevy", feature = "serde"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct NodeInfo {
+    /// The title of the node.
+    pub title: String,
+
+    /// The name of the file the node was found in.
+    pub file_name: String,
+
+    /// The range of the whole node, from its first header to the `===` that ends it.
+    pub range: Range<Position>,
+
+    /// The range of the body of the node, from the start of the line after the `---` to the start of the `===`.
+    pub body_range: Range<Position>,
+
+    /// All headers of the node in the order they appear in the source, including `title` and `tags`.
+    /// A key appears once for every time it is written.
+    pub headers: Vec<Header>,
+
+    /// The tags of the node, i.e. the space-separated values of its `tags` headers.
+    pub tags: Vec<String>,
+
+    /// The nodes this node jumps to, in the order the jumps appear in the source.
+    /// Only destinations known at compile time are listed, i.e. node names and expressions that are constant strings.
+    pub jumps: Vec<String>,
+
+    /// The IDs of the lines in this node, including the options, in the order they appear in the source.
+    pub line_ids: Vec<LineId>,
+}
+
+impl NodeInfo {
+    /// The values of all headers named `key`, in the order they appear in the source.
+    pub fn header_values<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.headers
+            .iter()
+            .filter(move |header| header.key == key)
+            .map(|header| header.value.as_str())
+    }
+}
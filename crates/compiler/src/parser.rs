@@ -1,8 +1,17 @@
 //! The parser for the compiler.
 
-mod actual_types;
-pub(crate) mod generated;
+mod contexts;
 mod indent_aware_lexer;
+mod listener;
+mod token;
+mod visitor;
+mod yarn_spinner_lexer;
+mod yarn_spinner_parser;
 
-pub(crate) use actual_types::*;
+pub(crate) use contexts::*;
 pub(crate) use indent_aware_lexer::IndentAwareYarnSpinnerLexer as YarnSpinnerLexer;
+pub(crate) use listener::*;
+pub(crate) use token::*;
+pub(crate) use visitor::*;
+pub(crate) use yarn_spinner_lexer::*;
+pub(crate) use yarn_spinner_parser::YarnSpinnerParser;
@@ -0,0 +1,563 @@
+//! The parse tree produced by the [`YarnSpinnerParser`](super::YarnSpinnerParser), with one context per rule of
+//! <https://github.com/YarnSpinnerTool/YarnSpinner/blob/da39c7195107d8211f21c263e4084f773b84eaff/YarnSpinner.Compiler/YarnSpinnerParser.g4>.
+//!
+//! Like the contexts generated by ANTLR, every context holds its children in the order they were parsed,
+//! including the tokens the parser skipped while recovering from a syntax error.
+//! Children that could not be parsed are missing, so all accessors of children return [`Option`]s or [`Vec`]s.
+//! The typed accessors are views on the children, which is why the tags the compiler adds to lines
+//! show up in them as well.
+
+use super::token::Token;
+use super::yarn_spinner_lexer;
+use std::cell::{Ref, RefCell};
+use std::rc::Rc;
+
+/// A child of a context: either a token or the context of another rule.
+#[derive(Debug, Clone)]
+pub(crate) enum ParseTree {
+    Token(Rc<Token>),
+    /// A token that was skipped while recovering from a syntax error.
+    Error(Rc<Token>),
+    Dialogue(Rc<DialogueContext>),
+    FileHashtag(Rc<FileHashtagContext>),
+    Node(Rc<NodeContext>),
+    Header(Rc<HeaderContext>),
+    Body(Rc<BodyContext>),
+    Statement(Rc<StatementContext>),
+    LineStatement(Rc<LineStatementContext>),
+    LineFormattedText(Rc<LineFormattedTextContext>),
+    Hashtag(Rc<HashtagContext>),
+    LineCondition(Rc<LineConditionContext>),
+    Expression(Rc<ExpressionContext>),
+    Value(Rc<ValueContext>),
+    Variable(Rc<VariableContext>),
+    FunctionCall(Rc<FunctionCallContext>),
+    IfStatement(Rc<IfStatementContext>),
+    IfClause(Rc<IfClauseContext>),
+    ElseIfClause(Rc<ElseIfClauseContext>),
+    ElseClause(Rc<ElseClauseContext>),
+    SetStatement(Rc<SetStatementContext>),
+    CallStatement(Rc<CallStatementContext>),
+    CommandStatement(Rc<CommandStatementContext>),
+    CommandFormattedText(Rc<CommandFormattedTextContext>),
+    ShortcutOptionStatement(Rc<ShortcutOptionStatementContext>),
+    ShortcutOption(Rc<ShortcutOptionContext>),
+    DeclareStatement(Rc<DeclareStatementContext>),
+    JumpStatement(Rc<JumpStatementContext>),
+}
+
+impl ParseTree {
+    /// The context of the rule, or [`None`] for tokens.
+    pub(crate) fn context(&self) -> Option<&dyn ParserRuleContext> {
+        macro_rules! context {
+            ($($variant:ident),*) => {
+                match self {
+                    ParseTree::Token(_) | ParseTree::Error(_) => None,
+                    $(ParseTree::$variant(ctx) => Some(ctx.as_ref()),)*
+                }
+            };
+        }
+        context!(
+            Dialogue,
+            FileHashtag,
+            Node,
+            Header,
+            Body,
+            Statement,
+            LineStatement,
+            LineFormattedText,
+            Hashtag,
+            LineCondition,
+            Expression,
+            Value,
+            Variable,
+            FunctionCall,
+            IfStatement,
+            IfClause,
+            ElseIfClause,
+            ElseClause,
+            SetStatement,
+            CallStatement,
+            CommandStatement,
+            CommandFormattedText,
+            ShortcutOptionStatement,
+            ShortcutOption,
+            DeclareStatement,
+            JumpStatement
+        )
+    }
+
+    /// The token, or [`None`] for contexts.
+    pub(crate) fn token(&self) -> Option<&Rc<Token>> {
+        match self {
+            ParseTree::Token(token) | ParseTree::Error(token) => Some(token),
+            _ => None,
+        }
+    }
+
+    /// The text of all tokens in this tree.
+    pub(crate) fn get_text(&self) -> String {
+        match self.context() {
+            Some(ctx) => ctx.get_text(),
+            None => self.token().unwrap().text.clone(),
+        }
+    }
+
+    /// Prints the tree in the LISP-like format of ANTLR, e.g. `(variable $gold)`, which is handy for debugging.
+    #[cfg(test)]
+    pub(crate) fn to_string_tree(&self) -> String {
+        match self.context() {
+            Some(ctx) => ctx.to_string_tree(),
+            None => self
+                .token()
+                .unwrap()
+                .text
+                .replace('\t', "\\t")
+                .replace('\n', "\\n")
+                .replace('\r', "\\r"),
+        }
+    }
+}
+
+/// What all contexts have in common.
+#[derive(Debug, Clone)]
+pub(crate) struct ContextBase {
+    children: RefCell<Vec<ParseTree>>,
+    start: Rc<Token>,
+    /// [`None`] if the rule did not read any token and there was no token before it.
+    stop: Option<Rc<Token>>,
+}
+
+impl ContextBase {
+    pub(crate) fn new(start: Rc<Token>) -> Self {
+        Self {
+            children: Default::default(),
+            start,
+            stop: None,
+        }
+    }
+
+    pub(crate) fn add_child(&self, child: ParseTree) {
+        self.children.borrow_mut().push(child);
+    }
+
+    pub(crate) fn set_stop(&mut self, stop: Option<Rc<Token>>) {
+        self.stop = stop;
+    }
+}
+
+/// Implemented by all contexts to get their children, tokens and position.
+pub(crate) trait ParserRuleContext: std::fmt::Debug {
+    fn base(&self) -> &ContextBase;
+
+    fn base_mut(&mut self) -> &mut ContextBase;
+
+    /// The name of the rule as written in the grammar, e.g. `line_statement`.
+    fn rule_name(&self) -> &'static str;
+
+    /// The children in the order they were parsed.
+    fn get_children(&self) -> Ref<'_, Vec<ParseTree>> {
+        self.base().children.borrow()
+    }
+
+    /// The first token of the rule.
+    /// If the rule did not read any token, this is the token after it.
+    fn start(&self) -> &Rc<Token> {
+        &self.base().start
+    }
+
+    /// The last token of the rule.
+    /// If the rule did not read any token, this is the token before it.
+    fn stop(&self) -> &Rc<Token> {
+        self.base().stop.as_ref().unwrap_or(&self.base().start)
+    }
+
+    /// The indices of the first and last token of the rule, both inclusive.
+    /// If the rule did not read any token, the last index is one less than the first.
+    fn get_source_interval(&self) -> (isize, isize) {
+        let start = self.start().token_index;
+        let stop = self
+            .base()
+            .stop
+            .as_ref()
+            .map_or(start - 1, |stop| stop.token_index);
+        (start, stop.max(start - 1))
+    }
+
+    /// The text of all tokens of the rule that the parser saw, i.e. without whitespace and comments.
+    fn get_text(&self) -> String {
+        self.get_children()
+            .iter()
+            .map(ParseTree::get_text)
+            .collect()
+    }
+
+    /// The `i`-th token of the given type among the children.
+    fn get_token(&self, token_type: isize, i: usize) -> Option<Rc<Token>> {
+        self.get_tokens(token_type).into_iter().nth(i)
+    }
+
+    /// All tokens of the given type among the children.
+    fn get_tokens(&self, token_type: isize) -> Vec<Rc<Token>> {
+        self.get_children()
+            .iter()
+            .filter_map(|child| match child {
+                ParseTree::Token(token) if token.token_type == token_type => Some(token.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Prints the context in the LISP-like format of ANTLR, e.g. `(variable $gold)`, which is handy for debugging.
+    #[cfg(test)]
+    fn to_string_tree(&self) -> String {
+        let mut string = format!("({}", self.rule_name());
+        for child in self.get_children().iter() {
+            string.push(' ');
+            string.push_str(&child.to_string_tree());
+        }
+        string.push(')');
+        string
+    }
+}
+
+/// Implemented by the contexts to find them among the children of another context.
+pub(crate) trait ChildContext: Sized {
+    fn from_tree(tree: &ParseTree) -> Option<&Rc<Self>>;
+}
+
+impl ContextBase {
+    /// The first child context of type `T`.
+    pub(crate) fn child<T: ChildContext>(&self) -> Option<Rc<T>> {
+        self.children
+            .borrow()
+            .iter()
+            .find_map(T::from_tree)
+            .cloned()
+    }
+
+    /// All child contexts of type `T`.
+    pub(crate) fn children_of<T: ChildContext>(&self) -> Vec<Rc<T>> {
+        self.children
+            .borrow()
+            .iter()
+            .filter_map(T::from_tree)
+            .cloned()
+            .collect()
+    }
+}
+
+macro_rules! contexts {
+    ($($(#[$doc:meta])* $variant:ident($context:ident, $rule_name:literal) { $($(#[$field_doc:meta])* $field:ident: $field_type:ty),* $(,)? })*) => {
+        $(
+            $(#[$doc])*
+            #[derive(Debug, Clone)]
+            pub(crate) struct $context {
+                pub(crate) base: ContextBase,
+                $($(#[$field_doc])* pub(crate) $field: $field_type,)*
+            }
+
+            impl $context {
+                pub(crate) fn new(start: Rc<Token>) -> Self {
+                    Self {
+                        base: ContextBase::new(start),
+                        $($field: Default::default(),)*
+                    }
+                }
+            }
+
+            impl ParserRuleContext for $context {
+                fn base(&self) -> &ContextBase {
+                    &self.base
+                }
+
+                fn base_mut(&mut self) -> &mut ContextBase {
+                    &mut self.base
+                }
+
+                fn rule_name(&self) -> &'static str {
+                    $rule_name
+                }
+            }
+
+            impl ChildContext for $context {
+                fn from_tree(tree: &ParseTree) -> Option<&Rc<Self>> {
+                    match tree {
+                        ParseTree::$variant(ctx) => Some(ctx),
+                        _ => None,
+                    }
+                }
+            }
+
+            impl From<$context> for ParseTree {
+                fn from(ctx: $context) -> Self {
+                    ParseTree::$variant(Rc::new(ctx))
+                }
+            }
+
+            impl From<Rc<$context>> for ParseTree {
+                fn from(ctx: Rc<$context>) -> Self {
+                    ParseTree::$variant(ctx)
+                }
+            }
+        )*
+    };
+}
+
+contexts! {
+    /// `dialogue: file_hashtag* node+`
+    Dialogue(DialogueContext, "dialogue") {}
+    /// `file_hashtag: HASHTAG text=HASHTAG_TEXT`
+    FileHashtag(FileHashtagContext, "file_hashtag") {
+        text: Option<Rc<Token>>,
+    }
+    /// `node: header+ BODY_START body BODY_END`
+    Node(NodeContext, "node") {}
+    /// `header: header_key=ID HEADER_DELIMITER header_value=REST_OF_LINE?`
+    Header(HeaderContext, "header") {
+        header_key: Option<Rc<Token>>,
+        header_value: Option<Rc<Token>>,
+    }
+    /// `body: statement*`
+    Body(BodyContext, "body") {}
+    /// One of the statements, or `INDENT statement* DEDENT`.
+    Statement(StatementContext, "statement") {}
+    /// `line_statement: line_formatted_text line_condition? hashtag* NEWLINE`
+    LineStatement(LineStatementContext, "line_statement") {}
+    /// `line_formatted_text: (TEXT+ | EXPRESSION_START expression EXPRESSION_END)+`
+    LineFormattedText(LineFormattedTextContext, "line_formatted_text") {}
+    /// `hashtag: HASHTAG text=HASHTAG_TEXT`
+    Hashtag(HashtagContext, "hashtag") {
+        text: Option<Rc<Token>>,
+    }
+    /// `line_condition: COMMAND_START COMMAND_IF expression COMMAND_END`
+    LineCondition(LineConditionContext, "line_condition") {}
+    /// One of the alternatives in [`ExpressionKind`].
+    Expression(ExpressionContext, "expression") {
+        kind: ExpressionKind,
+        /// The operator of all kinds except [`ExpressionKind::Parens`] and [`ExpressionKind::Value`].
+        op: Option<Rc<Token>>,
+    }
+    /// One of the alternatives in [`ValueKind`].
+    Value(ValueContext, "value") {
+        kind: ValueKind,
+    }
+    /// `variable: VAR_ID`
+    Variable(VariableContext, "variable") {}
+    /// `function_call: FUNC_ID '(' expression? (',' expression)* ')'`
+    FunctionCall(FunctionCallContext, "function_call") {}
+    /// `if_statement: if_clause else_if_clause* else_clause? COMMAND_START COMMAND_ENDIF COMMAND_END`
+    IfStatement(IfStatementContext, "if_statement") {}
+    /// `if_clause: COMMAND_START COMMAND_IF expression COMMAND_END statement*`
+    IfClause(IfClauseContext, "if_clause") {}
+    /// `else_if_clause: COMMAND_START COMMAND_ELSEIF expression COMMAND_END statement*`
+    ElseIfClause(ElseIfClauseContext, "else_if_clause") {}
+    /// `else_clause: COMMAND_START COMMAND_ELSE COMMAND_END statement*`
+    ElseClause(ElseClauseContext, "else_clause") {}
+    /// `set_statement: COMMAND_START COMMAND_SET variable op=(...) expression COMMAND_END`
+    SetStatement(SetStatementContext, "set_statement") {
+        op: Option<Rc<Token>>,
+    }
+    /// `call_statement: COMMAND_START COMMAND_CALL function_call COMMAND_END`
+    CallStatement(CallStatementContext, "call_statement") {}
+    /// `command_statement: COMMAND_START command_formatted_text COMMAND_TEXT_END hashtag*`
+    CommandStatement(CommandStatementContext, "command_statement") {}
+    /// `command_formatted_text: (COMMAND_TEXT | COMMAND_EXPRESSION_START expression EXPRESSION_END)*`
+    CommandFormattedText(CommandFormattedTextContext, "command_formatted_text") {}
+    /// `shortcut_option_statement: shortcut_option* (shortcut_option BLANK_LINE_FOLLOWING_OPTION?)`
+    ShortcutOptionStatement(ShortcutOptionStatementContext, "shortcut_option_statement") {}
+    /// `shortcut_option: SHORTCUT_ARROW line_statement (INDENT statement* DEDENT)?`
+    ShortcutOption(ShortcutOptionContext, "shortcut_option") {}
+    /// `declare_statement: COMMAND_START COMMAND_DECLARE variable '=' value ('as' declaration_type=FUNC_ID)? COMMAND_END`
+    DeclareStatement(DeclareStatementContext, "declare_statement") {
+        declaration_type: Option<Rc<Token>>,
+    }
+    /// One of the alternatives in [`JumpKind`].
+    JumpStatement(JumpStatementContext, "jump_statement") {
+        kind: JumpKind,
+        /// The name of the node of [`JumpKind::JumpToNodeName`].
+        destination: Option<Rc<Token>>,
+    }
+}
+
+/// The labelled alternatives of the `expression` rule, from the tightest to the loosest binding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum ExpressionKind {
+    /// `'(' expression ')'`
+    Parens,
+    /// `op='-' expression`
+    Negative,
+    /// `op=OPERATOR_LOGICAL_NOT expression`
+    Not,
+    /// `expression op=('*' | '/' | '%') expression`
+    MultDivMod,
+    /// `expression op=('+' | '-') expression`
+    AddSub,
+    /// `expression op=('<=' | '>=' | '<' | '>') expression`
+    Comparison,
+    /// `expression op=('==' | '!=') expression`
+    Equality,
+    /// `expression op=('and' | 'or' | 'xor') expression`
+    AndOrXor,
+    /// `value`
+    Value,
+    /// No alternative could be parsed.
+    #[default]
+    Error,
+}
+
+/// The labelled alternatives of the `value` rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum ValueKind {
+    Number,
+    True,
+    False,
+    Var,
+    String,
+    Null,
+    Func,
+    /// No alternative could be parsed.
+    #[default]
+    Error,
+}
+
+/// The labelled alternatives of the `jump_statement` rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum JumpKind {
+    /// `COMMAND_START COMMAND_JUMP destination=ID COMMAND_END`
+    JumpToNodeName,
+    /// `COMMAND_START COMMAND_JUMP EXPRESSION_START expression EXPRESSION_END COMMAND_END`
+    JumpToExpression,
+    /// No alternative could be parsed.
+    #[default]
+    Error,
+}
+
+/// Generates the accessors for the children of a context, mirroring the ones ANTLR generates.
+/// Not every accessor is needed by the compiler, but all of them are kept for symmetry.
+macro_rules! child_accessors {
+    ($context:ident { $($name:ident $(, $all:ident)?: $child:ident;)* }) => {
+        #[allow(dead_code)]
+        impl $context {
+            $(child_accessors!(@accessor $name $(, $all)?: $child);)*
+        }
+    };
+    (@accessor $name:ident: $child:ident) => {
+        pub(crate) fn $name(&self) -> Option<Rc<$child>> {
+            self.base.child()
+        }
+    };
+    (@accessor $name:ident, $all:ident: $child:ident) => {
+        /// The `i`-th child of this type.
+        pub(crate) fn $name(&self, i: usize) -> Option<Rc<$child>> {
+            self.$all().into_iter().nth(i)
+        }
+
+        pub(crate) fn $all(&self) -> Vec<Rc<$child>> {
+            self.base.children_of()
+        }
+    };
+}
+
+child_accessors!(DialogueContext {
+    file_hashtag, file_hashtag_all: FileHashtagContext;
+    node, node_all: NodeContext;
+});
+child_accessors!(NodeContext {
+    header, header_all: HeaderContext;
+    body: BodyContext;
+});
+child_accessors!(BodyContext {
+    statement, statement_all: StatementContext;
+});
+child_accessors!(StatementContext {
+    line_statement: LineStatementContext;
+    if_statement: IfStatementContext;
+    set_statement: SetStatementContext;
+    shortcut_option_statement: ShortcutOptionStatementContext;
+    call_statement: CallStatementContext;
+    command_statement: CommandStatementContext;
+    declare_statement: DeclareStatementContext;
+    jump_statement: JumpStatementContext;
+    statement, statement_all: StatementContext;
+});
+child_accessors!(LineStatementContext {
+    line_formatted_text: LineFormattedTextContext;
+    line_condition: LineConditionContext;
+    hashtag, hashtag_all: HashtagContext;
+});
+child_accessors!(LineFormattedTextContext {
+    expression, expression_all: ExpressionContext;
+});
+child_accessors!(LineConditionContext {
+    expression: ExpressionContext;
+});
+child_accessors!(ExpressionContext {
+    expression, expression_all: ExpressionContext;
+    value: ValueContext;
+});
+child_accessors!(ValueContext {
+    variable: VariableContext;
+    function_call: FunctionCallContext;
+});
+child_accessors!(FunctionCallContext {
+    expression, expression_all: ExpressionContext;
+});
+child_accessors!(IfStatementContext {
+    if_clause: IfClauseContext;
+    else_if_clause, else_if_clause_all: ElseIfClauseContext;
+    else_clause: ElseClauseContext;
+});
+child_accessors!(IfClauseContext {
+    expression: ExpressionContext;
+    statement, statement_all: StatementContext;
+});
+child_accessors!(ElseIfClauseContext {
+    expression: ExpressionContext;
+    statement, statement_all: StatementContext;
+});
+child_accessors!(ElseClauseContext {
+    statement, statement_all: StatementContext;
+});
+child_accessors!(SetStatementContext {
+    variable: VariableContext;
+    expression: ExpressionContext;
+});
+child_accessors!(CallStatementContext {
+    function_call: FunctionCallContext;
+});
+child_accessors!(CommandStatementContext {
+    command_formatted_text: CommandFormattedTextContext;
+    hashtag, hashtag_all: HashtagContext;
+});
+child_accessors!(CommandFormattedTextContext {
+    expression, expression_all: ExpressionContext;
+});
+child_accessors!(ShortcutOptionStatementContext {
+    shortcut_option, shortcut_option_all: ShortcutOptionContext;
+});
+child_accessors!(ShortcutOptionContext {
+    line_statement: LineStatementContext;
+    statement, statement_all: StatementContext;
+});
+child_accessors!(DeclareStatementContext {
+    variable: VariableContext;
+    value: ValueContext;
+});
+child_accessors!(JumpStatementContext {
+    expression: ExpressionContext;
+});
+
+impl FunctionCallContext {
+    /// The name of the function.
+    pub(crate) fn func_id(&self) -> Option<Rc<Token>> {
+        self.get_token(yarn_spinner_lexer::FUNC_ID, 0)
+    }
+}
+
+impl VariableContext {
+    /// The name of the variable, including the `$`.
+    pub(crate) fn var_id(&self) -> Option<Rc<Token>> {
+        self.get_token(yarn_spinner_lexer::VAR_ID, 0)
+    }
+}
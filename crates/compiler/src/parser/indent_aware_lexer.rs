@@ -3,53 +3,37 @@
 //! The C# implementation uses inheritance to do this.
 //! More specifically, the lexer generated by ANTLR derives from the `IndentAwareLexer`
 //! directly, and the `IndentAwareLexer` derives from the ANTLR Lexer base class.
-//! Instead of this, we use a proxy/wrapper around the generated lexer to handle everything correctly.
+//! Instead of this, we use a wrapper around the [`YarnSpinnerLexer`] to handle everything correctly.
 
-use super::generated::yarnspinnerlexer::{
-    self, LocalTokenFactory, YarnSpinnerLexer as GeneratedYarnSpinnerLexer,
-};
+use super::token::*;
+use super::yarn_spinner_lexer::{self, YarnSpinnerLexer};
 use crate::collections::*;
-use crate::listeners::Diagnostic;
-use crate::prelude::{create_common_token, DiagnosticSeverity, TokenExt};
-use antlr_rust::token::CommonToken;
-use antlr_rust::{
-    char_stream::CharStream,
-    token::{Token, TOKEN_DEFAULT_CHANNEL},
-    token_factory::{CommonTokenFactory, TokenFactory},
-    Lexer, TokenSource,
-};
-use std::cell::RefCell;
-use std::ops::{Deref, DerefMut, Range};
+use crate::listeners::{Diagnostic, DiagnosticCode};
+use crate::prelude::DiagnosticSeverity;
+use std::ops::Range;
 use std::rc::Rc;
 use yarnspinner_core::prelude::*;
 
-// To ensure we don't accidentally use the wrong lexer, this will produce errors on use.
-#[allow(dead_code)]
-type YarnSpinnerLexer = ();
-
-antlr_rust::tid! { impl<'input, Input> TidAble<'input> for IndentAwareYarnSpinnerLexer<'input, Input> where Input:CharStream<From<'input>> }
-
-/// A Lexer subclass that detects newlines and generates indent and dedent tokens accordingly.
+/// A lexer that detects newlines and generates indent and dedent tokens accordingly.
 ///
 /// ## Implementation notes
 ///
 /// In contrast to the original implementation, the warnings emitted by this lexer are actually respected in the diagnostics.
-pub(crate) struct IndentAwareYarnSpinnerLexer<
-    'input,
-    Input: CharStream<From<'input>>,
-    TF: TokenFactory<'input> = LocalTokenFactory<'input>,
-> {
-    base: GeneratedYarnSpinnerLexer<'input, Input>,
+pub(crate) struct IndentAwareYarnSpinnerLexer {
+    base: YarnSpinnerLexer,
     hit_eof: bool,
     /// Holds the last observed token from the stream.
     /// Used to see if a line is blank or not.
-    last_token: Option<TF::Tok>,
+    last_token: Option<Token>,
     /// The collection of tokens that we have seen, but have not yet
     /// returned. This is needed when NextToken encounters a newline,
     /// which means we need to buffer indents or dedents. [`next_token`]
     /// only returns a single [`Token`] at a time, which
     /// means we use this list to buffer it.
-    pending_tokens: Queue<TF::Tok>,
+    pending_tokens: Queue<Token>,
+    /// Tokens that were read from the base lexer while looking for a namespaced function name,
+    /// but turned out not to be part of one. They are processed before any new tokens are read.
+    lookahead_tokens: Queue<Token>,
     /// A flag to say the last line observed was a shortcut or not.
     /// Used to determine if tracking indents needs to occur.
     line_contains_shortcut: bool,
@@ -60,108 +44,88 @@ pub(crate) struct IndentAwareYarnSpinnerLexer<
     unbalanced_indents: Stack<isize>,
     /// holds the line number of the last seen option.
     /// Lets us work out if the blank line needs to end the option.
-    last_seen_option_content: Option<isize>,
+    last_seen_option_content: Option<usize>,
     file_name: String,
-    pub(crate) diagnostics: Rc<RefCell<Vec<Diagnostic>>>,
+    pub(crate) diagnostics: Vec<Diagnostic>,
 }
 
-impl<'input, Input: CharStream<From<'input>>> Deref for IndentAwareYarnSpinnerLexer<'input, Input> {
-    type Target = GeneratedYarnSpinnerLexer<'input, Input>;
-
-    fn deref(&self) -> &Self::Target {
-        &self.base
+impl IndentAwareYarnSpinnerLexer {
+    pub(crate) fn new(input: &[char], file_name: String) -> Self {
+        IndentAwareYarnSpinnerLexer {
+            base: YarnSpinnerLexer::new(input, file_name.clone()),
+            file_name,
+            hit_eof: false,
+            last_token: Default::default(),
+            pending_tokens: Default::default(),
+            lookahead_tokens: Default::default(),
+            line_contains_shortcut: false,
+            last_indent: Default::default(),
+            unbalanced_indents: Default::default(),
+            last_seen_option_content: None,
+            diagnostics: Default::default(),
+        }
     }
-}
 
-impl<'input, Input: CharStream<From<'input>>> DerefMut
-    for IndentAwareYarnSpinnerLexer<'input, Input>
-{
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.base
+    /// Reads all tokens up to and including the [`EOF`] token and numbers them.
+    /// Returns the tokens together with the problems found while reading them:
+    /// first the unrecognized input, then the problems with indentation and commands.
+    pub(crate) fn into_token_stream(mut self) -> (TokenStream, Vec<Diagnostic>) {
+        let mut tokens = Vec::new();
+        loop {
+            let mut token = self.next_token();
+            token.token_index = tokens.len() as isize;
+            let is_eof = token.token_type == EOF;
+            tokens.push(Rc::new(token));
+            if is_eof {
+                break;
+            }
+        }
+        let mut diagnostics = std::mem::take(&mut self.base.diagnostics);
+        diagnostics.append(&mut self.diagnostics);
+        let text: String = self.base.input().iter().collect();
+        (TokenStream::new(tokens, text), diagnostics)
     }
-}
-
-impl<'input, Input: CharStream<From<'input>>> TokenSource<'input>
-    for IndentAwareYarnSpinnerLexer<'input, Input>
-{
-    type TF = LocalTokenFactory<'input>;
 
-    fn next_token(&mut self) -> <Self::TF as TokenFactory<'input>>::Tok {
+    pub(crate) fn next_token(&mut self) -> Token {
         if self.hit_eof && !self.pending_tokens.0.is_empty() {
             // We have hit the EOF, but we have tokens still pending.
             // Start returning those tokens.
             self.pending_tokens.dequeue().unwrap()
-        } else if self.base.input().size() == 0 {
+        } else if self.base.input_size() == 0 {
             self.hit_eof = true;
-            create_common_token(antlr_rust::token::TOKEN_EOF, "<EOF>")
+            self.base.next_token()
         } else {
             // Get the next token, which will enqueue one or more new
             // tokens into the pending tokens queue.
             self.check_next_token();
 
-            // `check_next_token` will always set at least one pending token if `self.base.input().size() > 0`
-            // if `self.base.input().size() == 0`, the branch returning the EOF token is already entered ahead of this.
+            // `check_next_token` will always set at least one pending token if `self.base.input_size() > 0`
+            // if `self.base.input_size() == 0`, the branch returning the EOF token is already entered ahead of this.
             self.pending_tokens.dequeue().unwrap()
         }
     }
 
-    fn get_input_stream(&mut self) -> Option<&mut dyn antlr_rust::int_stream::IntStream> {
-        self.base.get_input_stream()
-    }
-
-    fn get_source_name(&self) -> String {
-        self.base.get_source_name()
-    }
-
-    fn get_token_factory(&self) -> &'input Self::TF {
-        self.base.get_token_factory()
-    }
-}
-
-/// Copied from generated/yarnspinnerlexer.rs
-type From<'a> = <LocalTokenFactory<'a> as TokenFactory<'a>>::From;
-
-impl<'input, Input: CharStream<From<'input>>> IndentAwareYarnSpinnerLexer<'input, Input>
-where
-    &'input LocalTokenFactory<'input>: Default,
-{
-    pub fn new(input: Input, file_name: String) -> Self {
-        IndentAwareYarnSpinnerLexer {
-            file_name,
-            base: GeneratedYarnSpinnerLexer::new(input),
-            hit_eof: false,
-            last_token: Default::default(),
-            pending_tokens: Default::default(),
-            line_contains_shortcut: false,
-            last_indent: Default::default(),
-            unbalanced_indents: Default::default(),
-            last_seen_option_content: None,
-            diagnostics: Default::default(),
-        }
-    }
-
     fn check_next_token(&mut self) {
-        let current = self.base.next_token();
+        let mut current = self.next_base_token();
+        if current.token_type == yarn_spinner_lexer::FUNC_ID {
+            current = self.merge_namespaced_function_id(current);
+        }
 
         match current.token_type {
             // Insert indents or dedents depending on the next token's
             // indentation, and enqueues the newline at the correct place
-            yarnspinnerlexer::NEWLINE => self.handle_newline_token(current.clone()),
+            yarn_spinner_lexer::NEWLINE => self.handle_newline_token(current.clone()),
             // Insert dedents before the end of the file, and then
             // enqueues the EOF.
-            antlr_rust::token::TOKEN_EOF => self.handle_eof_token(current.clone()),
-            yarnspinnerlexer::SHORTCUT_ARROW => {
+            EOF => self.handle_eof_token(current.clone()),
+            yarn_spinner_lexer::SHORTCUT_ARROW => {
                 self.pending_tokens.enqueue(current.clone());
                 self.line_contains_shortcut = true;
             }
             // we are at the end of the node
             // depth no longer matters
             // clear the stack
-            yarnspinnerlexer::COMMAND_TEXT => {
-                self.diagnose_newlines_in_commands(&current);
-                self.pending_tokens.enqueue(current.clone());
-            }
-            yarnspinnerlexer::BODY_END => {
+            yarn_spinner_lexer::BODY_END => {
                 self.line_contains_shortcut = false;
                 self.last_indent = 0;
                 self.unbalanced_indents.0.clear();
@@ -176,10 +140,38 @@ where
         self.last_token = Some(current);
     }
 
-    fn handle_newline_token(
-        &mut self,
-        current_token: Box<antlr_rust::token::GenericToken<std::borrow::Cow<'input, str>>>,
-    ) {
+    fn next_base_token(&mut self) -> Token {
+        self.lookahead_tokens
+            .dequeue()
+            .unwrap_or_else(|| self.base.next_token())
+    }
+
+    /// Joins a function name made up of dot-separated identifiers, e.g. `Math.floor`, into a single `FUNC_ID` token.
+    ///
+    /// ## Implementation notes
+    ///
+    /// The grammar only knows function names without dots, which are lexed as `FUNC_ID DOT FUNC_ID`.
+    /// We merge these tokens here, so that the parser and all visitors see the namespaced name as a regular `FUNC_ID`.
+    /// Only directly adjacent tokens are merged, so `Math . floor` is still rejected by the parser.
+    fn merge_namespaced_function_id(&mut self, mut function_id: Token) -> Token {
+        loop {
+            let dot = self.next_base_token();
+            if dot.token_type != yarn_spinner_lexer::DOT || dot.start != function_id.stop + 1 {
+                self.lookahead_tokens.0.push_front(dot);
+                return function_id;
+            }
+            let name = self.next_base_token();
+            if name.token_type != yarn_spinner_lexer::FUNC_ID || name.start != dot.stop + 1 {
+                self.lookahead_tokens.0.push_front(name);
+                self.lookahead_tokens.0.push_front(dot);
+                return function_id;
+            }
+            function_id.text = format!("{}.{}", function_id.text, name.text);
+            function_id.stop = name.stop;
+        }
+    }
+
+    fn handle_newline_token(&mut self, current_token: Token) {
         // We're about to go to a new line. Look ahead to see how indented it is.
 
         // insert the current NEWLINE token
@@ -194,13 +186,13 @@ where
                 .unwrap_or_default()
             {
                 // is the option content directly above us?
-                if self.base.get_line() - last_seen_option_content == 1 {
+                if self.base.line().checked_sub(last_seen_option_content) == Some(1) {
                     // [sic! (the whole thing)]
                     // so that we don't end up printing <ending option group> into the stream we set the text to be empty
                     // I dislike this and need to look into if you can set a debug text setting in ANTLR
                     // TODO: see above comment
                     // this.InsertToken("<ending option group>", YarnSpinnerLexer.BLANK_LINE_FOLLOWING_OPTION);
-                    self.insert_token("", yarnspinnerlexer::BLANK_LINE_FOLLOWING_OPTION);
+                    self.insert_token("", yarn_spinner_lexer::BLANK_LINE_FOLLOWING_OPTION);
                 }
                 // disabling the option tracking
                 self.last_seen_option_content = None;
@@ -224,12 +216,12 @@ where
                 // I dislike this and need to look into if you can set a debug text setting in ANTLR
                 // TODO: see above comment
                 // this.InsertToken($"<indent to {currentIndentationLength}>", YarnSpinnerLexer.INDENT);
-                self.insert_token("", yarnspinnerlexer::INDENT);
+                self.insert_token("", yarn_spinner_lexer::INDENT);
             }
 
             // we've now started tracking the indentation, or ignored it, so can turn this off
             self.line_contains_shortcut = false;
-            self.last_seen_option_content = Some(self.base.get_line());
+            self.last_seen_option_content = Some(self.base.line());
         }
 
         // now we need to see if the current depth requires any indents or dedents
@@ -249,7 +241,7 @@ where
                 // I dislike this and need to look into if you can set a debug text setting in ANTLR
                 // TODO: see above comment
                 // this.InsertToken($"<dedent from {top}>", YarnSpinnerLexer.DEDENT);
-                self.insert_token("", yarnspinnerlexer::DEDENT);
+                self.insert_token("", yarn_spinner_lexer::DEDENT);
 
                 self.unbalanced_indents.pop();
 
@@ -259,7 +251,7 @@ where
                     // we've dedented all the way out of the shortcut
                     // as such we are done with the option block
                     // previousLineWasOptionOrOptionBlock = false;
-                    self.last_seen_option_content = Some(self.base.get_line());
+                    self.last_seen_option_content = Some(self.base.line());
                     0
                 };
             }
@@ -269,17 +261,14 @@ where
         self.last_indent = current_indentation_length;
     }
 
-    fn handle_eof_token(
-        &mut self,
-        current_token: Box<antlr_rust::token::GenericToken<std::borrow::Cow<'input, str>>>,
-    ) {
+    fn handle_eof_token(&mut self, current_token: Token) {
         // We're at the end of the file. Emit as many dedents as we currently have on the stack.
         while let Some(_indent) = self.unbalanced_indents.pop() {
             // so that we don't end up printing <dedent from 8> into the stream we set the text to be empty
             // I dislike this and need to look into if you can set a debug text setting in ANTLR
             // TODO: see above comment
             // this.InsertToken($"<dedent: {indent}>", YarnSpinnerLexer.DEDENT);
-            self.insert_token("", yarnspinnerlexer::DEDENT);
+            self.insert_token("", yarn_spinner_lexer::DEDENT);
         }
 
         // Finally, enqueue the EOF token.
@@ -288,11 +277,8 @@ where
 
     /// Given a NEWLINE token, return the length of the indentation
     /// following it by counting the spaces and tabs after it.
-    fn get_length_of_newline_token(
-        &mut self,
-        current_token: &antlr_rust::token::GenericToken<std::borrow::Cow<'input, str>>,
-    ) -> isize {
-        if current_token.token_type != yarnspinnerlexer::NEWLINE {
+    fn get_length_of_newline_token(&mut self, current_token: &Token) -> isize {
+        if current_token.token_type != yarn_spinner_lexer::NEWLINE {
             panic!("Current token must NOT be newline")
         }
 
@@ -300,7 +286,7 @@ where
         let mut saw_spaces = false;
         let mut saw_tabs = false;
 
-        for c in current_token.get_text().chars() {
+        for c in current_token.text.chars() {
             match c {
                 ' ' => {
                     length += 1;
@@ -315,13 +301,16 @@ where
         }
 
         if saw_spaces && saw_tabs {
-            self.diagnostics.borrow_mut().push(
-                Diagnostic::from_message("Indentation contains tabs and spaces")
-                    .with_range(get_newline_indentation_range(current_token))
-                    .with_context(get_newline_indentation_text(current_token))
-                    .with_start_line(current_token.line as usize)
-                    .with_file_name(self.file_name.clone())
-                    .with_severity(DiagnosticSeverity::Warning),
+            self.diagnostics.push(
+                Diagnostic::new(
+                    DiagnosticCode::MixedIndentation,
+                    "Indentation contains tabs and spaces",
+                )
+                .with_range(get_newline_indentation_range(current_token))
+                .with_context(get_newline_indentation_text(current_token))
+                .with_start_line(current_token.line)
+                .with_file_name(self.file_name.clone())
+                .with_severity(DiagnosticSeverity::Warning),
             );
         }
 
@@ -331,66 +320,38 @@ where
     /// Inserts a new token with the given text and type, as though it
     /// had appeared in the input stream.
     fn insert_token(&mut self, text: impl Into<String>, token_type: isize) {
-        // https://www.antlr.org/api/Java/org/antlr/v4/runtime/Lexer.html#_tokenStartCharIndex
-        let start_index = self.base.token_start_char_index + self.base.get_text().len() as isize;
-
-        let line = self.get_line();
-        let char_position_in_line = self.get_char_position_in_line();
-
-        let token = CommonTokenFactory.create(
-            self.base.input.as_mut(),
+        let start_index = self.base.index() as isize;
+        let token = Token {
             token_type,
-            Some(text.into()),
-            TOKEN_DEFAULT_CHANNEL,
-            start_index,
-            start_index - 1,
-            line,
-            char_position_in_line,
-        );
+            text: text.into(),
+            channel: DEFAULT_CHANNEL,
+            start: start_index,
+            stop: start_index - 1,
+            line: self.base.line(),
+            column: self.base.column(),
+            token_index: -1,
+        };
 
         self.pending_tokens.enqueue(token);
     }
-
-    fn diagnose_newlines_in_commands(&mut self, token: &CommonToken<'input>) {
-        if token.get_text().contains('\n') {
-            let line_len = token.get_text().lines().count();
-            let last_line_len = token.get_text().lines().last().unwrap().len();
-            self.diagnostics.borrow_mut().push(
-                Diagnostic::from_message("Newlines are not allowed in commands")
-                    .with_range(
-                        Position {
-                            line: token.get_line_as_usize() - 1,
-                            character: token.get_column_as_usize(),
-                        }..Position {
-                            line: token.get_line_as_usize() - 1 + line_len,
-                            character: last_line_len,
-                        },
-                    )
-                    .with_context(token.get_text().to_string())
-                    .with_start_line(token.get_line_as_usize() - 1)
-                    .with_file_name(self.file_name.clone())
-                    .with_severity(DiagnosticSeverity::Error),
-            );
-        }
-    }
 }
 
-fn get_newline_indentation_range(token: &CommonToken<'_>) -> Range<Position> {
+fn get_newline_indentation_range(token: &Token) -> Range<Position> {
     // +1 compared to similar code because we don't want to start at the newline
-    let line = token.get_line_as_usize();
+    let line = token.line;
 
     let start = Position { line, character: 0 };
     let stop = Position {
         line,
-        character: token.get_text().len(),
+        character: token.text.len(),
     };
 
     start..stop
 }
 
-fn get_newline_indentation_text(token: &CommonToken<'_>) -> String {
+fn get_newline_indentation_text(token: &Token) -> String {
     // Skip newline
-    token.get_text().chars().skip(1).collect()
+    token.text.chars().skip(1).collect()
 }
 
 #[cfg(test)]
@@ -445,11 +406,28 @@ mod tests {
     //! }
     //! ```
     use super::*;
-    use crate::prelude::generated::yarnspinnerlexer::YarnSpinnerLexer as GeneratedYarnSpinnerLexer;
-    use antlr_rust::{
-        common_token_stream::CommonTokenStream, int_stream::IntStream, token::TOKEN_EOF,
-        InputStream,
-    };
+
+    fn chars(input: &str) -> Vec<char> {
+        input.chars().collect()
+    }
+
+    fn symbols(tokens: impl IntoIterator<Item = Token>) -> Vec<&'static str> {
+        tokens
+            .into_iter()
+            .map(|t| yarn_spinner_lexer::SYMBOLIC_NAMES[t.token_type as usize])
+            .collect()
+    }
+
+    fn read_all(mut next_token: impl FnMut() -> Token) -> Vec<Token> {
+        let mut tokens = Vec::new();
+        loop {
+            let token = next_token();
+            if token.token_type == EOF {
+                return tokens;
+            }
+            tokens.push(token);
+        }
+    }
 
     #[test]
     fn behaves_like_lexer_for_unindented_input() {
@@ -457,78 +435,40 @@ mod tests {
 ---
 This is the one and only line
 ===";
+        let input = chars(MINIMAL_INPUT);
 
-        let generated_lexer = GeneratedYarnSpinnerLexer::new(InputStream::new(MINIMAL_INPUT));
-        let indent_aware_lexer = IndentAwareYarnSpinnerLexer::new(
-            InputStream::new(MINIMAL_INPUT),
-            "input.yarn".to_owned(),
-        );
-
-        let mut reference_token_stream = CommonTokenStream::new(generated_lexer);
-        let mut indent_aware_token_stream = CommonTokenStream::new(indent_aware_lexer);
+        let mut lexer = YarnSpinnerLexer::new(&input, "input.yarn");
+        let mut indent_aware_lexer =
+            IndentAwareYarnSpinnerLexer::new(&input, "input.yarn".to_owned());
 
-        assert_eq!(
-            reference_token_stream.size(),
-            indent_aware_token_stream.size()
-        );
+        let reference_tokens = read_all(|| lexer.next_token());
+        let indent_aware_tokens = read_all(|| indent_aware_lexer.next_token());
 
         // Sanity check: Make sure at least one token is read: We do have input.
-        assert_eq!(
-            reference_token_stream.iter().next(),
-            indent_aware_token_stream.iter().next()
-        );
-
-        // Can not do this, as trying to read EOF panics...
-        // Iterator::eq(
-        //     reference_token_stream.iter(),
-        //     indent_aware_token_stream.iter(),
-        // );
-
-        while reference_token_stream.la(1) != TOKEN_EOF {
-            assert_eq!(
-                reference_token_stream.iter().next(),
-                indent_aware_token_stream.iter().next()
-            );
-        }
-
-        assert_eq!(TOKEN_EOF, reference_token_stream.la(1));
-        assert_eq!(TOKEN_EOF, indent_aware_token_stream.la(1));
+        assert!(!reference_tokens.is_empty());
+        assert_eq!(reference_tokens, indent_aware_tokens);
     }
 
     #[test]
     fn correctly_indents_and_dedents_with_token() {
-        let option_indentation_relevant_input: &str = include_str!("significant_whitespace.yarn");
+        let option_indentation_relevant_input = chars(include_str!("significant_whitespace.yarn"));
 
-        let indent_aware_lexer = IndentAwareYarnSpinnerLexer::new(
-            InputStream::new(option_indentation_relevant_input),
+        let mut indent_aware_lexer = IndentAwareYarnSpinnerLexer::new(
+            &option_indentation_relevant_input,
             "input.yarn".to_owned(),
         );
-
-        let mut indent_aware_token_stream = CommonTokenStream::new(indent_aware_lexer);
-
-        let mut tokens = vec![indent_aware_token_stream.iter().next().unwrap()];
-
-        while indent_aware_token_stream.la(1) != TOKEN_EOF {
-            tokens.push(indent_aware_token_stream.iter().next().unwrap());
-        }
-
-        let symbols: Vec<_> = tokens
-            .into_iter()
-            .map(|t| yarnspinnerlexer::_SYMBOLIC_NAMES[t as usize].unwrap())
-            .collect();
+        let tokens = read_all(|| indent_aware_lexer.next_token());
 
         // Tests the stability of the lexer, targeted at indents and dedents - might break due to internal changes!
-        // See generated_lexer_output_is_same_as_reference for the commented out lines :)
-        // TODO: investigate if we can do anything. Maybe fix the rust antlr generator?
         let expected = vec![
             "ID",
             "HEADER_DELIMITER",
             "REST_OF_LINE",
-            // "NEWLINE",
+            "NEWLINE",
             "BODY_START",
-            // "NEWLINE",
+            "NEWLINE",
             "SHORTCUT_ARROW",
-            // "BODY_WS",
+            "BODY_WS",
             "TEXT",
             "TEXT",
             "NEWLINE",
@@ -538,65 +478,53 @@ This is the one and only line
             "NEWLINE",
             "DEDENT",
             "SHORTCUT_ARROW",
-            // "BODY_WS",
+            "BODY_WS",
             "TEXT",
             "TEXT",
             "NEWLINE",
             "INDENT",
             "TEXT",
             "TEXT",
-            // "NEWLINE",
+            "NEWLINE",
             "NEWLINE",
             "TEXT",
             "TEXT",
             "NEWLINE",
             "DEDENT",
-            // "NEWLINE",
+            "NEWLINE",
             "BLANK_LINE_FOLLOWING_OPTION",
             "TEXT",
             "TEXT",
-            // "NEWLINE",
+            "NEWLINE",
             "NEWLINE",
             "TEXT",
             "TEXT",
-            // "NEWLINE",
+            "NEWLINE",
             "NEWLINE",
             "BODY_END",
+            "NEWLINE",
         ];
 
-        assert_eq!(expected, symbols);
+        assert_eq!(expected, symbols(tokens));
     }
 
     #[test]
-    fn generated_lexer_output_is_same_as_reference() {
-        let option_indentation_relevant_input: &str = include_str!("significant_whitespace.yarn");
-
-        let generated_lexer =
-            GeneratedYarnSpinnerLexer::new(InputStream::new(option_indentation_relevant_input));
-        let mut reference_token_stream = CommonTokenStream::new(generated_lexer);
+    fn lexer_output_is_same_as_reference() {
+        let option_indentation_relevant_input = chars(include_str!("significant_whitespace.yarn"));
 
-        let mut tokens = vec![reference_token_stream.iter().next().unwrap()];
+        let mut lexer = YarnSpinnerLexer::new(&option_indentation_relevant_input, "input.yarn");
+        let tokens = read_all(|| lexer.next_token());
 
-        while reference_token_stream.la(1) != TOKEN_EOF {
-            tokens.push(reference_token_stream.iter().next().unwrap());
-        }
-
-        let symbols: Vec<_> = tokens
-            .into_iter()
-            .map(|t| yarnspinnerlexer::_SYMBOLIC_NAMES[t as usize].unwrap())
-            .collect();
-
-        // Tests the compatibility of the generated lexer with a manually generated output from the reference implementation.
-        // The commented out lines are not correctly lexed by the generated lexer (in comparison with the C# generated lexer).
+        // Tests the compatibility of the lexer with a manually generated output from the reference implementation.
         let expected = vec![
             "ID",
             "HEADER_DELIMITER",
             "REST_OF_LINE",
-            // "NEWLINE",
+            "NEWLINE",
             "BODY_START",
-            // "NEWLINE",
+            "NEWLINE",
             "SHORTCUT_ARROW",
-            // "BODY_WS",
+            "BODY_WS",
             "TEXT",
             "TEXT",
             "NEWLINE",
@@ -604,29 +532,56 @@ This is the one and only line
             "TEXT",
             "NEWLINE",
             "SHORTCUT_ARROW",
-            // "BODY_WS",
+            "BODY_WS",
             "TEXT",
             "TEXT",
             "NEWLINE",
             "TEXT",
             "TEXT",
-            // "NEWLINE",
+            "NEWLINE",
             "NEWLINE",
             "TEXT",
             "TEXT",
-            // "NEWLINE",
+            "NEWLINE",
             "NEWLINE",
             "TEXT",
             "TEXT",
-            // "NEWLINE",
+            "NEWLINE",
             "NEWLINE",
             "TEXT",
             "TEXT",
-            // "NEWLINE",
+            "NEWLINE",
             "NEWLINE",
             "BODY_END",
+            "NEWLINE",
         ];
 
-        assert_eq!(expected, symbols);
+        assert_eq!(expected, symbols(tokens));
+    }
+
+    #[test]
+    fn merges_namespaced_function_names() {
+        const INPUT: &str = "title: Start
+---
+<<set $x = Math.floor(1.5) + Inventory . count()>>
+===";
+        let input = chars(INPUT);
+
+        let mut indent_aware_lexer =
+            IndentAwareYarnSpinnerLexer::new(&input, "input.yarn".to_owned());
+        let tokens = read_all(|| indent_aware_lexer.next_token());
+
+        let function_ids: Vec<_> = tokens
+            .iter()
+            .filter(|token| token.token_type == yarn_spinner_lexer::FUNC_ID)
+            .map(|token| token.text.as_str())
+            .collect();
+        let dot_count = tokens
+            .iter()
+            .filter(|token| token.token_type == yarn_spinner_lexer::DOT)
+            .count();
+
+        assert_eq!(vec!["Math.floor", "Inventory", "count"], function_ids);
+        assert_eq!(1, dot_count);
     }
 }
@@ -0,0 +1,69 @@
+//! The listener for walks over the parse tree, taking the place of the one generated by ANTLR.
+
+use super::contexts::*;
+
+macro_rules! listener {
+    ($($variant:ident($context:ident) => $enter:ident, $exit:ident;)*) => {
+        /// Gets notified when a [`walk`] over the parse tree enters and exits a context.
+        /// All methods do nothing by default.
+        pub(crate) trait YarnSpinnerParserListener {
+            $(
+                fn $enter(&mut self, _ctx: &$context) {}
+                fn $exit(&mut self, _ctx: &$context) {}
+            )*
+        }
+
+        /// Walks depth-first over `tree`, notifying `listener` when entering and exiting each context.
+        pub(crate) fn walk(listener: &mut (impl YarnSpinnerParserListener + ?Sized), tree: &ParseTree) {
+            match tree {
+                ParseTree::Token(_) | ParseTree::Error(_) => {}
+                $(
+                    ParseTree::$variant(ctx) => {
+                        listener.$enter(ctx);
+                        walk_children(listener, ctx.as_ref());
+                        listener.$exit(ctx);
+                    }
+                )*
+            }
+        }
+    };
+}
+
+listener! {
+    Dialogue(DialogueContext) => enter_dialogue, exit_dialogue;
+    FileHashtag(FileHashtagContext) => enter_file_hashtag, exit_file_hashtag;
+    Node(NodeContext) => enter_node, exit_node;
+    Header(HeaderContext) => enter_header, exit_header;
+    Body(BodyContext) => enter_body, exit_body;
+    Statement(StatementContext) => enter_statement, exit_statement;
+    LineStatement(LineStatementContext) => enter_line_statement, exit_line_statement;
+    LineFormattedText(LineFormattedTextContext) => enter_line_formatted_text, exit_line_formatted_text;
+    Hashtag(HashtagContext) => enter_hashtag, exit_hashtag;
+    LineCondition(LineConditionContext) => enter_line_condition, exit_line_condition;
+    Expression(ExpressionContext) => enter_expression, exit_expression;
+    Value(ValueContext) => enter_value, exit_value;
+    Variable(VariableContext) => enter_variable, exit_variable;
+    FunctionCall(FunctionCallContext) => enter_function_call, exit_function_call;
+    IfStatement(IfStatementContext) => enter_if_statement, exit_if_statement;
+    IfClause(IfClauseContext) => enter_if_clause, exit_if_clause;
+    ElseIfClause(ElseIfClauseContext) => enter_else_if_clause, exit_else_if_clause;
+    ElseClause(ElseClauseContext) => enter_else_clause, exit_else_clause;
+    SetStatement(SetStatementContext) => enter_set_statement, exit_set_statement;
+    CallStatement(CallStatementContext) => enter_call_statement, exit_call_statement;
+    CommandStatement(CommandStatementContext) => enter_command_statement, exit_command_statement;
+    CommandFormattedText(CommandFormattedTextContext) => enter_command_formatted_text, exit_command_formatted_text;
+    ShortcutOptionStatement(ShortcutOptionStatementContext) => enter_shortcut_option_statement, exit_shortcut_option_statement;
+    ShortcutOption(ShortcutOptionContext) => enter_shortcut_option, exit_shortcut_option;
+    DeclareStatement(DeclareStatementContext) => enter_declare_statement, exit_declare_statement;
+    JumpStatement(JumpStatementContext) => enter_jump_statement, exit_jump_statement;
+}
+
+fn walk_children(
+    listener: &mut (impl YarnSpinnerParserListener + ?Sized),
+    ctx: &dyn ParserRuleContext,
+) {
+    let children = ctx.get_children().clone();
+    for child in &children {
+        walk(listener, child);
+    }
+}
@@ -0,0 +1,191 @@
+//! The tokens produced by the [`YarnSpinnerLexer`](super::YarnSpinnerLexer) and the stream the parser reads them from.
+//! They mirror ANTLR's `CommonToken` and `BufferedTokenStream`, so that the compiler can keep asking the same questions
+//! about hidden tokens and source text as the original implementation.
+
+use crate::prelude::Position;
+use std::ops::Range;
+use std::rc::Rc;
+
+/// The channel of all tokens the parser sees.
+pub(crate) const DEFAULT_CHANNEL: usize = 0;
+/// The channel of whitespace between tokens.
+pub(crate) const HIDDEN: usize = 1;
+/// The channel of newlines in the body of a node, which the indentation tracking needs to see but the parser does not.
+pub(crate) const WHITESPACE: usize = 2;
+/// The channel of comments.
+pub(crate) const COMMENTS: usize = 3;
+
+/// The type of the token marking the end of the input.
+pub(crate) const EOF: isize = -1;
+
+/// A token, i.e. a piece of source text with a meaning for the parser.
+///
+/// Positions are counted in characters, not bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Token {
+    pub(crate) token_type: isize,
+    pub(crate) text: String,
+    pub(crate) channel: usize,
+    /// The index of the first character of the token in the source.
+    pub(crate) start: isize,
+    /// The index of the last character of the token in the source.
+    /// One less than [`Token::start`] for tokens without text, like indentation or the end of the input.
+    pub(crate) stop: isize,
+    /// The line the token starts on, starting at 1.
+    pub(crate) line: usize,
+    /// The column the token starts at, starting at 0.
+    pub(crate) column: usize,
+    /// The index of the token in its [`TokenStream`], or -1 for tokens that are not part of one.
+    pub(crate) token_index: isize,
+}
+
+impl Token {
+    /// Creates a token that was not read from any source, like the tags the compiler adds to lines.
+    pub(crate) fn synthetic(token_type: isize, text: impl Into<String>) -> Self {
+        Self {
+            token_type,
+            text: text.into(),
+            channel: DEFAULT_CHANNEL,
+            start: -1,
+            stop: -1,
+            line: 0,
+            column: 0,
+            token_index: -1,
+        }
+    }
+
+    /// The range of the source the token spans, assuming it does not span multiple lines.
+    pub(crate) fn range(&self) -> Range<Position> {
+        let start = Position {
+            line: self.line.saturating_sub(1),
+            character: self.column,
+        };
+        let end = Position {
+            character: start.character + self.text.chars().count(),
+            ..start
+        };
+        start..end
+    }
+}
+
+/// All tokens of a file, including the ones on hidden channels, in the order they were read.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TokenStream {
+    tokens: Vec<Rc<Token>>,
+    text: String,
+}
+
+impl TokenStream {
+    /// Creates a stream of `tokens` read from `text`.
+    pub(crate) fn new(tokens: Vec<Rc<Token>>, text: impl Into<String>) -> Self {
+        Self {
+            tokens,
+            text: text.into(),
+        }
+    }
+
+    pub(crate) fn get_tokens(&self) -> &[Rc<Token>] {
+        &self.tokens
+    }
+
+    /// The whole text the tokens were read from.
+    pub(crate) fn get_all_text(&self) -> &str {
+        &self.text
+    }
+
+    /// The tokens on `channel` directly preceding the token at `token_index`, up to the previous token on the default channel.
+    pub(crate) fn get_hidden_tokens_to_left(
+        &self,
+        token_index: isize,
+        channel: usize,
+    ) -> Vec<Rc<Token>> {
+        let Ok(token_index) = usize::try_from(token_index) else {
+            return Vec::new();
+        };
+        let end = token_index.min(self.tokens.len());
+        let start = self.tokens[..end]
+            .iter()
+            .rposition(|token| token.channel == DEFAULT_CHANNEL)
+            .map_or(0, |index| index + 1);
+        self.tokens[start..end]
+            .iter()
+            .filter(|token| token.channel == channel)
+            .cloned()
+            .collect()
+    }
+
+    /// The tokens on `channel` directly following the token at `token_index`, up to the next token on the default channel.
+    pub(crate) fn get_hidden_tokens_to_right(
+        &self,
+        token_index: isize,
+        channel: usize,
+    ) -> Vec<Rc<Token>> {
+        let Ok(token_index) = usize::try_from(token_index) else {
+            return Vec::new();
+        };
+        let start = (token_index + 1).min(self.tokens.len());
+        self.tokens[start..]
+            .iter()
+            .take_while(|token| token.channel != DEFAULT_CHANNEL)
+            .filter(|token| token.channel == channel)
+            .cloned()
+            .collect()
+    }
+
+    /// The source text of all tokens from `start` to `stop`, both inclusive, regardless of their channel.
+    pub(crate) fn get_text_from_interval(&self, start: isize, stop: isize) -> String {
+        let (Ok(start), Ok(stop)) = (usize::try_from(start), usize::try_from(stop)) else {
+            return String::new();
+        };
+        self.tokens
+            .iter()
+            .skip(start)
+            .take((stop + 1).saturating_sub(start))
+            .take_while(|token| token.token_type != EOF)
+            .map(|token| token.text.as_str())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(token_type: isize, text: &str, channel: usize, token_index: isize) -> Rc<Token> {
+        Rc::new(Token {
+            channel,
+            token_index,
+            ..Token::synthetic(token_type, text)
+        })
+    }
+
+    #[test]
+    fn finds_hidden_tokens_between_default_tokens() {
+        let stream = TokenStream::new(
+            vec![
+                token(1, "a", DEFAULT_CHANNEL, 0),
+                token(2, "// one", COMMENTS, 1),
+                token(3, " ", HIDDEN, 2),
+                token(1, "b", DEFAULT_CHANNEL, 3),
+                token(2, "// two", COMMENTS, 4),
+                token(EOF, "<EOF>", DEFAULT_CHANNEL, 5),
+            ],
+            "a// one b// two",
+        );
+
+        let left: Vec<_> = stream
+            .get_hidden_tokens_to_left(3, COMMENTS)
+            .into_iter()
+            .map(|token| token.text.clone())
+            .collect();
+        let right: Vec<_> = stream
+            .get_hidden_tokens_to_right(3, COMMENTS)
+            .into_iter()
+            .map(|token| token.text.clone())
+            .collect();
+
+        assert_eq!(vec!["// one"], left);
+        assert_eq!(vec!["// two"], right);
+        assert_eq!("a// one b// two", stream.get_text_from_interval(0, 5));
+    }
+}
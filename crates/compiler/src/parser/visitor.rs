@@ -0,0 +1,323 @@
+//! The visitor over the parse tree, taking the place of the one generated by ANTLR.
+
+use super::contexts::*;
+use super::token::Token;
+use std::rc::Rc;
+
+/// Implemented by everything a [`YarnSpinnerParserVisitor`] can visit.
+pub(crate) trait Visitable {
+    fn accept<V: YarnSpinnerParserVisitor + ?Sized>(&self, visitor: &mut V) -> V::Return;
+}
+
+/// Visits the parse tree produced by the [`YarnSpinnerParser`](super::YarnSpinnerParser).
+///
+/// Every method visits the children of the context by default, returning the result of the last child.
+/// The alternatives of `expression`, `value` and `jump_statement` are visited by their own methods.
+pub(crate) trait YarnSpinnerParserVisitor {
+    type Return: Default;
+
+    fn visit(&mut self, tree: &(impl Visitable + ?Sized)) -> Self::Return {
+        tree.accept(self)
+    }
+
+    fn visit_children(&mut self, ctx: &(impl ParserRuleContext + ?Sized)) -> Self::Return {
+        // Cloned so that visitors are free to add children, like the tags of lines.
+        let children = ctx.get_children().clone();
+        let mut result = Self::Return::default();
+        for child in &children {
+            result = self.visit(child);
+        }
+        result
+    }
+
+    fn visit_terminal(&mut self, _token: &Rc<Token>) -> Self::Return {
+        Self::Return::default()
+    }
+
+    fn visit_dialogue(&mut self, ctx: &DialogueContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_file_hashtag(&mut self, ctx: &FileHashtagContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_node(&mut self, ctx: &NodeContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_header(&mut self, ctx: &HeaderContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_body(&mut self, ctx: &BodyContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_statement(&mut self, ctx: &StatementContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_line_statement(&mut self, ctx: &LineStatementContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_line_formatted_text(&mut self, ctx: &LineFormattedTextContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_hashtag(&mut self, ctx: &HashtagContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_line_condition(&mut self, ctx: &LineConditionContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    /// Dispatches to the method of the alternative.
+    fn visit_expression(&mut self, ctx: &ExpressionContext) -> Self::Return {
+        match ctx.kind {
+            ExpressionKind::Parens => self.visit_exp_parens(ctx),
+            ExpressionKind::Negative => self.visit_exp_negative(ctx),
+            ExpressionKind::Not => self.visit_exp_not(ctx),
+            ExpressionKind::MultDivMod => self.visit_exp_mult_div_mod(ctx),
+            ExpressionKind::AddSub => self.visit_exp_add_sub(ctx),
+            ExpressionKind::Comparison => self.visit_exp_comparison(ctx),
+            ExpressionKind::Equality => self.visit_exp_equality(ctx),
+            ExpressionKind::AndOrXor => self.visit_exp_and_or_xor(ctx),
+            ExpressionKind::Value => self.visit_exp_value(ctx),
+            ExpressionKind::Error => self.visit_children(ctx),
+        }
+    }
+
+    fn visit_exp_parens(&mut self, ctx: &ExpressionContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_exp_negative(&mut self, ctx: &ExpressionContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_exp_not(&mut self, ctx: &ExpressionContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_exp_mult_div_mod(&mut self, ctx: &ExpressionContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_exp_add_sub(&mut self, ctx: &ExpressionContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_exp_comparison(&mut self, ctx: &ExpressionContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_exp_equality(&mut self, ctx: &ExpressionContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_exp_and_or_xor(&mut self, ctx: &ExpressionContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_exp_value(&mut self, ctx: &ExpressionContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    /// Dispatches to the method of the alternative.
+    fn visit_value(&mut self, ctx: &ValueContext) -> Self::Return {
+        match ctx.kind {
+            ValueKind::Number => self.visit_value_number(ctx),
+            ValueKind::True => self.visit_value_true(ctx),
+            ValueKind::False => self.visit_value_false(ctx),
+            ValueKind::Var => self.visit_value_var(ctx),
+            ValueKind::String => self.visit_value_string(ctx),
+            ValueKind::Null => self.visit_value_null(ctx),
+            ValueKind::Func => self.visit_value_func(ctx),
+            ValueKind::Error => self.visit_children(ctx),
+        }
+    }
+
+    fn visit_value_number(&mut self, ctx: &ValueContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_value_true(&mut self, ctx: &ValueContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_value_false(&mut self, ctx: &ValueContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_value_var(&mut self, ctx: &ValueContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_value_string(&mut self, ctx: &ValueContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_value_null(&mut self, ctx: &ValueContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_value_func(&mut self, ctx: &ValueContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_variable(&mut self, ctx: &VariableContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_function_call(&mut self, ctx: &FunctionCallContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_if_statement(&mut self, ctx: &IfStatementContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_if_clause(&mut self, ctx: &IfClauseContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_else_if_clause(&mut self, ctx: &ElseIfClauseContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_else_clause(&mut self, ctx: &ElseClauseContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_set_statement(&mut self, ctx: &SetStatementContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_call_statement(&mut self, ctx: &CallStatementContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_command_statement(&mut self, ctx: &CommandStatementContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_command_formatted_text(&mut self, ctx: &CommandFormattedTextContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_shortcut_option_statement(
+        &mut self,
+        ctx: &ShortcutOptionStatementContext,
+    ) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_shortcut_option(&mut self, ctx: &ShortcutOptionContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_declare_statement(&mut self, ctx: &DeclareStatementContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    /// Dispatches to the method of the alternative.
+    fn visit_jump_statement(&mut self, ctx: &JumpStatementContext) -> Self::Return {
+        match ctx.kind {
+            JumpKind::JumpToNodeName => self.visit_jump_to_node_name(ctx),
+            JumpKind::JumpToExpression => self.visit_jump_to_expression(ctx),
+            JumpKind::Error => self.visit_children(ctx),
+        }
+    }
+
+    fn visit_jump_to_node_name(&mut self, ctx: &JumpStatementContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+
+    fn visit_jump_to_expression(&mut self, ctx: &JumpStatementContext) -> Self::Return {
+        self.visit_children(ctx)
+    }
+}
+
+impl Visitable for ParseTree {
+    fn accept<V: YarnSpinnerParserVisitor + ?Sized>(&self, visitor: &mut V) -> V::Return {
+        match self {
+            ParseTree::Token(token) | ParseTree::Error(token) => visitor.visit_terminal(token),
+            ParseTree::Dialogue(ctx) => ctx.accept(visitor),
+            ParseTree::FileHashtag(ctx) => ctx.accept(visitor),
+            ParseTree::Node(ctx) => ctx.accept(visitor),
+            ParseTree::Header(ctx) => ctx.accept(visitor),
+            ParseTree::Body(ctx) => ctx.accept(visitor),
+            ParseTree::Statement(ctx) => ctx.accept(visitor),
+            ParseTree::LineStatement(ctx) => ctx.accept(visitor),
+            ParseTree::LineFormattedText(ctx) => ctx.accept(visitor),
+            ParseTree::Hashtag(ctx) => ctx.accept(visitor),
+            ParseTree::LineCondition(ctx) => ctx.accept(visitor),
+            ParseTree::Expression(ctx) => ctx.accept(visitor),
+            ParseTree::Value(ctx) => ctx.accept(visitor),
+            ParseTree::Variable(ctx) => ctx.accept(visitor),
+            ParseTree::FunctionCall(ctx) => ctx.accept(visitor),
+            ParseTree::IfStatement(ctx) => ctx.accept(visitor),
+            ParseTree::IfClause(ctx) => ctx.accept(visitor),
+            ParseTree::ElseIfClause(ctx) => ctx.accept(visitor),
+            ParseTree::ElseClause(ctx) => ctx.accept(visitor),
+            ParseTree::SetStatement(ctx) => ctx.accept(visitor),
+            ParseTree::CallStatement(ctx) => ctx.accept(visitor),
+            ParseTree::CommandStatement(ctx) => ctx.accept(visitor),
+            ParseTree::CommandFormattedText(ctx) => ctx.accept(visitor),
+            ParseTree::ShortcutOptionStatement(ctx) => ctx.accept(visitor),
+            ParseTree::ShortcutOption(ctx) => ctx.accept(visitor),
+            ParseTree::DeclareStatement(ctx) => ctx.accept(visitor),
+            ParseTree::JumpStatement(ctx) => ctx.accept(visitor),
+        }
+    }
+}
+
+impl<T: Visitable + ?Sized> Visitable for Rc<T> {
+    fn accept<V: YarnSpinnerParserVisitor + ?Sized>(&self, visitor: &mut V) -> V::Return {
+        self.as_ref().accept(visitor)
+    }
+}
+
+macro_rules! visitable {
+    ($($context:ident => $method:ident,)*) => {
+        $(
+            impl Visitable for $context {
+                fn accept<V: YarnSpinnerParserVisitor + ?Sized>(&self, visitor: &mut V) -> V::Return {
+                    visitor.$method(self)
+                }
+            }
+        )*
+    };
+}
+
+visitable! {
+    DialogueContext => visit_dialogue,
+    FileHashtagContext => visit_file_hashtag,
+    NodeContext => visit_node,
+    HeaderContext => visit_header,
+    BodyContext => visit_body,
+    StatementContext => visit_statement,
+    LineStatementContext => visit_line_statement,
+    LineFormattedTextContext => visit_line_formatted_text,
+    HashtagContext => visit_hashtag,
+    LineConditionContext => visit_line_condition,
+    ExpressionContext => visit_expression,
+    ValueContext => visit_value,
+    VariableContext => visit_variable,
+    FunctionCallContext => visit_function_call,
+    IfStatementContext => visit_if_statement,
+    IfClauseContext => visit_if_clause,
+    ElseIfClauseContext => visit_else_if_clause,
+    ElseClauseContext => visit_else_clause,
+    SetStatementContext => visit_set_statement,
+    CallStatementContext => visit_call_statement,
+    CommandStatementContext => visit_command_statement,
+    CommandFormattedTextContext => visit_command_formatted_text,
+    ShortcutOptionStatementContext => visit_shortcut_option_statement,
+    ShortcutOptionContext => visit_shortcut_option,
+    DeclareStatementContext => visit_declare_statement,
+    JumpStatementContext => visit_jump_statement,
+}
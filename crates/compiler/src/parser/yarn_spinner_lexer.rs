@@ -0,0 +1,1005 @@
+//! A hand-written lexer that produces the same tokens as the one ANTLR generates from
+//! <https://github.com/YarnSpinnerTool/YarnSpinner/blob/da39c7195107d8211f21c263e4084f773b84eaff/YarnSpinner.Compiler/YarnSpinnerLexer.g4>.
+//!
+//! Like the generated lexer, it switches between modes, e.g. when reading the text of a line or an expression,
+//! and picks the longest match among the rules of the current mode, preferring the rule listed first on a tie.
+//! Indentation is handled on top of this by the [`IndentAwareYarnSpinnerLexer`](super::IndentAwareYarnSpinnerLexer).
+
+use super::token::*;
+use crate::prelude::*;
+use yarnspinner_core::prelude::*;
+
+pub(crate) const INDENT: isize = 1;
+pub(crate) const DEDENT: isize = 2;
+pub(crate) const BLANK_LINE_FOLLOWING_OPTION: isize = 3;
+pub(crate) const WS: isize = 4;
+pub(crate) const COMMENT: isize = 5;
+pub(crate) const NEWLINE: isize = 6;
+pub(crate) const ID: isize = 7;
+pub(crate) const BODY_START: isize = 8;
+pub(crate) const HEADER_DELIMITER: isize = 9;
+pub(crate) const HASHTAG: isize = 10;
+pub(crate) const REST_OF_LINE: isize = 11;
+pub(crate) const BODY_WS: isize = 12;
+pub(crate) const BODY_END: isize = 13;
+pub(crate) const SHORTCUT_ARROW: isize = 14;
+pub(crate) const COMMAND_START: isize = 15;
+pub(crate) const EXPRESSION_START: isize = 16;
+// Part of the vocabulary of the grammar, but never produced by the lexer.
+#[allow(dead_code)]
+pub(crate) const ESCAPED_ANY: isize = 17;
+#[allow(dead_code)]
+pub(crate) const TEXT_ESCAPE: isize = 18;
+pub(crate) const TEXT_COMMENT: isize = 19;
+pub(crate) const TEXT: isize = 20;
+pub(crate) const UNESCAPABLE_CHARACTER: isize = 21;
+pub(crate) const TEXT_COMMANDHASHTAG_WS: isize = 22;
+pub(crate) const TEXT_COMMANDHASHTAG_COMMENT: isize = 23;
+pub(crate) const TEXT_COMMANDHASHTAG_ERROR: isize = 24;
+pub(crate) const HASHTAG_WS: isize = 25;
+pub(crate) const HASHTAG_TEXT: isize = 26;
+pub(crate) const EXPR_WS: isize = 27;
+pub(crate) const KEYWORD_TRUE: isize = 28;
+pub(crate) const KEYWORD_FALSE: isize = 29;
+pub(crate) const KEYWORD_NULL: isize = 30;
+pub(crate) const OPERATOR_ASSIGNMENT: isize = 31;
+pub(crate) const OPERATOR_LOGICAL_LESS_THAN_EQUALS: isize = 32;
+pub(crate) const OPERATOR_LOGICAL_GREATER_THAN_EQUALS: isize = 33;
+pub(crate) const OPERATOR_LOGICAL_EQUALS: isize = 34;
+pub(crate) const OPERATOR_LOGICAL_LESS: isize = 35;
+pub(crate) const OPERATOR_LOGICAL_GREATER: isize = 36;
+pub(crate) const OPERATOR_LOGICAL_NOT_EQUALS: isize = 37;
+pub(crate) const OPERATOR_LOGICAL_AND: isize = 38;
+pub(crate) const OPERATOR_LOGICAL_OR: isize = 39;
+pub(crate) const OPERATOR_LOGICAL_XOR: isize = 40;
+pub(crate) const OPERATOR_LOGICAL_NOT: isize = 41;
+pub(crate) const OPERATOR_MATHS_ADDITION_EQUALS: isize = 42;
+pub(crate) const OPERATOR_MATHS_SUBTRACTION_EQUALS: isize = 43;
+pub(crate) const OPERATOR_MATHS_MULTIPLICATION_EQUALS: isize = 44;
+pub(crate) const OPERATOR_MATHS_MODULUS_EQUALS: isize = 45;
+pub(crate) const OPERATOR_MATHS_DIVISION_EQUALS: isize = 46;
+pub(crate) const OPERATOR_MATHS_ADDITION: isize = 47;
+pub(crate) const OPERATOR_MATHS_SUBTRACTION: isize = 48;
+pub(crate) const OPERATOR_MATHS_MULTIPLICATION: isize = 49;
+pub(crate) const OPERATOR_MATHS_DIVISION: isize = 50;
+pub(crate) const OPERATOR_MATHS_MODULUS: isize = 51;
+pub(crate) const LPAREN: isize = 52;
+pub(crate) const RPAREN: isize = 53;
+pub(crate) const COMMA: isize = 54;
+pub(crate) const EXPRESSION_AS: isize = 55;
+pub(crate) const STRING: isize = 56;
+pub(crate) const FUNC_ID: isize = 57;
+pub(crate) const EXPRESSION_END: isize = 58;
+pub(crate) const VAR_ID: isize = 59;
+pub(crate) const DOT: isize = 60;
+pub(crate) const NUMBER: isize = 61;
+pub(crate) const COMMAND_WS: isize = 62;
+pub(crate) const COMMAND_IF: isize = 63;
+pub(crate) const COMMAND_ELSEIF: isize = 64;
+pub(crate) const COMMAND_ELSE: isize = 65;
+pub(crate) const COMMAND_SET: isize = 66;
+pub(crate) const COMMAND_ENDIF: isize = 67;
+pub(crate) const COMMAND_CALL: isize = 68;
+pub(crate) const COMMAND_DECLARE: isize = 69;
+pub(crate) const COMMAND_JUMP: isize = 70;
+pub(crate) const COMMAND_ENUM: isize = 71;
+pub(crate) const COMMAND_CASE: isize = 72;
+pub(crate) const COMMAND_ENDENUM: isize = 73;
+pub(crate) const COMMAND_LOCAL: isize = 74;
+pub(crate) const COMMAND_END: isize = 75;
+pub(crate) const COMMAND_TEXT_END: isize = 76;
+pub(crate) const COMMAND_EXPRESSION_START: isize = 77;
+pub(crate) const COMMAND_TEXT: isize = 78;
+// Part of the vocabulary of the grammar, but never produced by the lexer.
+#[allow(dead_code)]
+pub(crate) const TYPE_STRING: isize = 79;
+#[allow(dead_code)]
+pub(crate) const TYPE_NUMBER: isize = 80;
+#[allow(dead_code)]
+pub(crate) const TYPE_BOOL: isize = 81;
+
+/// The names of the token types as they appear in the grammar, indexed by token type.
+pub(crate) const SYMBOLIC_NAMES: [&str; 82] = [
+    "<INVALID>",
+    "INDENT",
+    "DEDENT",
+    "BLANK_LINE_FOLLOWING_OPTION",
+    "WS",
+    "COMMENT",
+    "NEWLINE",
+    "ID",
+    "BODY_START",
+    "HEADER_DELIMITER",
+    "HASHTAG",
+    "REST_OF_LINE",
+    "BODY_WS",
+    "BODY_END",
+    "SHORTCUT_ARROW",
+    "COMMAND_START",
+    "EXPRESSION_START",
+    "ESCAPED_ANY",
+    "TEXT_ESCAPE",
+    "TEXT_COMMENT",
+    "TEXT",
+    "UNESCAPABLE_CHARACTER",
+    "TEXT_COMMANDHASHTAG_WS",
+    "TEXT_COMMANDHASHTAG_COMMENT",
+    "TEXT_COMMANDHASHTAG_ERROR",
+    "HASHTAG_WS",
+    "HASHTAG_TEXT",
+    "EXPR_WS",
+    "KEYWORD_TRUE",
+    "KEYWORD_FALSE",
+    "KEYWORD_NULL",
+    "OPERATOR_ASSIGNMENT",
+    "OPERATOR_LOGICAL_LESS_THAN_EQUALS",
+    "OPERATOR_LOGICAL_GREATER_THAN_EQUALS",
+    "OPERATOR_LOGICAL_EQUALS",
+    "OPERATOR_LOGICAL_LESS",
+    "OPERATOR_LOGICAL_GREATER",
+    "OPERATOR_LOGICAL_NOT_EQUALS",
+    "OPERATOR_LOGICAL_AND",
+    "OPERATOR_LOGICAL_OR",
+    "OPERATOR_LOGICAL_XOR",
+    "OPERATOR_LOGICAL_NOT",
+    "OPERATOR_MATHS_ADDITION_EQUALS",
+    "OPERATOR_MATHS_SUBTRACTION_EQUALS",
+    "OPERATOR_MATHS_MULTIPLICATION_EQUALS",
+    "OPERATOR_MATHS_MODULUS_EQUALS",
+    "OPERATOR_MATHS_DIVISION_EQUALS",
+    "OPERATOR_MATHS_ADDITION",
+    "OPERATOR_MATHS_SUBTRACTION",
+    "OPERATOR_MATHS_MULTIPLICATION",
+    "OPERATOR_MATHS_DIVISION",
+    "OPERATOR_MATHS_MODULUS",
+    "LPAREN",
+    "RPAREN",
+    "COMMA",
+    "EXPRESSION_AS",
+    "STRING",
+    "FUNC_ID",
+    "EXPRESSION_END",
+    "VAR_ID",
+    "DOT",
+    "NUMBER",
+    "COMMAND_WS",
+    "COMMAND_IF",
+    "COMMAND_ELSEIF",
+    "COMMAND_ELSE",
+    "COMMAND_SET",
+    "COMMAND_ENDIF",
+    "COMMAND_CALL",
+    "COMMAND_DECLARE",
+    "COMMAND_JUMP",
+    "COMMAND_ENUM",
+    "COMMAND_CASE",
+    "COMMAND_ENDENUM",
+    "COMMAND_LOCAL",
+    "COMMAND_END",
+    "COMMAND_TEXT_END",
+    "COMMAND_EXPRESSION_START",
+    "COMMAND_TEXT",
+    "TYPE_STRING",
+    "TYPE_NUMBER",
+    "TYPE_BOOL",
+];
+
+/// The modes of the lexer, named like in the grammar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Default,
+    Header,
+    Body,
+    Text,
+    TextEscaped,
+    TextCommandOrHashtag,
+    Hashtag,
+    Expression,
+    Command,
+    CommandText,
+    CommandId,
+    CommandIdOrExpression,
+}
+
+/// A change to the mode stack after a rule matched, in the order given by the grammar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ModeChange {
+    Push(Mode),
+    Pop,
+    Set(Mode),
+}
+
+/// What a rule of the grammar does when it matches.
+#[derive(Debug, Clone, Copy)]
+struct Rule {
+    /// The type of the produced token, or [`None`] if the rule skips its text.
+    token_type: Option<isize>,
+    channel: usize,
+    mode_changes: &'static [ModeChange],
+}
+
+impl Rule {
+    const fn token(token_type: isize) -> Self {
+        Self {
+            token_type: Some(token_type),
+            channel: DEFAULT_CHANNEL,
+            mode_changes: &[],
+        }
+    }
+
+    const fn skip() -> Self {
+        Self {
+            token_type: None,
+            channel: DEFAULT_CHANNEL,
+            mode_changes: &[],
+        }
+    }
+
+    const fn on_channel(self, channel: usize) -> Self {
+        Self { channel, ..self }
+    }
+
+    const fn then(self, mode_changes: &'static [ModeChange]) -> Self {
+        Self {
+            mode_changes,
+            ..self
+        }
+    }
+}
+
+use ModeChange::*;
+
+/// Splits the source text of a Yarn file into tokens, including the ones on hidden channels.
+///
+/// Unrecognized characters are reported as warnings and skipped.
+pub(crate) struct YarnSpinnerLexer {
+    input: Vec<char>,
+    index: usize,
+    line: usize,
+    column: usize,
+    mode: Mode,
+    mode_stack: Vec<Mode>,
+    file_name: String,
+    pub(crate) diagnostics: Vec<Diagnostic>,
+}
+
+impl YarnSpinnerLexer {
+    pub(crate) fn new(input: &[char], file_name: impl Into<String>) -> Self {
+        Self {
+            input: input.to_vec(),
+            index: 0,
+            line: 1,
+            column: 0,
+            mode: Mode::Default,
+            mode_stack: Vec::new(),
+            file_name: file_name.into(),
+            diagnostics: Vec::new(),
+        }
+    }
+
+    pub(crate) fn input(&self) -> &[char] {
+        &self.input
+    }
+
+    pub(crate) fn input_size(&self) -> usize {
+        self.input.len()
+    }
+
+    /// The index of the next character to read.
+    pub(crate) fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The line of the next character to read, starting at 1.
+    pub(crate) fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The column of the next character to read, starting at 0.
+    pub(crate) fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Reads the next token, which is an [`EOF`] token once all input was read.
+    pub(crate) fn next_token(&mut self) -> Token {
+        loop {
+            let (start, line, column) = (self.index, self.line, self.column);
+            if start >= self.input.len() {
+                return Token {
+                    token_type: EOF,
+                    text: "<EOF>".to_owned(),
+                    channel: DEFAULT_CHANNEL,
+                    start: start as isize,
+                    stop: start as isize - 1,
+                    line,
+                    column,
+                    token_index: -1,
+                };
+            }
+            if self.is_inside_command_or_expression() {
+                if let Some(length) = self.newline() {
+                    return self.end_unterminated_line(length);
+                }
+            }
+            // A `#` without text ends at the end of its line, which the mode it was found in reads as usual.
+            if self.mode == Mode::Hashtag && self.newline().is_some() {
+                self.change_mode(Pop);
+                continue;
+            }
+            let Some((length, rule)) = self.match_rule() else {
+                self.recover_from_unrecognized_input();
+                continue;
+            };
+            self.consume(length);
+            for mode_change in rule.mode_changes {
+                self.change_mode(*mode_change);
+            }
+            let Some(token_type) = rule.token_type else {
+                continue;
+            };
+            return Token {
+                token_type,
+                text: self.input[start..self.index].iter().collect(),
+                channel: rule.channel,
+                start: start as isize,
+                stop: self.index as isize - 1,
+                line,
+                column,
+                token_index: -1,
+            };
+        }
+    }
+
+    fn consume(&mut self, length: usize) {
+        for _ in 0..length {
+            if self.input[self.index] == '\n' {
+                self.line += 1;
+                self.column = 0;
+            } else {
+                self.column += 1;
+            }
+            self.index += 1;
+        }
+    }
+
+    fn change_mode(&mut self, mode_change: ModeChange) {
+        match mode_change {
+            Push(mode) => {
+                self.mode_stack.push(self.mode);
+                self.mode = mode;
+            }
+            Pop => {
+                self.mode = self.mode_stack.pop().unwrap_or(Mode::Default);
+            }
+            Set(mode) => self.mode = mode,
+        }
+    }
+
+    fn is_inside_command_or_expression(&self) -> bool {
+        matches!(
+            self.mode,
+            Mode::Command
+                | Mode::CommandText
+                | Mode::CommandId
+                | Mode::CommandIdOrExpression
+                | Mode::Expression
+        )
+    }
+
+    /// Ends the commands and expressions that are still open at the end of a line,
+    /// so that the following lines and nodes are read as usual instead of as part of them.
+    /// The newline is returned like the one ending the statement they are part of.
+    fn end_unterminated_line(&mut self, length: usize) -> Token {
+        let (start, line, column) = (self.index, self.line, self.column);
+        let mut was_in_command = false;
+        while !matches!(self.mode, Mode::Body | Mode::Default) {
+            was_in_command |= matches!(
+                self.mode,
+                Mode::Command | Mode::CommandText | Mode::CommandId | Mode::CommandIdOrExpression
+            );
+            self.change_mode(Pop);
+        }
+        if was_in_command {
+            let diagnostic = self.diagnostic_at_next_character(
+                DiagnosticCode::NewlineInCommand,
+                "Newlines are not allowed in commands",
+            );
+            self.diagnostics
+                .push(diagnostic.with_severity(DiagnosticSeverity::Error));
+        }
+        self.consume(length);
+        Token {
+            token_type: NEWLINE,
+            text: self.input[start..self.index].iter().collect(),
+            channel: DEFAULT_CHANNEL,
+            start: start as isize,
+            stop: self.index as isize - 1,
+            line,
+            column,
+            token_index: -1,
+        }
+    }
+
+    /// Reports the characters that no rule of the current mode accepts and skips them,
+    /// which are the characters up to and including the first one that no rule could continue with.
+    /// Like the ANTLR based implementation, whitespace is skipped without a report.
+    fn recover_from_unrecognized_input(&mut self) {
+        let end = (self.index + self.unrecognized_length()).min(self.input.len());
+        let text: String = self.input[self.index..end].iter().collect();
+        if text.chars().all(char::is_whitespace) {
+            self.consume(end - self.index);
+            return;
+        }
+        let text = text
+            .replace('\n', "\\n")
+            .replace('\r', "\\r")
+            .replace('\t', "\\t");
+        let diagnostic = self.diagnostic_at_next_character(
+            DiagnosticCode::SyntaxError,
+            format!("token recognition error at: '{text}'"),
+        );
+        self.diagnostics.push(
+            // The lexer errors of the ANTLR based implementation never made it into the diagnostics,
+            // so reporting them as errors would break files that used to compile, like ones with stray characters between nodes.
+            diagnostic.with_severity(DiagnosticSeverity::Warning),
+        );
+        self.consume(end - self.index);
+    }
+
+    /// A diagnostic pointing at the next character to read, showing the line it is on.
+    fn diagnostic_at_next_character(
+        &self,
+        code: DiagnosticCode,
+        message: impl Into<String>,
+    ) -> Diagnostic {
+        let line = self.line - 1;
+        let line_text: String = self
+            .input
+            .split(|&c| c == '\n')
+            .nth(line)
+            .unwrap_or_default()
+            .iter()
+            .filter(|&&c| c != '\r')
+            .collect();
+        let range = Position {
+            line,
+            character: self.column,
+        }..Position {
+            line,
+            character: self.column + 1,
+        };
+        Diagnostic::new(code, message)
+            .with_range(range)
+            .with_context(line_text)
+            .with_start_line(line)
+            .with_file_name(&self.file_name)
+    }
+
+    /// How many characters a failed match read before giving up, including the one it gave up at.
+    fn unrecognized_length(&self) -> usize {
+        match (self.mode, self.peek(0)) {
+            (Mode::Default, Some('/')) => 2,
+            (Mode::Default, Some('-')) => 2 + self.count_while(1, |c| c == '-').min(1),
+            (Mode::CommandText | Mode::CommandId | Mode::CommandIdOrExpression, Some('>')) => 2,
+            (Mode::Expression, Some('"')) => {
+                let mut length = 1;
+                loop {
+                    match self.peek(length) {
+                        Some('\\') if matches!(self.peek(length + 1), Some('"' | '\\')) => {
+                            length += 2
+                        }
+                        Some('\\') if !matches!(self.peek(length + 1), Some('\r' | '\n')) => {
+                            return length + 2
+                        }
+                        Some('"' | '\\') => return length + 1,
+                        // The newline ends the unterminated command or expression.
+                        Some('\r' | '\n') | None => return length,
+                        Some(_) => length += 1,
+                    }
+                }
+            }
+            (Mode::Expression, Some('$' | '&' | '|')) => 2,
+            _ => 1,
+        }
+    }
+
+    fn peek(&self, offset: usize) -> Option<char> {
+        self.input.get(self.index + offset).copied()
+    }
+
+    fn is_next(&self, text: &str) -> bool {
+        text.chars()
+            .enumerate()
+            .all(|(offset, c)| self.peek(offset) == Some(c))
+    }
+
+    /// The number of characters starting at `offset` that satisfy `predicate`.
+    fn count_while(&self, offset: usize, predicate: impl Fn(char) -> bool) -> usize {
+        self.input
+            .get(self.index + offset..)
+            .unwrap_or_default()
+            .iter()
+            .take_while(|&&c| predicate(c))
+            .count()
+    }
+
+    fn literal(&self, text: &str) -> Option<usize> {
+        self.is_next(text).then(|| text.chars().count())
+    }
+
+    fn whitespace(&self) -> Option<usize> {
+        Some(self.count_while(0, |c| c == ' ' || c == '\t')).filter(|&length| length > 0)
+    }
+
+    fn comment(&self) -> Option<usize> {
+        self.is_next("//")
+            .then(|| 2 + self.count_while(2, |c| c != '\r' && c != '\n'))
+    }
+
+    fn newline(&self) -> Option<usize> {
+        let length = match (self.peek(0), self.peek(1)) {
+            (Some('\r'), Some('\n')) => 2,
+            (Some('\r' | '\n'), _) => 1,
+            _ => return None,
+        };
+        Some(length + self.count_while(length, |c| c == ' ' || c == '\t'))
+    }
+
+    fn id(&self) -> Option<usize> {
+        is_identifier_head(self.peek(0)?).then(|| 1 + self.count_while(1, is_identifier_character))
+    }
+
+    fn any(&self) -> Option<usize> {
+        self.peek(0).map(|_| 1)
+    }
+
+    fn single(&self, predicate: impl Fn(char) -> bool) -> Option<usize> {
+        self.peek(0).filter(|&c| predicate(c)).map(|_| 1)
+    }
+
+    fn one_or_more(&self, predicate: impl Fn(char) -> bool) -> Option<usize> {
+        Some(self.count_while(0, predicate)).filter(|&length| length > 0)
+    }
+
+    /// A keyword of a command, followed by a whitespace character that is part of the token.
+    fn command_keyword(&self, keyword: &str, whitespace_required: bool) -> Option<usize> {
+        let length = self.literal(keyword)?;
+        match self.peek(length) {
+            Some(c) if is_command_whitespace(c) => Some(length + 1),
+            _ if !whitespace_required => Some(length),
+            _ => None,
+        }
+    }
+
+    fn string(&self) -> Option<usize> {
+        if self.peek(0)? != '"' {
+            return None;
+        }
+        let mut length = 1;
+        loop {
+            match self.peek(length)? {
+                '"' => return Some(length + 1),
+                '\\' if matches!(self.peek(length + 1), Some('"' | '\\')) => length += 2,
+                '\\' | '\r' | '\n' => return None,
+                _ => length += 1,
+            }
+        }
+    }
+
+    fn number(&self) -> Option<usize> {
+        let integer = self.one_or_more(|c| c.is_ascii_digit())?;
+        if self.peek(integer) == Some('.') {
+            let fraction = self.count_while(integer + 1, |c| c.is_ascii_digit());
+            if fraction > 0 {
+                return Some(integer + 1 + fraction);
+            }
+        }
+        Some(integer)
+    }
+
+    fn variable(&self) -> Option<usize> {
+        if self.peek(0)? != '$' || !is_identifier_head(self.peek(1)?) {
+            return None;
+        }
+        Some(2 + self.count_while(2, is_identifier_character))
+    }
+
+    /// Finds the rule of the current mode with the longest match, preferring the first one listed on a tie.
+    fn match_rule(&self) -> Option<(usize, Rule)> {
+        let candidates = match self.mode {
+            Mode::Default => self.default_mode_rules(),
+            Mode::Header => vec![
+                (
+                    self.one_or_more(|c| c != '\r' && c != '\n'),
+                    Rule::token(REST_OF_LINE),
+                ),
+                (
+                    self.newline(),
+                    Rule::token(NEWLINE).on_channel(WHITESPACE).then(&[Pop]),
+                ),
+            ],
+            Mode::Body => self.body_mode_rules(),
+            Mode::Text => self.text_mode_rules(),
+            Mode::TextEscaped => vec![
+                (
+                    self.single(|c| matches!(c, '\\' | '<' | '>' | '{' | '}' | '#' | '/')),
+                    Rule::token(TEXT).then(&[Pop]),
+                ),
+                (self.any(), Rule::token(UNESCAPABLE_CHARACTER).then(&[Pop])),
+            ],
+            Mode::TextCommandOrHashtag => vec![
+                (
+                    self.whitespace(),
+                    Rule::token(TEXT_COMMANDHASHTAG_WS).on_channel(HIDDEN),
+                ),
+                (
+                    self.comment(),
+                    Rule::token(TEXT_COMMANDHASHTAG_COMMENT).on_channel(COMMENTS),
+                ),
+                (
+                    self.literal("<<"),
+                    Rule::token(COMMAND_START).then(&[Push(Mode::Command)]),
+                ),
+                (
+                    self.literal("#"),
+                    Rule::token(HASHTAG).then(&[Push(Mode::Hashtag)]),
+                ),
+                (self.newline(), Rule::token(NEWLINE).then(&[Pop])),
+                (self.any(), Rule::token(TEXT_COMMANDHASHTAG_ERROR)),
+            ],
+            Mode::Hashtag => vec![
+                (
+                    self.whitespace(),
+                    Rule::token(HASHTAG_WS).on_channel(HIDDEN),
+                ),
+                (self.literal("#"), Rule::token(HASHTAG)),
+                (
+                    self.one_or_more(|c| !matches!(c, '\t' | '\n' | '\r' | ' ' | '#' | '$' | '<')),
+                    Rule::token(HASHTAG_TEXT).then(&[Pop]),
+                ),
+            ],
+            Mode::Expression => self.expression_mode_rules(),
+            Mode::Command => self.command_mode_rules(),
+            Mode::CommandText => vec![
+                (
+                    self.literal(">>"),
+                    Rule::token(COMMAND_TEXT_END).then(&[Pop]),
+                ),
+                (
+                    self.literal("{"),
+                    Rule::token(COMMAND_EXPRESSION_START).then(&[Push(Mode::Expression)]),
+                ),
+                (
+                    self.one_or_more(|c| c != '>' && c != '{'),
+                    Rule::token(COMMAND_TEXT),
+                ),
+            ],
+            Mode::CommandId => vec![
+                (self.id(), Rule::token(ID).then(&[Pop])),
+                (self.literal(">>"), Rule::token(COMMAND_END).then(&[Pop])),
+            ],
+            Mode::CommandIdOrExpression => vec![
+                (self.id(), Rule::token(ID).then(&[Pop])),
+                (
+                    self.literal("{"),
+                    Rule::token(EXPRESSION_START).then(&[Set(Mode::Expression)]),
+                ),
+                (self.literal(">>"), Rule::token(COMMAND_END).then(&[Pop])),
+            ],
+        };
+        candidates
+            .into_iter()
+            .filter_map(|(length, rule)| length.map(|length| (length, rule)))
+            .fold(
+                None,
+                |best: Option<(usize, Rule)>, (length, rule)| match best {
+                    Some((best_length, _)) if best_length >= length => best,
+                    _ => Some((length, rule)),
+                },
+            )
+    }
+
+    fn default_mode_rules(&self) -> Vec<(Option<usize>, Rule)> {
+        vec![
+            (self.whitespace(), Rule::token(WS).on_channel(HIDDEN)),
+            (self.comment(), Rule::token(COMMENT).on_channel(COMMENTS)),
+            (self.newline(), Rule::token(NEWLINE).on_channel(WHITESPACE)),
+            (self.id(), Rule::token(ID)),
+            (
+                self.literal("---"),
+                Rule::token(BODY_START).then(&[Push(Mode::Body)]),
+            ),
+            (
+                self.literal(":")
+                    .map(|length| length + self.count_while(1, |c| c == ' ')),
+                Rule::token(HEADER_DELIMITER).then(&[Push(Mode::Header)]),
+            ),
+            (
+                self.literal("#"),
+                Rule::token(HASHTAG).then(&[Push(Mode::Hashtag)]),
+            ),
+        ]
+    }
+
+    fn body_mode_rules(&self) -> Vec<(Option<usize>, Rule)> {
+        vec![
+            (self.whitespace(), Rule::token(BODY_WS).on_channel(HIDDEN)),
+            (self.newline(), Rule::token(NEWLINE).on_channel(WHITESPACE)),
+            (self.comment(), Rule::token(COMMENT).on_channel(COMMENTS)),
+            (self.literal("==="), Rule::token(BODY_END).then(&[Pop])),
+            (self.literal("->"), Rule::token(SHORTCUT_ARROW)),
+            (
+                self.literal("<<"),
+                Rule::token(COMMAND_START).then(&[Push(Mode::Command)]),
+            ),
+            (
+                self.literal("#"),
+                Rule::token(HASHTAG).then(&[Push(Mode::TextCommandOrHashtag), Push(Mode::Hashtag)]),
+            ),
+            (
+                self.literal("{"),
+                Rule::token(EXPRESSION_START).then(&[Push(Mode::Text), Push(Mode::Expression)]),
+            ),
+            (
+                self.literal("\\"),
+                Rule::skip().then(&[Push(Mode::Text), Push(Mode::TextEscaped)]),
+            ),
+            (self.any(), Rule::token(TEXT).then(&[Push(Mode::Text)])),
+        ]
+    }
+
+    fn text_mode_rules(&self) -> Vec<(Option<usize>, Rule)> {
+        vec![
+            (self.newline(), Rule::token(NEWLINE).then(&[Pop])),
+            (
+                self.literal("\\[").or_else(|| self.literal("\\]")),
+                Rule::token(TEXT),
+            ),
+            (
+                self.literal("\\"),
+                Rule::skip().then(&[Push(Mode::TextEscaped)]),
+            ),
+            (
+                self.literal("#"),
+                Rule::token(HASHTAG).then(&[Set(Mode::TextCommandOrHashtag), Push(Mode::Hashtag)]),
+            ),
+            (
+                self.literal("{"),
+                Rule::token(EXPRESSION_START).then(&[Push(Mode::Expression)]),
+            ),
+            (
+                self.literal("<<"),
+                Rule::token(COMMAND_START)
+                    .then(&[Set(Mode::TextCommandOrHashtag), Push(Mode::Command)]),
+            ),
+            (
+                self.comment(),
+                Rule::token(TEXT_COMMENT).on_channel(COMMENTS),
+            ),
+            (
+                self.one_or_more(|c| !matches!(c, '\r' | '\n' | '#' | '/' | '<' | '\\' | '{'))
+                    .or_else(|| self.single(|c| c == '/' || c == '<')),
+                Rule::token(TEXT),
+            ),
+        ]
+    }
+
+    fn expression_mode_rules(&self) -> Vec<(Option<usize>, Rule)> {
+        // Keywords that are followed by more characters of an identifier are function names instead,
+        // which the longest match takes care of.
+        let keyword = |keyword: &str| self.literal(keyword);
+        let operator = |symbol: &str, word: &str| self.literal(symbol).or_else(|| keyword(word));
+        vec![
+            (self.whitespace(), Rule::token(EXPR_WS).on_channel(HIDDEN)),
+            (keyword("true"), Rule::token(KEYWORD_TRUE)),
+            (keyword("false"), Rule::token(KEYWORD_FALSE)),
+            (keyword("null"), Rule::token(KEYWORD_NULL)),
+            (operator("=", "to"), Rule::token(OPERATOR_ASSIGNMENT)),
+            (
+                operator("<=", "lte"),
+                Rule::token(OPERATOR_LOGICAL_LESS_THAN_EQUALS),
+            ),
+            (
+                operator(">=", "gte"),
+                Rule::token(OPERATOR_LOGICAL_GREATER_THAN_EQUALS),
+            ),
+            (
+                self.literal("==")
+                    .or_else(|| keyword("is"))
+                    .or_else(|| keyword("eq")),
+                Rule::token(OPERATOR_LOGICAL_EQUALS),
+            ),
+            (operator("<", "lt"), Rule::token(OPERATOR_LOGICAL_LESS)),
+            (operator(">", "gt"), Rule::token(OPERATOR_LOGICAL_GREATER)),
+            (
+                operator("!=", "neq"),
+                Rule::token(OPERATOR_LOGICAL_NOT_EQUALS),
+            ),
+            (operator("&&", "and"), Rule::token(OPERATOR_LOGICAL_AND)),
+            (operator("||", "or"), Rule::token(OPERATOR_LOGICAL_OR)),
+            (operator("^", "xor"), Rule::token(OPERATOR_LOGICAL_XOR)),
+            (operator("!", "not"), Rule::token(OPERATOR_LOGICAL_NOT)),
+            (
+                self.literal("+="),
+                Rule::token(OPERATOR_MATHS_ADDITION_EQUALS),
+            ),
+            (
+                self.literal("-="),
+                Rule::token(OPERATOR_MATHS_SUBTRACTION_EQUALS),
+            ),
+            (
+                self.literal("*="),
+                Rule::token(OPERATOR_MATHS_MULTIPLICATION_EQUALS),
+            ),
+            (
+                self.literal("%="),
+                Rule::token(OPERATOR_MATHS_MODULUS_EQUALS),
+            ),
+            (
+                self.literal("/="),
+                Rule::token(OPERATOR_MATHS_DIVISION_EQUALS),
+            ),
+            (self.literal("+"), Rule::token(OPERATOR_MATHS_ADDITION)),
+            (self.literal("-"), Rule::token(OPERATOR_MATHS_SUBTRACTION)),
+            (
+                self.literal("*"),
+                Rule::token(OPERATOR_MATHS_MULTIPLICATION),
+            ),
+            (self.literal("/"), Rule::token(OPERATOR_MATHS_DIVISION)),
+            (self.literal("%"), Rule::token(OPERATOR_MATHS_MODULUS)),
+            (self.literal("("), Rule::token(LPAREN)),
+            (self.literal(")"), Rule::token(RPAREN)),
+            (self.literal(","), Rule::token(COMMA)),
+            (keyword("as"), Rule::token(EXPRESSION_AS)),
+            (
+                keyword("string")
+                    .or_else(|| keyword("number"))
+                    .or_else(|| keyword("bool")),
+                Rule::token(FUNC_ID),
+            ),
+            (self.string(), Rule::token(STRING)),
+            (self.id(), Rule::token(FUNC_ID)),
+            (self.literal("}"), Rule::token(EXPRESSION_END).then(&[Pop])),
+            (
+                self.literal(">>"),
+                Rule::token(COMMAND_END).then(&[Pop, Pop]),
+            ),
+            (self.variable(), Rule::token(VAR_ID)),
+            (self.literal("."), Rule::token(DOT)),
+            (self.number(), Rule::token(NUMBER)),
+        ]
+    }
+
+    fn command_mode_rules(&self) -> Vec<(Option<usize>, Rule)> {
+        let push_expression: &'static [ModeChange] = &[Push(Mode::Expression)];
+        vec![
+            (
+                self.whitespace(),
+                Rule::token(COMMAND_WS).on_channel(HIDDEN),
+            ),
+            (
+                self.command_keyword("if", true),
+                Rule::token(COMMAND_IF).then(push_expression),
+            ),
+            (
+                self.command_keyword("elseif", true),
+                Rule::token(COMMAND_ELSEIF).then(push_expression),
+            ),
+            (
+                self.command_keyword("else", false),
+                Rule::token(COMMAND_ELSE),
+            ),
+            (
+                self.command_keyword("set", true),
+                Rule::token(COMMAND_SET).then(push_expression),
+            ),
+            (self.literal("endif"), Rule::token(COMMAND_ENDIF)),
+            (
+                self.command_keyword("call", true),
+                Rule::token(COMMAND_CALL).then(push_expression),
+            ),
+            (
+                self.command_keyword("declare", true),
+                Rule::token(COMMAND_DECLARE).then(push_expression),
+            ),
+            (
+                self.command_keyword("jump", true),
+                Rule::token(COMMAND_JUMP).then(&[Push(Mode::CommandIdOrExpression)]),
+            ),
+            (
+                self.command_keyword("enum", true),
+                Rule::token(COMMAND_ENUM).then(&[Push(Mode::CommandId)]),
+            ),
+            (
+                self.command_keyword("case", true),
+                Rule::token(COMMAND_CASE).then(&[Push(Mode::CommandId)]),
+            ),
+            (
+                self.command_keyword("endenum", false),
+                Rule::token(COMMAND_ENDENUM),
+            ),
+            (
+                self.command_keyword("local", true),
+                Rule::token(COMMAND_LOCAL),
+            ),
+            (self.literal(">>"), Rule::token(COMMAND_END).then(&[Pop])),
+            (
+                self.any(),
+                Rule::token(COMMAND_TEXT).then(&[Set(Mode::CommandText)]),
+            ),
+        ]
+    }
+}
+
+/// Whether `c` may start an identifier, following the Unicode ranges allowed by the grammar.
+fn is_identifier_head(c: char) -> bool {
+    let c = c as u32;
+    if c >= 0x10000 {
+        return c <= 0xEFFFD && c & 0xFFFF <= 0xFFFD;
+    }
+    matches!(c,
+        0x41..=0x5A
+        | 0x5F
+        | 0x61..=0x7A
+        | 0xA8
+        | 0xAA
+        | 0xAD
+        | 0xAF
+        | 0xB2..=0xB5
+        | 0xB7..=0xBA
+        | 0xBC..=0xBE
+        | 0xC0..=0xD6
+        | 0xD8..=0xF6
+        | 0xF8..=0x2FF
+        | 0x370..=0x167F
+        | 0x1681..=0x180D
+        | 0x180F..=0x1DBF
+        | 0x1E00..=0x1FFF
+        | 0x200B..=0x200D
+        | 0x202A..=0x202E
+        | 0x203F..=0x2040
+        | 0x2054
+        | 0x2060..=0x20CF
+        | 0x2100..=0x218F
+        | 0x2460..=0x24FF
+        | 0x2776..=0x2793
+        | 0x2C00..=0x2DFF
+        | 0x2E80..=0x2FFF
+        | 0x3004..=0x3007
+        | 0x3021..=0x302F
+        | 0x3031..=0xD7FF
+        | 0xF900..=0xFD3D
+        | 0xFD40..=0xFDCF
+        | 0xFDF0..=0xFE1F
+        | 0xFE30..=0xFE44
+        | 0xFE47..=0xFFFD
+    )
+}
+
+/// Whether `c` may appear in an identifier after its first character.
+fn is_identifier_character(c: char) -> bool {
+    is_identifier_head(c)
+        || matches!(c as u32,
+            0x30..=0x39
+            | 0x300..=0x36F
+            | 0x1DC0..=0x1DFF
+            | 0x20D0..=0x20FF
+            | 0xFE20..=0xFE2F
+        )
+}
+
+/// Whether `c` may separate a command keyword from the rest of the command.
+fn is_command_whitespace(c: char) -> bool {
+    matches!(c as u32,
+        0x09..=0x0D
+        | 0x20
+        | 0x85
+        | 0xA0
+        | 0x1680
+        | 0x2000..=0x200A
+        | 0x2028..=0x2029
+        | 0x202F
+        | 0x205F
+        | 0x3000
+    )
+}
@@ -0,0 +1,1111 @@
+//! A hand-written recursive descent parser for
+//! <https://github.com/YarnSpinnerTool/YarnSpinner/blob/da39c7195107d8211f21c263e4084f773b84eaff/YarnSpinner.Compiler/YarnSpinnerParser.g4>,
+//! taking the place of the one generated by ANTLR together with the error strategy of
+//! <https://github.com/YarnSpinnerTool/YarnSpinner/blob/da39c7195107d8211f21c263e4084f773b84eaff/YarnSpinner.Compiler/ErrorStrategy.cs>.
+//!
+//! Every rule of the grammar is a method producing the context of the same name. Where the grammar needs more than one
+//! token of lookahead to pick an alternative, the parser peeks at the following tokens just like the prediction of ANTLR does.
+//! The left-recursive `expression` rule is parsed by precedence climbing, producing the same trees as ANTLR.
+
+use super::contexts::*;
+use super::token::*;
+use super::yarn_spinner_lexer::*;
+use crate::prelude::*;
+use std::rc::Rc;
+use yarnspinner_core::prelude::*;
+
+/// Parses the tokens of a file into a [`DialogueContext`].
+///
+/// ## Implementation notes
+///
+/// Errors are handled like ANTLR does: the rule that runs into an error reports it, skips ahead, and returns
+/// what it parsed so far to the rule that invoked it, which carries on as if nothing happened.
+/// After reporting an error, no further errors are reported until a token was matched successfully,
+/// so that one mistake results in one message.
+///
+/// Where to skip to is decided like in the `ErrorStrategy` that was used with ANTLR: an error in a statement skips to the end
+/// of its line or command, and an error in the structure of a node skips to the end of the node.
+/// Indentation is never skipped, as it delimits the bodies of options.
+///
+/// ANTLR's recovery within a rule by pretending that a single missing token was there is only done right after skipping ahead,
+/// which is where it is needed to let the invoking rules finish. Elsewhere, a missing or superfluous token is reported
+/// like any other unexpected token. Since every error is reported once, the messages ANTLR added
+/// after the ones of the `ErrorStrategy`, like "mismatched input", are gone.
+pub(crate) struct YarnSpinnerParser<'a> {
+    tokens: Rc<TokenStream>,
+    /// The index of the current token in `tokens`, which is always on the default channel.
+    index: usize,
+    /// The names and first tokens of the rules currently being parsed, innermost last.
+    rule_stack: Vec<(&'static str, Rc<Token>)>,
+    /// Whether an error was reported and no token was matched since.
+    is_in_error_recovery_mode: bool,
+    /// The index of the token at which the last recovery from an error stopped.
+    last_recovery_index: Option<usize>,
+    file: &'a File,
+    pub(crate) diagnostics: Vec<Diagnostic>,
+}
+
+/// Why a rule could not be parsed.
+#[derive(Debug, Clone)]
+enum SyntaxError {
+    /// None of the alternatives of a rule fit the tokens starting at `start`.
+    NoViableAlternative {
+        start: Rc<Token>,
+        offending: Rc<Token>,
+    },
+    /// The current token is not one of the `expected` ones.
+    InputMismatch {
+        expected: Vec<isize>,
+        offending: Rc<Token>,
+    },
+}
+
+type ParseResult<T = ()> = Result<T, SyntaxError>;
+
+/// Where the parser resumes after an error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Boundary {
+    /// After the end of the line.
+    Line,
+    /// After the end of the line or of the command, whichever comes first.
+    Statement,
+    /// After the `===` ending the node.
+    Node,
+    /// At the next header or at the start of the body, like ANTLR would.
+    Header,
+}
+
+/// The alternatives of the `statement` rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StatementKind {
+    Line,
+    If,
+    Set,
+    ShortcutOption,
+    Call,
+    Command,
+    Declare,
+    Jump,
+    Indented,
+}
+
+impl<'a> YarnSpinnerParser<'a> {
+    /// Creates a parser for the `tokens` read from `file`, which is used for the context of diagnostics.
+    pub(crate) fn new(tokens: Rc<TokenStream>, file: &'a File) -> Self {
+        let mut parser = Self {
+            tokens,
+            index: 0,
+            rule_stack: Vec::new(),
+            is_in_error_recovery_mode: false,
+            last_recovery_index: None,
+            file,
+            diagnostics: Vec::new(),
+        };
+        parser.index = parser.next_index_on_default_channel(0);
+        parser
+    }
+
+    /// `dialogue: file_hashtag* node+`
+    ///
+    /// This is the entry point of the parser.
+    pub(crate) fn dialogue(&mut self) -> Rc<DialogueContext> {
+        let ctx = self.rule(DialogueContext::new, |parser, ctx| {
+            if !matches!(parser.la(1), HASHTAG | ID) {
+                return Err(parser.input_mismatch(&[HASHTAG, ID]));
+            }
+            while parser.la(1) == HASHTAG {
+                ctx.base.add_child(parser.file_hashtag().into());
+            }
+            if parser.la(1) != ID {
+                return Err(parser.input_mismatch(&[ID]));
+            }
+            while parser.la(1) == ID {
+                ctx.base.add_child(parser.node().into());
+            }
+            Ok(())
+        });
+        Rc::new(ctx)
+    }
+
+    /// `file_hashtag: HASHTAG text=HASHTAG_TEXT`
+    fn file_hashtag(&mut self) -> FileHashtagContext {
+        self.rule(FileHashtagContext::new, |parser, ctx| {
+            parser.match_token(&ctx.base, HASHTAG)?;
+            ctx.text = Some(parser.match_token(&ctx.base, HASHTAG_TEXT)?);
+            Ok(())
+        })
+    }
+
+    /// `node: header+ BODY_START body BODY_END`
+    fn node(&mut self) -> NodeContext {
+        self.rule(NodeContext::new, |parser, ctx| {
+            if parser.la(1) != ID {
+                return Err(parser.input_mismatch(&[ID]));
+            }
+            while parser.la(1) == ID {
+                ctx.base.add_child(parser.header().into());
+            }
+            parser.match_token(&ctx.base, BODY_START)?;
+            ctx.base.add_child(parser.body().into());
+            parser.match_token(&ctx.base, BODY_END)?;
+            Ok(())
+        })
+    }
+
+    /// `header: header_key=ID HEADER_DELIMITER header_value=REST_OF_LINE?`
+    fn header(&mut self) -> HeaderContext {
+        self.rule(HeaderContext::new, |parser, ctx| {
+            ctx.header_key = Some(parser.match_token(&ctx.base, ID)?);
+            parser.match_token(&ctx.base, HEADER_DELIMITER)?;
+            if parser.la(1) == REST_OF_LINE {
+                ctx.header_value = Some(parser.match_token(&ctx.base, REST_OF_LINE)?);
+            }
+            Ok(())
+        })
+    }
+
+    /// `body: statement*`
+    fn body(&mut self) -> BodyContext {
+        self.rule(BodyContext::new, |parser, ctx| {
+            while starts_statement(parser.la(1)) {
+                ctx.base.add_child(parser.statement().into());
+            }
+            Ok(())
+        })
+    }
+
+    /// ```text
+    /// statement
+    ///     : line_statement
+    ///     | if_statement
+    ///     | set_statement
+    ///     | shortcut_option_statement
+    ///     | call_statement
+    ///     | command_statement
+    ///     | declare_statement
+    ///     | jump_statement
+    ///     | INDENT statement* DEDENT
+    /// ```
+    fn statement(&mut self) -> StatementContext {
+        self.rule(StatementContext::new, |parser, ctx| {
+            let child = match parser.predict_statement()? {
+                StatementKind::Line => parser.line_statement().into(),
+                StatementKind::If => parser.if_statement().into(),
+                StatementKind::Set => parser.set_statement().into(),
+                StatementKind::ShortcutOption => parser.shortcut_option_statement().into(),
+                StatementKind::Call => parser.call_statement().into(),
+                StatementKind::Command => parser.command_statement().into(),
+                StatementKind::Declare => parser.declare_statement().into(),
+                StatementKind::Jump => parser.jump_statement().into(),
+                StatementKind::Indented => {
+                    return parser.indented_statements(&ctx.base);
+                }
+            };
+            ctx.base.add_child(child);
+            Ok(())
+        })
+    }
+
+    /// `INDENT statement* DEDENT`, as found in `statement` and `shortcut_option`.
+    fn indented_statements(&mut self, ctx: &ContextBase) -> ParseResult {
+        self.match_token(ctx, INDENT)?;
+        while starts_statement(self.la(1)) {
+            ctx.add_child(self.statement().into());
+        }
+        self.match_token(ctx, DEDENT)?;
+        Ok(())
+    }
+
+    /// `line_statement: line_formatted_text line_condition? hashtag* NEWLINE`
+    fn line_statement(&mut self) -> LineStatementContext {
+        self.rule(LineStatementContext::new, |parser, ctx| {
+            ctx.base.add_child(parser.line_formatted_text().into());
+            if parser.la(1) == COMMAND_START {
+                ctx.base.add_child(parser.line_condition().into());
+            }
+            while parser.la(1) == HASHTAG {
+                ctx.base.add_child(parser.hashtag().into());
+            }
+            parser.match_token(&ctx.base, NEWLINE)?;
+            Ok(())
+        })
+    }
+
+    /// `line_formatted_text: (TEXT+ | EXPRESSION_START expression EXPRESSION_END)+`
+    fn line_formatted_text(&mut self) -> LineFormattedTextContext {
+        self.rule(LineFormattedTextContext::new, |parser, ctx| {
+            if !matches!(parser.la(1), TEXT | EXPRESSION_START) {
+                return Err(parser.input_mismatch(&[TEXT, EXPRESSION_START]));
+            }
+            loop {
+                match parser.la(1) {
+                    TEXT => {
+                        parser.match_token(&ctx.base, TEXT)?;
+                    }
+                    EXPRESSION_START => {
+                        parser.match_token(&ctx.base, EXPRESSION_START)?;
+                        ctx.base.add_child(parser.expression(0).into());
+                        parser.match_token(&ctx.base, EXPRESSION_END)?;
+                    }
+                    _ => break,
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// `hashtag: HASHTAG text=HASHTAG_TEXT`
+    fn hashtag(&mut self) -> HashtagContext {
+        self.rule(HashtagContext::new, |parser, ctx| {
+            parser.match_token(&ctx.base, HASHTAG)?;
+            ctx.text = Some(parser.match_token(&ctx.base, HASHTAG_TEXT)?);
+            Ok(())
+        })
+    }
+
+    /// `line_condition: COMMAND_START COMMAND_IF expression COMMAND_END`
+    fn line_condition(&mut self) -> LineConditionContext {
+        self.rule(LineConditionContext::new, |parser, ctx| {
+            parser.match_token(&ctx.base, COMMAND_START)?;
+            parser.match_token(&ctx.base, COMMAND_IF)?;
+            ctx.base.add_child(parser.expression(0).into());
+            parser.match_token(&ctx.base, COMMAND_END)?;
+            Ok(())
+        })
+    }
+
+    /// ```text
+    /// expression
+    ///     : '(' expression ')' #expParens
+    ///     | <assoc=right>op='-' expression #expNegative
+    ///     | <assoc=right>op=OPERATOR_LOGICAL_NOT expression #expNot
+    ///     | expression op=('*' | '/' | '%') expression #expMultDivMod
+    ///     | expression op=('+' | '-') expression #expAddSub
+    ///     | expression op=(LESS_THAN_EQUALS | GREATER_THAN_EQUALS | LESS | GREATER) expression #expComparison
+    ///     | expression op=(EQUALS | NOT_EQUALS) expression #expEquality
+    ///     | expression op=(AND | OR | XOR) expression #expAndOrXor
+    ///     | value #expValue
+    /// ```
+    ///
+    /// Only binary operators binding at least as tightly as `precedence` are read, like ANTLR does for left-recursive rules.
+    fn expression(&mut self, precedence: u8) -> ExpressionContext {
+        self.rule(ExpressionContext::new, |parser, ctx| {
+            parser.primary_expression(ctx)?;
+            while let Some((kind, operator_precedence)) = binary_operator(parser.la(1)) {
+                if operator_precedence < precedence {
+                    break;
+                }
+                // The expression read so far becomes the left operand.
+                let start = ctx.start().clone();
+                let mut left = std::mem::replace(ctx, ExpressionContext::new(start));
+                left.base.set_stop(parser.previous_token());
+                ctx.base.add_child(left.into());
+                ctx.kind = kind;
+                ctx.op = Some(parser.consume(&ctx.base));
+                parser.is_in_error_recovery_mode = false;
+                ctx.base
+                    .add_child(parser.expression(operator_precedence + 1).into());
+            }
+            Ok(())
+        })
+    }
+
+    /// The alternatives of `expression` that don't start with an expression.
+    fn primary_expression(&mut self, ctx: &mut ExpressionContext) -> ParseResult {
+        match self.la(1) {
+            LPAREN => {
+                ctx.kind = ExpressionKind::Parens;
+                self.match_token(&ctx.base, LPAREN)?;
+                ctx.base.add_child(self.expression(0).into());
+                self.match_token(&ctx.base, RPAREN)?;
+            }
+            OPERATOR_MATHS_SUBTRACTION => {
+                ctx.kind = ExpressionKind::Negative;
+                ctx.op = Some(self.match_token(&ctx.base, OPERATOR_MATHS_SUBTRACTION)?);
+                ctx.base.add_child(self.expression(8).into());
+            }
+            OPERATOR_LOGICAL_NOT => {
+                ctx.kind = ExpressionKind::Not;
+                ctx.op = Some(self.match_token(&ctx.base, OPERATOR_LOGICAL_NOT)?);
+                ctx.base.add_child(self.expression(7).into());
+            }
+            token_type if starts_value(token_type) => {
+                ctx.kind = ExpressionKind::Value;
+                ctx.base.add_child(self.value().into());
+            }
+            _ => return Err(self.no_viable_alternative(1)),
+        }
+        Ok(())
+    }
+
+    /// ```text
+    /// value
+    ///     : NUMBER #valueNumber
+    ///     | KEYWORD_TRUE #valueTrue
+    ///     | KEYWORD_FALSE #valueFalse
+    ///     | variable #valueVar
+    ///     | STRING #valueString
+    ///     | KEYWORD_NULL #valueNull
+    ///     | function_call #valueFunc
+    /// ```
+    fn value(&mut self) -> ValueContext {
+        self.rule(ValueContext::new, |parser, ctx| {
+            let token_type = parser.la(1);
+            ctx.kind = match token_type {
+                NUMBER => ValueKind::Number,
+                KEYWORD_TRUE => ValueKind::True,
+                KEYWORD_FALSE => ValueKind::False,
+                VAR_ID => ValueKind::Var,
+                STRING => ValueKind::String,
+                KEYWORD_NULL => ValueKind::Null,
+                FUNC_ID => ValueKind::Func,
+                _ => return Err(parser.no_viable_alternative(1)),
+            };
+            match ctx.kind {
+                ValueKind::Var => ctx.base.add_child(parser.variable().into()),
+                ValueKind::Func => ctx.base.add_child(parser.function_call().into()),
+                _ => {
+                    parser.match_token(&ctx.base, token_type)?;
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// `variable: VAR_ID`
+    fn variable(&mut self) -> VariableContext {
+        self.rule(VariableContext::new, |parser, ctx| {
+            parser.match_token(&ctx.base, VAR_ID)?;
+            Ok(())
+        })
+    }
+
+    /// `function_call: FUNC_ID '(' expression? (',' expression)* ')'`
+    fn function_call(&mut self) -> FunctionCallContext {
+        self.rule(FunctionCallContext::new, |parser, ctx| {
+            parser.match_token(&ctx.base, FUNC_ID)?;
+            parser.match_token(&ctx.base, LPAREN)?;
+            if starts_expression(parser.la(1)) {
+                ctx.base.add_child(parser.expression(0).into());
+            }
+            while parser.la(1) == COMMA {
+                parser.match_token(&ctx.base, COMMA)?;
+                ctx.base.add_child(parser.expression(0).into());
+            }
+            parser.match_token(&ctx.base, RPAREN)?;
+            Ok(())
+        })
+    }
+
+    /// `if_statement: if_clause else_if_clause* else_clause? COMMAND_START COMMAND_ENDIF COMMAND_END`
+    fn if_statement(&mut self) -> IfStatementContext {
+        self.rule(IfStatementContext::new, |parser, ctx| {
+            ctx.base.add_child(parser.if_clause().into());
+            while parser.la(1) == COMMAND_START && parser.la(2) == COMMAND_ELSEIF {
+                ctx.base.add_child(parser.else_if_clause().into());
+            }
+            if parser.la(1) == COMMAND_START && parser.la(2) == COMMAND_ELSE {
+                ctx.base.add_child(parser.else_clause().into());
+            }
+            parser.match_token(&ctx.base, COMMAND_START)?;
+            parser.match_token(&ctx.base, COMMAND_ENDIF)?;
+            parser.match_token(&ctx.base, COMMAND_END)?;
+            Ok(())
+        })
+    }
+
+    /// `if_clause: COMMAND_START COMMAND_IF expression COMMAND_END statement*`
+    fn if_clause(&mut self) -> IfClauseContext {
+        self.rule(IfClauseContext::new, |parser, ctx| {
+            parser.match_token(&ctx.base, COMMAND_START)?;
+            parser.match_token(&ctx.base, COMMAND_IF)?;
+            ctx.base.add_child(parser.expression(0).into());
+            parser.match_token(&ctx.base, COMMAND_END)?;
+            parser.clause_statements(&ctx.base);
+            Ok(())
+        })
+    }
+
+    /// `else_if_clause: COMMAND_START COMMAND_ELSEIF expression COMMAND_END statement*`
+    fn else_if_clause(&mut self) -> ElseIfClauseContext {
+        self.rule(ElseIfClauseContext::new, |parser, ctx| {
+            parser.match_token(&ctx.base, COMMAND_START)?;
+            parser.match_token(&ctx.base, COMMAND_ELSEIF)?;
+            ctx.base.add_child(parser.expression(0).into());
+            parser.match_token(&ctx.base, COMMAND_END)?;
+            parser.clause_statements(&ctx.base);
+            Ok(())
+        })
+    }
+
+    /// `else_clause: COMMAND_START COMMAND_ELSE COMMAND_END statement*`
+    fn else_clause(&mut self) -> ElseClauseContext {
+        self.rule(ElseClauseContext::new, |parser, ctx| {
+            parser.match_token(&ctx.base, COMMAND_START)?;
+            parser.match_token(&ctx.base, COMMAND_ELSE)?;
+            parser.match_token(&ctx.base, COMMAND_END)?;
+            parser.clause_statements(&ctx.base);
+            Ok(())
+        })
+    }
+
+    /// The `statement*` ending the clauses of an if statement.
+    /// A command that is not a statement, like `<<else>>`, ends them.
+    fn clause_statements(&mut self, ctx: &ContextBase) {
+        while self.predict_statement().is_ok() {
+            ctx.add_child(self.statement().into());
+        }
+    }
+
+    /// `set_statement: COMMAND_START COMMAND_SET variable op=('=' | '+=' | '-=' | '*=' | '%=' | '/=') expression COMMAND_END`
+    fn set_statement(&mut self) -> SetStatementContext {
+        self.rule(SetStatementContext::new, |parser, ctx| {
+            parser.match_token(&ctx.base, COMMAND_START)?;
+            parser.match_token(&ctx.base, COMMAND_SET)?;
+            ctx.base.add_child(parser.variable().into());
+            ctx.op = Some(parser.match_any(
+                &ctx.base,
+                &[
+                    OPERATOR_ASSIGNMENT,
+                    OPERATOR_MATHS_ADDITION_EQUALS,
+                    OPERATOR_MATHS_SUBTRACTION_EQUALS,
+                    OPERATOR_MATHS_MULTIPLICATION_EQUALS,
+                    OPERATOR_MATHS_MODULUS_EQUALS,
+                    OPERATOR_MATHS_DIVISION_EQUALS,
+                ],
+            )?);
+            ctx.base.add_child(parser.expression(0).into());
+            parser.match_token(&ctx.base, COMMAND_END)?;
+            Ok(())
+        })
+    }
+
+    /// `call_statement: COMMAND_START COMMAND_CALL function_call COMMAND_END`
+    fn call_statement(&mut self) -> CallStatementContext {
+        self.rule(CallStatementContext::new, |parser, ctx| {
+            parser.match_token(&ctx.base, COMMAND_START)?;
+            parser.match_token(&ctx.base, COMMAND_CALL)?;
+            ctx.base.add_child(parser.function_call().into());
+            parser.match_token(&ctx.base, COMMAND_END)?;
+            Ok(())
+        })
+    }
+
+    /// `command_statement: COMMAND_START command_formatted_text COMMAND_TEXT_END hashtag*`
+    fn command_statement(&mut self) -> CommandStatementContext {
+        self.rule(CommandStatementContext::new, |parser, ctx| {
+            parser.match_token(&ctx.base, COMMAND_START)?;
+            ctx.base.add_child(parser.command_formatted_text().into());
+            parser.match_token(&ctx.base, COMMAND_TEXT_END)?;
+            while parser.la(1) == HASHTAG {
+                ctx.base.add_child(parser.hashtag().into());
+            }
+            Ok(())
+        })
+    }
+
+    /// `command_formatted_text: (COMMAND_TEXT | COMMAND_EXPRESSION_START expression EXPRESSION_END)*`
+    fn command_formatted_text(&mut self) -> CommandFormattedTextContext {
+        self.rule(CommandFormattedTextContext::new, |parser, ctx| {
+            loop {
+                match parser.la(1) {
+                    COMMAND_TEXT => {
+                        parser.match_token(&ctx.base, COMMAND_TEXT)?;
+                    }
+                    COMMAND_EXPRESSION_START => {
+                        parser.match_token(&ctx.base, COMMAND_EXPRESSION_START)?;
+                        ctx.base.add_child(parser.expression(0).into());
+                        parser.match_token(&ctx.base, EXPRESSION_END)?;
+                    }
+                    _ => break,
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// `shortcut_option_statement: shortcut_option* (shortcut_option BLANK_LINE_FOLLOWING_OPTION?)`
+    ///
+    /// A blank line after an option ends the group of options.
+    fn shortcut_option_statement(&mut self) -> ShortcutOptionStatementContext {
+        self.rule(ShortcutOptionStatementContext::new, |parser, ctx| {
+            if parser.la(1) != SHORTCUT_ARROW {
+                return Err(parser.input_mismatch(&[SHORTCUT_ARROW]));
+            }
+            while parser.la(1) == SHORTCUT_ARROW {
+                ctx.base.add_child(parser.shortcut_option().into());
+                if parser.la(1) == BLANK_LINE_FOLLOWING_OPTION {
+                    parser.match_token(&ctx.base, BLANK_LINE_FOLLOWING_OPTION)?;
+                    break;
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// `shortcut_option: SHORTCUT_ARROW line_statement (INDENT statement* DEDENT)?`
+    fn shortcut_option(&mut self) -> ShortcutOptionContext {
+        self.rule(ShortcutOptionContext::new, |parser, ctx| {
+            parser.match_token(&ctx.base, SHORTCUT_ARROW)?;
+            ctx.base.add_child(parser.line_statement().into());
+            if parser.la(1) == INDENT {
+                parser.indented_statements(&ctx.base)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// `declare_statement: COMMAND_START COMMAND_DECLARE variable '=' value ('as' declaration_type=FUNC_ID)? COMMAND_END`
+    fn declare_statement(&mut self) -> DeclareStatementContext {
+        self.rule(DeclareStatementContext::new, |parser, ctx| {
+            parser.match_token(&ctx.base, COMMAND_START)?;
+            parser.match_token(&ctx.base, COMMAND_DECLARE)?;
+            ctx.base.add_child(parser.variable().into());
+            parser.match_token(&ctx.base, OPERATOR_ASSIGNMENT)?;
+            ctx.base.add_child(parser.value().into());
+            if parser.la(1) == EXPRESSION_AS {
+                parser.match_token(&ctx.base, EXPRESSION_AS)?;
+                ctx.declaration_type = Some(parser.match_token(&ctx.base, FUNC_ID)?);
+            }
+            parser.match_token(&ctx.base, COMMAND_END)?;
+            Ok(())
+        })
+    }
+
+    /// ```text
+    /// jump_statement
+    ///     : COMMAND_START COMMAND_JUMP destination=ID COMMAND_END #jumpToNodeName
+    ///     | COMMAND_START COMMAND_JUMP EXPRESSION_START expression EXPRESSION_END COMMAND_END #jumpToExpression
+    /// ```
+    fn jump_statement(&mut self) -> JumpStatementContext {
+        self.rule(JumpStatementContext::new, |parser, ctx| {
+            if parser.la(1) != COMMAND_START {
+                return Err(parser.input_mismatch(&[COMMAND_START]));
+            }
+            if parser.la(2) != COMMAND_JUMP {
+                return Err(parser.no_viable_alternative(2));
+            }
+            ctx.kind = match parser.la(3) {
+                ID => JumpKind::JumpToNodeName,
+                EXPRESSION_START => JumpKind::JumpToExpression,
+                _ => return Err(parser.no_viable_alternative(3)),
+            };
+            parser.match_token(&ctx.base, COMMAND_START)?;
+            parser.match_token(&ctx.base, COMMAND_JUMP)?;
+            if ctx.kind == JumpKind::JumpToNodeName {
+                ctx.destination = Some(parser.match_token(&ctx.base, ID)?);
+            } else {
+                parser.match_token(&ctx.base, EXPRESSION_START)?;
+                ctx.base.add_child(parser.expression(0).into());
+                parser.match_token(&ctx.base, EXPRESSION_END)?;
+            }
+            parser.match_token(&ctx.base, COMMAND_END)?;
+            Ok(())
+        })
+    }
+}
+
+/// Error handling.
+impl YarnSpinnerParser<'_> {
+    /// Parses a rule with `body`, which adds the children to the context created by `new`.
+    /// If the body runs into an error, the error is reported and the parser skips ahead so that the invoking rule can continue.
+    fn rule<C: ParserRuleContext>(
+        &mut self,
+        new: fn(Rc<Token>) -> C,
+        body: impl FnOnce(&mut Self, &mut C) -> ParseResult,
+    ) -> C {
+        let mut ctx = new(self.lt(1));
+        self.rule_stack.push((ctx.rule_name(), ctx.start().clone()));
+        if let Err(error) = body(self, &mut ctx) {
+            self.report_error(error);
+            self.recover(ctx.base());
+        }
+        self.rule_stack.pop();
+        let stop = self.previous_token();
+        ctx.base_mut().set_stop(stop);
+        ctx
+    }
+
+    fn report_error(&mut self, error: SyntaxError) {
+        if self.is_in_error_recovery_mode {
+            return;
+        }
+        self.is_in_error_recovery_mode = true;
+
+        let (rule_name, rule_start) = self.rule_stack.last().cloned().unwrap();
+        let (message, offending) = match error {
+            SyntaxError::NoViableAlternative { start, offending } => {
+                let message = if self.is_inside_rule("if_statement")
+                    && rule_name == "statement"
+                    && start.token_type == COMMAND_START
+                    && offending.token_type == COMMAND_ELSE
+                {
+                    // We are inside an if statement, we're attempting to parse a
+                    // statement, and we got an '<<', 'else', and we weren't able
+                    // to match that. The programmer included an extra '<<else>>'.
+                    "More than one <<else>> statement in an <<if>> statement isn't allowed"
+                        .to_owned()
+                } else if start.token_type == COMMAND_START && offending.token_type == COMMAND_END {
+                    // We saw a << immediately followed by a >>. The programmer
+                    // forgot to include command text.
+                    "Command text expected".to_owned()
+                } else {
+                    unexpected_token_message(&offending, rule_name)
+                };
+                (message, offending)
+            }
+            SyntaxError::InputMismatch {
+                expected,
+                offending,
+            } => {
+                let message = match (rule_name, offending.token_type) {
+                    ("if_statement", BODY_END) => {
+                        // We have exited a body in the middle of an if
+                        // statement. The programmer forgot to include an
+                        // <<endif>>.
+                        format!(
+                            "Expected an <<endif>> to match the <<if>> statement on line {}",
+                            rule_start.line
+                        )
+                    }
+                    ("if_statement", COMMAND_ELSE) if expected.contains(&COMMAND_ENDIF) => {
+                        // We saw an else, but we expected to see an endif. The
+                        // programmer wrote an additional <<else>>.
+                        "More than one <<else>> statement in an <<if>> statement isn't allowed"
+                            .to_owned()
+                    }
+                    ("variable", FUNC_ID) => {
+                        // We're parsing a variable (which starts with a '$'),
+                        // but we encountered a FUNC_ID (which doesn't). The
+                        // programmer forgot to include the '$'.
+                        "Variable names need to start with a $".to_owned()
+                    }
+                    _ => unexpected_token_message(&offending, rule_name),
+                };
+                (message, offending)
+            }
+        };
+        let diagnostic = self.diagnostic_at(&offending, message);
+        self.diagnostics.push(diagnostic);
+    }
+
+    /// Creates a syntax error pointing at `token`, with the line containing it as the context.
+    fn diagnostic_at(&self, token: &Token, message: String) -> Diagnostic {
+        let line = token.line.saturating_sub(1);
+        let column = token.column;
+
+        // the line with the error on it
+        let input = &self.file.source;
+        let mut context = input.lines().nth(line).unwrap_or(input).to_owned();
+        context.push('\n');
+
+        // adding indicator symbols pointing out where the error is
+        // on the line
+        if token.start >= 0 && token.stop >= 0 {
+            // the end point of the error in "line space", which is empty for the EOF token
+            let end = (token.stop - token.start + 1).max(0) as usize + column;
+            for i in 0..end {
+                // move over until we are at the point we need to be
+                context.push(if i >= column { '^' } else { ' ' });
+            }
+        }
+
+        Diagnostic::new(DiagnosticCode::SyntaxError, message)
+            .with_file_name(&self.file.file_name)
+            .with_context(context)
+            .with_start_line(line)
+            .with_range(
+                Position {
+                    line,
+                    character: column,
+                }..Position {
+                    line,
+                    character: column + token.text.len(),
+                },
+            )
+    }
+
+    /// Skips tokens up to the boundary after which parsing resumes, adding them to `ctx` as errors.
+    fn recover(&mut self, ctx: &ContextBase) {
+        let (rule_name, _) = self.rule_stack.last().cloned().unwrap();
+        let boundary = if self.is_inside_rule("line_statement") {
+            Boundary::Line
+        } else if rule_name == "body" || self.is_inside_rule("statement") {
+            Boundary::Statement
+        } else if matches!(rule_name, "dialogue" | "file_hashtag" | "node") {
+            Boundary::Node
+        } else {
+            Boundary::Header
+        };
+
+        if self.last_recovery_index == Some(self.index) && self.la(1) != EOF {
+            // We already recovered at this token, and the parser ran into an error again without consuming anything.
+            // Skip the token so that we don't end up in an endless loop.
+            self.consume(ctx);
+        }
+        loop {
+            let token_type = self.la(1);
+            let is_boundary = match boundary {
+                Boundary::Line | Boundary::Statement => {
+                    matches!(token_type, BODY_END | INDENT | DEDENT)
+                }
+                Boundary::Node => false,
+                Boundary::Header => matches!(token_type, ID | BODY_START),
+            };
+            if token_type == EOF || is_boundary {
+                break;
+            }
+            self.consume(ctx);
+            let is_end = match boundary {
+                Boundary::Line => token_type == NEWLINE,
+                Boundary::Statement => matches!(token_type, NEWLINE | COMMAND_END),
+                Boundary::Node => token_type == BODY_END,
+                Boundary::Header => false,
+            };
+            if is_end {
+                break;
+            }
+        }
+        self.last_recovery_index = Some(self.index);
+    }
+
+    fn is_inside_rule(&self, rule_name: &str) -> bool {
+        self.rule_stack.iter().any(|(name, _)| *name == rule_name)
+    }
+
+    fn input_mismatch(&self, expected: &[isize]) -> SyntaxError {
+        SyntaxError::InputMismatch {
+            expected: expected.to_vec(),
+            offending: self.lt(1),
+        }
+    }
+
+    /// No alternative fits the tokens from the current one up to the `k`-th one.
+    fn no_viable_alternative(&self, k: usize) -> SyntaxError {
+        SyntaxError::NoViableAlternative {
+            start: self.lt(1),
+            offending: self.lt(k),
+        }
+    }
+}
+
+/// Reading tokens.
+impl YarnSpinnerParser<'_> {
+    /// The `k`-th token on the default channel, starting at 1 for the current token.
+    fn lt(&self, k: usize) -> Rc<Token> {
+        let tokens = self.tokens.get_tokens();
+        let mut index = self.index;
+        for _ in 1..k {
+            if tokens[index].token_type == EOF {
+                break;
+            }
+            index = self.next_index_on_default_channel(index + 1);
+        }
+        tokens[index].clone()
+    }
+
+    /// The type of the `k`-th token on the default channel, starting at 1 for the current token.
+    fn la(&self, k: usize) -> isize {
+        self.lt(k).token_type
+    }
+
+    /// The token on the default channel before the current one.
+    fn previous_token(&self) -> Option<Rc<Token>> {
+        self.tokens.get_tokens()[..self.index]
+            .iter()
+            .rev()
+            .find(|token| token.channel == DEFAULT_CHANNEL)
+            .cloned()
+    }
+
+    fn next_index_on_default_channel(&self, mut index: usize) -> usize {
+        let tokens = self.tokens.get_tokens();
+        while index < tokens.len() - 1 && tokens[index].channel != DEFAULT_CHANNEL {
+            index += 1;
+        }
+        index
+    }
+
+    /// Adds the current token to `ctx` and moves on to the next one.
+    fn consume(&mut self, ctx: &ContextBase) -> Rc<Token> {
+        let token = self.lt(1);
+        if token.token_type != EOF {
+            self.index = self.next_index_on_default_channel(self.index + 1);
+        }
+        ctx.add_child(if self.is_in_error_recovery_mode {
+            ParseTree::Error(token.clone())
+        } else {
+            ParseTree::Token(token.clone())
+        });
+        token
+    }
+
+    fn match_token(&mut self, ctx: &ContextBase, token_type: isize) -> ParseResult<Rc<Token>> {
+        self.match_any(ctx, &[token_type])
+    }
+
+    /// Consumes the current token if it is one of the `expected` ones.
+    ///
+    /// Right after skipping ahead because of an error, a missing token is conjured up instead,
+    /// so that the rules interrupted by the error can finish at the boundary the parser skipped to.
+    fn match_any(&mut self, ctx: &ContextBase, expected: &[isize]) -> ParseResult<Rc<Token>> {
+        let token = self.lt(1);
+        if expected.contains(&token.token_type) {
+            self.is_in_error_recovery_mode = false;
+            Ok(self.consume(ctx))
+        } else if self.is_in_error_recovery_mode && self.last_recovery_index == Some(self.index) {
+            let token_type = expected[0];
+            let missing = Rc::new(Token {
+                line: token.line,
+                column: token.column,
+                ..Token::synthetic(
+                    token_type,
+                    format!("<missing {}>", SYMBOLIC_NAMES[token_type as usize]),
+                )
+            });
+            ctx.add_child(ParseTree::Error(missing.clone()));
+            Ok(missing)
+        } else {
+            Err(self.input_mismatch(expected))
+        }
+    }
+
+    /// Picks the alternative of `statement` for the current tokens.
+    fn predict_statement(&self) -> ParseResult<StatementKind> {
+        let kind = match self.la(1) {
+            TEXT | EXPRESSION_START => StatementKind::Line,
+            SHORTCUT_ARROW => StatementKind::ShortcutOption,
+            INDENT => StatementKind::Indented,
+            COMMAND_START => match self.la(2) {
+                COMMAND_IF => StatementKind::If,
+                COMMAND_SET => StatementKind::Set,
+                COMMAND_CALL => StatementKind::Call,
+                COMMAND_DECLARE => StatementKind::Declare,
+                COMMAND_JUMP => StatementKind::Jump,
+                COMMAND_TEXT | COMMAND_EXPRESSION_START | COMMAND_TEXT_END => {
+                    StatementKind::Command
+                }
+                _ => return Err(self.no_viable_alternative(2)),
+            },
+            _ => return Err(self.no_viable_alternative(1)),
+        };
+        Ok(kind)
+    }
+}
+
+fn starts_statement(token_type: isize) -> bool {
+    matches!(
+        token_type,
+        TEXT | EXPRESSION_START | COMMAND_START | SHORTCUT_ARROW | INDENT
+    )
+}
+
+fn starts_value(token_type: isize) -> bool {
+    matches!(
+        token_type,
+        NUMBER | KEYWORD_TRUE | KEYWORD_FALSE | VAR_ID | STRING | KEYWORD_NULL | FUNC_ID
+    )
+}
+
+fn starts_expression(token_type: isize) -> bool {
+    matches!(
+        token_type,
+        LPAREN | OPERATOR_MATHS_SUBTRACTION | OPERATOR_LOGICAL_NOT
+    ) || starts_value(token_type)
+}
+
+/// The alternative and precedence of a binary operator. Higher precedences bind more tightly.
+fn binary_operator(token_type: isize) -> Option<(ExpressionKind, u8)> {
+    let operator = match token_type {
+        OPERATOR_MATHS_MULTIPLICATION | OPERATOR_MATHS_DIVISION | OPERATOR_MATHS_MODULUS => {
+            (ExpressionKind::MultDivMod, 6)
+        }
+        OPERATOR_MATHS_ADDITION | OPERATOR_MATHS_SUBTRACTION => (ExpressionKind::AddSub, 5),
+        OPERATOR_LOGICAL_LESS_THAN_EQUALS
+        | OPERATOR_LOGICAL_GREATER_THAN_EQUALS
+        | OPERATOR_LOGICAL_LESS
+        | OPERATOR_LOGICAL_GREATER => (ExpressionKind::Comparison, 4),
+        OPERATOR_LOGICAL_EQUALS | OPERATOR_LOGICAL_NOT_EQUALS => (ExpressionKind::Equality, 3),
+        OPERATOR_LOGICAL_AND | OPERATOR_LOGICAL_OR | OPERATOR_LOGICAL_XOR => {
+            (ExpressionKind::AndOrXor, 2)
+        }
+        _ => return None,
+    };
+    Some(operator)
+}
+
+fn unexpected_token_message(offending: &Token, rule_name: &str) -> String {
+    let friendly_name = rule_name.replace('_', " ");
+    // If the friendly name's first character is a vowel, the
+    // article is 'an'; otherwise, 'a'.
+    let first_letter = friendly_name.chars().next().unwrap();
+    let article = if "aeiou".contains(first_letter) {
+        "an"
+    } else {
+        "a"
+    };
+    format!(
+        "Unexpected \"{}\" while reading {article} {friendly_name}",
+        offending.text
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::YarnSpinnerLexer;
+
+    const MINIMAL_INPUT: &str = "title: Minimal Yarn
+---
+This is the one and only line
+===";
+
+    fn parse(source: &str) -> (Rc<DialogueContext>, Vec<Diagnostic>) {
+        let file = File {
+            file_name: "input".to_owned(),
+            source: source.to_owned(),
+        };
+        let chars: Vec<_> = source.chars().collect();
+        let lexer = YarnSpinnerLexer::new(&chars, file.file_name.clone());
+        let (tokens, mut diagnostics) = lexer.into_token_stream();
+        let mut parser = YarnSpinnerParser::new(Rc::new(tokens), &file);
+        let dialogue = parser.dialogue();
+        diagnostics.extend(parser.diagnostics);
+        (dialogue, diagnostics)
+    }
+
+    #[test]
+    fn parses_root() {
+        let (dialogue, diagnostics) = parse(MINIMAL_INPUT);
+        let expected_string_tree = "(dialogue (node (header title :  Minimal Yarn) --- (body (statement (line_statement (line_formatted_text T his is the one and only line) \\n))) ===))";
+        assert!(diagnostics.is_empty());
+        assert_eq!(expected_string_tree, dialogue.to_string_tree());
+    }
+
+    #[test]
+    fn does_random_stuffs() {
+        let (dialogue_context, _) = parse(
+            "# hello
+# nonono
+title: Node_Title
+---
+Here are some lines!
+That's weird?
+Wow!
+===",
+        );
+        let hashtags = dialogue_context.file_hashtag_all();
+
+        let output = hashtags[0].get_text();
+        assert_eq!(output, "#hello");
+        let output = &hashtags[0].text.as_ref().unwrap().text;
+        assert_eq!(output, "hello");
+
+        let output = &hashtags[1].text.as_ref().unwrap().text;
+        assert_eq!(output, "nonono");
+
+        let first_node = dialogue_context.node(0).unwrap();
+        let statements = first_node.body().unwrap().statement_all();
+
+        let output = statements[0]
+            .line_statement()
+            .unwrap()
+            .line_formatted_text()
+            .unwrap()
+            .get_text();
+        assert_eq!(output, "Here are some lines!");
+
+        let output = statements[1]
+            .line_statement()
+            .unwrap()
+            .line_formatted_text()
+            .unwrap()
+            .get_text();
+
+        assert_eq!(output, "That's weird?");
+        let output = statements[2]
+            .line_statement()
+            .unwrap()
+            .line_formatted_text()
+            .unwrap()
+            .get_text();
+        assert_eq!(output, "Wow!");
+
+        let title = first_node.header(0).unwrap();
+        let output = &title.header_value.as_ref().unwrap().text;
+        assert_eq!(output, "Node_Title");
+    }
+
+    #[test]
+    fn respects_operator_precedence_and_associativity() {
+        let (dialogue, diagnostics) = parse(
+            "title: Start
+---
+<<set $a = 1 - 2 - 3 * -4 == 5 and !true>>
+===",
+        );
+        assert!(diagnostics.is_empty());
+        let statement = dialogue
+            .node(0)
+            .unwrap()
+            .body()
+            .unwrap()
+            .statement(0)
+            .unwrap();
+        let expression = statement.set_statement().unwrap().expression().unwrap();
+        assert_eq!(
+            "(expression (expression (expression (expression (expression (value 1)) - (expression (value 2))) - \
+             (expression (expression (value 3)) * (expression - (expression (value 4))))) == (expression (value 5))) \
+             and (expression ! (expression (value true))))",
+            expression.to_string_tree()
+        );
+    }
+
+    #[test]
+    fn reports_one_error_per_mistake_and_resumes_at_next_line() {
+        let (dialogue, diagnostics) = parse(
+            "title: Start
+---
+<<set $broken to >>
+A line
+===",
+        );
+        assert_eq!(1, diagnostics.len());
+        assert_eq!(2, diagnostics[0].start_line);
+        let statements = dialogue.node(0).unwrap().body().unwrap().statement_all();
+        assert_eq!(2, statements.len());
+        assert_eq!(
+            "A line",
+            statements[1]
+                .line_statement()
+                .unwrap()
+                .line_formatted_text()
+                .unwrap()
+                .get_text()
+        );
+    }
+
+    #[test]
+    fn ends_hashtags_without_text_at_their_line() {
+        let (dialogue, diagnostics) = parse(
+            "title: Start
+---
+Hi #
+Bye
+===",
+        );
+        assert_eq!(1, diagnostics.len());
+        assert_eq!(2, diagnostics[0].start_line);
+        let statements = dialogue.node(0).unwrap().body().unwrap().statement_all();
+        assert_eq!(2, statements.len());
+        assert_eq!(
+            "Bye",
+            statements[1]
+                .line_statement()
+                .unwrap()
+                .line_formatted_text()
+                .unwrap()
+                .get_text()
+        );
+    }
+}
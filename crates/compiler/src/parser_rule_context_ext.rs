@@ -1,20 +1,17 @@
 //! Adapted from <https://github.com/YarnSpinnerTool/YarnSpinner/blob/da39c7195107d8211f21c263e4084f773b84eaff/YarnSpinner.Compiler/YarnSpinnerRuleContextExt.cs>
 
 use crate::prelude::*;
-use antlr_rust::parser_rule_context::ParserRuleContext;
-use antlr_rust::token::Token;
-use antlr_rust::token_stream::TokenStream;
 use std::iter;
 
-pub(crate) trait ParserRuleContextExt<'input>: ParserRuleContext<'input> {
+pub(crate) trait ParserRuleContextExt: ParserRuleContext {
     /// Returns the original text of this [`ParserRuleContext`], including all
     /// whitespace.
     ///
     /// ## Implementation Notes
     ///
     /// In contrast to the original, we need to pass a token stream here, because
-    /// antlr4rust does not allow us to retrieve it from the context or a token.
-    fn get_text_with_whitespace(&self, token_stream: &ActualTokenStream<'input>) -> String {
+    /// contexts and tokens don't know the stream they belong to.
+    fn get_text_with_whitespace(&self, token_stream: &TokenStream) -> String {
         // We can't use "expressionContext.GetText()" here, because
         // that just concatenates the text of all captured tokens,
         // and doesn't include text on hidden channels (e.g.
@@ -23,40 +20,35 @@ pub(crate) trait ParserRuleContextExt<'input>: ParserRuleContext<'input> {
         // some times it seems that vscode can request a negative interval
         // almost certainly something wrong we are doing
         // but as a non-crashing fallback we prevent this
-        let start = self.start().get_token_index();
-        let stop = self.stop().get_token_index();
+        let start = self.start().token_index;
+        let stop = self.stop().token_index;
         if start > stop {
             self.get_text()
         } else {
-            // ## Implementation Notes
-            // Uses `get_token_index()` instead of `get_start()` and `get_stop()`.
-            // I suspect the `get_text_from_interval` implementation behaves differently
-            // from the C# ANTLR runtime. Might even be bugged. Alas, the way this
-            // function is written now behaves the same way the original did, even if it does not seem so.
             token_stream.get_text_from_interval(start, stop)
         }
     }
 
     fn get_lines_around(
         &self,
-        token_stream: &ActualTokenStream<'input>,
+        token_stream: &TokenStream,
         surrounding_lines: usize,
     ) -> LinesAroundResult {
         // This seems expensive, but it's only used for error reporting.
         let whole_file = token_stream.get_all_text();
-        let char_start = self.start().get_start() as usize;
-        let char_stop = self.stop().get_stop() as usize + 1;
+        let char_start = self.start().start as usize;
+        let char_stop = (self.stop().stop + 1) as usize;
         let byte_start = whole_file
             .char_indices()
             .map(|(byte_start, _)| byte_start)
             .nth(char_start)
-            .unwrap();
+            .unwrap_or(whole_file.len());
         let byte_stop = whole_file
             .char_indices()
             .map(|(byte_start, _)| byte_start)
             .nth(char_stop)
-            .unwrap();
-        let first_line = self.start().get_line_as_usize().saturating_sub(1);
+            .unwrap_or(whole_file.len());
+        let first_line = self.start().line.saturating_sub(1);
 
         let head = &whole_file[..byte_start];
         let body = &whole_file[byte_start..byte_stop];
@@ -98,4 +90,4 @@ pub(crate) struct LinesAroundResult {
     pub(crate) first_line: usize,
 }
 
-impl<'input, T: ?Sized> ParserRuleContextExt<'input> for T where T: ParserRuleContext<'input> {}
+impl<T: ParserRuleContext + ?Sized> ParserRuleContextExt for T {}
@@ -0,0 +1,359 @@
+//! Converts [`Diagnostic`]s into a log in the [Static Analysis Results Interchange Format (SARIF)](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html),
+//! which CI services like GitHub code scanning use to annotate the lines of a pull request.
+//! Serialize a [`SarifLog`] as JSON to get a `.sarif` file:
+//!
+//! ```ignore
+//! let diagnostics = match compiler.compile() {
+//!     Ok(compilation) => compilation.warnings,
+//!     Err(error) => error.0,
+//! };
+//! let sarif = serde_json::to_string_pretty(&SarifLog::from_diagnostics(&diagnostics))?;
+//! ```
+//!
+//! Only the parts of SARIF needed to describe diagnostics are modeled here. Every [`DiagnosticCode`] is a rule of the log,
+//! and the [`Suggestion`]s of a diagnostic are its fixes.
+//!
+//! ## Implementation notes
+//!
+//! SARIF counts lines and columns from 1, while [`Position`] counts them from 0.
+//! Columns are counted in Unicode code points like in [`Position`], which the log declares with `columnKind`.
+
+use crate::prelude::*;
+use std::ops::Range;
+
+/// The version of SARIF the log follows.
+pub const SARIF_VERSION: &str = "2.1.0";
+
+/// The JSON schema of [`SARIF_VERSION`].
+pub const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// The root of a SARIF log. See the [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifLog {
+    /// Always [`SARIF_SCHEMA`].
+    #[serde(rename = "$schema")]
+    pub schema: String,
+    /// Always [`SARIF_VERSION`].
+    pub version: String,
+    /// The runs of the tools in the log. Logs created by [`SarifLog::from_diagnostics`] contain a single run.
+    pub runs: Vec<SarifRun>,
+}
+
+impl SarifLog {
+    /// Creates a log with a single run that reports `diagnostics`.
+    pub fn from_diagnostics<'a>(diagnostics: impl IntoIterator<Item = &'a Diagnostic>) -> Self {
+        let rules = DiagnosticCode::ALL
+            .iter()
+            .map(|code| SarifRule {
+                id: code.as_str().to_owned(),
+                name: code.name().to_owned(),
+                short_description: SarifMessage::new(code.description()),
+            })
+            .collect();
+        let results = diagnostics.into_iter().map(SarifResult::new).collect();
+        Self {
+            schema: SARIF_SCHEMA.to_owned(),
+            version: SARIF_VERSION.to_owned(),
+            runs: vec![SarifRun {
+                tool: SarifTool {
+                    driver: SarifToolComponent {
+                        name: "yarnspinner".to_owned(),
+                        version: env!("CARGO_PKG_VERSION").to_owned(),
+                        information_uri: "https://github.com/YarnSpinnerTool/YarnSpinner-Rust"
+                            .to_owned(),
+                        rules,
+                    },
+                },
+                column_kind: "unicodeCodePoints".to_owned(),
+                results,
+            }],
+        }
+    }
+}
+
+/// A single run of a tool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRun {
+    /// The tool that produced the results.
+    pub tool: SarifTool,
+    /// How columns are counted, which is `unicodeCodePoints` for Yarn Spinner.
+    pub column_kind: String,
+    /// The issues found.
+    pub results: Vec<SarifResult>,
+}
+
+/// Describes the tool that produced the results of a [`SarifRun`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifTool {
+    /// The tool itself.
+    pub driver: SarifToolComponent,
+}
+
+/// The name, version and rules of a tool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifToolComponent {
+    /// The name of the tool.
+    pub name: String,
+    /// The version of the tool.
+    pub version: String,
+    /// Where to find out more about the tool.
+    pub information_uri: String,
+    /// The kinds of issues the tool reports, referred to by [`SarifResult::rule_index`].
+    pub rules: Vec<SarifRule>,
+}
+
+/// A kind of issue, which corresponds to a [`DiagnosticCode`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRule {
+    /// The code, e.g. `YS0001`.
+    pub id: String,
+    /// The name of the code, e.g. `UndeclaredVariable`.
+    pub name: String,
+    /// What the code means.
+    pub short_description: SarifMessage,
+}
+
+/// A text for the user.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifMessage {
+    /// The text as plain text.
+    pub text: String,
+}
+
+impl SarifMessage {
+    fn new(text: impl Into<String>) -> Self {
+        Self { text: text.into() }
+    }
+}
+
+/// An issue found by a tool, which corresponds to a [`Diagnostic`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+    /// The [`SarifRule::id`] of the kind of issue.
+    pub rule_id: String,
+    /// The index of the kind of issue in [`SarifToolComponent::rules`].
+    pub rule_index: usize,
+    /// `error` or `warning`.
+    pub level: String,
+    /// The [`Diagnostic::message`].
+    pub message: SarifMessage,
+    /// Where the issue is. Empty if the diagnostic has no file name.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub locations: Vec<SarifLocation>,
+    /// The [`Diagnostic::suggestions`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fixes: Vec<SarifFix>,
+}
+
+impl SarifResult {
+    fn new(diagnostic: &Diagnostic) -> Self {
+        let level = match diagnostic.severity {
+            DiagnosticSeverity::Error => "error",
+            DiagnosticSeverity::Warning => "warning",
+        };
+        let artifact_location = diagnostic
+            .file_name
+            .as_deref()
+            .map(SarifArtifactLocation::new);
+        let locations = artifact_location
+            .iter()
+            .map(|artifact_location| SarifLocation {
+                physical_location: SarifPhysicalLocation {
+                    artifact_location: artifact_location.clone(),
+                    region: diagnostic.range.as_ref().map(SarifRegion::new),
+                },
+            })
+            .collect();
+        let fixes = artifact_location
+            .iter()
+            .flat_map(|artifact_location| {
+                diagnostic.suggestions.iter().map(|suggestion| SarifFix {
+                    description: SarifMessage::new(&suggestion.message),
+                    artifact_changes: vec![SarifArtifactChange {
+                        artifact_location: artifact_location.clone(),
+                        replacements: vec![SarifReplacement {
+                            deleted_region: SarifRegion::new(&suggestion.range),
+                            inserted_content: SarifArtifactContent {
+                                text: suggestion.replacement.clone(),
+                            },
+                        }],
+                    }],
+                })
+            })
+            .collect();
+        Self {
+            rule_id: diagnostic.code.as_str().to_owned(),
+            rule_index: DiagnosticCode::ALL
+                .iter()
+                .position(|code| *code == diagnostic.code)
+                .unwrap_or_default(),
+            level: level.to_owned(),
+            message: SarifMessage::new(&diagnostic.message),
+            locations,
+            fixes,
+        }
+    }
+}
+
+/// A place in a file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifLocation {
+    /// The file and the region in it.
+    pub physical_location: SarifPhysicalLocation,
+}
+
+/// A region of a file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifPhysicalLocation {
+    /// The file.
+    pub artifact_location: SarifArtifactLocation,
+    /// The region of the file. [`None`] if the issue concerns the whole file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<SarifRegion>,
+}
+
+/// A file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifArtifactLocation {
+    /// The path of the file, with `/` as separator.
+    pub uri: String,
+}
+
+impl SarifArtifactLocation {
+    fn new(file_name: &str) -> Self {
+        Self {
+            uri: file_name.replace('\\', "/"),
+        }
+    }
+}
+
+/// A range of a file. Lines and columns start at 1, and the end column is exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRegion {
+    /// The line the region starts on.
+    pub start_line: usize,
+    /// The column the region starts at.
+    pub start_column: usize,
+    /// The line the region ends on.
+    pub end_line: usize,
+    /// The column after the end of the region.
+    pub end_column: usize,
+}
+
+impl SarifRegion {
+    fn new(range: &Range<Position>) -> Self {
+        Self {
+            start_line: range.start.line + 1,
+            start_column: range.start.character + 1,
+            end_line: range.end.line + 1,
+            end_column: range.end.character + 1,
+        }
+    }
+}
+
+/// A fix for a [`SarifResult`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifFix {
+    /// The [`Suggestion::message`].
+    pub description: SarifMessage,
+    /// The changes to make.
+    pub artifact_changes: Vec<SarifArtifactChange>,
+}
+
+/// The changes to make to a file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifArtifactChange {
+    /// The file to change.
+    pub artifact_location: SarifArtifactLocation,
+    /// The replacements to make in the file.
+    pub replacements: Vec<SarifReplacement>,
+}
+
+/// Replaces a region of a file with new text.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifReplacement {
+    /// The region to remove.
+    pub deleted_region: SarifRegion,
+    /// The text to insert in its place.
+    pub inserted_content: SarifArtifactContent,
+}
+
+/// The content of a file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifArtifactContent {
+    /// The content as plain text.
+    pub text: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_diagnostics_with_suggestions() {
+        let range = Position {
+            line: 2,
+            character: 4,
+        }..Position {
+            line: 2,
+            character: 9,
+        };
+        let diagnostic = Diagnostic::new(DiagnosticCode::UndeclaredVariable, "Unknown $glod")
+            .with_file_name("dialogue\\start.yarn")
+            .with_range(range.clone())
+            .with_suggestion(Suggestion {
+                message: "did you mean `$gold`?".to_owned(),
+                range,
+                replacement: "$gold".to_owned(),
+            });
+
+        let log = SarifLog::from_diagnostics([&diagnostic]);
+
+        let run = &log.runs[0];
+        assert_eq!(DiagnosticCode::ALL.len(), run.tool.driver.rules.len());
+        let result = &run.results[0];
+        assert_eq!("YS0001", result.rule_id);
+        assert_eq!("YS0001", run.tool.driver.rules[result.rule_index].id);
+        assert_eq!("error", result.level);
+        let location = &result.locations[0].physical_location;
+        assert_eq!("dialogue/start.yarn", location.artifact_location.uri);
+        let expected_region = SarifRegion {
+            start_line: 3,
+            start_column: 5,
+            end_line: 3,
+            end_column: 10,
+        };
+        assert_eq!(Some(expected_region), location.region);
+        let replacement = &result.fixes[0].artifact_changes[0].replacements[0];
+        assert_eq!(expected_region, replacement.deleted_region);
+        assert_eq!("$gold", replacement.inserted_content.text);
+    }
+
+    #[test]
+    fn omits_locations_of_diagnostics_without_file() {
+        let diagnostic = Diagnostic::new(DiagnosticCode::SyntaxError, "Oops")
+            .with_severity(DiagnosticSeverity::Warning);
+
+        let log = SarifLog::from_diagnostics([&diagnostic]);
+
+        let result = &log.runs[0].results[0];
+        assert_eq!("warning", result.level);
+        assert!(result.locations.is_empty());
+        assert!(result.fixes.is_empty());
+    }
+}
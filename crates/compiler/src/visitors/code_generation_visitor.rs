@@ -1,58 +1,46 @@
 //! Adapted from <https://github.com/YarnSpinnerTool/YarnSpinner/blob/da39c7195107d8211f21c263e4084f773b84eaff/YarnSpinner.Compiler/CodeGenerationVisitor.cs>
 
 use crate::listeners::{CompilerListener, Emit};
-use crate::prelude::generated::yarnspinnerlexer;
-use crate::prelude::generated::yarnspinnerparser::*;
-use crate::prelude::generated::yarnspinnerparservisitor::YarnSpinnerParserVisitorCompat;
 use crate::prelude::*;
-use antlr_rust::parser_rule_context::ParserRuleContext;
-use antlr_rust::token::Token;
-use antlr_rust::tree::{ParseTree, ParseTreeVisitorCompat, Tree};
 use std::ops::Deref;
 use std::rc::Rc;
 use yarnspinner_core::prelude::OpCode;
 use yarnspinner_core::prelude::*;
 use yarnspinner_core::types::Type;
 
-pub(crate) struct CodeGenerationVisitor<'a, 'input: 'a> {
-    compiler_listener: &'a mut CompilerListener<'input>,
+pub(crate) struct CodeGenerationVisitor<'a> {
+    compiler_listener: &'a mut CompilerListener,
     tracking_enabled: Option<String>,
-    _dummy: (),
 }
 
-impl<'a, 'input: 'a> CodeGenerationVisitor<'a, 'input> {
+impl<'a> CodeGenerationVisitor<'a> {
     pub(crate) fn new(
-        compiler_listener: &'a mut CompilerListener<'input>,
+        compiler_listener: &'a mut CompilerListener,
         tracking_enabled: impl Into<Option<String>>,
     ) -> Self {
         Self {
             compiler_listener,
             tracking_enabled: tracking_enabled.into(),
-            _dummy: Default::default(),
         }
     }
     pub(crate) fn token_to_operator(token: isize) -> Option<Operator> {
         // operators for the standard expressions
         match token {
-            yarnspinnerlexer::OPERATOR_LOGICAL_LESS_THAN_EQUALS => {
-                Some(Operator::LessThanOrEqualTo)
-            }
-            yarnspinnerlexer::OPERATOR_LOGICAL_GREATER_THAN_EQUALS => {
-                Some(Operator::GreaterThanOrEqualTo)
-            }
-            yarnspinnerlexer::OPERATOR_LOGICAL_LESS => Some(Operator::LessThan),
-            yarnspinnerlexer::OPERATOR_LOGICAL_GREATER => Some(Operator::GreaterThan),
-            yarnspinnerlexer::OPERATOR_LOGICAL_EQUALS => Some(Operator::EqualTo),
-            yarnspinnerlexer::OPERATOR_LOGICAL_NOT_EQUALS => Some(Operator::NotEqualTo),
-            yarnspinnerlexer::OPERATOR_LOGICAL_AND => Some(Operator::And),
-            yarnspinnerlexer::OPERATOR_LOGICAL_OR => Some(Operator::Or),
-            yarnspinnerlexer::OPERATOR_LOGICAL_XOR => Some(Operator::Xor),
-            yarnspinnerlexer::OPERATOR_LOGICAL_NOT => Some(Operator::Not),
-            yarnspinnerlexer::OPERATOR_MATHS_ADDITION => Some(Operator::Add),
-            yarnspinnerlexer::OPERATOR_MATHS_SUBTRACTION => Some(Operator::Subtract),
-            yarnspinnerlexer::OPERATOR_MATHS_MULTIPLICATION => Some(Operator::Multiply),
-            yarnspinnerlexer::OPERATOR_MATHS_DIVISION => Some(Operator::Divide),
-            yarnspinnerlexer::OPERATOR_MATHS_MODULUS => Some(Operator::Modulo),
+            OPERATOR_LOGICAL_LESS_THAN_EQUALS => Some(Operator::LessThanOrEqualTo),
+            OPERATOR_LOGICAL_GREATER_THAN_EQUALS => Some(Operator::GreaterThanOrEqualTo),
+            OPERATOR_LOGICAL_LESS => Some(Operator::LessThan),
+            OPERATOR_LOGICAL_GREATER => Some(Operator::GreaterThan),
+            OPERATOR_LOGICAL_EQUALS => Some(Operator::EqualTo),
+            OPERATOR_LOGICAL_NOT_EQUALS => Some(Operator::NotEqualTo),
+            OPERATOR_LOGICAL_AND => Some(Operator::And),
+            OPERATOR_LOGICAL_OR => Some(Operator::Or),
+            OPERATOR_LOGICAL_XOR => Some(Operator::Xor),
+            OPERATOR_LOGICAL_NOT => Some(Operator::Not),
+            OPERATOR_MATHS_ADDITION => Some(Operator::Add),
+            OPERATOR_MATHS_SUBTRACTION => Some(Operator::Subtract),
+            OPERATOR_MATHS_MULTIPLICATION => Some(Operator::Multiply),
+            OPERATOR_MATHS_DIVISION => Some(Operator::Divide),
+            OPERATOR_MATHS_MODULUS => Some(Operator::Modulo),
             _ => None,
         }
     }
@@ -75,18 +63,11 @@ impl<'a, 'input: 'a> CodeGenerationVisitor<'a, 'input> {
     }
 }
 
-impl<'a, 'input: 'a> ParseTreeVisitorCompat<'input> for CodeGenerationVisitor<'a, 'input> {
-    type Node = YarnSpinnerParserContextType;
+impl YarnSpinnerParserVisitor for CodeGenerationVisitor<'_> {
     type Return = ();
 
-    fn temp_result(&mut self) -> &mut Self::Return {
-        &mut self._dummy
-    }
-}
-
-impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVisitor<'a, 'input> {
     /// a regular ol' line of text
-    fn visit_line_statement(&mut self, ctx: &Line_statementContext<'input>) -> Self::Return {
+    fn visit_line_statement(&mut self, ctx: &LineStatementContext) -> Self::Return {
         // [sic] TODO: add support for line conditions:
         //
         // Mae: here's a line <<if true>>
@@ -98,11 +79,12 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
         // Evaluate the inline expressions and push the results onto the
         // stack.
         let formatted_text = ctx.line_formatted_text().unwrap();
-        let expression_count =
-            self.generate_code_for_expressions_in_formatted_text(formatted_text.get_children());
+        let expression_count = self.generate_code_for_expressions_in_formatted_text(
+            &formatted_text.get_children().clone(),
+        );
         let line_id_tag = get_line_id_tag(&ctx.hashtag_all())
             .expect("Internal error: line should have an implicit or explicit line ID tag, but none was found. This is a bug. Please report it at https://github.com/YarnSpinnerTool/YarnSpinner-Rust/issues/new");
-        let line_id = line_id_tag.text.as_ref().unwrap().get_text().to_owned();
+        let line_id = line_id_tag.text.as_ref().unwrap().text.clone();
         self.compiler_listener.emit(
             Emit::from_op_code(OpCode::RunLine)
                 .with_token(ctx.start().deref())
@@ -112,39 +94,39 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
     }
 
     /// (expression)
-    fn visit_expParens(&mut self, ctx: &ExpParensContext<'input>) -> Self::Return {
-        self.visit(ctx.expression().unwrap().as_ref())
+    fn visit_exp_parens(&mut self, ctx: &ExpressionContext) -> Self::Return {
+        self.visit(ctx.expression(0).unwrap().as_ref())
     }
 
     /// * / %
-    fn visit_expMultDivMod(&mut self, ctx: &ExpMultDivModContext<'input>) -> Self::Return {
+    fn visit_exp_mult_div_mod(&mut self, ctx: &ExpressionContext) -> Self::Return {
         let operator_token = ctx.op.as_ref().unwrap();
-        let operator = Self::token_to_operator(operator_token.get_token_type()).unwrap();
+        let operator = Self::token_to_operator(operator_token.token_type).unwrap();
         let r#type = self.compiler_listener.types.get(ctx).unwrap().clone();
         let expressions = vec![
-            ctx.expression(0).unwrap() as Rc<ActualParserContext<'input>>,
-            ctx.expression(1).unwrap(),
+            ctx.expression(0).unwrap().into(),
+            ctx.expression(1).unwrap().into(),
         ];
         self.generate_code_for_operation(operator, operator_token.deref(), &r#type, &expressions)
     }
 
     /// < <= > >=
-    fn visit_expComparison(&mut self, ctx: &ExpComparisonContext<'input>) -> Self::Return {
+    fn visit_exp_comparison(&mut self, ctx: &ExpressionContext) -> Self::Return {
         let operator_token = ctx.op.as_ref().unwrap();
-        let operator = Self::token_to_operator(operator_token.get_token_type()).unwrap();
+        let operator = Self::token_to_operator(operator_token.token_type).unwrap();
         let r#type = self.compiler_listener.types.get(ctx).unwrap().clone();
         let expressions = vec![
-            ctx.expression(0).unwrap() as Rc<ActualParserContext<'input>>,
-            ctx.expression(1).unwrap(),
+            ctx.expression(0).unwrap().into(),
+            ctx.expression(1).unwrap().into(),
         ];
         self.generate_code_for_operation(operator, operator_token.deref(), &r#type, &expressions)
     }
 
     /// -expression
-    fn visit_expNegative(&mut self, ctx: &ExpNegativeContext<'input>) -> Self::Return {
+    fn visit_exp_negative(&mut self, ctx: &ExpressionContext) -> Self::Return {
         let operator_token = ctx.op.as_ref().unwrap();
         let r#type = self.compiler_listener.types.get(ctx).unwrap().clone();
-        let expressions = vec![ctx.expression().unwrap() as Rc<ActualParserContext<'input>>];
+        let expressions = vec![ctx.expression(0).unwrap().into()];
         self.generate_code_for_operation(
             Operator::UnarySubtract,
             operator_token.deref(),
@@ -154,34 +136,34 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
     }
 
     /// and && or || xor ^
-    fn visit_expAndOrXor(&mut self, ctx: &ExpAndOrXorContext<'input>) -> Self::Return {
+    fn visit_exp_and_or_xor(&mut self, ctx: &ExpressionContext) -> Self::Return {
         let operator_token = ctx.op.as_ref().unwrap();
-        let operator = Self::token_to_operator(operator_token.get_token_type()).unwrap();
+        let operator = Self::token_to_operator(operator_token.token_type).unwrap();
         let r#type = self.compiler_listener.types.get(ctx).unwrap().clone();
         let expressions = vec![
-            ctx.expression(0).unwrap() as Rc<ActualParserContext<'input>>,
-            ctx.expression(1).unwrap(),
+            ctx.expression(0).unwrap().into(),
+            ctx.expression(1).unwrap().into(),
         ];
         self.generate_code_for_operation(operator, operator_token.deref(), &r#type, &expressions)
     }
 
     /// + -
-    fn visit_expAddSub(&mut self, ctx: &ExpAddSubContext<'input>) -> Self::Return {
+    fn visit_exp_add_sub(&mut self, ctx: &ExpressionContext) -> Self::Return {
         let operator_token = ctx.op.as_ref().unwrap();
-        let operator = Self::token_to_operator(operator_token.get_token_type()).unwrap();
+        let operator = Self::token_to_operator(operator_token.token_type).unwrap();
         let r#type = self.compiler_listener.types.get(ctx).unwrap().clone();
         let expressions = vec![
-            ctx.expression(0).unwrap() as Rc<ActualParserContext<'input>>,
-            ctx.expression(1).unwrap(),
+            ctx.expression(0).unwrap().into(),
+            ctx.expression(1).unwrap().into(),
         ];
         self.generate_code_for_operation(operator, operator_token.deref(), &r#type, &expressions)
     }
 
     /// [sic] (not NOT !)expression
-    fn visit_expNot(&mut self, ctx: &ExpNotContext<'input>) -> Self::Return {
+    fn visit_exp_not(&mut self, ctx: &ExpressionContext) -> Self::Return {
         let operator_token = ctx.op.as_ref().unwrap();
         let r#type = self.compiler_listener.types.get(ctx).unwrap().clone();
-        let expressions = vec![ctx.expression().unwrap() as Rc<ActualParserContext<'input>>];
+        let expressions = vec![ctx.expression(0).unwrap().into()];
         self.generate_code_for_operation(
             Operator::Not,
             operator_token.deref(),
@@ -191,24 +173,24 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
     }
 
     /// Variable
-    fn visit_expValue(&mut self, ctx: &ExpValueContext<'input>) -> Self::Return {
+    fn visit_exp_value(&mut self, ctx: &ExpressionContext) -> Self::Return {
         self.visit(ctx.value().unwrap().as_ref())
     }
 
     /// == !=
-    fn visit_expEquality(&mut self, ctx: &ExpEqualityContext<'input>) -> Self::Return {
+    fn visit_exp_equality(&mut self, ctx: &ExpressionContext) -> Self::Return {
         let operator_token = ctx.op.as_ref().unwrap();
-        let operator = Self::token_to_operator(operator_token.get_token_type()).unwrap();
+        let operator = Self::token_to_operator(operator_token.token_type).unwrap();
         let r#type = self.compiler_listener.types.get(ctx).unwrap().clone();
         let expressions = vec![
-            ctx.expression(0).unwrap() as Rc<ActualParserContext<'input>>,
-            ctx.expression(1).unwrap(),
+            ctx.expression(0).unwrap().into(),
+            ctx.expression(1).unwrap().into(),
         ];
         self.generate_code_for_operation(operator, operator_token.deref(), &r#type, &expressions)
     }
 
-    fn visit_valueNumber(&mut self, ctx: &ValueNumberContext<'input>) -> Self::Return {
-        let number: f32 = ctx.NUMBER().unwrap().get_text().parse().unwrap();
+    fn visit_value_number(&mut self, ctx: &ValueContext) -> Self::Return {
+        let number: f32 = ctx.get_token(NUMBER, 0).unwrap().text.parse().unwrap();
         self.compiler_listener.emit(
             Emit::from_op_code(OpCode::PushFloat)
                 .with_token(ctx.start().deref())
@@ -216,7 +198,7 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
         )
     }
 
-    fn visit_valueTrue(&mut self, ctx: &ValueTrueContext<'input>) -> Self::Return {
+    fn visit_value_true(&mut self, ctx: &ValueContext) -> Self::Return {
         self.compiler_listener.emit(
             Emit::from_op_code(OpCode::PushBool)
                 .with_token(ctx.start().deref())
@@ -224,7 +206,7 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
         )
     }
 
-    fn visit_valueFalse(&mut self, ctx: &ValueFalseContext<'input>) -> Self::Return {
+    fn visit_value_false(&mut self, ctx: &ValueContext) -> Self::Return {
         self.compiler_listener.emit(
             Emit::from_op_code(OpCode::PushBool)
                 .with_token(ctx.start().deref())
@@ -232,16 +214,16 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
         )
     }
 
-    fn visit_valueVar(&mut self, ctx: &ValueVarContext<'input>) -> Self::Return {
+    fn visit_value_var(&mut self, ctx: &ValueContext) -> Self::Return {
         self.visit(ctx.variable().unwrap().as_ref())
     }
 
-    fn visit_valueString(&mut self, ctx: &ValueStringContext<'input>) -> Self::Return {
+    fn visit_value_string(&mut self, ctx: &ValueContext) -> Self::Return {
         // [sic] stripping the " off the front and back actually is this what we want?
         let string_value = ctx
-            .STRING()
+            .get_token(STRING, 0)
             .unwrap()
-            .get_text()
+            .text
             .trim_matches('"')
             .to_owned();
         self.compiler_listener.emit(
@@ -252,18 +234,18 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
     }
 
     /// null value
-    fn visit_valueNull(&mut self, ctx: &ValueNullContext<'input>) -> Self::Return {
+    fn visit_value_null(&mut self, ctx: &ValueContext) -> Self::Return {
         self.compiler_listener
             .emit(Emit::from_op_code(OpCode::PushNull).with_token(ctx.start().deref()))
     }
 
     /// all we need do is visit the function itself, it will handle everything
-    fn visit_valueFunc(&mut self, ctx: &ValueFuncContext<'input>) -> Self::Return {
+    fn visit_value_func(&mut self, ctx: &ValueContext) -> Self::Return {
         self.visit(ctx.function_call().unwrap().as_ref())
     }
 
-    fn visit_variable(&mut self, ctx: &VariableContext<'input>) -> Self::Return {
-        let variable_name = ctx.VAR_ID().unwrap().get_text();
+    fn visit_variable(&mut self, ctx: &VariableContext) -> Self::Return {
+        let variable_name = ctx.var_id().unwrap().text.clone();
         self.compiler_listener.emit(
             Emit::from_op_code(OpCode::PushVariable)
                 .with_token(ctx.start().deref())
@@ -272,7 +254,7 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
     }
 
     /// handles emitting the correct instructions for the function
-    fn visit_function_call(&mut self, ctx: &Function_callContext<'input>) -> Self::Return {
+    fn visit_function_call(&mut self, ctx: &FunctionCallContext) -> Self::Return {
         // generate the instructions for all of the parameters
         let expressions = ctx.expression_all();
         for parameter in &expressions {
@@ -288,7 +270,7 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
         );
 
         // then call the function itself
-        let function_name = ctx.FUNC_ID().unwrap().get_text();
+        let function_name = ctx.func_id().unwrap().text.clone();
         self.compiler_listener.emit(
             Emit::from_op_code(OpCode::CallFunc)
                 .with_token(token.deref())
@@ -297,7 +279,7 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
     }
 
     /// if statement ifclause (elseifclause)* (elseclause)? <<endif>>
-    fn visit_if_statement(&mut self, ctx: &If_statementContext<'input>) -> Self::Return {
+    fn visit_if_statement(&mut self, ctx: &IfStatementContext) -> Self::Return {
         // Implementation note: Idk what this is supposed to do. Looks like a noop.
         // context.AddErrorNode(null);
 
@@ -341,7 +323,7 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
     }
 
     /// A set command: explicitly setting a value to an expression <<set $foo to 1>>
-    fn visit_set_statement(&mut self, ctx: &Set_statementContext<'input>) -> Self::Return {
+    fn visit_set_statement(&mut self, ctx: &SetStatementContext) -> Self::Return {
         // Ensure that the correct result is on the stack by evaluating the
         // expression. If this assignment includes an operation (e.g. +=),
         // do that work here too.
@@ -359,26 +341,26 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
                 op,
                 operator_token.as_ref(),
                 &r#type,
-                &[variable.clone(), expression.clone()],
+                &[variable.clone().into(), expression.clone().into()],
             )
         };
-        match operator_token.get_token_type() {
-            yarnspinnerlexer::OPERATOR_ASSIGNMENT => {
+        match operator_token.token_type {
+            OPERATOR_ASSIGNMENT => {
                 self.visit(expression.as_ref());
             }
-            yarnspinnerlexer::OPERATOR_MATHS_ADDITION_EQUALS => {
+            OPERATOR_MATHS_ADDITION_EQUALS => {
                 generate_code_for_operation(Operator::Add);
             }
-            yarnspinnerlexer::OPERATOR_MATHS_SUBTRACTION_EQUALS => {
+            OPERATOR_MATHS_SUBTRACTION_EQUALS => {
                 generate_code_for_operation(Operator::Subtract);
             }
-            yarnspinnerlexer::OPERATOR_MATHS_MULTIPLICATION_EQUALS => {
+            OPERATOR_MATHS_MULTIPLICATION_EQUALS => {
                 generate_code_for_operation(Operator::Multiply);
             }
-            yarnspinnerlexer::OPERATOR_MATHS_DIVISION_EQUALS => {
+            OPERATOR_MATHS_DIVISION_EQUALS => {
                 generate_code_for_operation(Operator::Divide);
             }
-            yarnspinnerlexer::OPERATOR_MATHS_MODULUS_EQUALS => {
+            OPERATOR_MATHS_MODULUS_EQUALS => {
                 generate_code_for_operation(Operator::Modulo);
             }
             _ => {
@@ -399,7 +381,7 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
             .emit(Emit::from_op_code(OpCode::Pop).with_token(token.deref()));
     }
 
-    fn visit_call_statement(&mut self, ctx: &Call_statementContext<'input>) -> Self::Return {
+    fn visit_call_statement(&mut self, ctx: &CallStatementContext) -> Self::Return {
         // Visit our function call, which will invoke the function
         self.visit(ctx.function_call().unwrap().as_ref());
         // [sic] TODO: if this function returns a value, it will be pushed onto
@@ -409,17 +391,18 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
 
     /// semi-free form text that gets passed along to the game for things
     /// like <<turn fred left>> or <<unlockAchievement FacePlant>>
-    fn visit_command_statement(&mut self, ctx: &Command_statementContext<'input>) -> Self::Return {
+    fn visit_command_statement(&mut self, ctx: &CommandStatementContext) -> Self::Return {
         let formatted_text = ctx.command_formatted_text().unwrap();
-        let (composed_string, expression_count) = formatted_text.get_children().fold(
+        let children = formatted_text.get_children().clone();
+        let (composed_string, expression_count) = children.iter().fold(
             (String::new(), 0_usize),
             |(composed_string, expression_count), node| {
-                if node.get_child_count() == 0 {
+                if node.context().is_none() {
                     // Terminal node
                     (composed_string + &node.get_text(), expression_count)
                 } else {
                     // Generate code for evaluating the expression at runtime
-                    self.visit(node.as_ref());
+                    self.visit(node);
                     // Don't include the '{' and '}', because it will have been
                     // added as a terminal node already
                     (
@@ -453,7 +436,7 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
     /// for the shortcut options (-> line of text <<if expression>> indent statements dedent)+
     fn visit_shortcut_option_statement(
         &mut self,
-        ctx: &Shortcut_option_statementContext<'input>,
+        ctx: &ShortcutOptionStatementContext,
     ) -> Self::Return {
         let end_of_group_label = self.compiler_listener.register_label("group_end");
         let mut labels = Vec::new();
@@ -501,13 +484,17 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
             // involve evaluating any inline expressions.
             let line_statement = shortcut.line_statement().unwrap();
             let expression_count = self.generate_code_for_expressions_in_formatted_text(
-                line_statement.line_formatted_text().unwrap().get_children(),
+                &line_statement
+                    .line_formatted_text()
+                    .unwrap()
+                    .get_children()
+                    .clone(),
             );
 
             // Get the line ID from the hashtags if it has one
             let line_id_tag = get_line_id_tag(&line_statement.hashtag_all())
                 .expect("Internal error: no line ID provided. This is a bug. Please report it at https://github.com/YarnSpinnerTool/YarnSpinner-Rust/issues/new");
-            let line_id = line_id_tag.text.as_ref().unwrap().get_text().to_owned();
+            let line_id = line_id_tag.text.as_ref().unwrap().text.clone();
 
             // And add this option to the list.
             self.compiler_listener.emit(
@@ -560,12 +547,12 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
             .emit(Emit::from_op_code(OpCode::Pop).with_token(token.deref()));
     }
 
-    fn visit_declare_statement(&mut self, _ctx: &Declare_statementContext<'input>) -> Self::Return {
+    fn visit_declare_statement(&mut self, _ctx: &DeclareStatementContext) -> Self::Return {
         // Declare statements do not participate in code generation
     }
 
     /// A <<jump>> command, which immediately jumps to another node, given its name.
-    fn visit_jumpToNodeName(&mut self, ctx: &JumpToNodeNameContext<'input>) -> Self::Return {
+    fn visit_jump_to_node_name(&mut self, ctx: &JumpStatementContext) -> Self::Return {
         if let Some(tracking_enabled) = self.tracking_enabled.clone() {
             Self::generate_tracking_code(self.compiler_listener, tracking_enabled);
         }
@@ -573,7 +560,7 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
         self.compiler_listener.emit(
             Emit::from_op_code(OpCode::PushString)
                 .with_token(destination.deref())
-                .with_operand(destination.get_text().to_owned()),
+                .with_operand(destination.text.clone()),
         );
         self.compiler_listener
             .emit(Emit::from_op_code(OpCode::RunNode).with_token(ctx.start().deref()))
@@ -581,7 +568,7 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
 
     /// A <<jump>> command, which immediately jumps to another node, given an
     /// expression that resolves to a node's name.
-    fn visit_jumpToExpression(&mut self, ctx: &JumpToExpressionContext<'input>) -> Self::Return {
+    fn visit_jump_to_expression(&mut self, ctx: &JumpStatementContext) -> Self::Return {
         if let Some(tracking_enabled) = self.tracking_enabled.clone() {
             Self::generate_tracking_code(self.compiler_listener, tracking_enabled);
         }
@@ -592,11 +579,8 @@ impl<'a, 'input: 'a> YarnSpinnerParserVisitorCompat<'input> for CodeGenerationVi
     }
 }
 
-impl<'a, 'input: 'a> CodeGenerationVisitor<'a, 'input> {
-    fn generate_code_for_expressions_in_formatted_text(
-        &mut self,
-        nodes: impl Iterator<Item = Rc<ActualParserContext<'input>>>,
-    ) -> usize {
+impl<'a> CodeGenerationVisitor<'a> {
+    fn generate_code_for_expressions_in_formatted_text(&mut self, nodes: &[ParseTree]) -> usize {
         // First, visit all of the nodes, which are either terminal text
         // nodes or expressions. if they're expressions, we evaluate them,
         // and inject a positional reference into the final string.
@@ -610,7 +594,9 @@ impl<'a, 'input: 'a> CodeGenerationVisitor<'a, 'input> {
         // final value of this expression onto the stack. running
         // the line will pop these expressions off the stack.
         nodes
-            .filter_map(|child| (child.get_child_count() > 0).then(|| self.visit(child.as_ref())))
+            .iter()
+            .filter(|child| child.context().is_some())
+            .map(|child| self.visit(child))
             .count()
     }
 
@@ -618,14 +604,14 @@ impl<'a, 'input: 'a> CodeGenerationVisitor<'a, 'input> {
     fn generate_code_for_operation(
         &mut self,
         op: Operator,
-        operator_token: &impl Token,
+        operator_token: &Token,
         r#type: &Type,
-        operands: &[Rc<ActualParserContext<'input>>],
+        operands: &[ParseTree],
     ) {
         // Generate code for each of the operands, so that their value is
         // now on the stack.
         for operand in operands {
-            self.visit(operand.as_ref());
+            self.visit(operand);
         }
 
         // Indicate that we are pushing this many items for comparison
@@ -656,9 +642,9 @@ impl<'a, 'input: 'a> CodeGenerationVisitor<'a, 'input> {
     fn generate_code_for_clause(
         &mut self,
         jump_label: String,
-        ctx: &impl ParserRuleContext<'input>,
-        children: &[Rc<StatementContext<'input>>],
-        expression: impl Into<Option<Rc<ExpressionContextAll<'input>>>>,
+        ctx: &impl ParserRuleContext,
+        children: &[Rc<StatementContext>],
+        expression: impl Into<Option<Rc<ExpressionContext>>>,
     ) {
         let expression = expression.into();
         let end_of_clause_label = self.compiler_listener.register_label("skipclause");
@@ -1,11 +1,6 @@
 //! Adapted from <https://github.com/YarnSpinnerTool/YarnSpinner/blob/da39c7195107d8211f21c263e4084f773b84eaff/YarnSpinner.Compiler/ConstantValueVisitor.cs>
 
-use crate::prelude::generated::yarnspinnerparser::*;
-use crate::prelude::generated::yarnspinnerparservisitor::YarnSpinnerParserVisitorCompat;
 use crate::prelude::*;
-use antlr_rust::parser::ParserNodeType;
-use antlr_rust::tree::{ParseTree, ParseTreeVisitorCompat, VisitChildren};
-use std::mem;
 use std::ops::{Deref, DerefMut};
 use yarnspinner_core::prelude::*;
 
@@ -13,48 +8,35 @@ use yarnspinner_core::prelude::*;
 /// Currently only supports terminals, not expressions,
 /// even if those expressions would be constant.
 #[derive(Clone)]
-pub(crate) struct ConstantValueVisitor<'input> {
+pub(crate) struct ConstantValueVisitor {
     pub(crate) diagnostics: Vec<Diagnostic>,
-    _dummy: ConstantValue,
-    file: FileParseResult<'input>,
+    file: FileParseResult,
 }
 
-impl<'input> ConstantValueVisitor<'input> {
-    pub(crate) fn new(diagnostics: Vec<Diagnostic>, file: FileParseResult<'input>) -> Self {
-        Self {
-            diagnostics,
-            file,
-            _dummy: ConstantValue::non_panicking_default(),
-        }
+impl ConstantValueVisitor {
+    pub(crate) fn new(diagnostics: Vec<Diagnostic>, file: FileParseResult) -> Self {
+        Self { diagnostics, file }
     }
 }
 
-impl<'input> ParseTreeVisitorCompat<'input> for ConstantValueVisitor<'input> {
-    type Node = YarnSpinnerParserContextType;
+impl YarnSpinnerParserVisitor for ConstantValueVisitor {
     type Return = ConstantValue;
 
-    fn temp_result(&mut self) -> &mut Self::Return {
-        &mut self._dummy
-    }
-
-    fn visit(&mut self, node: &<Self::Node as ParserNodeType<'input>>::Type) -> Self::Return {
-        // Calling `self.visit_node` resolves to the wrong trait, so we need to be explicit.
-        VisitChildren::visit_node(self, node);
-        // The default implementation uses `mem::take`, which replaces the value with the default.
-        // However, we calling `default` on `ConstantValue` panics by design, so let's use the non-panicking version.
-        mem::replace(self.temp_result(), ConstantValue::non_panicking_default())
+    fn visit_children(&mut self, _ctx: &(impl ParserRuleContext + ?Sized)) -> Self::Return {
+        // The default implementation starts out with the default value.
+        // However, calling `default` on `ConstantValue` panics by design, so let's use the non-panicking version.
+        // This is reached for values that could not be parsed, which were already reported as syntax errors.
+        ConstantValue::non_panicking_default()
     }
-}
 
-impl<'input> YarnSpinnerParserVisitorCompat<'input> for ConstantValueVisitor<'input> {
-    fn visit_valueNumber(&mut self, ctx: &ValueNumberContext<'input>) -> Self::Return {
+    fn visit_value_number(&mut self, ctx: &ValueContext) -> Self::Return {
         let text = ctx.get_text();
         if let Ok(number) = text.parse::<f32>() {
             InternalValue::from(number).into()
         } else {
             let message = format!("Failed to parse {text} as a float",);
             self.diagnostics.push(
-                Diagnostic::from_message(message)
+                Diagnostic::new(DiagnosticCode::InvalidNumber, message)
                     .with_file_name(&self.file.name)
                     .with_parser_context(ctx, self.file.tokens()),
             );
@@ -65,48 +47,48 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for ConstantValueVisitor<'in
         }
     }
 
-    fn visit_valueTrue(&mut self, _ctx: &ValueTrueContext<'input>) -> Self::Return {
+    fn visit_value_true(&mut self, _ctx: &ValueContext) -> Self::Return {
         InternalValue::from(true).into()
     }
 
-    fn visit_valueFalse(&mut self, _ctx: &ValueFalseContext<'input>) -> Self::Return {
+    fn visit_value_false(&mut self, _ctx: &ValueContext) -> Self::Return {
         InternalValue::from(false).into()
     }
 
-    fn visit_valueVar(&mut self, ctx: &ValueVarContext<'input>) -> Self::Return {
+    fn visit_value_var(&mut self, ctx: &ValueContext) -> Self::Return {
         let text = ctx.get_text();
         let message = format!(
             "Variable declarations must be constant values, but `{text}` is another variable",
         );
         self.diagnostics.push(
-            Diagnostic::from_message(message)
+            Diagnostic::new(DiagnosticCode::NonConstantDeclaration, message)
                 .with_file_name(&self.file.name)
                 .with_parser_context(ctx, self.file.tokens()),
         );
         ConstantValue::non_panicking_default()
     }
 
-    fn visit_valueString(&mut self, ctx: &ValueStringContext<'input>) -> Self::Return {
-        let text = ctx.STRING().unwrap().get_text();
+    fn visit_value_string(&mut self, ctx: &ValueContext) -> Self::Return {
+        let text = ctx.get_token(STRING, 0).unwrap().text.clone();
         InternalValue::from(text.trim_matches('"')).into()
     }
 
-    fn visit_valueNull(&mut self, ctx: &ValueNullContext<'input>) -> Self::Return {
+    fn visit_value_null(&mut self, ctx: &ValueContext) -> Self::Return {
         let message = "Null is not a permitted type in Yarn Spinner 2.0 and later";
         self.diagnostics.push(
-            Diagnostic::from_message(message)
+            Diagnostic::new(DiagnosticCode::NullValue, message)
                 .with_file_name(&self.file.name)
                 .with_parser_context(ctx, self.file.tokens()),
         );
         ConstantValue::non_panicking_default()
     }
 
-    fn visit_valueFunc(&mut self, ctx: &ValueFuncContext<'input>) -> Self::Return {
+    fn visit_value_func(&mut self, ctx: &ValueContext) -> Self::Return {
         let text = ctx.get_text();
         let message =
             format!("Variable declarations must be constant values, but `{text}` is a function",);
         self.diagnostics.push(
-            Diagnostic::from_message(message)
+            Diagnostic::new(DiagnosticCode::NonConstantDeclaration, message)
                 .with_file_name(&self.file.name)
                 .with_parser_context(ctx, self.file.tokens()),
         );
@@ -114,10 +96,10 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for ConstantValueVisitor<'in
     }
 }
 
-/// Needed because ANTLR needs visitors' return values to have a default.
+/// Needed because visitors' return values need to have a default.
 /// While the C# implementation allows overriding a `DefaultResult` property,
 /// the Rust implementation simply takes the `Default` implementation of the associated `Return` type.
-/// However, we don't have a default [`InternalValue`], which wouldn't make much sense, but panic when it would have been built by antl4rust,
+/// However, we don't have a default [`InternalValue`], which wouldn't make much sense, but panic when it would have been built by the visitor,
 /// so we use this wrapper to accomplish that.
 ///
 /// This seems weird, I know. The original implementation writes a `Diagnostic` whenever the default value is constructed.
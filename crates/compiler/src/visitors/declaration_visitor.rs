@@ -1,11 +1,6 @@
 //! Adapted from <https://github.com/YarnSpinnerTool/YarnSpinner/blob/da39c7195107d8211f21c263e4084f773b84eaff/YarnSpinner.Compiler/DeclarationVisitor.cs>
-
-use crate::prelude::generated::yarnspinnerparser::*;
-use crate::prelude::generated::yarnspinnerparservisitor::YarnSpinnerParserVisitorCompat;
 use crate::prelude::*;
 use crate::visitors::constant_value_visitor::ConstantValueVisitor;
-use antlr_rust::token::Token;
-use antlr_rust::tree::{ParseTree, ParseTreeVisitorCompat};
 use regex::Regex;
 use yarnspinner_core::prelude::*;
 use yarnspinner_core::types::*;
@@ -14,7 +9,7 @@ use yarnspinner_core::types::*;
 /// After visiting an entire parse tree for a file, the
 /// [`NewDeclarations`] property will contain all explicit
 /// variable declarations that were found.
-pub(crate) struct DeclarationVisitor<'input> {
+pub(crate) struct DeclarationVisitor {
     /// Gets the collection of new variable declarations that were
     /// found as a result of using this
     /// [`DeclarationVisitor`] to visit a
@@ -29,7 +24,7 @@ pub(crate) struct DeclarationVisitor<'input> {
 
     /// The CommonTokenStream derived from the file we're parsing. This
     /// is used to find documentation comments for declarations.
-    file: FileParseResult<'input>,
+    file: FileParseResult,
 
     /// The collection of variable declarations we know about before starting our work
     existing_declarations: Vec<Declaration>,
@@ -39,15 +34,10 @@ pub(crate) struct DeclarationVisitor<'input> {
 
     /// A regular expression used to detect illegal characters in node titles.
     regex: Regex,
-
-    _dummy: (),
 }
 
-impl<'input> DeclarationVisitor<'input> {
-    pub(crate) fn new(
-        existing_declarations: Vec<Declaration>,
-        file: FileParseResult<'input>,
-    ) -> Self {
+impl DeclarationVisitor {
+    pub(crate) fn new(existing_declarations: Vec<Declaration>, file: FileParseResult) -> Self {
         Self {
             file,
             existing_declarations,
@@ -56,7 +46,6 @@ impl<'input> DeclarationVisitor<'input> {
             file_tags: Default::default(),
             diagnostics: Default::default(),
             current_node_name: None,
-            _dummy: Default::default(),
         }
     }
 
@@ -71,36 +60,32 @@ impl<'input> DeclarationVisitor<'input> {
     }
 }
 
-impl<'input> ParseTreeVisitorCompat<'input> for DeclarationVisitor<'input> {
-    type Node = YarnSpinnerParserContextType;
+impl YarnSpinnerParserVisitor for DeclarationVisitor {
     type Return = ();
 
-    fn temp_result(&mut self) -> &mut Self::Return {
-        &mut self._dummy
-    }
-}
-
-impl<'input> YarnSpinnerParserVisitorCompat<'input> for DeclarationVisitor<'input> {
-    fn visit_file_hashtag(&mut self, ctx: &File_hashtagContext<'input>) -> Self::Return {
-        let hashtag_text = ctx.text.as_ref().unwrap();
-        self.file_tags.push(hashtag_text.get_text().to_owned());
+    fn visit_file_hashtag(&mut self, ctx: &FileHashtagContext) -> Self::Return {
+        if let Some(hashtag_text) = ctx.text.as_ref() {
+            self.file_tags.push(hashtag_text.text.clone());
+        }
     }
 
-    fn visit_node(&mut self, ctx: &NodeContext<'input>) -> Self::Return {
+    fn visit_node(&mut self, ctx: &NodeContext) -> Self::Return {
         for header in ctx.header_all() {
             let header_key = header.header_key.as_ref().unwrap();
-            if header_key.get_text() != "title" {
+            if header_key.text != "title" {
                 continue;
             }
 
-            let header_value = header.header_value.as_ref().unwrap();
-            let current_node_name = header_value.get_text();
+            let current_node_name = header
+                .header_value
+                .as_ref()
+                .map_or("", |header_value| header_value.text.as_str());
             self.current_node_name = Some(current_node_name.to_owned());
             if self.regex.is_match(current_node_name) {
                 let message =
                     format!("The node '{current_node_name}' contains illegal characters.");
                 self.diagnostics.push(
-                    Diagnostic::from_message(message)
+                    Diagnostic::new(DiagnosticCode::InvalidNodeName, message)
                         .with_file_name(self.file.name.clone())
                         .with_parser_context(header.as_ref(), self.file.tokens()),
                 );
@@ -111,7 +96,7 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for DeclarationVisitor<'inpu
         }
     }
 
-    fn visit_declare_statement(&mut self, ctx: &Declare_statementContext<'input>) -> Self::Return {
+    fn visit_declare_statement(&mut self, ctx: &DeclareStatementContext) -> Self::Return {
         // Get the name of the variable we're declaring
         let variable_context = ctx.variable().unwrap();
         let variable_name = variable_context.get_text();
@@ -132,7 +117,7 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for DeclarationVisitor<'inpu
                 existing_explicit_declaration.name, existing_explicit_declaration.source_file_name,
             );
             self.diagnostics.push(
-                Diagnostic::from_message(msg)
+                Diagnostic::new(DiagnosticCode::DuplicateDeclaration, msg)
                     .with_file_name(&self.file.name)
                     .with_parser_context(ctx, self.file.tokens()),
             );
@@ -142,28 +127,31 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for DeclarationVisitor<'inpu
         // Figure out the value and its type
         let mut constant_value_visitor =
             ConstantValueVisitor::new(self.diagnostics.clone(), self.file.clone());
-        let value_context = ctx.value().unwrap();
+        // Without a value, the statement is a syntax error that has already been reported.
+        let Some(value_context) = ctx.value() else {
+            return;
+        };
         let value = constant_value_visitor.visit(value_context.as_ref());
         self.diagnostics
             .extend_from_slice(&constant_value_visitor.diagnostics);
 
         // Did the source code name an explicit type?
         if let Some(declaration_type) = ctx.declaration_type.as_ref() {
-            let explicit_type = match keyword_to_type(declaration_type.get_text()) {
+            let explicit_type = match keyword_to_type(&declaration_type.text) {
                 Some(builtin_type) => builtin_type,
 
                 // The type name provided didn't map to a built-in
                 // type. Look for the type in our type collection.
                 None => match Type::EXPLICITLY_CONSTRUCTABLE
                     .iter()
-                    .find(|t| t.to_string() == declaration_type.get_text())
+                    .find(|t| t.to_string() == declaration_type.text)
                 {
                     Some(explicit_type) => explicit_type.clone(),
                     None => {
                         // We didn't find a type by this name.
-                        let msg = format!("Unknown type {}", declaration_type.get_text());
+                        let msg = format!("Unknown type {}", declaration_type.text);
                         self.diagnostics.push(
-                            Diagnostic::from_message(msg)
+                            Diagnostic::new(DiagnosticCode::UnknownType, msg)
                                 .with_file_name(&self.file.name)
                                 .with_parser_context(ctx, self.file.tokens()),
                         );
@@ -179,12 +167,12 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for DeclarationVisitor<'inpu
                 if !value.r#type.is_sub_type_of(&explicit_type) {
                     let msg = format!(
                         "Type {} does not match value {} ({})",
-                        declaration_type.get_text(),
+                        declaration_type.text,
                         value_context.get_text(),
                         value.r#type.format()
                     );
                     self.diagnostics.push(
-                        Diagnostic::from_message(msg)
+                        Diagnostic::new(DiagnosticCode::DeclarationTypeMismatch, msg)
                             .with_file_name(&self.file.name)
                             .with_parser_context(ctx, self.file.tokens()),
                     );
@@ -238,6 +226,8 @@ mod tests {
             library: Default::default(),
             compilation_type: CompilationType::FullCompilation,
             variable_declarations: vec![],
+            optimize: false,
+            partial_compilation: false,
         }
         .compile()
         .unwrap();
@@ -328,6 +318,8 @@ mod tests {
             library: Default::default(),
             compilation_type: CompilationType::FullCompilation,
             variable_declarations: vec![],
+            optimize: false,
+            partial_compilation: false,
         }
         .compile();
 
@@ -335,22 +327,25 @@ mod tests {
         assert_eq!(2, diagnostics.len());
         assert_eq!(
             diagnostics[0],
-            Diagnostic::from_message("Type string does not match value 1 (Number)".to_string())
-                .with_file_name("test.yarn".to_string())
-                .with_context(file.source.clone())
-                .with_range(
-                    Position {
-                        line: 2,
-                        character: 0,
-                    }..Position {
-                        line: 2,
-                        character: 31,
-                    }
-                )
+            Diagnostic::new(
+                DiagnosticCode::DeclarationTypeMismatch,
+                "Type string does not match value 1 (Number)".to_string()
+            )
+            .with_file_name("test.yarn".to_string())
+            .with_context(file.source.clone())
+            .with_range(
+                Position {
+                    line: 2,
+                    character: 0,
+                }..Position {
+                    line: 2,
+                    character: 31,
+                }
+            )
         );
         assert_eq!(
             diagnostics[1],
-            Diagnostic::from_message("Can't figure out the type of variable $foo given its context. Specify its type with a <<declare>> statement.".to_string())
+            Diagnostic::new(DiagnosticCode::UndeclaredVariable, "Can't figure out the type of variable $foo given its context. Specify its type with a <<declare>> statement.".to_string())
                 .with_file_name("test.yarn".to_string())
                 .with_context(file.source)
                 .with_range(
@@ -1,35 +1,29 @@
-use crate::parser::generated::yarnspinnerparser::YarnSpinnerParserContext;
-use antlr_rust::interval_set::Interval;
-use antlr_rust::parser_rule_context::ParserRuleContext;
-use std::cmp::Ordering;
+use crate::prelude::*;
 use std::collections::HashMap;
-use std::hash::{Hash, Hasher};
 use std::ops::{Deref, DerefMut};
 use yarnspinner_core::prelude::*;
 
-#[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub(crate) struct HashableInterval(Interval);
+/// The interval of token indices a context spans, used to identify the context.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub(crate) struct HashableInterval(isize, isize);
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub(crate) struct KnownTypes(pub(crate) HashMap<HashableInterval, Type>);
 
 impl KnownTypes {
-    pub(crate) fn get<'input>(&self, ctx: &impl YarnSpinnerParserContext<'input>) -> Option<&Type> {
+    pub(crate) fn get(&self, ctx: &(impl ParserRuleContext + ?Sized)) -> Option<&Type> {
         let hashable_interval = ctx.get_hashable_interval();
         self.0.get(&hashable_interval)
     }
 
-    pub(crate) fn get_mut<'input>(
-        &mut self,
-        ctx: &impl YarnSpinnerParserContext<'input>,
-    ) -> Option<&mut Type> {
+    pub(crate) fn get_mut(&mut self, ctx: &(impl ParserRuleContext + ?Sized)) -> Option<&mut Type> {
         let hashable_interval = ctx.get_hashable_interval();
         self.0.get_mut(&hashable_interval)
     }
 
-    pub(crate) fn insert<'input>(
+    pub(crate) fn insert(
         &mut self,
-        ctx: &impl YarnSpinnerParserContext<'input>,
+        ctx: &(impl ParserRuleContext + ?Sized),
         r#type: impl Into<Option<Type>>,
     ) -> Option<Type> {
         let r#type = r#type.into()?;
@@ -42,42 +36,9 @@ impl KnownTypes {
     }
 }
 
-impl From<Interval> for HashableInterval {
-    fn from(interval: Interval) -> Self {
-        Self(interval)
-    }
-}
-
-impl Ord for HashableInterval {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.0.a.cmp(&other.0.a).then(self.0.b.cmp(&other.0.b))
-    }
-}
-
-impl PartialOrd for HashableInterval {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl Hash for HashableInterval {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.0.a.hash(state);
-        self.0.b.hash(state);
-    }
-}
-
-impl Deref for HashableInterval {
-    type Target = Interval;
-
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-
-impl DerefMut for HashableInterval {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+impl From<(isize, isize)> for HashableInterval {
+    fn from((start, stop): (isize, isize)) -> Self {
+        Self(start, stop)
     }
 }
 
@@ -95,11 +56,10 @@ impl DerefMut for KnownTypes {
     }
 }
 
-pub(crate) trait GetHashableInterval<'input>: ParserRuleContext<'input> {
+pub(crate) trait GetHashableInterval: ParserRuleContext {
     fn get_hashable_interval(&self) -> HashableInterval {
-        let interval = self.get_source_interval();
-        HashableInterval(interval)
+        self.get_source_interval().into()
     }
 }
 
-impl<'input, T: ParserRuleContext<'input>> GetHashableInterval<'input> for T {}
+impl<T: ParserRuleContext + ?Sized> GetHashableInterval for T {}
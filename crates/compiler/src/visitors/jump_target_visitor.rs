@@ -0,0 +1,94 @@
+use crate::prelude::*;
+use crate::visitors::constant_value_visitor::ConstantValueVisitor;
+use std::ops::Range;
+use std::rc::Rc;
+
+/// A node a jump goes to, as far as it is known at compile time.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct JumpTarget {
+    pub(crate) node_name: String,
+    /// Whether the name is written as a string, i.e. `<<jump {"Start"}>>`, so that a replacement needs quotes.
+    pub(crate) is_string: bool,
+    /// The diagnostic to report if no node is named [`JumpTarget::node_name`], pointing at the name.
+    pub(crate) diagnostic: Diagnostic,
+}
+
+/// Collects the targets of all jumps to a node name, and of all jumps to an expression that is a constant string.
+pub(crate) struct JumpTargetVisitor {
+    pub(crate) jump_targets: Vec<JumpTarget>,
+    file: FileParseResult,
+}
+
+impl JumpTargetVisitor {
+    pub(crate) fn new(file: FileParseResult) -> Self {
+        Self {
+            jump_targets: Vec::new(),
+            file,
+        }
+    }
+
+    fn add_jump_target(
+        &mut self,
+        node_name: String,
+        is_string: bool,
+        ctx: &(impl ParserRuleContext + ?Sized),
+        range: Range<Position>,
+    ) {
+        let diagnostic = Diagnostic::new(
+            DiagnosticCode::UnknownNode,
+            format!("There is no node named {node_name}"),
+        )
+        .with_file_name(self.file.name.clone())
+        .with_parser_context(ctx, self.file.tokens())
+        .with_range(range)
+        .with_severity(DiagnosticSeverity::Warning);
+        self.jump_targets.push(JumpTarget {
+            node_name,
+            is_string,
+            diagnostic,
+        });
+    }
+
+    /// Folds `expression` to the string it always evaluates to, if it is a string literal, possibly in parentheses.
+    fn constant_string(
+        &self,
+        expression: &ExpressionContext,
+    ) -> Option<(String, Rc<ValueContext>)> {
+        match expression.kind {
+            ExpressionKind::Parens => self.constant_string(expression.expression(0)?.as_ref()),
+            ExpressionKind::Value => {
+                let value = expression.value()?;
+                if value.kind != ValueKind::String {
+                    return None;
+                }
+                let mut constant_value_visitor =
+                    ConstantValueVisitor::new(Vec::new(), self.file.clone());
+                match constant_value_visitor.visit(value.as_ref()).0?.raw_value {
+                    YarnValue::String(node_name) => Some((node_name, value)),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+impl YarnSpinnerParserVisitor for JumpTargetVisitor {
+    type Return = ();
+
+    fn visit_jump_to_node_name(&mut self, ctx: &JumpStatementContext) -> Self::Return {
+        if let Some(destination) = &ctx.destination {
+            // Point at the name rather than the whole statement, so that it can be replaced.
+            self.add_jump_target(destination.text.clone(), false, ctx, destination.range());
+        }
+    }
+
+    fn visit_jump_to_expression(&mut self, ctx: &JumpStatementContext) -> Self::Return {
+        let Some(expression) = ctx.expression() else {
+            return;
+        };
+        if let Some((node_name, value)) = self.constant_string(&expression) {
+            self.add_jump_target(node_name, true, value.as_ref(), value.range());
+        }
+    }
+}
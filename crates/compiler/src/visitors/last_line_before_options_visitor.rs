@@ -1,29 +1,17 @@
 //! Adapted from <https://github.com/YarnSpinnerTool/YarnSpinner/blob/da39c7195107d8211f21c263e4084f773b84eaff/YarnSpinner.Compiler/LastLineBeforeOptionsVisitor.cs>
 
-use crate::prelude::generated::yarnspinnerparser::*;
-use crate::prelude::generated::yarnspinnerparservisitor::YarnSpinnerParserVisitorCompat;
 use crate::prelude::*;
-use antlr_rust::tree::ParseTreeVisitorCompat;
 use std::rc::Rc;
 
 #[derive(Debug, Clone, Default)]
-pub(crate) struct LastLineBeforeOptionsVisitor {
-    _dummy: (),
-}
+pub(crate) struct LastLineBeforeOptionsVisitor;
 
-impl ParseTreeVisitorCompat<'_> for LastLineBeforeOptionsVisitor {
-    type Node = YarnSpinnerParserContextType;
+impl YarnSpinnerParserVisitor for LastLineBeforeOptionsVisitor {
     type Return = ();
 
-    fn temp_result(&mut self) -> &mut Self::Return {
-        &mut self._dummy
-    }
-}
-
-impl<'input> YarnSpinnerParserVisitorCompat<'input> for LastLineBeforeOptionsVisitor {
     // entry point for everything
     // if there are no ifs or options with embedded statements this will be all that is visited
-    fn visit_body(&mut self, ctx: &BodyContext<'input>) -> Self::Return {
+    fn visit_body(&mut self, ctx: &BodyContext) -> Self::Return {
         self.run_through_statement(&ctx.statement_all());
     }
 
@@ -31,13 +19,13 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for LastLineBeforeOptionsVis
     // no checking is needed because only lines that are needing to be tagged will be visited, others are skipped.
     // The line is tagged regardless of if there is a #lastline there already
     // technically unnecessary in that case but this feels uncommon enough to not bother edgecasing
-    fn visit_line_statement(&mut self, ctx: &Line_statementContext<'input>) -> Self::Return {
+    fn visit_line_statement(&mut self, ctx: &LineStatementContext) -> Self::Return {
         add_hashtag_child(ctx, "lastline")
     }
 
     // handles the statements inside of an if statement
     // chunks its way through the if, any else-ifs and elses internal block of statements
-    fn visit_if_statement(&mut self, ctx: &If_statementContext<'input>) -> Self::Return {
+    fn visit_if_statement(&mut self, ctx: &IfStatementContext) -> Self::Return {
         let statements = ctx.if_clause().unwrap().statement_all();
         self.run_through_statement(&statements);
         for else_if in ctx.else_if_clause_all() {
@@ -55,7 +43,7 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for LastLineBeforeOptionsVis
     // basically just run through the statement (if any exist)
     fn visit_shortcut_option_statement(
         &mut self,
-        ctx: &Shortcut_option_statementContext<'input>,
+        ctx: &ShortcutOptionStatementContext,
     ) -> Self::Return {
         for shortcut in ctx.shortcut_option_all() {
             let statements = shortcut.statement_all();
@@ -72,7 +60,7 @@ impl LastLineBeforeOptionsVisitor {
     // 3. if both of these hold true we have found a line we need to flag as being before options
     // 4. repeat this process until we run out of statements to check
     // this has the potential to have VERY deep call stacks
-    fn run_through_statement(&mut self, statements: &[Rc<StatementContextAll>]) {
+    fn run_through_statement(&mut self, statements: &[Rc<StatementContext>]) {
         for (i, statement) in statements.iter().enumerate() {
             // if we are an if-block we have to visit it in case there are options and lines inside of that
             // once that is done we can move onto the next statement
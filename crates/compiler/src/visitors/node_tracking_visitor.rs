@@ -1,15 +1,10 @@
-use crate::parser::generated::yarnspinnerparser::{self, *};
-use crate::prelude::generated::yarnspinnerparservisitor::YarnSpinnerParserVisitorCompat;
-use antlr_rust::parser_rule_context::ParserRuleContext;
-use antlr_rust::token::Token;
-use antlr_rust::tree::{ParseTree, ParseTreeVisitorCompat};
+use crate::prelude::*;
 use std::collections::HashSet;
 
 #[derive(Clone, Default)]
 pub(crate) struct NodeTrackingVisitor {
     pub(crate) tracking_nodes: HashSet<String>,
     pub(crate) ignoring_nodes: HashSet<String>,
-    _dummy: Option<String>,
 }
 
 impl NodeTrackingVisitor {
@@ -18,25 +13,15 @@ impl NodeTrackingVisitor {
     }
 }
 
-impl ParseTreeVisitorCompat<'_> for NodeTrackingVisitor {
-    type Node = YarnSpinnerParserContextType;
+impl YarnSpinnerParserVisitor for NodeTrackingVisitor {
     type Return = Option<String>;
 
-    fn temp_result(&mut self) -> &mut Self::Return {
-        &mut self._dummy
-    }
-}
-
-impl<'input> YarnSpinnerParserVisitorCompat<'input> for NodeTrackingVisitor {
-    fn visit_node(&mut self, ctx: &NodeContext<'input>) -> Self::Return {
+    fn visit_node(&mut self, ctx: &NodeContext) -> Self::Return {
         let mut title = None;
         let mut tracking = None;
         for header in ctx.header_all() {
-            let key = header.header_key.as_ref().unwrap().get_text();
-            let value = header
-                .header_value
-                .as_ref()
-                .map(|val| val.get_text().to_owned());
+            let key = header.header_key.as_ref().unwrap().text.as_str();
+            let value = header.header_value.as_ref().map(|val| val.text.clone());
             match key {
                 "title" => {
                     title = value;
@@ -66,28 +51,25 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for NodeTrackingVisitor {
         None
     }
 
-    fn visit_valueString(&mut self, ctx: &ValueStringContext<'input>) -> Self::Return {
-        ctx.get_token(yarnspinnerparser::STRING, 0)
+    fn visit_value_string(&mut self, ctx: &ValueContext) -> Self::Return {
+        ctx.get_token(STRING, 0)
             .unwrap()
-            .get_text()
+            .text
             .trim_matches('"')
             .to_owned()
             .into()
     }
 
-    fn visit_function_call(&mut self, ctx: &Function_callContext<'input>) -> Self::Return {
-        let function_name = ctx
-            .get_token(yarnspinnerparser::FUNC_ID, 0)
-            .unwrap()
-            .get_text();
+    fn visit_function_call(&mut self, ctx: &FunctionCallContext) -> Self::Return {
+        let function_name = ctx.get_token(FUNC_ID, 0)?.text.clone();
 
         if !["visited", "visited_count"].contains(&function_name.as_str()) {
             return None;
         }
         // we aren't bothering to test anything about the value itself
         // if it isn't a static string we'll get back null so can ignore it
-        // if the func has more than one parameter later on it will cause an error so again can ignore
-        let expression = ctx.expression(0).unwrap();
+        // if the func has more or less than one parameter later on it will cause an error so again can ignore
+        let expression = ctx.expression(0)?;
         let result = self.visit(expression.as_ref());
         if let Some(result) = result {
             self.tracking_nodes.insert(result);
@@ -99,10 +81,6 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for NodeTrackingVisitor {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::prelude::generated::yarnspinnerparser::YarnSpinnerParser;
-    use crate::prelude::*;
-    use antlr_rust::common_token_stream::CommonTokenStream;
-    use antlr_rust::InputStream;
 
     #[test]
     fn finds_title_and_tracking_headers() {
@@ -134,11 +112,14 @@ tracking: always
     }
 
     fn process_input(input: &str) -> NodeTrackingVisitor {
-        let lexer = YarnSpinnerLexer::new(InputStream::new(input), "input.yarn".to_owned());
-        let mut parser = YarnSpinnerParser::new(CommonTokenStream::new(lexer));
-        let tree = parser.dialogue().unwrap();
+        let file = File {
+            file_name: "input.yarn".to_owned(),
+            source: input.to_owned(),
+        };
+        let chars: Vec<_> = input.chars().collect();
+        let parse_result = parse_syntax_tree(&file, &chars, &mut Vec::new());
         let mut visitor = NodeTrackingVisitor::new();
-        visitor.visit(tree.as_ref());
+        visitor.visit(parse_result.tree.as_ref());
         visitor
     }
 }
@@ -1,9 +1,5 @@
 //! Adapted from <https://github.com/YarnSpinnerTool/YarnSpinner/blob/da39c7195107d8211f21c263e4084f773b84eaff/YarnSpinner.Compiler/StringTableGeneratorVisitor.cs>
-use crate::prelude::generated::{yarnspinnerparser::*, yarnspinnerparservisitor::*};
 use crate::prelude::*;
-use antlr_rust::parser_rule_context::ParserRuleContext;
-use antlr_rust::token::Token;
-use antlr_rust::tree::{ParseTree, ParseTreeVisitorCompat, Tree};
 use std::rc::Rc;
 
 #[derive(Clone)]
@@ -12,56 +8,43 @@ use std::rc::Rc;
 /// This string table can then be provided
 /// to future compilation passes, or stored for later use. Call the
 /// [`visit`] method to begin generating string table entries.
-pub(crate) struct StringTableGeneratorVisitor<'input> {
+pub(crate) struct StringTableGeneratorVisitor {
     pub(crate) diagnostics: Vec<Diagnostic>,
     current_node_name: String,
     pub(crate) string_table_manager: StringTableManager,
-    file: FileParseResult<'input>,
-    _dummy: (),
+    file: FileParseResult,
 }
 
-impl<'input> StringTableGeneratorVisitor<'input> {
-    pub(crate) fn new(
-        string_table_manager: StringTableManager,
-        file: FileParseResult<'input>,
-    ) -> Self {
+impl StringTableGeneratorVisitor {
+    pub(crate) fn new(string_table_manager: StringTableManager, file: FileParseResult) -> Self {
         Self {
             file,
             string_table_manager,
             diagnostics: Default::default(),
             current_node_name: Default::default(),
-            _dummy: (),
         }
     }
 }
 
-impl<'input> ParseTreeVisitorCompat<'input> for StringTableGeneratorVisitor<'input> {
-    type Node = YarnSpinnerParserContextType;
-
+impl YarnSpinnerParserVisitor for StringTableGeneratorVisitor {
     type Return = ();
 
-    fn temp_result(&mut self) -> &mut Self::Return {
-        &mut self._dummy
-    }
-}
-
-impl<'input> YarnSpinnerParserVisitorCompat<'input> for StringTableGeneratorVisitor<'input> {
-    fn visit_node(&mut self, ctx: &NodeContext<'input>) -> Self::Return {
+    fn visit_node(&mut self, ctx: &NodeContext) -> Self::Return {
         let mut tags = Vec::new();
         for header in ctx.header_all() {
-            let header_key = header.header_key.as_ref().unwrap().get_text();
+            let header_key = header.header_key.as_ref().unwrap().text.as_str();
             if header_key == "title" {
                 header
                     .header_value
                     .as_ref()
-                    .unwrap()
-                    .get_text()
+                    .map(|header| header.text.as_str())
+                    .unwrap_or_default()
                     .clone_into(&mut self.current_node_name)
             } else if header_key == "tags" {
                 let header_value = header
                     .header_value
                     .as_ref()
-                    .map(|header| header.get_text())
+                    .map(|header| header.text.as_str())
                     .unwrap_or_default();
                 // Split the list of tags by spaces, and use that
                 tags = header_value
@@ -79,7 +62,7 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for StringTableGeneratorVisi
                 StringInfo {
                     text: ctx.body().unwrap().get_text(),
                     node_name: self.current_node_name.clone(),
-                    line_number: ctx.body().unwrap().start().line as usize,
+                    line_number: ctx.body().unwrap().start().line,
                     file_name: self.file.name.clone(),
                     ..Default::default()
                 },
@@ -93,7 +76,7 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for StringTableGeneratorVisi
         }
     }
 
-    fn visit_line_statement(&mut self, ctx: &Line_statementContext<'input>) -> Self::Return {
+    fn visit_line_statement(&mut self, ctx: &LineStatementContext) -> Self::Return {
         let hashtags = ctx.hashtag_all();
         let line_id_tag = get_line_id_tag(&hashtags);
         let line_id = line_id_tag.as_ref().and_then(|t| t.text.as_ref());
@@ -101,28 +84,31 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for StringTableGeneratorVisi
         if let Some(line_id) = line_id {
             if self
                 .string_table_manager
-                .contains_key(&line_id.get_text().into())
+                .contains_key(&line_id.text.clone().into())
             {
                 // The original has a fallback for when this is `null` / `None`,
                 // but this can logically not be the case in this scope.
                 let diagnostic_context = line_id_tag.clone().unwrap();
-                let line_id = line_id.get_text();
+                let line_id = &line_id.text;
                 self.diagnostics.push(
-                    Diagnostic::from_message(format!("Duplicate line ID {line_id}"))
-                        .with_parser_context(diagnostic_context.as_ref(), self.file.tokens())
-                        .with_file_name(&self.file.name),
+                    Diagnostic::new(
+                        DiagnosticCode::DuplicateLineId,
+                        format!("Duplicate line ID {line_id}"),
+                    )
+                    .with_parser_context(diagnostic_context.as_ref(), self.file.tokens())
+                    .with_file_name(&self.file.name),
                 );
                 return;
             }
         };
 
-        let line_number = ctx.start().get_line_as_usize();
+        let line_number = ctx.start().line;
         let hashtag_texts = get_hashtag_texts(&hashtags);
 
         let composed_string = generate_formatted_text(&ctx.line_formatted_text().unwrap());
 
         let string_id = self.string_table_manager.insert(
-            line_id.map(|t| t.get_text().into()),
+            line_id.map(|t| t.text.clone().into()),
             StringInfo {
                 text: composed_string,
                 node_name: self.current_node_name.clone(),
@@ -143,15 +129,15 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for StringTableGeneratorVisi
 /// `Hi there { some_expression }, how are you { another_expression } doing?`
 /// and turns it into
 /// `Hi there {0}, how are you {1}? doing`
-fn generate_formatted_text(ctx: &Line_formatted_textContext) -> String {
+pub(crate) fn generate_formatted_text(ctx: &LineFormattedTextContext) -> String {
     let mut expression_count = 0;
     let mut composed_string = String::new();
     // First, visit all of the nodes, which are either terminal
     // text nodes or expressions. if they're expressions, we
     // evaluate them, and inject a positional reference into the
     // final string.
-    for child in ctx.get_children() {
-        if child.get_child_count() == 0 {
+    for child in ctx.get_children().iter() {
+        if child.context().is_none() {
             composed_string.push_str(&child.get_text());
         } else {
             // Expressions in the final string are denoted as the
@@ -170,22 +156,14 @@ fn generate_formatted_text(ctx: &Line_formatted_textContext) -> String {
 pub(crate) fn get_hashtag_texts(hashtags: &[Rc<HashtagContext>]) -> Vec<String> {
     hashtags
         .iter()
-        .map(|t| {
-            t.text
-                .as_ref()
-                .expect("No text in hashtag")
-                .get_text()
-                .trim()
-                .to_owned()
-        })
+        .filter_map(|t| t.text.as_ref())
+        .map(|text| text.text.trim().to_owned())
         .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use antlr_rust::common_token_stream::CommonTokenStream;
-    use antlr_rust::InputStream;
     use yarnspinner_core::prelude::Position;
 
     #[test]
@@ -225,11 +203,13 @@ A line with {$many} many {(1 -(1 * 2))}{$cool} expressions
     }
 
     fn process_input(input: &str) -> String {
-        let lexer = YarnSpinnerLexer::new(InputStream::new(input), "input.yarn".to_owned());
-        let mut parser = YarnSpinnerParser::new(CommonTokenStream::new(lexer));
-        let line_formatted_text = parser
-            .dialogue()
-            .unwrap()
+        let file = File {
+            file_name: "input.yarn".to_owned(),
+            source: input.to_owned(),
+        };
+        let chars: Vec<_> = input.chars().collect();
+        let line_formatted_text = parse_syntax_tree(&file, &chars, &mut Vec::new())
+            .tree
             .node(0)
             .unwrap()
             .body()
@@ -260,6 +240,8 @@ a {1 + 3} cool expression
             library: Default::default(),
             compilation_type: CompilationType::FullCompilation,
             variable_declarations: vec![],
+            optimize: false,
+            partial_compilation: false,
         }
         .compile()
         .unwrap();
@@ -318,11 +300,13 @@ a {very} cool expression
             library: Default::default(),
             compilation_type: CompilationType::FullCompilation,
             variable_declarations: vec![],
+            optimize: false,
+            partial_compilation: false,
         }
         .compile();
 
         let diagnostics = result.unwrap_err().0;
-        assert_eq!(2, diagnostics.len());
+        assert_eq!(1, diagnostics.len());
 
         let range = Position {
             line: 4,
@@ -332,26 +316,15 @@ a {very} cool expression
             character: 8,
         };
         let context = "a {very} cool expression\n       ^".to_owned();
-        let first_expected =
-            Diagnostic::from_message("Unexpected \"}\" while reading a function call".to_string())
-                .with_file_name("test.yarn".to_string())
-                .with_range(range.clone())
-                .with_context(context.clone())
-                .with_start_line(4)
-                .with_severity(DiagnosticSeverity::Error);
-
-        let second_expected =
-            Diagnostic::from_message("mismatched input '}' expecting '('".to_string())
-                .with_file_name("test.yarn".to_string())
-                .with_range(range)
-                .with_context(context)
-                .with_start_line(4)
-                .with_severity(DiagnosticSeverity::Error);
-        if diagnostics[0] == first_expected {
-            assert_eq!(diagnostics[1], second_expected);
-        } else {
-            assert_eq!(diagnostics[0], second_expected);
-            assert_eq!(diagnostics[1], first_expected);
-        }
+        let expected = Diagnostic::new(
+            DiagnosticCode::SyntaxError,
+            "Unexpected \"}\" while reading a function call".to_string(),
+        )
+        .with_file_name("test.yarn".to_string())
+        .with_range(range)
+        .with_context(context)
+        .with_start_line(4)
+        .with_severity(DiagnosticSeverity::Error);
+        assert_eq!(diagnostics[0], expected);
     }
 }
@@ -1,14 +1,8 @@
 //! Adapted from <https://github.com/YarnSpinnerTool/YarnSpinner/blob/da39c7195107d8211f21c263e4084f773b84eaff/YarnSpinner.Compiler/TypeCheckVisitor.cs>
 
 use crate::parser_rule_context_ext::ParserRuleContextExt;
-use crate::prelude::generated::yarnspinnerlexer;
-use crate::prelude::generated::yarnspinnerparser::*;
-use crate::prelude::generated::yarnspinnerparservisitor::YarnSpinnerParserVisitorCompat;
 use crate::prelude::*;
 use crate::visitors::{CodeGenerationVisitor, KnownTypes};
-use antlr_rust::parser_rule_context::ParserRuleContext;
-use antlr_rust::token::Token;
-use antlr_rust::tree::{ParseTree, ParseTreeVisitorCompat};
 use check_operation::*;
 use std::path::Path;
 use yarnspinner_core::prelude::*;
@@ -22,7 +16,7 @@ mod check_operation;
 /// attempt to infer the type of variables that don't have an explicit
 /// declaration; for each of these, a new Declaration will be created
 /// and made available via the [`new_declaration`] property.
-pub(crate) struct TypeCheckVisitor<'input> {
+pub(crate) struct TypeCheckVisitor {
     /// <summary>
     /// Gets the collection of all declarations - both the ones we received
     /// at the start, and the new ones we've derived ourselves.
@@ -68,15 +62,11 @@ pub(crate) struct TypeCheckVisitor<'input> {
     /// on the [`ValueContext`] directly using a `partial`
     hints: KnownTypes,
 
-    file: FileParseResult<'input>,
-    _dummy: Option<Type>,
+    file: FileParseResult,
 }
 
-impl<'input> TypeCheckVisitor<'input> {
-    pub(crate) fn new(
-        existing_declarations: Vec<Declaration>,
-        file: FileParseResult<'input>,
-    ) -> Self {
+impl TypeCheckVisitor {
+    pub(crate) fn new(existing_declarations: Vec<Declaration>, file: FileParseResult) -> Self {
         Self {
             file,
             existing_declarations,
@@ -86,7 +76,6 @@ impl<'input> TypeCheckVisitor<'input> {
             current_node_name: Default::default(),
             known_types: Default::default(),
             hints: Default::default(),
-            _dummy: Default::default(),
         }
     }
 
@@ -107,23 +96,19 @@ impl<'input> TypeCheckVisitor<'input> {
     }
 }
 
-impl<'input> ParseTreeVisitorCompat<'input> for TypeCheckVisitor<'input> {
-    type Node = YarnSpinnerParserContextType;
-
+impl YarnSpinnerParserVisitor for TypeCheckVisitor {
     type Return = Option<Type>;
 
-    fn temp_result(&mut self) -> &mut Self::Return {
-        &mut self._dummy
-    }
-}
-
-impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input> {
-    fn visit_node(&mut self, ctx: &NodeContext<'input>) -> Self::Return {
+    fn visit_node(&mut self, ctx: &NodeContext) -> Self::Return {
         for header in ctx.header_all() {
-            let key = header.header_key.as_ref().unwrap().get_text();
+            let key = header.header_key.as_ref().unwrap().text.as_str();
             if key == "title" {
-                let value = header.header_value.as_ref().unwrap().get_text();
-                self.current_node_name = Some(value.to_owned());
+                let value = header
+                    .header_value
+                    .as_ref()
+                    .map(|value| value.text.clone())
+                    .unwrap_or_default();
+                self.current_node_name = Some(value);
             }
         }
         if let Some(body) = ctx.body() {
@@ -132,79 +117,77 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input>
         None
     }
 
-    fn visit_expParens(&mut self, ctx: &ExpParensContext<'input>) -> Self::Return {
+    fn visit_exp_parens(&mut self, ctx: &ExpressionContext) -> Self::Return {
         // Parens expressions have the type of their inner expression
-        let r#type = self.visit(ctx.expression().unwrap().as_ref());
+        let r#type = self.visit(ctx.expression(0).unwrap().as_ref());
         self.known_types.insert(ctx, r#type.clone());
         r#type
     }
 
-    fn visit_expMultDivMod(&mut self, ctx: &ExpMultDivModContext<'input>) -> Self::Return {
+    fn visit_exp_mult_div_mod(&mut self, ctx: &ExpressionContext) -> Self::Return {
         let expressions: Vec<_> = ctx.expression_all().into_iter().map(|e| e.into()).collect();
         let op = ctx.op.as_ref().unwrap();
         let operator = CodeGenerationVisitor::token_to_operator(op.token_type);
         // *, /, % all support numbers only
         // ## Implementation notes
         // The original passes no permitted types, but judging by the comment above, this seems like a bug
-        let r#type =
-            self.check_operation(ctx, &expressions, operator, op.get_text(), &[Type::Number]);
+        let r#type = self.check_operation(ctx, &expressions, operator, &op.text, &[Type::Number]);
         self.known_types.insert(ctx, r#type.clone());
         r#type
     }
 
-    fn visit_expComparison(&mut self, ctx: &ExpComparisonContext<'input>) -> Self::Return {
+    fn visit_exp_comparison(&mut self, ctx: &ExpressionContext) -> Self::Return {
         let expressions: Vec<_> = ctx.expression_all().into_iter().map(|e| e.into()).collect();
         let op = ctx.op.as_ref().unwrap();
         let operator = CodeGenerationVisitor::token_to_operator(op.token_type);
-        let r#type = self.check_operation(ctx, &expressions, operator, op.get_text(), &[]);
+        let r#type = self.check_operation(ctx, &expressions, operator, &op.text, &[]);
         self.known_types.insert(ctx, r#type);
         // Comparisons always return bool
         Some(Type::Boolean)
     }
 
-    fn visit_expNegative(&mut self, ctx: &ExpNegativeContext<'input>) -> Self::Return {
-        let expressions = &[ctx.expression().unwrap().into()];
+    fn visit_exp_negative(&mut self, ctx: &ExpressionContext) -> Self::Return {
+        let expressions = &[ctx.expression(0).unwrap().into()];
         let op = ctx.op.as_ref().unwrap();
         let operator = CodeGenerationVisitor::token_to_operator(op.token_type);
-        let r#type = self.check_operation(ctx, expressions, operator, op.get_text(), &[]);
+        let r#type = self.check_operation(ctx, expressions, operator, &op.text, &[]);
         self.known_types.insert(ctx, r#type.clone());
         r#type
     }
 
-    fn visit_expAndOrXor(&mut self, ctx: &ExpAndOrXorContext<'input>) -> Self::Return {
+    fn visit_exp_and_or_xor(&mut self, ctx: &ExpressionContext) -> Self::Return {
         let expressions: Vec<_> = ctx.expression_all().into_iter().map(Term::from).collect();
         let operator_context = ctx.op.as_ref().unwrap();
         let operator =
             CodeGenerationVisitor::token_to_operator(operator_context.token_type).unwrap();
-        let description = operator_context.get_text();
+        let description = &operator_context.text;
         let r#type = self.check_operation(ctx, &expressions, operator, description, &[]);
         self.known_types.insert(ctx, r#type.clone());
         r#type
     }
 
-    fn visit_expAddSub(&mut self, ctx: &ExpAddSubContext<'input>) -> Self::Return {
+    fn visit_exp_add_sub(&mut self, ctx: &ExpressionContext) -> Self::Return {
         let expressions: Vec<_> = ctx.expression_all().into_iter().map(|e| e.into()).collect();
         let op = ctx.op.as_ref().unwrap();
         let operator = CodeGenerationVisitor::token_to_operator(op.token_type);
-        let r#type = self.check_operation(ctx, &expressions, operator, op.get_text(), &[]);
+        let r#type = self.check_operation(ctx, &expressions, operator, &op.text, &[]);
         self.known_types.insert(ctx, r#type.clone());
         r#type
     }
 
-    fn visit_expNot(&mut self, ctx: &ExpNotContext<'input>) -> Self::Return {
-        let expressions = &[ctx.expression().unwrap().into()];
+    fn visit_exp_not(&mut self, ctx: &ExpressionContext) -> Self::Return {
+        let expressions = &[ctx.expression(0).unwrap().into()];
         let op = ctx.op.as_ref().unwrap();
         let operator = CodeGenerationVisitor::token_to_operator(op.token_type);
         // ! supports only bool types
         // ## Implementation notes
         // The original passes no permitted types, but judging by the comment above, this seems like a bug
-        let r#type =
-            self.check_operation(ctx, expressions, operator, op.get_text(), &[Type::Boolean]);
+        let r#type = self.check_operation(ctx, expressions, operator, &op.text, &[Type::Boolean]);
         self.known_types.insert(ctx, r#type.clone());
         r#type
     }
 
-    fn visit_expValue(&mut self, ctx: &ExpValueContext<'input>) -> Self::Return {
+    fn visit_exp_value(&mut self, ctx: &ExpressionContext) -> Self::Return {
         // passing the hint from the expression down into the values within
         let hint = self.hints.get(ctx).cloned();
         let value = ctx.value().unwrap();
@@ -215,56 +198,54 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input>
         r#type
     }
 
-    fn visit_expEquality(&mut self, ctx: &ExpEqualityContext<'input>) -> Self::Return {
+    fn visit_exp_equality(&mut self, ctx: &ExpressionContext) -> Self::Return {
         let expressions: Vec<_> = ctx.expression_all().into_iter().map(|e| e.into()).collect();
         let op = ctx.op.as_ref().unwrap();
         let operator = CodeGenerationVisitor::token_to_operator(op.token_type);
         // == and != support any defined type, as long as terms are the
         // same type
-        let r#type = self.check_operation(ctx, &expressions, operator, op.get_text(), &[]);
+        let r#type = self.check_operation(ctx, &expressions, operator, &op.text, &[]);
         self.known_types.insert(ctx, r#type);
         // Equality always returns bool
         Some(Type::Boolean)
     }
 
-    fn visit_valueNumber(&mut self, _ctx: &ValueNumberContext<'input>) -> Self::Return {
+    fn visit_value_number(&mut self, _ctx: &ValueContext) -> Self::Return {
         Some(Type::Number)
     }
 
-    fn visit_valueTrue(&mut self, _ctx: &ValueTrueContext<'input>) -> Self::Return {
+    fn visit_value_true(&mut self, _ctx: &ValueContext) -> Self::Return {
         Some(Type::Boolean)
     }
 
-    fn visit_valueFalse(&mut self, _ctx: &ValueFalseContext<'input>) -> Self::Return {
+    fn visit_value_false(&mut self, _ctx: &ValueContext) -> Self::Return {
         Some(Type::Boolean)
     }
 
-    fn visit_valueVar(&mut self, ctx: &ValueVarContext<'input>) -> Self::Return {
+    fn visit_value_var(&mut self, ctx: &ValueContext) -> Self::Return {
         let variable = ctx.variable().unwrap();
         self.visit_variable(&variable)
     }
 
-    fn visit_valueString(&mut self, _ctx: &ValueStringContext<'input>) -> Self::Return {
+    fn visit_value_string(&mut self, _ctx: &ValueContext) -> Self::Return {
         Some(Type::String)
     }
 
-    fn visit_valueNull(&mut self, ctx: &ValueNullContext<'input>) -> Self::Return {
+    fn visit_value_null(&mut self, ctx: &ValueContext) -> Self::Return {
         self.diagnostics.push(
-            Diagnostic::from_message("Null is not a permitted type in Yarn Spinner 2.0 and later")
-                .with_file_name(&self.file.name)
-                .with_parser_context(ctx, self.file.tokens()),
+            Diagnostic::new(
+                DiagnosticCode::NullValue,
+                "Null is not a permitted type in Yarn Spinner 2.0 and later",
+            )
+            .with_file_name(&self.file.name)
+            .with_parser_context(ctx, self.file.tokens()),
         );
 
         None
     }
 
-    fn visit_valueFunc(&mut self, ctx: &ValueFuncContext<'input>) -> Self::Return {
-        let function_name = ctx
-            .function_call()
-            .unwrap()
-            .get_token(yarnspinnerlexer::FUNC_ID, 0)
-            .unwrap()
-            .get_text();
+    fn visit_value_func(&mut self, ctx: &ValueContext) -> Self::Return {
+        let function_name = ctx.function_call().unwrap().func_id().unwrap().text.clone();
 
         let function_declaration = self
             .declarations()
@@ -306,8 +287,8 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input>
                 function_type.add_parameter(parameter_type);
             }
 
-            let line = ctx.start().get_line_as_usize();
-            let column = ctx.start().get_column_as_usize();
+            let line = ctx.start().line;
+            let column = ctx.start().column;
             let function_declaration =
                 Declaration::new(function_name.clone(), function_type.clone())
                     .with_description(format!(
@@ -321,35 +302,47 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input>
         };
         // Check each parameter of the function
         let supplied_parameters = ctx.function_call().unwrap().expression_all();
-        let expected_parameter_types = function_type.parameters;
-
-        if supplied_parameters.len() != expected_parameter_types.len() {
+        if !function_type.accepts_argument_count(supplied_parameters.len()) {
             // Wrong number of parameters supplied
-            let parameters = if expected_parameter_types.len() == 1 {
-                "parameter"
+            let required_parameter_count = function_type.required_parameter_count();
+            let max_parameter_count = function_type.parameters.len();
+            let (expected_count, is_plural) = if function_type.variadic_parameter_type.is_some() {
+                (
+                    format!("at least {required_parameter_count}"),
+                    required_parameter_count != 1,
+                )
+            } else if required_parameter_count == max_parameter_count {
+                (
+                    required_parameter_count.to_string(),
+                    required_parameter_count != 1,
+                )
             } else {
-                "parameters"
+                (
+                    format!("between {required_parameter_count} and {max_parameter_count}"),
+                    true,
+                )
             };
-            let diagnostic = Diagnostic::from_message(format!(
-                "Function \"{}\" expects {} {}, but received {}",
-                function_name,
-                expected_parameter_types.len(),
-                parameters,
-                supplied_parameters.len()
-            ))
+            let parameters = if is_plural { "parameters" } else { "parameter" };
+            let diagnostic = Diagnostic::new(
+                DiagnosticCode::WrongArgumentCount,
+                format!(
+                    "Function \"{}\" expects {} {}, but received {}",
+                    function_name,
+                    expected_count,
+                    parameters,
+                    supplied_parameters.len()
+                ),
+            )
             .with_file_name(&self.file.name)
             .with_parser_context(ctx, self.file.tokens());
             self.diagnostics.push(diagnostic);
             return *function_type.return_type;
         }
 
-        for (i, (supplied_parameter, mut expected_type)) in supplied_parameters
-            .iter()
-            .cloned()
-            .zip(expected_parameter_types.iter())
-            .enumerate()
-        {
+        for (i, supplied_parameter) in supplied_parameters.iter().cloned().enumerate() {
             let supplied_type = self.visit(supplied_parameter.as_ref());
+            // Guaranteed to be Some because the argument count was checked above
+            let mut expected_type = function_type.parameter_type_at(i).unwrap();
             if expected_type.is_none() {
                 // The type of this parameter hasn't yet been bound.
                 // Bind this parameter type to what we've resolved the
@@ -362,16 +355,19 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input>
                     unreachable!();
                 };
                 function_type.parameters[i].clone_from(&supplied_type);
-                expected_type = &supplied_type;
+                expected_type.clone_from(&supplied_type);
             }
-            if !supplied_type.is_sub_type_of(expected_type) {
-                let diagnostic = Diagnostic::from_message(format!(
-                    "{} parameter {} expects a {}, not a {}",
-                    function_name,
-                    i + 1,
-                    expected_type.format(),
-                    supplied_type.format()
-                ))
+            if !supplied_type.is_sub_type_of(&expected_type) {
+                let diagnostic = Diagnostic::new(
+                    DiagnosticCode::ArgumentTypeMismatch,
+                    format!(
+                        "{} parameter {} expects a {}, not a {}",
+                        function_name,
+                        i + 1,
+                        expected_type.format(),
+                        supplied_type.format()
+                    ),
+                )
                 .with_file_name(&self.file.name)
                 .with_parser_context(ctx, self.file.tokens());
                 self.diagnostics.push(diagnostic);
@@ -384,14 +380,14 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input>
         *function_type.return_type
     }
 
-    fn visit_variable(&mut self, ctx: &VariableContext<'input>) -> Self::Return {
+    fn visit_variable(&mut self, ctx: &VariableContext) -> Self::Return {
         // The type of the value depends on the declared type of the
         // variable
         // the parser will have generated an error for us in an
         // earlier stage if we don't have a variable name for
         // this Variable context; here, we'll bail out.
-        let var_id = ctx.get_token(yarnspinnerlexer::VAR_ID, 0)?;
-        let name = var_id.get_text();
+        let var_id = ctx.var_id()?;
+        let name = var_id.text.clone();
         if let Some(declaration) = self.declarations().find(|decl| decl.name == name) {
             return Some(declaration.r#type.clone());
         }
@@ -409,10 +405,12 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input>
         // creating a new diagnostic for us having an undefined variable
         // this won't get added into the existing diags though because its possible a later pass will clear it up
         // so we save this as a potential diagnostic for the compiler itself to resolve
-        let diagnostic =
-            Diagnostic::from_message(format_cannot_determine_variable_type_error(&name))
-                .with_file_name(&self.file.name)
-                .with_parser_context(ctx, self.file.tokens());
+        let diagnostic = Diagnostic::new(
+            DiagnosticCode::UndeclaredVariable,
+            format_cannot_determine_variable_type_error(&name),
+        )
+        .with_file_name(&self.file.name)
+        .with_parser_context(ctx, self.file.tokens());
         self.deferred_types
             .push(DeferredTypeDiagnostic { name, diagnostic });
 
@@ -422,21 +420,21 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input>
         None
     }
 
-    fn visit_if_clause(&mut self, ctx: &If_clauseContext<'input>) -> Self::Return {
-        ParseTreeVisitorCompat::visit_children(self, ctx);
+    fn visit_if_clause(&mut self, ctx: &IfClauseContext) -> Self::Return {
+        self.visit_children(ctx);
         // If clauses are required to be boolean
         let expressions = &[ctx.expression().unwrap().into()];
         self.check_operation(ctx, expressions, None, "if statement", &[Type::Boolean])
     }
 
-    fn visit_else_if_clause(&mut self, ctx: &Else_if_clauseContext<'input>) -> Self::Return {
-        ParseTreeVisitorCompat::visit_children(self, ctx);
+    fn visit_else_if_clause(&mut self, ctx: &ElseIfClauseContext) -> Self::Return {
+        self.visit_children(ctx);
         // Else if clauses are required to be boolean
         let expressions = &[ctx.expression().unwrap().into()];
         self.check_operation(ctx, expressions, None, "elseif statement", &[Type::Boolean])
     }
 
-    fn visit_set_statement(&mut self, ctx: &Set_statementContext<'input>) -> Self::Return {
+    fn visit_set_statement(&mut self, ctx: &SetStatementContext) -> Self::Return {
         let variable_context = ctx.variable()?;
         let expression_context = ctx.expression()?;
         let variable_type = self.visit(variable_context.as_ref());
@@ -455,18 +453,21 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input>
 
         let op = ctx.op.as_ref().unwrap();
         match op.token_type {
-            yarnspinnerlexer::OPERATOR_ASSIGNMENT => {
+            OPERATOR_ASSIGNMENT => {
                 // Straight assignment supports any assignment, as long
                 // as it's consistent; we already know the type of the
                 // expression, so let's check to see if it's assignable
                 // to the type of the variable.
                 match (variable_type.as_ref(), expression_type.as_ref()) {
                     (Some(variable_type), _) if !expression_type.is_sub_type_of(variable_type) => {
-                        let diagnostic = Diagnostic::from_message(format!(
-                            "{variable_name} ({}) cannot be assigned a {}",
-                            variable_type.format(),
-                            expression_type.format(),
-                        ))
+                        let diagnostic = Diagnostic::new(
+                            DiagnosticCode::TypeMismatch,
+                            format!(
+                                "{variable_name} ({}) cannot be assigned a {}",
+                                variable_type.format(),
+                                expression_type.format(),
+                            ),
+                        )
                         .with_file_name(&self.file.name)
                         .with_parser_context(ctx, self.file.tokens());
                         self.diagnostics.push(diagnostic);
@@ -484,7 +485,7 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input>
                                 .with_description(format!(
                                     "Implicitly declared in {}, node {}",
                                     get_filename(&self.file.name),
-                                    self.current_node_name.as_ref().unwrap()
+                                    self.current_node_name.as_deref().unwrap_or_default()
                                 ))
                                 .with_default_value(default_value)
                                 .with_source_file_name(self.file.name.clone())
@@ -494,7 +495,8 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input>
                             self.new_declarations.push(decl);
                         } else {
                             self.diagnostics.push(
-                                Diagnostic::from_message(
+                                Diagnostic::new(
+                                    DiagnosticCode::UndeclaredVariable,
                                     format_cannot_determine_variable_type_error(&variable_name),
                                 )
                                 .with_file_name(&self.file.name)
@@ -507,37 +509,37 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input>
                     }
                 }
             }
-            yarnspinnerlexer::OPERATOR_MATHS_ADDITION_EQUALS => {
+            OPERATOR_MATHS_ADDITION_EQUALS => {
                 // += supports strings and numbers
                 let operator =
-                    CodeGenerationVisitor::token_to_operator(yarnspinnerlexer::OPERATOR_MATHS_ADDITION).unwrap();
-                expression_type = self.check_operation(ctx, terms, operator, op.get_text(), &[]);
+                    CodeGenerationVisitor::token_to_operator(OPERATOR_MATHS_ADDITION).unwrap();
+                expression_type = self.check_operation(ctx, terms, operator, &op.text, &[]);
             }
-            yarnspinnerlexer::OPERATOR_MATHS_SUBTRACTION_EQUALS => {
+            OPERATOR_MATHS_SUBTRACTION_EQUALS => {
                 // -=, *=, /=, %= supports only numbers
                 let operator =
-                    CodeGenerationVisitor::token_to_operator(yarnspinnerlexer::OPERATOR_MATHS_SUBTRACTION).unwrap();
-                expression_type = self.check_operation(ctx, terms, operator, op.get_text(), &[]);
+                    CodeGenerationVisitor::token_to_operator(OPERATOR_MATHS_SUBTRACTION).unwrap();
+                expression_type = self.check_operation(ctx, terms, operator, &op.text, &[]);
             }
-            yarnspinnerlexer::OPERATOR_MATHS_MULTIPLICATION_EQUALS => {
+            OPERATOR_MATHS_MULTIPLICATION_EQUALS => {
                 let operator =
-                    CodeGenerationVisitor::token_to_operator(yarnspinnerlexer::OPERATOR_MATHS_MULTIPLICATION).unwrap();
-                expression_type = self.check_operation(ctx, terms, operator, op.get_text(), &[]);
+                    CodeGenerationVisitor::token_to_operator(OPERATOR_MATHS_MULTIPLICATION).unwrap();
+                expression_type = self.check_operation(ctx, terms, operator, &op.text, &[]);
             }
-            yarnspinnerlexer::OPERATOR_MATHS_DIVISION_EQUALS => {
+            OPERATOR_MATHS_DIVISION_EQUALS => {
                 let operator =
-                    CodeGenerationVisitor::token_to_operator(yarnspinnerlexer::OPERATOR_MATHS_DIVISION).unwrap();
-                expression_type = self.check_operation(ctx, terms, operator, op.get_text(), &[]);
+                    CodeGenerationVisitor::token_to_operator(OPERATOR_MATHS_DIVISION).unwrap();
+                expression_type = self.check_operation(ctx, terms, operator, &op.text, &[]);
             }
-            yarnspinnerlexer::OPERATOR_MATHS_MODULUS_EQUALS => {
-                let operator = CodeGenerationVisitor::token_to_operator(yarnspinnerlexer::OPERATOR_MATHS_MODULUS).unwrap();
-                expression_type = self.check_operation(ctx, terms, operator, op.get_text(), &[]);
+            OPERATOR_MATHS_MODULUS_EQUALS => {
+                let operator = CodeGenerationVisitor::token_to_operator(OPERATOR_MATHS_MODULUS).unwrap();
+                expression_type = self.check_operation(ctx, terms, operator, &op.text, &[]);
             }
-            _ => panic!("Internal error: `visit_set_statement` got unexpected operand {}. This is a bug. Please report it at https://github.com/YarnSpinnerTool/YarnSpinner-Rust/issues/new", op.get_text())
+            _ => panic!("Internal error: `visit_set_statement` got unexpected operand {}. This is a bug. Please report it at https://github.com/YarnSpinnerTool/YarnSpinner-Rust/issues/new", &op.text)
         }
         if variable_type.is_none() && expression_type.is_none() {
             self.diagnostics.push(
-                            Diagnostic::from_message(
+                            Diagnostic::new(DiagnosticCode::IndeterminateType,
                                 format!("Type of expression \"{}\" can't be determined without more context. Please declare one or more terms.", ctx.get_text_with_whitespace(self.file.tokens())))
                                 .with_file_name(&self.file.name)
                                 .with_parser_context(ctx, self.file.tokens()));
@@ -547,7 +549,7 @@ impl<'input> YarnSpinnerParserVisitorCompat<'input> for TypeCheckVisitor<'input>
         expression_type
     }
 
-    fn visit_jumpToExpression(&mut self, ctx: &JumpToExpressionContext<'input>) -> Self::Return {
+    fn visit_jump_to_expression(&mut self, ctx: &JumpStatementContext) -> Self::Return {
         let expressions = &[ctx.expression().unwrap().into()];
         // The expression's type must resolve to a string.
         self.check_operation(ctx, expressions, None, "jump statement", &[Type::String])
@@ -629,6 +631,8 @@ mod tests {
             library: Default::default(),
             compilation_type: CompilationType::FullCompilation,
             variable_declarations: vec![],
+            optimize: false,
+            partial_compilation: false,
         }
         .compile()
         .unwrap();
@@ -654,6 +658,8 @@ mod tests {
             library: Default::default(),
             compilation_type: CompilationType::FullCompilation,
             variable_declarations: vec![],
+            optimize: false,
+            partial_compilation: false,
         }
         .compile();
 
@@ -663,47 +669,56 @@ mod tests {
 
         assert_contains(
             &diagnostics,
-            &Diagnostic::from_message("$foo (Number) cannot be assigned a String")
-                .with_file_name("test.yarn")
-                .with_range(
-                    Position {
-                        line: 3,
-                        character: 0,
-                    }..Position {
-                        line: 3,
-                        character: 25,
-                    },
-                ),
+            &Diagnostic::new(
+                DiagnosticCode::TypeMismatch,
+                "$foo (Number) cannot be assigned a String",
+            )
+            .with_file_name("test.yarn")
+            .with_range(
+                Position {
+                    line: 3,
+                    character: 0,
+                }..Position {
+                    line: 3,
+                    character: 25,
+                },
+            ),
         );
 
         assert_contains(
             &diagnostics,
-            &Diagnostic::from_message("$bar (Bool) cannot be assigned a Number")
-                .with_file_name("test.yarn")
-                .with_range(
-                    Position {
-                        line: 6,
-                        character: 0,
-                    }..Position {
-                        line: 6,
-                        character: 19,
-                    },
-                ),
+            &Diagnostic::new(
+                DiagnosticCode::TypeMismatch,
+                "$bar (Bool) cannot be assigned a Number",
+            )
+            .with_file_name("test.yarn")
+            .with_range(
+                Position {
+                    line: 6,
+                    character: 0,
+                }..Position {
+                    line: 6,
+                    character: 19,
+                },
+            ),
         );
 
         assert_contains(
             &diagnostics,
-            &Diagnostic::from_message("$baz (String) cannot be assigned a Bool")
-                .with_file_name("test.yarn")
-                .with_range(
-                    Position {
-                        line: 7,
-                        character: 0,
-                    }..Position {
-                        line: 7,
-                        character: 21,
-                    },
-                ),
+            &Diagnostic::new(
+                DiagnosticCode::TypeMismatch,
+                "$baz (String) cannot be assigned a Bool",
+            )
+            .with_file_name("test.yarn")
+            .with_range(
+                Position {
+                    line: 7,
+                    character: 0,
+                }..Position {
+                    line: 7,
+                    character: 21,
+                },
+            ),
         );
     }
 
@@ -741,6 +756,8 @@ mod tests {
             library: Default::default(),
             compilation_type: CompilationType::FullCompilation,
             variable_declarations: vec![],
+            optimize: false,
+            partial_compilation: false,
         }
         .compile()
         .unwrap();
@@ -764,6 +781,8 @@ mod tests {
             library: Default::default(),
             compilation_type: CompilationType::FullCompilation,
             variable_declarations: vec![],
+            optimize: false,
+            partial_compilation: false,
         }
         .compile();
 
@@ -773,62 +792,74 @@ mod tests {
 
         assert_contains(
             &diagnostics,
-            &Diagnostic::from_message("$foo (Number) cannot be assigned a undefined")
-                .with_file_name("test.yarn")
-                .with_range(
-                    Position {
-                        line: 4,
-                        character: 0,
-                    }..Position {
-                        line: 4,
-                        character: 27,
-                    },
-                ),
+            &Diagnostic::new(
+                DiagnosticCode::TypeMismatch,
+                "$foo (Number) cannot be assigned a undefined",
+            )
+            .with_file_name("test.yarn")
+            .with_range(
+                Position {
+                    line: 4,
+                    character: 0,
+                }..Position {
+                    line: 4,
+                    character: 27,
+                },
+            ),
         );
 
         assert_contains(
             &diagnostics,
-            &Diagnostic::from_message("$foo (Number) cannot be assigned a undefined")
-                .with_file_name("test.yarn")
-                .with_range(
-                    Position {
-                        line: 5,
-                        character: 0,
-                    }..Position {
-                        line: 5,
-                        character: 32,
-                    },
-                ),
+            &Diagnostic::new(
+                DiagnosticCode::TypeMismatch,
+                "$foo (Number) cannot be assigned a undefined",
+            )
+            .with_file_name("test.yarn")
+            .with_range(
+                Position {
+                    line: 5,
+                    character: 0,
+                }..Position {
+                    line: 5,
+                    character: 32,
+                },
+            ),
         );
 
         assert_contains(
             &diagnostics,
-            &Diagnostic::from_message("All terms of + must be the same, not Number, String")
-                .with_file_name("test.yarn")
-                .with_range(
-                    Position {
-                        line: 4,
-                        character: 14,
-                    }..Position {
-                        line: 4,
-                        character: 25,
-                    },
-                ),
+            &Diagnostic::new(
+                DiagnosticCode::TypeMismatch,
+                "All terms of + must be the same, not Number, String",
+            )
+            .with_file_name("test.yarn")
+            .with_range(
+                Position {
+                    line: 4,
+                    character: 14,
+                }..Position {
+                    line: 4,
+                    character: 25,
+                },
+            ),
         );
 
         assert_contains(
             &diagnostics,
-            &Diagnostic::from_message("All terms of * must be the same, not Number, String")
-                .with_file_name("test.yarn")
-                .with_range(
-                    Position {
-                        line: 5,
-                        character: 14,
-                    }..Position {
-                        line: 5,
-                        character: 30,
-                    },
-                ),
+            &Diagnostic::new(
+                DiagnosticCode::TypeMismatch,
+                "All terms of * must be the same, not Number, String",
+            )
+            .with_file_name("test.yarn")
+            .with_range(
+                Position {
+                    line: 5,
+                    character: 14,
+                }..Position {
+                    line: 5,
+                    character: 30,
+                },
+            ),
         );
     }
 }
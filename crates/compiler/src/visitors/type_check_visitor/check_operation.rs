@@ -1,22 +1,15 @@
 use crate::parser_rule_context_ext::ParserRuleContextExt;
-use crate::prelude::generated::yarnspinnerparser::*;
 use crate::prelude::*;
 use crate::visitors::type_check_visitor::{
     format_cannot_determine_variable_type_error, get_filename, DefaultValue,
 };
 use crate::visitors::*;
-use antlr_rust::rule_context::CustomRuleContext;
-use antlr_rust::token::Token;
-use antlr_rust::token_factory::TokenFactory;
-use antlr_rust::tree::{ParseTree, ParseTreeVisitorCompat};
-use better_any::TidExt;
 use std::cmp::Ordering;
-use std::ops::Deref;
 use std::rc::Rc;
 use yarnspinner_core::prelude::Operator;
 use yarnspinner_core::types::{SubTypeOf, Type, TypeFormat};
 
-impl<'input> TypeCheckVisitor<'input> {
+impl TypeCheckVisitor {
     /// ok so what do we actually need to do in here?
     /// we need to do a few different things
     /// basically we need to go through the various types in the expression
@@ -24,23 +17,20 @@ impl<'input> TypeCheckVisitor<'input> {
     /// then at the end if there are still unknowns we check if the operation itself forces a type
     /// so if we have say Undefined = Undefined + Number then we know that only one operation supports + Number and that is Number + Number
     /// so we can slot the type into the various parts
-    pub(super) fn check_operation<T>(
+    pub(super) fn check_operation(
         &mut self,
-        context: &T,
-        terms: &[Term<'input>],
+        context: &(impl ParserRuleContext + ?Sized),
+        terms: &[Term],
         operation_type: impl Into<Option<Operator>>,
         operation_description: &str,
         permitted_types: &[Type],
-    ) -> Option<Type>
-    where
-        T: ParserRuleContextExt<'input>,
-    <<<<T as CustomRuleContext<'input>>::TF as TokenFactory<'input>>::Inner as Token>::Data as ToOwned>::Owned: Into<String>{
+    ) -> Option<Type> {
         let operation_type = operation_type.into();
         let mut term_types = Vec::new();
         let mut expression_type = None;
         for expression in terms {
             // Visit this expression, and determine its type.
-            let r#type = self.visit(expression.deref());
+            let r#type = self.visit(expression);
             if let Some(r#type) = r#type.clone() {
                 if expression_type.is_none() {
                     // This is the first concrete type we've seen. This
@@ -93,9 +83,10 @@ impl<'input> TypeCheckVisitor<'input> {
                         "Type of expression \"{}\" can't be determined without more context (the compiler thinks it could be {type_names}). Use a type cast on at least one of the terms (e.g. the string(), number(), bool() functions)",
                         context.get_text_with_whitespace(self.file.tokens()),
                     );
-                        let diagnostic = Diagnostic::from_message(message)
-                            .with_file_name(&self.file.name)
-                            .with_parser_context(context, self.file.tokens());
+                        let diagnostic =
+                            Diagnostic::new(DiagnosticCode::IndeterminateType, message)
+                                .with_file_name(&self.file.name)
+                                .with_parser_context(context, self.file.tokens());
                         self.diagnostics.push(diagnostic);
                         return None;
                     }
@@ -105,9 +96,10 @@ impl<'input> TypeCheckVisitor<'input> {
                         "Type of expression \"{}\" can't be determined without more context. Use a type cast on at least one of the terms (e.g. the string(), number(), bool() functions)",
                         context.get_text_with_whitespace(self.file.tokens()),
                     );
-                        let diagnostic = Diagnostic::from_message(message)
-                            .with_file_name(&self.file.name)
-                            .with_parser_context(context, self.file.tokens());
+                        let diagnostic =
+                            Diagnostic::new(DiagnosticCode::IndeterminateType, message)
+                                .with_file_name(&self.file.name)
+                                .with_parser_context(context, self.file.tokens());
                         self.diagnostics.push(diagnostic);
                         return None;
                     }
@@ -125,22 +117,23 @@ impl<'input> TypeCheckVisitor<'input> {
             let Term::Expression(expression) = term else {
                 continue;
             };
-            let ExpressionContextAll::ExpValueContext(value_context) = expression.as_ref() else {
+            if expression.kind != ExpressionKind::Value {
                 continue;
-            };
-            let Some(value) = value_context.value() else {
+            }
+            let Some(value) = expression.value() else {
                 continue;
             };
-            let ValueContextAll::ValueFuncContext(func_context) = value.as_ref() else {
+            if value.kind != ValueKind::Func {
                 continue;
-            };
+            }
 
-            let id = func_context
+            let id = value
                 .function_call()
                 .unwrap()
-                .FUNC_ID()
+                .func_id()
                 .unwrap()
-                .get_text();
+                .text
+                .clone();
 
             let function_type = self
                 .new_declarations
@@ -159,7 +152,7 @@ impl<'input> TypeCheckVisitor<'input> {
                 }
                 func.return_type = Box::new(expression_type.clone());
             } else {
-                self.visit(term.deref());
+                self.visit(term);
             }
         }
         // Were any of the terms variables for which we don't currently
@@ -167,7 +160,7 @@ impl<'input> TypeCheckVisitor<'input> {
 
         // Start by building a list of all terms that are variables.
         // These are either variable values, or variable names . (The
-        // difference between these two is that a ValueVarContext
+        // difference between these two is that a ValueContext
         // occurs in syntax where the value of the variable is used
         // (like an expression), while a VariableContext occurs in
         // syntax where it's just a variable name (like a set
@@ -178,37 +171,19 @@ impl<'input> TypeCheckVisitor<'input> {
         let variable_contexts = terms
             .iter()
             .filter_map(|term| {
-                term.child_of_type_unsized::<ValueContextAll>(0)
-                    .and_then(|value_context| {
-                        if let ValueContextAll::ValueVarContext(context) = value_context.as_ref() {
-                            context.variable()
-                        } else {
-                            None
-                        }
-                    })
+                term.child::<ValueContext>()
+                    .filter(|value_context| value_context.kind == ValueKind::Var)
+                    .and_then(|value_context| value_context.variable())
             })
             .chain(
                 terms
                     .iter()
-                    .find_map(|term| term.child_of_type_unsized::<VariableContext>(0)),
+                    .find_map(|term| term.child::<VariableContext>()),
             )
-            .chain(
-                terms.iter().filter_map(|term| {
-                    term.generic_context().downcast_rc::<VariableContext>().ok()
-                }),
-            )
-            .chain(
-                terms
-                    .iter()
-                    .filter_map(|term| term.generic_context().downcast_rc::<ValueContextAll>().ok())
-                    .filter_map(|value_context| {
-                        if let ValueContextAll::ValueVarContext(context) = value_context.as_ref() {
-                            context.variable()
-                        } else {
-                            None
-                        }
-                    }),
-            );
+            .chain(terms.iter().filter_map(|term| match term {
+                Term::Variable(ctx) => Some(ctx.clone()),
+                Term::Expression(_) => None,
+            }));
 
         // Build the list of variable contexts that we don't have a
         // declaration for. We'll check for explicit declarations first.
@@ -216,7 +191,7 @@ impl<'input> TypeCheckVisitor<'input> {
             .filter(|v| {
                 !self
                     .declarations()
-                    .any(|d| d.name == v.VAR_ID().unwrap().get_text())
+                    .any(|d| d.name == v.var_id().unwrap().text)
             })
             .collect();
         // Implementation note: The original compares by reference here. The interval should be unique for each context, so let's use that instead.
@@ -228,7 +203,7 @@ impl<'input> TypeCheckVisitor<'input> {
             // explicit declaration for! Time to create implicit
             // references for them!
 
-            let var_name = undefined_variable_context.VAR_ID().unwrap().get_text();
+            let var_name = undefined_variable_context.var_id().unwrap().text.clone();
             // We can only create an implicit declaration for a variable
             // if we have a default value for it, because all variables
             // are required to have a value. If we can't, it's generally
@@ -253,7 +228,8 @@ impl<'input> TypeCheckVisitor<'input> {
             } else {
                 // If we can't produce this, then we can't generate the
                 // declaration.
-                let diagnostic = Diagnostic::from_message(
+                let diagnostic = Diagnostic::new(
+                    DiagnosticCode::UndeclaredVariable,
                     format_cannot_determine_variable_type_error(&var_name),
                 )
                 .with_file_name(&self.file.name)
@@ -279,7 +255,7 @@ impl<'input> TypeCheckVisitor<'input> {
                 .join(", ");
             let message =
                 format!("All terms of {operation_description} must be the same, not {type_list}");
-            let diagnostic = Diagnostic::from_message(message)
+            let diagnostic = Diagnostic::new(DiagnosticCode::TypeMismatch, message)
                 .with_file_name(&self.file.name)
                 .with_parser_context(context, self.file.tokens());
             self.diagnostics.push(diagnostic);
@@ -314,7 +290,7 @@ impl<'input> TypeCheckVisitor<'input> {
                     "{} has no implementation defined for {operation_description}",
                     expression_type.format(),
                 );
-                let diagnostic = Diagnostic::from_message(message)
+                let diagnostic = Diagnostic::new(DiagnosticCode::UnsupportedOperator, message)
                     .with_file_name(&self.file.name)
                     .with_parser_context(context, self.file.tokens());
                 self.diagnostics.push(diagnostic);
@@ -348,7 +324,7 @@ impl<'input> TypeCheckVisitor<'input> {
             let message = format!(
                 "Terms of '{operation_description}' must be {permitted_types_list}, not {type_list}",
             );
-            let diagnostic = Diagnostic::from_message(message)
+            let diagnostic = Diagnostic::new(DiagnosticCode::TypeMismatch, message)
                 .with_file_name(&self.file.name)
                 .with_parser_context(context, self.file.tokens());
             self.diagnostics.push(diagnostic);
@@ -374,7 +350,7 @@ impl<'input> TypeCheckVisitor<'input> {
                 expression_type.format()
             );
             self.diagnostics.push(
-                Diagnostic::from_message(message)
+                Diagnostic::new(DiagnosticCode::UnsupportedOperator, message)
                     .with_file_name(&self.file.name)
                     .with_parser_context(context, self.file.tokens()),
             );
@@ -385,39 +361,42 @@ impl<'input> TypeCheckVisitor<'input> {
 }
 
 /// Bandaid enum to allow static type checks that work via dynamic dispatch on C#
-pub(super) enum Term<'input> {
-    Expression(Rc<ExpressionContextAll<'input>>),
-    Variable(Rc<VariableContextAll<'input>>),
+pub(super) enum Term {
+    Expression(Rc<ExpressionContext>),
+    Variable(Rc<VariableContext>),
 }
 
-impl<'input> Term<'input> {
-    pub(super) fn generic_context(&self) -> Rc<ActualParserContext<'input>> {
+impl Term {
+    fn context(&self) -> &dyn ParserRuleContext {
         match self {
-            Term::Expression(ctx) => ctx.clone() as Rc<ActualParserContext<'input>>,
-            Term::Variable(ctx) => ctx.clone(),
+            Term::Expression(ctx) => ctx.as_ref(),
+            Term::Variable(ctx) => ctx.as_ref(),
         }
     }
-}
 
-impl<'input> Deref for Term<'input> {
-    type Target = ActualParserContext<'input>;
+    /// The first child context of type `T`.
+    fn child<T: ChildContext>(&self) -> Option<Rc<T>> {
+        self.context().base().child()
+    }
+}
 
-    fn deref(&self) -> &Self::Target {
+impl Visitable for Term {
+    fn accept<V: YarnSpinnerParserVisitor + ?Sized>(&self, visitor: &mut V) -> V::Return {
         match self {
-            Term::Expression(ctx) => ctx.as_ref() as &ActualParserContext<'input>,
-            Term::Variable(ctx) => ctx.as_ref(),
+            Term::Expression(ctx) => ctx.accept(visitor),
+            Term::Variable(ctx) => ctx.accept(visitor),
         }
     }
 }
 
-impl<'input> From<Rc<ExpressionContextAll<'input>>> for Term<'input> {
-    fn from(ctx: Rc<ExpressionContextAll<'input>>) -> Self {
+impl From<Rc<ExpressionContext>> for Term {
+    fn from(ctx: Rc<ExpressionContext>) -> Self {
         Self::Expression(ctx)
     }
 }
 
-impl<'input> From<Rc<VariableContextAll<'input>>> for Term<'input> {
-    fn from(ctx: Rc<VariableContextAll<'input>>) -> Self {
+impl From<Rc<VariableContext>> for Term {
+    fn from(ctx: Rc<VariableContext>) -> Self {
         Self::Variable(ctx)
     }
 }
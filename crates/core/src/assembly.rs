@@ -0,0 +1,588 @@
+//! A human-readable listing format for [`Program`]s, which can be written with [`Program::disassemble`] and read back with [`Program::assemble`].
+//!
+//! ## Format
+//!
+//! ```text
+//! ; Comments start with a semicolon and run until the end of the line.
+//! program "Demo"
+//! initial "$gold" 5
+//!
+//! node "Start"
+//!     header "title" "Start"
+//!     tag "intro"
+//!     0  PUSH_VARIABLE "$gold"
+//!     1  PUSH_FLOAT 2
+//!     2  PUSH_FLOAT 2
+//!     3  CALL_FUNC "Number.GreaterThan"
+//!     4  JUMP_IF_FALSE "skip"
+//!     5  RUN_LINE "line:rich" 0
+//! skip:
+//!     6  STOP
+//! ```
+//!
+//! - `program` sets the name of the program and is omitted when it is empty.
+//! - `initial` adds an initial value for a variable.
+//! - `node` starts a new node. All following lines up to the next `node` belong to it.
+//! - `header`, `tag` and `source_text` set the corresponding fields of the node.
+//! - A line consisting of a name followed by a colon defines a label pointing to the next instruction.
+//! - Every other line inside a node is an instruction: an optional index, the name of the [`OpCode`] and its operands.
+//!   The index is only there for readability, but must be correct if it is given.
+//!   Operands are either quoted strings, numbers, `true` or `false`, or `_` for an operand without a value.
+//!   Instructions with an opcode that is not part of [`OpCode`] are written as `#` followed by the number of the opcode.
+//!
+//! Names and strings are quoted with `"` and may use the escapes `\"`, `\\`, `\n`, `\r` and `\t`.
+//! Labels are only left unquoted when they consist of letters, digits, `_`, `-` and `.`.
+
+use crate::prelude::*;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter, Write};
+
+impl Program {
+    /// Writes the program in the textual listing format described in the [module documentation](self).
+    /// Nodes are written in alphabetical order.
+    #[must_use]
+    pub fn disassemble(&self) -> String {
+        self.disassemble_with_comments(|_node, _instruction_index| None)
+    }
+
+    /// Like [`Program::disassemble`], but appends the comment returned by `comment` to each instruction, if any.
+    /// This is intended for interleaving information like source positions with the listing.
+    #[must_use]
+    pub fn disassemble_with_comments(
+        &self,
+        comment: impl Fn(&Node, usize) -> Option<String>,
+    ) -> String {
+        let mut output = String::new();
+        if !self.name.is_empty() {
+            writeln!(output, "program {}", quote(&self.name)).unwrap();
+        }
+        let mut initial_values: Vec<_> = self.initial_values.iter().collect();
+        initial_values.sort_by_key(|(name, _)| *name);
+        for (name, value) in initial_values {
+            writeln!(output, "initial {} {}", quote(name), format_operand(value)).unwrap();
+        }
+
+        let mut nodes: Vec<_> = self.nodes.values().collect();
+        nodes.sort_by(|a, b| a.name.cmp(&b.name));
+        for node in nodes {
+            if !output.is_empty() {
+                output.push('\n');
+            }
+            disassemble_node(&mut output, node, &comment);
+        }
+        output
+    }
+
+    /// Reads a program written in the textual listing format described in the [module documentation](self).
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`AssemblyError`] pointing to the first line that could not be read.
+    pub fn assemble(source: &str) -> Result<Self, AssemblyError> {
+        let mut program = Program::default();
+        let mut current_node: Option<Node> = None;
+
+        for (line_index, line) in source.lines().enumerate() {
+            let error = |message: String| AssemblyError {
+                line: line_index + 1,
+                message,
+            };
+            let tokens = tokenize(line).map_err(error)?;
+            let Some(first) = tokens.first() else {
+                continue;
+            };
+
+            match (first, &tokens[1..]) {
+                (AssemblyToken::Bare(directive), [AssemblyToken::Quoted(name)])
+                    if directive == "program" =>
+                {
+                    program.name = name.clone();
+                }
+                (AssemblyToken::Bare(directive), [AssemblyToken::Quoted(name), value])
+                    if directive == "initial" =>
+                {
+                    let value = parse_operand(value).map_err(error)?;
+                    program.initial_values.insert(name.clone(), value);
+                }
+                (AssemblyToken::Bare(directive), [AssemblyToken::Quoted(name)])
+                    if directive == "node" =>
+                {
+                    if let Some(node) = current_node.take() {
+                        add_node(&mut program, node).map_err(error)?;
+                    }
+                    current_node = Some(Node {
+                        name: name.clone(),
+                        ..Default::default()
+                    });
+                }
+                _ => {
+                    let node = current_node.as_mut().ok_or_else(|| {
+                        error("Expected a `program`, `initial` or `node` directive".to_owned())
+                    })?;
+                    assemble_node_line(node, first, &tokens[1..]).map_err(error)?;
+                }
+            }
+        }
+        if let Some(node) = current_node {
+            add_node(&mut program, node).map_err(|message| AssemblyError {
+                line: source.lines().count(),
+                message,
+            })?;
+        }
+        Ok(program)
+    }
+}
+
+/// An error returned by [`Program::assemble`] when the source is not in the expected format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssemblyError {
+    /// The 1-indexed line the error occurred on.
+    pub line: usize,
+    /// A description of what went wrong.
+    pub message: String,
+}
+
+impl Error for AssemblyError {}
+
+impl Display for AssemblyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Line {}: {}", self.line, self.message)
+    }
+}
+
+fn disassemble_node(
+    output: &mut String,
+    node: &Node,
+    comment: &impl Fn(&Node, usize) -> Option<String>,
+) {
+    writeln!(output, "node {}", quote(&node.name)).unwrap();
+    for header in &node.headers {
+        writeln!(
+            output,
+            "    header {} {}",
+            quote(&header.key),
+            quote(&header.value)
+        )
+        .unwrap();
+    }
+    for tag in &node.tags {
+        writeln!(output, "    tag {}", quote(tag)).unwrap();
+    }
+    if !node.source_text_string_id.is_empty() {
+        writeln!(
+            output,
+            "    source_text {}",
+            quote(&node.source_text_string_id)
+        )
+        .unwrap();
+    }
+
+    let mut labels: Vec<_> = node.labels.iter().collect();
+    labels.sort_by(|(a_name, a_index), (b_name, b_index)| {
+        a_index.cmp(b_index).then_with(|| a_name.cmp(b_name))
+    });
+    let mut labels = labels.into_iter().peekable();
+
+    let index_width = node.instructions.len().saturating_sub(1).to_string().len();
+    let instructions: Vec<_> = node
+        .instructions
+        .iter()
+        .enumerate()
+        .map(|(index, instruction)| {
+            let mut text = format!("{index:>index_width$}  {}", format_opcode(instruction));
+            for operand in &instruction.operands {
+                text.push(' ');
+                text.push_str(&format_operand(operand));
+            }
+            text
+        })
+        .collect();
+    let comment_column = instructions.iter().map(String::len).max().unwrap_or(0) + 2;
+
+    for (index, text) in instructions.into_iter().enumerate() {
+        while let Some((label, _)) = labels.next_if(|(_, position)| **position <= index as i32) {
+            writeln!(output, "{}:", quote_label(label)).unwrap();
+        }
+        match comment(node, index) {
+            Some(comment) => {
+                writeln!(output, "    {text:<comment_column$}; {comment}").unwrap();
+            }
+            None => writeln!(output, "    {text}").unwrap(),
+        }
+    }
+    // Labels pointing past the last instruction
+    for (label, _) in labels {
+        writeln!(output, "{}:", quote_label(label)).unwrap();
+    }
+}
+
+fn assemble_node_line(
+    node: &mut Node,
+    first: &AssemblyToken,
+    rest: &[AssemblyToken],
+) -> Result<(), String> {
+    let label = match (first, rest) {
+        (AssemblyToken::Bare(text), []) if text.len() > 1 && text.ends_with(':') => {
+            Some(text[..text.len() - 1].to_owned())
+        }
+        (AssemblyToken::Quoted(text), [AssemblyToken::Bare(colon)]) if colon == ":" => {
+            Some(text.clone())
+        }
+        _ => None,
+    };
+    if let Some(label) = label {
+        let position = node.instructions.len() as i32;
+        if node.labels.insert(label.clone(), position).is_some() {
+            return Err(format!("The label \"{label}\" is defined more than once"));
+        }
+        return Ok(());
+    }
+
+    let AssemblyToken::Bare(keyword) = first else {
+        return Err("Expected a directive, label or instruction".to_owned());
+    };
+    match (keyword.as_str(), rest) {
+        ("header", [AssemblyToken::Quoted(key), AssemblyToken::Quoted(value)]) => {
+            node.headers.push(Header {
+                key: key.clone(),
+                value: value.clone(),
+            });
+            return Ok(());
+        }
+        ("tag", [AssemblyToken::Quoted(tag)]) => {
+            node.tags.push(tag.clone());
+            return Ok(());
+        }
+        ("source_text", [AssemblyToken::Quoted(string_id)]) => {
+            node.source_text_string_id = string_id.clone();
+            return Ok(());
+        }
+        _ => {}
+    }
+
+    let (opcode_name, operands) = match keyword.parse::<usize>() {
+        Ok(index) => {
+            if index != node.instructions.len() {
+                return Err(format!(
+                    "Expected instruction {}, but found index {index}",
+                    node.instructions.len()
+                ));
+            }
+            match rest.split_first() {
+                Some((AssemblyToken::Bare(opcode_name), operands)) => (opcode_name, operands),
+                _ => return Err("Expected an opcode after the instruction index".to_owned()),
+            }
+        }
+        Err(_) => (keyword, rest),
+    };
+    let opcode = parse_opcode(opcode_name)?;
+    let operands = operands
+        .iter()
+        .map(parse_operand)
+        .collect::<Result<_, _>>()?;
+    node.instructions.push(Instruction { opcode, operands });
+    Ok(())
+}
+
+fn add_node(program: &mut Program, node: Node) -> Result<(), String> {
+    if program.nodes.contains_key(&node.name) {
+        return Err(format!(
+            "The node \"{}\" is defined more than once",
+            node.name
+        ));
+    }
+    program.nodes.insert(node.name.clone(), node);
+    Ok(())
+}
+
+fn format_opcode(instruction: &Instruction) -> String {
+    match OpCode::try_from(instruction.opcode) {
+        Ok(opcode) => opcode.as_str_name().to_owned(),
+        Err(_) => format!("#{}", instruction.opcode),
+    }
+}
+
+fn parse_opcode(name: &str) -> Result<i32, String> {
+    if let Some(number) = name.strip_prefix('#') {
+        return number
+            .parse()
+            .map_err(|_| format!("\"{name}\" is not a valid opcode number"));
+    }
+    OpCode::from_str_name(name)
+        .map(Into::into)
+        .ok_or_else(|| format!("Unknown opcode \"{name}\""))
+}
+
+fn format_operand(operand: &Operand) -> String {
+    match &operand.value {
+        Some(OperandValue::StringValue(string)) => quote(string),
+        Some(OperandValue::FloatValue(float)) => float.to_string(),
+        Some(OperandValue::BoolValue(boolean)) => boolean.to_string(),
+        None => "_".to_owned(),
+    }
+}
+
+fn parse_operand(token: &AssemblyToken) -> Result<Operand, String> {
+    match token {
+        AssemblyToken::Quoted(string) => Ok(string.clone().into()),
+        AssemblyToken::Bare(text) => match text.as_str() {
+            "true" => Ok(true.into()),
+            "false" => Ok(false.into()),
+            "_" => Ok(Operand { value: None }),
+            _ => text
+                .parse::<f32>()
+                .map(Into::into)
+                .map_err(|_| format!("\"{text}\" is not a valid operand")),
+        },
+    }
+}
+
+fn quote_label(label: &str) -> String {
+    let is_bare = !label.is_empty()
+        && label
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'));
+    if is_bare {
+        label.to_owned()
+    } else {
+        quote(label)
+    }
+}
+
+fn quote(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum AssemblyToken {
+    Bare(String),
+    Quoted(String),
+}
+
+fn tokenize(line: &str) -> Result<Vec<AssemblyToken>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = line.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            ';' => break,
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '"' => {
+                chars.next();
+                let mut text = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some('"') => text.push('"'),
+                            Some('\\') => text.push('\\'),
+                            Some('n') => text.push('\n'),
+                            Some('r') => text.push('\r'),
+                            Some('t') => text.push('\t'),
+                            Some(other) => {
+                                return Err(format!("Unknown escape sequence \"\\{other}\""))
+                            }
+                            None => return Err("Unterminated string".to_owned()),
+                        },
+                        Some(c) => text.push(c),
+                        None => return Err("Unterminated string".to_owned()),
+                    }
+                }
+                tokens.push(AssemblyToken::Quoted(text));
+            }
+            _ => {
+                let mut text = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || c == ';' || c == '"' {
+                        break;
+                    }
+                    text.push(c);
+                    chars.next();
+                }
+                tokens.push(AssemblyToken::Bare(text));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_program() -> Program {
+        let mut start = Node {
+            name: "Start".to_owned(),
+            headers: vec![Header {
+                key: "title".to_owned(),
+                value: "Start".to_owned(),
+            }],
+            tags: vec!["intro".to_owned()],
+            ..Default::default()
+        };
+        for (opcode, operands) in [
+            (OpCode::PushVariable, vec!["$gold".to_owned().into()]),
+            (OpCode::PushFloat, vec![2.0.into()]),
+            (OpCode::PushFloat, vec![2.0.into()]),
+            (
+                OpCode::CallFunc,
+                vec!["Number.GreaterThan".to_owned().into()],
+            ),
+            (OpCode::JumpIfFalse, vec!["skip".to_owned().into()]),
+            (
+                OpCode::RunCommand,
+                vec!["say \"hi\"\tnow".to_owned().into(), 0.5.into()],
+            ),
+        ] {
+            start.instructions.push(Instruction {
+                opcode: opcode.into(),
+                operands,
+            });
+        }
+        start.instructions.push(Instruction {
+            opcode: 99,
+            operands: vec![true.into(), Operand { value: None }],
+        });
+        start.labels.insert("skip".to_owned(), 6);
+        start.labels.insert("end of node".to_owned(), 7);
+
+        let other = Node {
+            name: "Other".to_owned(),
+            source_text_string_id: "line:Other".to_owned(),
+            ..Default::default()
+        };
+
+        Program {
+            name: "Demo".to_owned(),
+            nodes: [(start.name.clone(), start), (other.name.clone(), other)]
+                .into_iter()
+                .collect(),
+            initial_values: [("$gold".to_owned(), 5.0.into())].into_iter().collect(),
+        }
+    }
+
+    #[test]
+    fn disassembles_program() {
+        let listing = example_program().disassemble_with_comments(|node, index| {
+            (node.name == "Start" && index == 0).then(|| "Start.yarn:3:1".to_owned())
+        });
+
+        let expected = r#"program "Demo"
+initial "$gold" 5
+
+node "Other"
+    source_text "line:Other"
+
+node "Start"
+    header "title" "Start"
+    tag "intro"
+    0  PUSH_VARIABLE "$gold"              ; Start.yarn:3:1
+    1  PUSH_FLOAT 2
+    2  PUSH_FLOAT 2
+    3  CALL_FUNC "Number.GreaterThan"
+    4  JUMP_IF_FALSE "skip"
+    5  RUN_COMMAND "say \"hi\"\tnow" 0.5
+skip:
+    6  #99 true _
+"end of node":
+"#;
+        assert_eq!(expected, listing);
+    }
+
+    #[test]
+    fn assembles_disassembled_program() {
+        let program = example_program();
+
+        let assembled = Program::assemble(&program.disassemble()).unwrap();
+
+        assert_eq!(program, assembled);
+    }
+
+    #[test]
+    fn assembles_hand_written_program() {
+        let program = Program::assemble(
+            r#"
+            ; Indices are optional
+            node "Start"
+                PUSH_STRING "Other" ; comments are ignored
+                RUN_NODE
+            node "Other"
+            "label with spaces" :
+                STOP
+            "#,
+        )
+        .unwrap();
+
+        let start = &program.nodes["Start"];
+        assert_eq!(2, start.instructions.len());
+        assert_eq!(i32::from(OpCode::RunNode), start.instructions[1].opcode);
+        assert_eq!(
+            Some(&0),
+            program.nodes["Other"].labels.get("label with spaces")
+        );
+    }
+
+    #[test]
+    fn reports_errors_with_line() {
+        for (source, line, message) in [
+            (
+                "PUSH_FLOAT 1",
+                1,
+                "Expected a `program`, `initial` or `node` directive",
+            ),
+            (
+                "node \"Start\"\n  1  STOP",
+                2,
+                "Expected instruction 0, but found index 1",
+            ),
+            (
+                "node \"Start\"\n  JUMP_SOMEWHERE",
+                2,
+                "Unknown opcode \"JUMP_SOMEWHERE\"",
+            ),
+            (
+                "node \"Start\"\n  PUSH_FLOAT one",
+                2,
+                "\"one\" is not a valid operand",
+            ),
+            (
+                "node \"Start\"\n  PUSH_STRING \"open",
+                2,
+                "Unterminated string",
+            ),
+            (
+                "node \"Start\"\na:\na:",
+                3,
+                "The label \"a\" is defined more than once",
+            ),
+            (
+                "node \"A\"\nnode \"A\"\n  STOP",
+                3,
+                "The node \"A\" is defined more than once",
+            ),
+        ] {
+            assert_eq!(
+                AssemblyError {
+                    line,
+                    message: message.to_owned()
+                },
+                Program::assemble(source).unwrap_err(),
+                "{source}"
+            );
+        }
+    }
+}
@@ -6,6 +6,7 @@
 //! - If you wish to write an adapter crate for an engine yourself, use the [`yarnspinner`](https://crates.io/crates/yarnspinner) crate.
 
 #![warn(missing_docs, missing_debug_implementations)]
+mod assembly;
 mod feature_gates;
 mod generated;
 mod internal_value;
@@ -13,8 +14,11 @@ mod library;
 mod line_id;
 mod operator;
 mod position;
+mod program_file;
 pub mod types;
+mod upstream;
 mod yarn_fn;
+mod yarn_rng;
 mod yarn_value;
 
 pub mod prelude {
@@ -23,6 +27,7 @@ pub mod prelude {
     pub use crate::feature_gates::*;
 
     pub use crate::{
+        assembly::*,
         generated::{
             instruction::OpCode, operand::Value as OperandValue, Header, Instruction,
             InvalidOpCodeError, Node, Operand, Program,
@@ -32,8 +37,11 @@ pub mod prelude {
         line_id::*,
         operator::*,
         position::*,
+        program_file::*,
         types::Type,
+        upstream::*,
         yarn_fn::*,
+        yarn_rng::*,
         yarn_value::*,
     };
 }
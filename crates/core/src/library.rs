@@ -3,7 +3,8 @@
 use crate::prelude::*;
 use std::borrow::Cow;
 use std::collections::hash_map;
-use std::fmt::Display;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
 
 /// A collection of functions that can be called from Yarn scripts.
 ///
@@ -11,6 +12,8 @@ use std::fmt::Display;
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct Library(YarnFnRegistry);
 
+/// Overwrites existing functions with the same name.
+/// Use [`Library::try_extend`] or [`Library::import`] to report such collisions instead.
 impl Extend<<YarnFnRegistry as IntoIterator>::Item> for Library {
     fn extend<T: IntoIterator<Item = (Cow<'static, str>, Box<dyn UntypedYarnFn>)>>(
         &mut self,
@@ -37,13 +40,84 @@ impl Library {
 
     /// Loads functions from another [`Library`].
     ///
-    /// Will overwrite any functions that have the same name.
+    /// Returns a [`LibraryError::FunctionsAlreadyExist`] listing all clashing names if any of the functions
+    /// in `other` have the same name as one in this library. In that case, no function is imported.
+    /// Use [`Extend::extend`] if you want to overwrite existing functions instead.
     ///
-    /// ## Implementation Notes
+    /// ## Examples
+    /// ```
+    /// # use yarnspinner_core::prelude::*;
+    /// let mut library = Library::new();
+    /// library.add_function("count", || 1);
     ///
-    /// The original implementation throws an exception if a function with the same name already exists.
-    pub fn import(&mut self, other: Self) {
-        self.0.extend(other.0 .0);
+    /// let other = yarn_library! {
+    ///     "count" => || 2,
+    /// };
+    /// let error = library.import(other).unwrap_err();
+    /// assert_eq!(LibraryError::FunctionsAlreadyExist { names: vec!["count".to_owned()] }, error);
+    /// ```
+    pub fn import(&mut self, other: Self) -> Result<&mut Self, LibraryError> {
+        self.try_extend(other)
+    }
+
+    /// Adds the given functions like [`Extend::extend`], but returns a [`LibraryError::FunctionsAlreadyExist`]
+    /// listing all clashing names instead of overwriting existing functions. In that case, no function is added.
+    pub fn try_extend(
+        &mut self,
+        functions: impl IntoIterator<Item = (Cow<'static, str>, Box<dyn UntypedYarnFn>)>,
+    ) -> Result<&mut Self, LibraryError> {
+        let functions: Vec<_> = functions.into_iter().collect();
+        let mut clashing_names: Vec<_> = functions
+            .iter()
+            .map(|(name, _)| name)
+            .filter(|name| self.contains_function(name))
+            .map(|name| name.clone().into_owned())
+            .collect();
+        if !clashing_names.is_empty() {
+            clashing_names.sort();
+            return Err(LibraryError::FunctionsAlreadyExist {
+                names: clashing_names,
+            });
+        }
+        self.0.extend(functions);
+        Ok(self)
+    }
+
+    /// Loads functions from another [`Library`] as a module, i.e. prefixes all of their names with `namespace` followed by a dot.
+    /// The functions can then be called from Yarn with their namespaced name, e.g. `Math.floor(1.5)`.
+    ///
+    /// The namespace must consist of one or more identifiers separated by dots, e.g. `Math` or `Plugins.Inventory`.
+    /// Otherwise, a [`LibraryError::InvalidNamespace`] is returned.
+    /// Clashing names are reported the same way as in [`Library::import`].
+    ///
+    /// ## Examples
+    /// ```
+    /// # use yarnspinner_core::prelude::*;
+    /// let math = yarn_library! {
+    ///     "floor" => |num: f32| num.floor(),
+    /// };
+    /// let mut library = Library::new();
+    /// library.import_module("Math", math).unwrap();
+    /// assert!(library.contains_function("Math.floor"));
+    /// ```
+    pub fn import_module(
+        &mut self,
+        namespace: impl AsRef<str>,
+        other: Self,
+    ) -> Result<&mut Self, LibraryError> {
+        let namespace = namespace.as_ref();
+        if !is_valid_namespace(namespace) {
+            return Err(LibraryError::InvalidNamespace {
+                namespace: namespace.to_owned(),
+            });
+        }
+        let mut module = Library::new();
+        module.extend(
+            other
+                .into_iter()
+                .map(|(name, function)| (format!("{namespace}.{name}").into(), function)),
+        );
+        self.import(module)
     }
 
     /// Iterates over the names and functions in the library.
@@ -69,12 +143,81 @@ impl Library {
     /// - `string`: Converts a value to a string.
     /// - `number`: Converts a value to a number.
     /// - `bool`: Converts a value to a boolean.
+    /// - `random`: Returns a random number between 0 and 1.
+    /// - `random_range`: Returns a random number between two numbers. If both are integers, the range is inclusive.
+    /// - `dice`: Rolls a die with the given number of sides and returns the result, starting at 1.
+    /// - `round`: Rounds a number to the nearest integer.
+    /// - `round_places`: Rounds a number to the given number of decimal places.
+    /// - `floor`: Rounds a number down to the nearest integer.
+    /// - `ceil`: Rounds a number up to the nearest integer.
+    /// - `inc`: Increments a number to the next integer. Non-integers are rounded up.
+    /// - `dec`: Decrements a number to the previous integer. Non-integers are rounded down.
+    /// - `decimal`: Returns the fractional part of a number.
+    /// - `int`: Truncates a number to its integer part.
+    /// - `min`: Returns the smallest of one or more numbers.
+    /// - `max`: Returns the largest of one or more numbers.
+    /// - `clamp`: Restricts a number to a range.
+    /// - `abs`: Returns the absolute value of a number.
+    /// - `format_invariant`: Formats a number as a string independently of the current language.
+    /// - `length`: Returns the number of characters in a string.
+    /// - `upper`: Converts a string to uppercase.
+    /// - `lower`: Converts a string to lowercase.
+    /// - `contains`: Returns whether a string contains another string.
+    /// - `substring`: Returns the characters of a string starting at an index, up to a given length.
+    /// - `format`: Replaces the `{0}`, `{1}`, ... placeholders in a string with the values that follow it.
     /// - Comparison operators for numbers, strings, and booleans. (`==`, `!=`, `<`, `<=`, `>`, `>=`)
+    ///
+    /// The random functions draw from a [`YarnRng`] seeded from entropy.
+    /// Use [`Library::standard_library_with_rng`] to control the randomness.
     pub fn standard_library() -> Self {
+        Self::standard_library_with_rng(YarnRng::from_entropy())
+    }
+
+    /// Creates a [`Library`] with the same functions as [`Library::standard_library`],
+    /// with `random`, `random_range` and `dice` drawing from the given [`YarnRng`].
+    pub fn standard_library_with_rng(rng: YarnRng) -> Self {
         let mut library = yarn_library!(
             "string" => <String as From<YarnValue >>::from,
-            "number" => |value: YarnValue| f32::try_from(value).expect("Failed to convert a Yarn value to a number"),
-            "bool" => |value: YarnValue| bool::try_from(value).expect("Failed to convert a Yarn value to a bool"),
+            "number" => |value: YarnValue| f32::try_from(&value)
+                .map_err(|error| format!("Cannot convert \"{value}\" to a number: {error}")),
+            "bool" => |value: YarnValue| bool::try_from(&value)
+                .map_err(|error| format!("Cannot convert \"{value}\" to a bool: {error}")),
+            "random" => random(rng.clone()),
+            "random_range" => random_range(rng.clone()),
+            "dice" => dice(rng),
+            "round" => |num: f32| num.round() as i32,
+            "round_places" => |num: f32, places: u32| num.round_places(places),
+            "floor" => |num: f32| num.floor() as i32,
+            "ceil" => |num: f32| num.ceil() as i32,
+            "inc" => |num: f32| {
+                if let Some(num) = num.as_int() {
+                    num + 1
+                } else {
+                    num.ceil() as i32
+                }
+            },
+            "dec" => |num: f32| {
+                if let Some(num) = num.as_int() {
+                    num - 1
+                } else {
+                    num.floor() as i32
+                }
+            },
+            "decimal" => |num: f32| num.fract(),
+            "int" => |num: f32| num.trunc() as i32,
+            "min" => |first: f32, rest: Variadic<f32>| rest.iter().fold(first, |min, &num| min.min(num)),
+            "max" => |first: f32, rest: Variadic<f32>| rest.iter().fold(first, |max, &num| max.max(num)),
+            "clamp" => |num: f32, min: f32, max: f32| num.max(min).min(max),
+            "abs" => |num: f32| num.abs(),
+            "format_invariant" => |num: f32| num.to_string(),
+            "length" => |string: &str| string.chars().count(),
+            "upper" => |string: &str| string.to_uppercase(),
+            "lower" => |string: &str| string.to_lowercase(),
+            "contains" => |string: &str, substring: &str| string.contains(substring),
+            "substring" => |string: &str, start: usize, length: usize| {
+                string.chars().skip(start).take(length).collect::<String>()
+            },
+            "format" => |format: &str, values: Variadic<YarnValue>| substitute_placeholders(format, &values),
         );
         for r#type in [Type::Number, Type::String, Type::Boolean] {
             library.add_methods(r#type);
@@ -116,12 +259,33 @@ impl Library {
     where
         Marker: 'static,
         F: YarnFn<Marker> + 'static + Clone,
-        F::Out: IntoYarnValueFromNonYarnValue + 'static + Clone,
+        F::Out: YarnFnReturn + 'static,
     {
         self.0.register_function(name, function);
         self
     }
 
+    /// Adds a new function to the registry like [`Library::add_function`],
+    /// but returns a [`LibraryError::FunctionsAlreadyExist`] instead of overwriting an existing function with the same name.
+    pub fn try_add_function<Marker, F>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        function: F,
+    ) -> Result<&mut Self, LibraryError>
+    where
+        Marker: 'static,
+        F: YarnFn<Marker> + 'static + Clone,
+        F::Out: YarnFnReturn + 'static,
+    {
+        let name = name.into();
+        if self.contains_function(&name) {
+            return Err(LibraryError::FunctionsAlreadyExist {
+                names: vec![name.into_owned()],
+            });
+        }
+        Ok(self.add_function(name, function))
+    }
+
     /// Returns `true` if the library contains a function with the given name.
     pub fn contains_function(&self, name: &str) -> bool {
         self.0.contains_function(name)
@@ -188,3 +352,421 @@ macro_rules! yarn_library {
     };
 }
 pub use yarn_library;
+
+/// An error returned when functions cannot be added to a [`Library`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LibraryError {
+    /// The library already contains functions with these names.
+    FunctionsAlreadyExist {
+        /// The clashing names, sorted alphabetically.
+        names: Vec<String>,
+    },
+    /// The namespace passed to [`Library::import_module`] is not a dot-separated list of identifiers.
+    InvalidNamespace {
+        /// The invalid namespace.
+        namespace: String,
+    },
+}
+
+impl Error for LibraryError {}
+
+impl Display for LibraryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LibraryError::FunctionsAlreadyExist { names } => write!(
+                f,
+                "The library already contains functions named {}",
+                names.join(", ")
+            ),
+            LibraryError::InvalidNamespace { namespace } => {
+                write!(
+                    f,
+                    "\"{namespace}\" is not a valid namespace for Yarn functions"
+                )
+            }
+        }
+    }
+}
+
+fn is_valid_namespace(namespace: &str) -> bool {
+    namespace.split('.').all(|segment| {
+        let mut chars = segment.chars();
+        chars
+            .next()
+            .is_some_and(|first| first.is_alphabetic() || first == '_')
+            && chars.all(|c| c.is_alphanumeric() || c == '_')
+    })
+}
+
+fn random(rng: YarnRng) -> yarn_fn_type! { impl Fn() -> f32 } {
+    move || rng.gen_f32()
+}
+
+fn random_range(rng: YarnRng) -> yarn_fn_type! { impl Fn(f32, f32) -> f32 } {
+    move |min: f32, max: f32| {
+        if let (Some(min), Some(max_inclusive)) = (min.as_int(), max.as_int()) {
+            return rng.gen_range_inclusive(min.into(), max_inclusive.into()) as f32;
+        }
+        rng.gen_range_f32(min, max)
+    }
+}
+
+fn dice(rng: YarnRng) -> yarn_fn_type! { impl Fn(u32) -> u32 } {
+    move |sides: u32| {
+        if sides == 0 {
+            return 1;
+        }
+        rng.gen_range_inclusive(1, sides.into()) as u32
+    }
+}
+
+/// Replaces each `{n}` in `template` with the `n`th value. The template is only scanned once,
+/// so placeholders contained in the values are kept as they are. Placeholders without a value are kept as well.
+fn substitute_placeholders(template: &str, values: &[YarnValue]) -> String {
+    let mut text = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        text.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let placeholder = rest[1..].find('}').and_then(|end| {
+            let digits = &rest[1..1 + end];
+            let index: usize = digits.parse().ok()?;
+            let value = values.get(index).filter(|_| index.to_string() == digits)?;
+            Some((value, end + 2))
+        });
+        match placeholder {
+            Some((value, len)) => {
+                text.push_str(&value.to_string());
+                rest = &rest[len..];
+            }
+            None => {
+                text.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+    text.push_str(rest);
+    text
+}
+
+trait FloatExt: Copy {
+    fn as_int(self) -> Option<i32>;
+    fn round_places(self, places: u32) -> Self;
+}
+
+impl FloatExt for f32 {
+    fn as_int(self) -> Option<i32> {
+        (self.fract().abs() <= f32::EPSILON).then_some(self as i32)
+    }
+
+    /// Returns the number unchanged if rounding to `places` is out of the range of a float,
+    /// which is also beyond its precision.
+    fn round_places(self, places: u32) -> Self {
+        let Ok(places) = i32::try_from(places) else {
+            return self;
+        };
+        let factor = 10_f64.powi(places);
+        let scaled = f64::from(self) * factor;
+        if !scaled.is_finite() {
+            return self;
+        }
+        (scaled.round() / factor) as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rounds_places() {
+        for (num, places, expected) in [
+            (1.0, 0, 1.0),
+            (1.2, 1, 1.2),
+            (0.4, 0, 0.0),
+            (43.132, 0, 43.0),
+            (1.1, 2, 1.1),
+            (123.123, 3, 123.123),
+            (-10.3, 1, -10.3),
+            (-11.99, 1, -12.0),
+            (1.5, 10, 1.5),
+            (1.5, 12, 1.5),
+            (1.25, 400, 1.25),
+            (-1.25, u32::MAX, -1.25),
+        ] {
+            assert_eq!(expected, num.round_places(places));
+        }
+    }
+
+    #[test]
+    fn only_whole_numbers_convert_to_int() {
+        assert_eq!(Some(3), 3.0.as_int());
+        assert_eq!(Some(-3), (-3.0).as_int());
+        assert_eq!(None, 1.5.as_int());
+        assert_eq!(None, (-1.5).as_int());
+        assert_eq!(None, (-2.5).as_int());
+    }
+
+    #[test]
+    fn standard_library_string_functions() {
+        let library = Library::standard_library();
+        let call =
+            |name: &str, args: Vec<YarnValue>| library.get(name).unwrap().call(args, &()).unwrap();
+
+        assert_eq!(YarnValue::from(5), call("length", vec!["héllo".into()]));
+        assert_eq!(YarnValue::from("HI"), call("upper", vec!["hi".into()]));
+        assert_eq!(YarnValue::from("hi"), call("lower", vec!["HI".into()]));
+        assert_eq!(
+            YarnValue::from(true),
+            call("contains", vec!["hello".into(), "ell".into()])
+        );
+        assert_eq!(
+            YarnValue::from("llo"),
+            call("substring", vec!["hello".into(), 2.into(), 10.into()])
+        );
+        assert_eq!(
+            YarnValue::from("I have 3 apples"),
+            call("format", vec!["I have {0} apples".into(), 3.into()])
+        );
+        assert_eq!(
+            YarnValue::from("1 and two"),
+            call("format", vec!["{0} and {1}".into(), 1.into(), "two".into()])
+        );
+        assert_eq!(
+            YarnValue::from("{1} x"),
+            call("format", vec!["{0} {1}".into(), "{1}".into(), "x".into()])
+        );
+        assert_eq!(
+            YarnValue::from("{2} {01} {a} { 1"),
+            call("format", vec!["{2} {01} {a} { {0}".into(), 1.into()])
+        );
+    }
+
+    #[test]
+    fn standard_library_conversions_report_errors() {
+        let library = Library::standard_library();
+        let number = library.get("number").unwrap();
+        assert_eq!(
+            YarnValue::from(1.5),
+            number.call(vec!["1.5".into()], &()).unwrap()
+        );
+        assert!(number.call(vec!["not a number".into()], &()).is_err());
+        assert!(library
+            .get("bool")
+            .unwrap()
+            .call(vec!["maybe".into()], &())
+            .is_err());
+    }
+
+    #[test]
+    fn calls_with_the_wrong_number_of_arguments_fail() {
+        let library = Library::standard_library();
+        let call = |name: &str, args: Vec<YarnValue>| {
+            library
+                .get(name)
+                .unwrap()
+                .call(args, &())
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert_eq!(
+            "Expected 1 arguments, but received 2",
+            call("upper", vec!["a".into(), "b".into()])
+        );
+        assert_eq!(
+            "Expected at least 1 arguments, but received 0",
+            call("min", vec![])
+        );
+    }
+
+    #[test]
+    fn standard_library_number_functions() {
+        let library = Library::standard_library();
+        let call =
+            |name: &str, args: Vec<YarnValue>| library.get(name).unwrap().call(args, &()).unwrap();
+
+        assert_eq!(YarnValue::from(2), call("min", vec![2.into(), 3.into()]));
+        assert_eq!(YarnValue::from(3), call("max", vec![2.into(), 3.into()]));
+        assert_eq!(
+            YarnValue::from(4),
+            call("max", vec![1.into(), 4.into(), 2.into(), 3.into()])
+        );
+        assert_eq!(YarnValue::from(7), call("min", vec![7.into()]));
+        assert_eq!(
+            YarnValue::from(10),
+            call("clamp", vec![12.into(), 0.into(), 10.into()])
+        );
+        assert_eq!(YarnValue::from(1.5), call("abs", vec![(-1.5).into()]));
+        assert_eq!(YarnValue::from(2), call("inc", vec![1.into()]));
+        assert_eq!(YarnValue::from(2), call("inc", vec![1.2.into()]));
+        assert_eq!(YarnValue::from(0), call("dec", vec![1.into()]));
+        assert_eq!(YarnValue::from(1), call("dec", vec![1.2.into()]));
+        assert_eq!(YarnValue::from(-1), call("inc", vec![(-1.5).into()]));
+        assert_eq!(YarnValue::from(-3), call("inc", vec![(-4).into()]));
+        assert_eq!(YarnValue::from(-2), call("dec", vec![(-1.5).into()]));
+        assert_eq!(YarnValue::from(-5), call("dec", vec![(-4).into()]));
+        assert_eq!(YarnValue::from(-1), call("int", vec![(-1.7).into()]));
+        assert_eq!(
+            YarnValue::from("1.5"),
+            call("format_invariant", vec![1.5.into()])
+        );
+    }
+
+    #[test]
+    fn random_functions_are_deterministic_for_same_seed() {
+        let call_all = |library: &Library| -> Vec<YarnValue> {
+            vec![
+                library.get("random").unwrap().call(vec![], &()).unwrap(),
+                library
+                    .get("random_range")
+                    .unwrap()
+                    .call(vec![1.into(), 100.into()], &())
+                    .unwrap(),
+                library
+                    .get("dice")
+                    .unwrap()
+                    .call(vec![20.into()], &())
+                    .unwrap(),
+            ]
+        };
+        let first = Library::standard_library_with_rng(YarnRng::from_seed(99));
+        let second = Library::standard_library_with_rng(YarnRng::from_seed(99));
+        assert_eq!(call_all(&first), call_all(&second));
+    }
+
+    #[test]
+    fn random_range_with_negative_fraction_is_not_an_integer_range() {
+        let library = Library::standard_library_with_rng(YarnRng::from_seed(7));
+        let random_range = library.get("random_range").unwrap();
+        let rolls: Vec<f32> = (0..100)
+            .map(|_| {
+                random_range
+                    .call(vec![(-2.5).into(), 3.into()], &())
+                    .unwrap()
+                    .try_into()
+                    .unwrap()
+            })
+            .collect();
+        assert!(rolls.iter().all(|roll| (-2.5..3.0).contains(roll)));
+        assert!(rolls.iter().any(|roll| roll.fract() != 0.0));
+    }
+
+    #[test]
+    fn dice_rolls_within_sides() {
+        let library = Library::standard_library();
+        let dice = library.get("dice").unwrap();
+        for _ in 0..100 {
+            let roll: f32 = dice.call(vec![6.into()], &()).unwrap().try_into().unwrap();
+            assert!((1.0..=6.0).contains(&roll));
+        }
+    }
+
+    #[test]
+    fn import_reports_all_collisions_without_importing() {
+        let mut library = yarn_library! {
+            "count" => || 1,
+            "total" => || 2,
+        };
+        let other = yarn_library! {
+            "total" => || 3,
+            "count" => || 4,
+            "unique" => || 5,
+        };
+
+        let error = library.import(other).unwrap_err();
+
+        assert_eq!(
+            LibraryError::FunctionsAlreadyExist {
+                names: vec!["count".to_owned(), "total".to_owned()]
+            },
+            error
+        );
+        assert!(!library.contains_function("unique"));
+        let count = library.get("count").unwrap().call(vec![], &()).unwrap();
+        assert_eq!(YarnValue::from(1), count);
+    }
+
+    #[test]
+    fn modules_with_same_function_names_do_not_collide() {
+        let mut library = Library::new();
+        library
+            .import_module("Inventory", yarn_library! { "count" => || 1, })
+            .unwrap()
+            .import_module("Quests.Log", yarn_library! { "count" => || 2, })
+            .unwrap();
+
+        let call = |name: &str| library.get(name).unwrap().call(vec![], &()).unwrap();
+        assert_eq!(YarnValue::from(1), call("Inventory.count"));
+        assert_eq!(YarnValue::from(2), call("Quests.Log.count"));
+        assert!(!library.contains_function("count"));
+
+        let error = library
+            .import_module("Inventory", yarn_library! { "count" => || 3, })
+            .unwrap_err();
+        assert_eq!(
+            LibraryError::FunctionsAlreadyExist {
+                names: vec!["Inventory.count".to_owned()]
+            },
+            error
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_namespaces() {
+        for namespace in ["", "Math.", ".Math", "1Math", "Math Utils", "Math(x)"] {
+            let error = Library::new()
+                .import_module(
+                    namespace,
+                    yarn_library! { "floor" => |num: f32| num.floor(), },
+                )
+                .unwrap_err();
+            assert_eq!(
+                LibraryError::InvalidNamespace {
+                    namespace: namespace.to_owned()
+                },
+                error
+            );
+        }
+    }
+
+    #[test]
+    fn try_extend_reports_collisions_without_extending() {
+        let mut library = Library::standard_library();
+        let plugin = yarn_library! {
+            "count" => || 1,
+            "floor" => |num: f32| num,
+        };
+
+        let error = library.try_extend(plugin.clone()).unwrap_err();
+        assert_eq!(
+            LibraryError::FunctionsAlreadyExist {
+                names: vec!["floor".to_owned()]
+            },
+            error
+        );
+        assert!(!library.contains_function("count"));
+
+        let mut overwritten = Library::standard_library();
+        overwritten.extend(plugin);
+        assert!(overwritten.contains_function("count"));
+    }
+
+    #[test]
+    fn try_add_function_does_not_overwrite() {
+        let mut library = Library::standard_library();
+        let error = library
+            .try_add_function("floor", |num: f32| num)
+            .unwrap_err();
+        assert_eq!(
+            LibraryError::FunctionsAlreadyExist {
+                names: vec!["floor".to_owned()]
+            },
+            error
+        );
+        library
+            .try_add_function("Math.floor", |num: f32| num.floor())
+            .unwrap();
+        assert!(library.contains_function("Math.floor"));
+    }
+}
@@ -2,7 +2,9 @@
 use crate::prelude::*;
 
 /// Represents a position in a multi-line string.
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+///
+/// Positions are ordered by line first and character second, i.e. in the order they appear in the string.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
 #[cfg_attr(feature = "bevy", derive(Reflect))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "bevy", reflect(Debug, PartialEq, Hash))]
@@ -0,0 +1,384 @@
+//! Compiling Yarn requires the whole compiler, which a game may not want to ship.
+//! The types in this module allow saving a compiled [`Program`] as a `.yarnc` file ahead of time and loading it again with only the runtime.
+
+use crate::prelude::*;
+use prost::Message;
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// A compiled [`Program`] together with the text and metadata of its lines, as stored in a `.yarnc` file.
+///
+/// ## File format
+///
+/// A `.yarnc` file starts with the bytes of [`ProgramFile::MAGIC`], followed by the [`ProgramFile::VERSION`] of the format
+/// as a little-endian `u32`. The rest of the file is a Protocol Buffers message containing the program, the string table and the line metadata.
+/// All maps are written sorted by their keys, so compiling the same program always produces the same bytes.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ProgramFile {
+    /// The compiled program.
+    pub program: Program,
+
+    /// The text of each line in the program, in the base language.
+    pub string_table: HashMap<LineId, String>,
+
+    /// The metadata of each line in the program that has any, i.e. the hashtags written after the line.
+    pub line_metadata: HashMap<LineId, Vec<String>>,
+}
+
+impl ProgramFile {
+    /// The bytes every `.yarnc` file starts with.
+    pub const MAGIC: [u8; 5] = *b"YARNC";
+
+    /// The version of the format written by [`ProgramFile::to_bytes`].
+    /// It is incremented whenever the format or the instruction set changes in a way that makes older files unusable.
+    pub const VERSION: u32 = 1;
+
+    /// Encodes this file in the `.yarnc` format.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let message = ProgramFileMessage {
+            program: Some(ProgramMessage::from(&self.program)),
+            string_table: self
+                .string_table
+                .iter()
+                .map(|(line_id, text)| (line_id.0.clone(), text.clone()))
+                .collect(),
+            line_metadata: self
+                .line_metadata
+                .iter()
+                .map(|(line_id, metadata)| {
+                    let metadata = LineMetadataMessage {
+                        metadata: metadata.clone(),
+                    };
+                    (line_id.0.clone(), metadata)
+                })
+                .collect(),
+        };
+        let mut bytes = Vec::with_capacity(Self::header_len() + message.encoded_len());
+        bytes.extend_from_slice(&Self::MAGIC);
+        bytes.extend_from_slice(&Self::VERSION.to_le_bytes());
+        message
+            .encode(&mut bytes)
+            .expect("Encoding into a Vec cannot run out of space");
+        bytes
+    }
+
+    /// Decodes a file previously encoded with [`ProgramFile::to_bytes`].
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if `bytes` is not a `.yarnc` file, was written in a different version of the format, or is corrupted.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ProgramFileError> {
+        let body = bytes
+            .strip_prefix(&Self::MAGIC)
+            .ok_or(ProgramFileError::NotAProgramFile)?;
+        let (version, body) = body
+            .split_first_chunk::<4>()
+            .ok_or(ProgramFileError::NotAProgramFile)?;
+        let version = u32::from_le_bytes(*version);
+        if version != Self::VERSION {
+            return Err(ProgramFileError::UnsupportedVersion { version });
+        }
+        let message = ProgramFileMessage::decode(body).map_err(ProgramFileError::InvalidData)?;
+        Ok(Self {
+            program: message.program.map(Program::from).unwrap_or_default(),
+            string_table: message
+                .string_table
+                .into_iter()
+                .map(|(line_id, text)| (line_id.into(), text))
+                .collect(),
+            line_metadata: message
+                .line_metadata
+                .into_iter()
+                .map(|(line_id, metadata)| (line_id.into(), metadata.metadata))
+                .collect(),
+        })
+    }
+
+    const fn header_len() -> usize {
+        Self::MAGIC.len() + std::mem::size_of::<u32>()
+    }
+}
+
+impl Program {
+    /// Encodes the program in the `.yarnc` format, without any string table or line metadata.
+    /// Use [`ProgramFile`] to store these alongside the program.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        ProgramFile {
+            program: self.clone(),
+            ..Default::default()
+        }
+        .to_bytes()
+    }
+
+    /// Decodes the program of a `.yarnc` file, ignoring its string table and line metadata.
+    /// Use [`ProgramFile::from_bytes`] to read these as well.
+    ///
+    /// ## Errors
+    ///
+    /// See [`ProgramFile::from_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ProgramFileError> {
+        ProgramFile::from_bytes(bytes).map(|file| file.program)
+    }
+}
+
+/// An error returned by [`ProgramFile::from_bytes`] and [`Program::from_bytes`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProgramFileError {
+    /// The data does not start with [`ProgramFile::MAGIC`] and a version, so it is not a `.yarnc` file.
+    NotAProgramFile,
+    /// The file was written in a version of the format other than [`ProgramFile::VERSION`].
+    /// It needs to be compiled again with a compiler matching this runtime.
+    UnsupportedVersion {
+        /// The version the file was written in.
+        version: u32,
+    },
+    /// The file has a valid header, but its contents are corrupted.
+    InvalidData(prost::DecodeError),
+}
+
+impl Error for ProgramFileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ProgramFileError::InvalidData(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl Display for ProgramFileError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ProgramFileError::NotAProgramFile => f.write_str("The data is not a compiled Yarn program"),
+            ProgramFileError::UnsupportedVersion { version } => write!(
+                f,
+                "The compiled Yarn program has format version {version}, but only version {} is supported. Please compile it again",
+                ProgramFile::VERSION
+            ),
+            ProgramFileError::InvalidData(error) => {
+                write!(f, "The compiled Yarn program is corrupted: {error}")
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct ProgramFileMessage {
+    #[prost(message, optional, tag = "1")]
+    program: Option<ProgramMessage>,
+    #[prost(btree_map = "string, string", tag = "2")]
+    string_table: BTreeMap<String, String>,
+    #[prost(btree_map = "string, message", tag = "3")]
+    line_metadata: BTreeMap<String, LineMetadataMessage>,
+}
+
+/// The same message as [`Program`], but with its maps sorted so that encoding it is deterministic.
+#[derive(Clone, PartialEq, Message)]
+pub(crate) struct ProgramMessage {
+    #[prost(string, tag = "1")]
+    name: String,
+    #[prost(btree_map = "string, message", tag = "2")]
+    nodes: BTreeMap<String, NodeMessage>,
+    #[prost(btree_map = "string, message", tag = "3")]
+    initial_values: BTreeMap<String, Operand>,
+}
+
+/// The same message as [`Node`], but with its labels sorted.
+#[derive(Clone, PartialEq, Message)]
+struct NodeMessage {
+    #[prost(string, tag = "1")]
+    name: String,
+    #[prost(message, repeated, tag = "2")]
+    instructions: Vec<Instruction>,
+    #[prost(btree_map = "string, int32", tag = "3")]
+    labels: BTreeMap<String, i32>,
+    #[prost(string, repeated, tag = "4")]
+    tags: Vec<String>,
+    #[prost(string, tag = "5")]
+    source_text_string_id: String,
+    #[prost(message, repeated, tag = "6")]
+    headers: Vec<Header>,
+}
+
+impl From<&Program> for ProgramMessage {
+    fn from(program: &Program) -> Self {
+        Self {
+            name: program.name.clone(),
+            nodes: program
+                .nodes
+                .iter()
+                .map(|(name, node)| (name.clone(), NodeMessage::from(node)))
+                .collect(),
+            initial_values: program
+                .initial_values
+                .iter()
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect(),
+        }
+    }
+}
+
+impl From<ProgramMessage> for Program {
+    fn from(message: ProgramMessage) -> Self {
+        Self {
+            name: message.name,
+            nodes: message
+                .nodes
+                .into_iter()
+                .map(|(name, node)| (name, node.into()))
+                .collect(),
+            initial_values: message.initial_values.into_iter().collect(),
+        }
+    }
+}
+
+impl From<&Node> for NodeMessage {
+    fn from(node: &Node) -> Self {
+        Self {
+            name: node.name.clone(),
+            instructions: node.instructions.clone(),
+            labels: node
+                .labels
+                .iter()
+                .map(|(label, index)| (label.clone(), *index))
+                .collect(),
+            tags: node.tags.clone(),
+            source_text_string_id: node.source_text_string_id.clone(),
+            headers: node.headers.clone(),
+        }
+    }
+}
+
+impl From<NodeMessage> for Node {
+    fn from(message: NodeMessage) -> Self {
+        Self {
+            name: message.name,
+            instructions: message.instructions,
+            labels: message.labels.into_iter().collect(),
+            tags: message.tags,
+            source_text_string_id: message.source_text_string_id,
+            headers: message.headers,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct LineMetadataMessage {
+    #[prost(string, repeated, tag = "1")]
+    metadata: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn program_file() -> ProgramFile {
+        let node = Node {
+            name: "Start".to_owned(),
+            instructions: vec![
+                Instruction {
+                    opcode: OpCode::RunLine.into(),
+                    operands: vec![String::from("line:hello").into(), 0_usize.into()],
+                },
+                Instruction {
+                    opcode: OpCode::Stop.into(),
+                    operands: vec![],
+                },
+            ],
+            ..Default::default()
+        };
+        ProgramFile {
+            program: Program {
+                name: "Example".to_owned(),
+                nodes: HashMap::from([("Start".to_owned(), node)]),
+                initial_values: HashMap::from([("$gold".to_owned(), 5.0.into())]),
+            },
+            string_table: HashMap::from([("line:hello".into(), "Hello!".to_owned())]),
+            line_metadata: HashMap::from([("line:hello".into(), vec!["greeting".to_owned()])]),
+        }
+    }
+
+    #[test]
+    fn round_trips_program_files() {
+        let file = program_file();
+        let bytes = file.to_bytes();
+
+        assert!(bytes.starts_with(b"YARNC\x01\x00\x00\x00"));
+        assert_eq!(ProgramFile::from_bytes(&bytes), Ok(file.clone()));
+        assert_eq!(Program::from_bytes(&bytes), Ok(file.program));
+    }
+
+    #[test]
+    fn encodes_maps_in_a_stable_order() {
+        let names: Vec<_> = (0..32).map(|i| format!("Node{i}")).collect();
+        let program = |names: &mut dyn Iterator<Item = &String>| {
+            let node = |name: &String| Node {
+                name: name.clone(),
+                labels: HashMap::from([("L1".to_owned(), 1), ("L2".to_owned(), 2)]),
+                ..Default::default()
+            };
+            ProgramFile {
+                program: Program {
+                    nodes: names.map(|name| (name.clone(), node(name))).collect(),
+                    ..Default::default()
+                },
+                ..program_file()
+            }
+        };
+
+        let forwards = program(&mut names.iter());
+        let backwards = program(&mut names.iter().rev());
+
+        assert_eq!(forwards.to_bytes(), backwards.to_bytes());
+        assert_eq!(ProgramFile::from_bytes(&forwards.to_bytes()), Ok(forwards));
+    }
+
+    #[test]
+    fn round_trips_programs() {
+        let program = program_file().program;
+
+        let file = ProgramFile::from_bytes(&program.to_bytes()).unwrap();
+
+        assert_eq!(file.program, program);
+        assert!(file.string_table.is_empty());
+        assert!(file.line_metadata.is_empty());
+    }
+
+    #[test]
+    fn rejects_data_without_header() {
+        let raw_program = program_file().program.encode_to_vec();
+
+        assert_eq!(
+            Program::from_bytes(&raw_program),
+            Err(ProgramFileError::NotAProgramFile)
+        );
+        assert_eq!(
+            Program::from_bytes(b"YARNC\x01"),
+            Err(ProgramFileError::NotAProgramFile)
+        );
+    }
+
+    #[test]
+    fn rejects_other_versions() {
+        let mut bytes = program_file().to_bytes();
+        bytes[5..9].copy_from_slice(&2_u32.to_le_bytes());
+
+        assert_eq!(
+            ProgramFile::from_bytes(&bytes),
+            Err(ProgramFileError::UnsupportedVersion { version: 2 })
+        );
+    }
+
+    #[test]
+    fn rejects_corrupted_data() {
+        let mut bytes = program_file().to_bytes();
+        bytes.truncate(bytes.len() - 3);
+
+        assert!(matches!(
+            ProgramFile::from_bytes(&bytes),
+            Err(ProgramFileError::InvalidData(_))
+        ));
+    }
+}
@@ -27,9 +27,17 @@ pub struct FunctionType {
     /// The list of the parameter types that this function is called with.
     ///
     /// The length of this list also determines the number of parameters this function accepts
-    /// (also known as the function's *arity*).
+    /// (also known as the function's *arity*), unless some of them are optional or the function is variadic.
     pub parameters: Vec<Option<Type>>,
 
+    /// The number of trailing entries in [`FunctionType::parameters`] that may be omitted by the caller.
+    pub optional_parameter_count: usize,
+
+    #[cfg_attr(feature = "bevy", reflect(ignore))]
+    /// The type of the arguments that may follow [`FunctionType::parameters`] in any number, if this function is variadic.
+    // Needs to be on the heap because of type recursion
+    pub variadic_parameter_type: Option<Box<Type>>,
+
     #[cfg_attr(feature = "bevy", reflect(ignore))]
     ///The type of value that this function returns.
     // Needs to be on the heap because of type recursion
@@ -54,17 +62,108 @@ impl FunctionType {
         self.parameters.push(parameter.into());
         self
     }
+
+    /// Adds a parameter type to this function signature that may be omitted by the caller.
+    /// Must be called after all required parameters have been added.
+    pub fn add_optional_parameter(&mut self, parameter: impl Into<Option<Type>>) -> &mut Self {
+        self.parameters.push(parameter.into());
+        self.optional_parameter_count += 1;
+        self
+    }
+
+    /// Makes this function accept any number of arguments of the given type after its other parameters.
+    pub fn set_variadic_parameter_type(
+        &mut self,
+        variadic_parameter_type: impl Into<Option<Type>>,
+    ) -> &mut Self {
+        self.variadic_parameter_type = variadic_parameter_type.into().map(Box::new);
+        self
+    }
+
+    /// The number of arguments that must be supplied when calling this function.
+    pub fn required_parameter_count(&self) -> usize {
+        self.parameters
+            .len()
+            .saturating_sub(self.optional_parameter_count)
+    }
+
+    /// Whether this function can be called with the given number of arguments.
+    pub fn accepts_argument_count(&self, count: usize) -> bool {
+        count >= self.required_parameter_count()
+            && (self.variadic_parameter_type.is_some() || count <= self.parameters.len())
+    }
+
+    /// The expected type of the argument at the given index, taking variadic parameters into account.
+    /// Returns [`None`] if the function does not accept an argument at that index.
+    pub fn parameter_type_at(&self, index: usize) -> Option<Option<Type>> {
+        self.parameters.get(index).cloned().or_else(|| {
+            self.variadic_parameter_type
+                .as_deref()
+                .map(|variadic| Some(variadic.clone()))
+        })
+    }
 }
 
 impl Display for FunctionType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let required_parameter_count = self.required_parameter_count();
         let parameters = self
             .parameters
             .iter()
-            .map(TypeFormat::format)
+            .enumerate()
+            .map(|(i, parameter)| {
+                if i < required_parameter_count {
+                    parameter.format()
+                } else {
+                    format!("{}?", parameter.format())
+                }
+            })
+            .chain(
+                self.variadic_parameter_type
+                    .iter()
+                    .map(|variadic| format!("...{}", variadic.format())),
+            )
             .collect::<Vec<_>>()
             .join(", ");
         let return_type = self.return_type.as_ref().format();
         write!(f, "Fn({}) -> {}", parameters, return_type)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_argument_count() {
+        let mut function_type = FunctionType::default();
+        function_type
+            .add_parameter(Type::String)
+            .add_optional_parameter(Type::Number);
+        assert!(!function_type.accepts_argument_count(0));
+        assert!(function_type.accepts_argument_count(1));
+        assert!(function_type.accepts_argument_count(2));
+        assert!(!function_type.accepts_argument_count(3));
+
+        function_type.set_variadic_parameter_type(Type::Boolean);
+        assert!(function_type.accepts_argument_count(5));
+        assert_eq!(
+            Some(Some(Type::Boolean)),
+            function_type.parameter_type_at(4)
+        );
+    }
+
+    #[test]
+    fn displays_optional_and_variadic_parameters() {
+        let mut function_type = FunctionType::default();
+        function_type
+            .add_parameter(Type::String)
+            .add_optional_parameter(Type::Number)
+            .set_variadic_parameter_type(Type::Any)
+            .set_return_type(Type::String);
+        assert_eq!(
+            "Fn(String, Number?, ...Any) -> String",
+            function_type.to_string()
+        );
+    }
+}
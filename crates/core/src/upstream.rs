@@ -0,0 +1,450 @@
+//! Reads and writes the files produced by `ysc compile`, the command line compiler of the original implementation:
+//! - `<name>.yarnc` contains the [`Program`] as a bare Protocol Buffers message, i.e. without the header of [`ProgramFile`].
+//! - `<name>-Lines.csv` contains the string table with the columns `id,text,file,node,lineNumber`, see [`LineEntry`].
+//! - `<name>-Metadata.csv` contains the hashtags of each line that has any besides its line ID, with the columns `id,node,lineNumber`
+//!   followed by one column per tag, see [`LineMetadataEntry`].
+//!
+//! The CSV files are written the way the original implementation writes them: records end with `\r\n`, and fields are only quoted
+//! if they contain a comma, a quote or a line break, or start or end with whitespace.
+
+use crate::prelude::*;
+use crate::program_file::ProgramMessage;
+use prost::Message;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+impl Program {
+    /// Encodes the program the way the original implementation writes `.yarnc` files.
+    /// Unlike [`Program::to_bytes`], the output has no header and is a bare Protocol Buffers message.
+    /// Like there, its maps are written sorted by their keys.
+    #[must_use]
+    pub fn to_upstream_bytes(&self) -> Vec<u8> {
+        ProgramMessage::from(self).encode_to_vec()
+    }
+
+    /// Decodes a `.yarnc` file written by the original implementation.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`UpstreamFormatError::InvalidProgram`] if `bytes` is not a valid program.
+    pub fn from_upstream_bytes(bytes: &[u8]) -> Result<Self, UpstreamFormatError> {
+        Self::decode(bytes).map_err(UpstreamFormatError::InvalidProgram)
+    }
+}
+
+impl ProgramFile {
+    /// Combines the files written by `ysc compile` into a [`ProgramFile`].
+    ///
+    /// The metadata CSV omits line IDs and lines without any other hashtags,
+    /// so [`ProgramFile::line_metadata`] will not contain them either.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the program or one of the CSV files is malformed.
+    pub fn from_upstream(
+        program: &[u8],
+        lines_csv: &str,
+        metadata_csv: Option<&str>,
+    ) -> Result<Self, UpstreamFormatError> {
+        let program = Program::from_upstream_bytes(program)?;
+        let string_table = LineEntry::read_csv(lines_csv)?
+            .into_iter()
+            .map(|entry| (entry.id, entry.text))
+            .collect();
+        let line_metadata = metadata_csv
+            .map(LineMetadataEntry::read_csv)
+            .transpose()?
+            .unwrap_or_default()
+            .into_iter()
+            .map(|entry| (entry.id, entry.tags))
+            .collect();
+        Ok(Self {
+            program,
+            string_table,
+            line_metadata,
+        })
+    }
+}
+
+/// A record of the `<name>-Lines.csv` file written by `ysc compile`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LineEntry {
+    /// The ID of the line.
+    pub id: LineId,
+    /// The text of the line in the base language.
+    pub text: String,
+    /// The file the line was declared in.
+    pub file: String,
+    /// The node the line was declared in.
+    pub node: String,
+    /// The 1-indexed line number in `file` the line was declared on.
+    pub line_number: usize,
+}
+
+impl LineEntry {
+    const HEADER: [&'static str; 5] = ["id", "text", "file", "node", "lineNumber"];
+
+    /// Writes the entries as a `<name>-Lines.csv` file, including the header.
+    #[must_use]
+    pub fn write_csv(entries: &[LineEntry]) -> String {
+        let records = entries.iter().map(|entry| {
+            vec![
+                entry.id.0.clone(),
+                entry.text.clone(),
+                entry.file.clone(),
+                entry.node.clone(),
+                entry.line_number.to_string(),
+            ]
+        });
+        write_csv(&Self::HEADER, records)
+    }
+
+    /// Reads the entries of a `<name>-Lines.csv` file.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`UpstreamFormatError::InvalidCsv`] if the file is not valid CSV or does not have the expected columns.
+    pub fn read_csv(csv: &str) -> Result<Vec<LineEntry>, UpstreamFormatError> {
+        read_csv(csv, &Self::HEADER)?
+            .into_iter()
+            .map(|(line, fields)| {
+                let [id, text, file, node, line_number] =
+                    <[String; 5]>::try_from(fields).map_err(|fields| {
+                        invalid_csv(line, format!("Expected 5 fields, found {}", fields.len()))
+                    })?;
+                Ok(LineEntry {
+                    id: id.into(),
+                    text,
+                    file,
+                    node,
+                    line_number: parse_line_number(line, &line_number)?,
+                })
+            })
+            .collect()
+    }
+}
+
+/// A record of the `<name>-Metadata.csv` file written by `ysc compile`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LineMetadataEntry {
+    /// The ID of the line.
+    pub id: LineId,
+    /// The node the line was declared in.
+    pub node: String,
+    /// The 1-indexed line number the line was declared on.
+    pub line_number: usize,
+    /// The hashtags of the line, excluding its line ID.
+    pub tags: Vec<String>,
+}
+
+impl LineMetadataEntry {
+    const HEADER: [&'static str; 4] = ["id", "node", "lineNumber", "tags"];
+
+    /// Writes the entries as a `<name>-Metadata.csv` file, including the header.
+    #[must_use]
+    pub fn write_csv(entries: &[LineMetadataEntry]) -> String {
+        let records = entries.iter().map(|entry| {
+            [
+                entry.id.0.clone(),
+                entry.node.clone(),
+                entry.line_number.to_string(),
+            ]
+            .into_iter()
+            .chain(entry.tags.iter().cloned())
+            .collect()
+        });
+        write_csv(&Self::HEADER, records)
+    }
+
+    /// Reads the entries of a `<name>-Metadata.csv` file.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`UpstreamFormatError::InvalidCsv`] if the file is not valid CSV or does not have the expected columns.
+    pub fn read_csv(csv: &str) -> Result<Vec<LineMetadataEntry>, UpstreamFormatError> {
+        read_csv(csv, &Self::HEADER)?
+            .into_iter()
+            .map(|(line, fields)| {
+                let mut fields = fields.into_iter();
+                let (Some(id), Some(node), Some(line_number)) =
+                    (fields.next(), fields.next(), fields.next())
+                else {
+                    return Err(invalid_csv(line, "Expected at least 3 fields"));
+                };
+                Ok(LineMetadataEntry {
+                    id: id.into(),
+                    node,
+                    line_number: parse_line_number(line, &line_number)?,
+                    tags: fields.collect(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// An error returned when reading files in the format of the original implementation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UpstreamFormatError {
+    /// The `.yarnc` file is not a valid program.
+    InvalidProgram(prost::DecodeError),
+    /// A CSV file is malformed.
+    InvalidCsv {
+        /// The 1-indexed line of the CSV file the error occurred on.
+        line: usize,
+        /// A description of what went wrong.
+        message: String,
+    },
+}
+
+impl Error for UpstreamFormatError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            UpstreamFormatError::InvalidProgram(error) => Some(error),
+            UpstreamFormatError::InvalidCsv { .. } => None,
+        }
+    }
+}
+
+impl Display for UpstreamFormatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            UpstreamFormatError::InvalidProgram(error) => {
+                write!(f, "The compiled Yarn program is invalid: {error}")
+            }
+            UpstreamFormatError::InvalidCsv { line, message } => {
+                write!(f, "Line {line} of the CSV file is invalid: {message}")
+            }
+        }
+    }
+}
+
+fn invalid_csv(line: usize, message: impl Into<String>) -> UpstreamFormatError {
+    UpstreamFormatError::InvalidCsv {
+        line,
+        message: message.into(),
+    }
+}
+
+fn parse_line_number(line: usize, field: &str) -> Result<usize, UpstreamFormatError> {
+    field
+        .parse()
+        .map_err(|_| invalid_csv(line, format!("{field:?} is not a line number")))
+}
+
+fn write_csv(header: &[&str], records: impl Iterator<Item = Vec<String>>) -> String {
+    let mut csv = String::new();
+    let header = header.iter().map(|field| field.to_string()).collect();
+    for record in std::iter::once(header).chain(records) {
+        let fields: Vec<_> = record.iter().map(|field| quote_field(field)).collect();
+        csv.push_str(&fields.join(","));
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+fn quote_field(field: &str) -> String {
+    let needs_quotes = field.contains([',', '"', '\r', '\n'])
+        || field.starts_with(char::is_whitespace)
+        || field.ends_with(char::is_whitespace);
+    if needs_quotes {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+/// Parses the records of a CSV file and checks that its header starts with `expected_header`.
+/// Returns the records after the header together with the 1-indexed line they start on.
+fn read_csv(
+    csv: &str,
+    expected_header: &[&str],
+) -> Result<Vec<(usize, Vec<String>)>, UpstreamFormatError> {
+    let mut records = parse_csv(csv)?.into_iter();
+    let Some((line, header)) = records.next() else {
+        return Err(invalid_csv(1, "The file is empty"));
+    };
+    let header_matches = header.len() >= expected_header.len()
+        && header
+            .iter()
+            .zip(expected_header)
+            .all(|(found, expected)| found == expected);
+    if !header_matches {
+        return Err(invalid_csv(
+            line,
+            format!(
+                "Expected the header {}, found {}",
+                expected_header.join(","),
+                header.join(",")
+            ),
+        ));
+    }
+    Ok(records.collect())
+}
+
+fn parse_csv(csv: &str) -> Result<Vec<(usize, Vec<String>)>, UpstreamFormatError> {
+    let mut records = Vec::new();
+    let mut chars = csv.chars().peekable();
+    let mut line = 1;
+    while chars.peek().is_some() {
+        let record_line = line;
+        let mut record = Vec::new();
+        loop {
+            let mut field = String::new();
+            if chars.next_if_eq(&'"').is_some() {
+                loop {
+                    match chars.next() {
+                        Some('"') if chars.next_if_eq(&'"').is_some() => field.push('"'),
+                        Some('"') => break,
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            field.push(c);
+                        }
+                        None => return Err(invalid_csv(record_line, "Unterminated quoted field")),
+                    }
+                }
+            }
+            while let Some(c) = chars.next_if(|&c| !matches!(c, ',' | '\r' | '\n')) {
+                field.push(c);
+            }
+            record.push(field);
+            if chars.next_if_eq(&',').is_none() {
+                break;
+            }
+        }
+        chars.next_if_eq(&'\r');
+        chars.next_if_eq(&'\n');
+        line += 1;
+        // Blank lines don't contain records
+        if record != [""] {
+            records.push((record_line, record));
+        }
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn line_entry(id: &str, text: &str, line_number: usize) -> LineEntry {
+        LineEntry {
+            id: id.into(),
+            text: text.to_owned(),
+            file: "Example.yarn".to_owned(),
+            node: "Start".to_owned(),
+            line_number,
+        }
+    }
+
+    #[test]
+    fn writes_lines_csv() {
+        let entries = [
+            line_entry("line:a", "Plain text", 3),
+            line_entry("line:b", "Hello, \"friend\"", 4),
+            line_entry("line:c", " padded", 5),
+        ];
+
+        let csv = LineEntry::write_csv(&entries);
+
+        assert_eq!(
+            csv,
+            "id,text,file,node,lineNumber\r\n\
+             line:a,Plain text,Example.yarn,Start,3\r\n\
+             line:b,\"Hello, \"\"friend\"\"\",Example.yarn,Start,4\r\n\
+             line:c,\" padded\",Example.yarn,Start,5\r\n"
+        );
+        assert_eq!(LineEntry::read_csv(&csv), Ok(entries.to_vec()));
+    }
+
+    #[test]
+    fn round_trips_metadata_csv() {
+        let entries = vec![
+            LineMetadataEntry {
+                id: "line:a".into(),
+                node: "Start".to_owned(),
+                line_number: 3,
+                tags: vec!["mood:happy".to_owned(), "lastline".to_owned()],
+            },
+            LineMetadataEntry {
+                id: "line:b".into(),
+                node: "Start".to_owned(),
+                line_number: 7,
+                tags: vec!["shout".to_owned()],
+            },
+        ];
+
+        let csv = LineMetadataEntry::write_csv(&entries);
+
+        assert_eq!(
+            csv,
+            "id,node,lineNumber,tags\r\n\
+             line:a,Start,3,mood:happy,lastline\r\n\
+             line:b,Start,7,shout\r\n"
+        );
+        assert_eq!(LineMetadataEntry::read_csv(&csv), Ok(entries));
+    }
+
+    #[test]
+    fn reads_multiline_fields_and_unix_line_endings() {
+        let csv = "id,text,file,node,lineNumber\n\
+                   line:a,\"First\nSecond\",Example.yarn,Start,3\n\
+                   \n\
+                   line:b,Third,Example.yarn,Start,4";
+
+        let entries = LineEntry::read_csv(csv).unwrap();
+
+        assert_eq!(
+            entries,
+            vec![
+                line_entry("line:a", "First\nSecond", 3),
+                line_entry("line:b", "Third", 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_malformed_csv() {
+        assert_eq!(
+            LineEntry::read_csv("id,node,lineNumber,tags\r\n"),
+            Err(invalid_csv(
+                1,
+                "Expected the header id,text,file,node,lineNumber, found id,node,lineNumber,tags"
+            ))
+        );
+        assert_eq!(
+            LineEntry::read_csv("id,text,file,node,lineNumber\nline:a,\"Text\nmore,Example.yarn"),
+            Err(invalid_csv(2, "Unterminated quoted field"))
+        );
+        assert_eq!(
+            LineEntry::read_csv(
+                "id,text,file,node,lineNumber\n\"line:a\",\"Multi\nline\",Example.yarn,Start\n"
+            ),
+            Err(invalid_csv(2, "Expected 5 fields, found 4"))
+        );
+        assert_eq!(
+            LineMetadataEntry::read_csv("id,node,lineNumber,tags\nline:a,Start,three,shout\n"),
+            Err(invalid_csv(2, "\"three\" is not a line number"))
+        );
+    }
+
+    #[test]
+    fn round_trips_programs() {
+        let program = Program {
+            name: "Example".to_owned(),
+            initial_values: HashMap::from([("$gold".to_owned(), 0.0.into())]),
+            ..Default::default()
+        };
+
+        let bytes = program.to_upstream_bytes();
+
+        assert_eq!(bytes, program.encode_to_vec());
+        assert_eq!(Program::from_upstream_bytes(&bytes), Ok(program));
+        assert!(matches!(
+            Program::from_upstream_bytes(&[0xff]),
+            Err(UpstreamFormatError::InvalidProgram(_))
+        ));
+    }
+}
@@ -2,10 +2,12 @@
 //! Inspired by how Bevy stores [`FnSystem`](https://docs.rs/bevy_ecs/0.10.1/bevy_ecs/system/struct.FnSystem.html)s.
 //! This is all here just to emulate the `Dictionary<string, Delegate>` used in Yarn Spinner's `Library` class.
 
+mod context;
 mod function_registry;
 mod function_wrapping;
 pub mod optionality;
 mod parameter_wrapping;
+mod return_wrapping;
 
 pub(crate) use function_registry::*;
-pub use {function_wrapping::*, parameter_wrapping::*};
+pub use {context::*, function_wrapping::*, parameter_wrapping::*, return_wrapping::*};
@@ -0,0 +1,63 @@
+use crate::prelude::*;
+
+/// Read access to the state of the dialogue that is calling a [`YarnFn`].
+///
+/// A [`YarnFn`] can request this by taking a `&dyn YarnFnContext` as its first parameter.
+/// This parameter is not visible to Yarn scripts, i.e. it is not passed as an argument and not counted towards the parameters of the function.
+/// Because the context is handed over on every call, functions using it always see the dialogue's current variable storage and program,
+/// unlike functions that capture a clone of them when they are registered.
+///
+/// The `()` implementation provides an empty context, which is useful when calling a function outside of a running dialogue.
+///
+/// ## Examples
+/// ```rust
+/// # use yarnspinner_core::prelude::*;
+/// fn has_gold(context: &dyn YarnFnContext, amount: f32) -> bool {
+///     match context.variable("$gold") {
+///         Some(YarnValue::Number(gold)) => gold >= amount,
+///         _ => false,
+///     }
+/// }
+///
+/// let mut library = Library::new();
+/// library.add_function("has_gold", has_gold);
+/// ```
+/// Which may be called from Yarn as follows:
+/// ```text
+/// <<if has_gold(10)>>
+///     Merchant: That will be 10 gold, please.
+/// <<endif>>
+/// ```
+pub trait YarnFnContext {
+    /// Gets the value of the variable with the given name, including the leading `$`.
+    /// Returns [`None`] if the variable storage has no value for it.
+    fn variable(&self, name: &str) -> Option<YarnValue>;
+
+    /// The name of the node that is currently being run, if any.
+    fn current_node(&self) -> Option<&str>;
+
+    /// The IETF BCP 47 code of the language the dialogue is currently set to, if any.
+    fn language_code(&self) -> Option<String>;
+
+    /// The IDs of the lines that have been delivered by the dialogue so far, in the order they were delivered.
+    /// The dialogue may only keep a limited number of the most recent lines.
+    fn line_history(&self) -> &[LineId];
+}
+
+impl YarnFnContext for () {
+    fn variable(&self, _name: &str) -> Option<YarnValue> {
+        None
+    }
+
+    fn current_node(&self) -> Option<&str> {
+        None
+    }
+
+    fn language_code(&self) -> Option<String> {
+        None
+    }
+
+    fn line_history(&self) -> &[LineId] {
+        &[]
+    }
+}
@@ -39,7 +39,7 @@ impl YarnFnRegistry {
     where
         Marker: 'static,
         F: YarnFn<Marker> + 'static + Clone,
-        F::Out: IntoYarnValueFromNonYarnValue + 'static + Clone,
+        F::Out: YarnFnReturn + 'static,
     {
         let name = name.into();
         let wrapped = YarnFnWrapper::from(function);
@@ -104,7 +104,7 @@ mod tests {
 
         functions.register_function("test", || true);
         let function = functions.get("test").unwrap();
-        let result: bool = function.call(vec![]).try_into().unwrap();
+        let result: bool = function.call(vec![], &()).unwrap().try_into().unwrap();
 
         assert!(result);
     }
@@ -115,7 +115,11 @@ mod tests {
 
         functions.register_function("test", |a: f32| a);
         let function = functions.get("test").unwrap();
-        let result: f32 = function.call(to_function_params([1.0])).try_into().unwrap();
+        let result: f32 = function
+            .call(to_function_params([1.0]), &())
+            .unwrap()
+            .try_into()
+            .unwrap();
 
         assert_eq!(result, 1.0);
     }
@@ -137,9 +141,10 @@ mod tests {
         let function1 = functions.get("test1").unwrap();
         let function2 = functions.get("test2").unwrap();
 
-        let result1: bool = function1.call(vec![]).try_into().unwrap();
+        let result1: bool = function1.call(vec![], &()).unwrap().try_into().unwrap();
         let result2: f32 = function2
-            .call(to_function_params([1.0]))
+            .call(to_function_params([1.0]), &())
+            .unwrap()
             .try_into()
             .unwrap();
 
@@ -164,23 +169,29 @@ mod tests {
         let function3 = functions.get("test3").unwrap();
         let function4 = functions.get("test4").unwrap();
 
-        let result1: bool = function1.call(vec![]).try_into().unwrap();
+        let result1: bool = function1.call(vec![], &()).unwrap().try_into().unwrap();
         let result2: f32 = function2
-            .call(to_function_params([1.0, 2.0]))
+            .call(to_function_params([1.0, 2.0]), &())
+            .unwrap()
             .try_into()
             .unwrap();
         let result3: f32 = function3
-            .call(to_function_params([1.0, 2.0, 3.0]))
+            .call(to_function_params([1.0, 2.0, 3.0]), &())
+            .unwrap()
             .try_into()
             .unwrap();
         let result4: String = function4
-            .call(to_function_params([
-                YarnValue::from("a"),
-                "b".into(),
-                "c".into(),
-                true.into(),
-                1.0.into(),
-            ]))
+            .call(
+                to_function_params([
+                    YarnValue::from("a"),
+                    "b".into(),
+                    "c".into(),
+                    true.into(),
+                    1.0.into(),
+                ]),
+                &(),
+            )
+            .unwrap()
             .into();
 
         assert!(result1);
@@ -8,6 +8,8 @@ use yarnspinner_macros::all_tuples;
 /// A function that can be registered into and called from Yarn.
 /// It must have the following properties:
 /// - It is allowed to have zero or more parameters
+/// - Trailing parameters may be optional by wrapping them in an [`Option`], and the last parameter may be a [`Variadic`] to accept any number of arguments.
+/// - It may take a `&dyn YarnFnContext` as its first parameter to read the state of the dialogue calling it. See [`YarnFnContext`].
 /// - Each parameter must be a [`YarnFnParam`], which means of the following types or a reference to them:
 ///   - [`bool`]
 ///   - A numeric type, i.e. one of [`f32`], [`f64`], [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`], [`isize`]
@@ -19,6 +21,7 @@ use yarnspinner_macros::all_tuples;
 ///   - [`bool`]
 ///   - A numeric type, i.e. one of [`f32`], [`f64`], [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`], [`isize`]
 ///   - [`String`]
+///   - A [`Result`] wrapping one of the above types, whose error can be converted into a [`YarnFnError`]. See [`YarnFnReturn`].
 ///
 /// Note that in particular, no references can be returned.
 /// ## Examples
@@ -36,14 +39,23 @@ use yarnspinner_macros::all_tuples;
 /// ```
 pub trait YarnFn<Marker>: Clone + Send + Sync {
     /// The type of the value returned by this function. See [`YarnFn`] for more information about what is allowed.
-    type Out: IntoYarnValueFromNonYarnValue + 'static;
+    type Out: YarnFnReturn + 'static;
     #[doc(hidden)]
-    fn call(&self, input: Vec<YarnValue>) -> Self::Out;
-    /// The [`TypeId`]s of the parameters of this function.
+    fn call(&self, input: Vec<YarnValue>, context: &dyn YarnFnContext) -> Self::Out;
+    /// The [`TypeId`]s of the parameters of this function, not including a [`YarnFnContext`] or a [`Variadic`] parameter.
+    /// Tuples are flattened and [`Option`]s are unwrapped.
     fn parameter_types(&self) -> Vec<TypeId>;
-    /// The [`TypeId`] of the return type of this function.
+    /// The number of trailing parameters in [`YarnFn::parameter_types`] that may be omitted by the caller.
+    fn optional_parameter_count(&self) -> usize {
+        0
+    }
+    /// The [`TypeId`] of the values accepted by a trailing [`Variadic`] parameter, if this function has one.
+    fn variadic_parameter_type(&self) -> Option<TypeId> {
+        None
+    }
+    /// The [`TypeId`] of the return type of this function. For fallible functions, this is the type returned on success.
     fn return_type(&self) -> TypeId {
-        TypeId::of::<Self::Out>()
+        TypeId::of::<<Self::Out as YarnFnReturn>::Value>()
     }
 }
 
@@ -51,15 +63,54 @@ pub trait YarnFn<Marker>: Clone + Send + Sync {
 /// See its documentation for more information about what kind of functions are allowed.
 pub trait UntypedYarnFn: Debug + Display + Send + Sync {
     #[doc(hidden)]
-    fn call(&self, input: Vec<YarnValue>) -> YarnValue;
+    fn call(
+        &self,
+        input: Vec<YarnValue>,
+        context: &dyn YarnFnContext,
+    ) -> Result<YarnValue, YarnFnError>;
     #[doc(hidden)]
     fn clone_box(&self) -> Box<dyn UntypedYarnFn>;
-    /// The [`TypeId`]s of the parameters of this function.
+    /// The [`TypeId`] of the wrapped function. Two functions with the same ID have the same implementation.
+    #[doc(hidden)]
+    fn function_type_id(&self) -> TypeId;
+    /// The [`TypeId`]s of the parameters of this function, not including a [`YarnFnContext`] or a [`Variadic`] parameter.
     fn parameter_types(&self) -> Vec<TypeId>;
+    /// The number of trailing parameters in [`UntypedYarnFn::parameter_types`] that may be omitted by the caller.
+    fn optional_parameter_count(&self) -> usize {
+        0
+    }
+    /// The [`TypeId`] of the values accepted by a trailing [`Variadic`] parameter, if this function has one.
+    fn variadic_parameter_type(&self) -> Option<TypeId> {
+        None
+    }
     /// The [`TypeId`] of the return type of this function.
     fn return_type(&self) -> TypeId;
 }
 
+/// Returns an error if a function with the signature of `function` cannot be called with `argument_count` arguments.
+/// Checked before calling a [`YarnFn`], which panics on the wrong number of arguments.
+fn check_argument_count(
+    function: &dyn UntypedYarnFn,
+    argument_count: usize,
+) -> Result<(), YarnFnError> {
+    let parameter_count = function.parameter_types().len();
+    let required_parameter_count = parameter_count - function.optional_parameter_count();
+    let is_variadic = function.variadic_parameter_type().is_some();
+    if argument_count >= required_parameter_count
+        && (is_variadic || argument_count <= parameter_count)
+    {
+        return Ok(());
+    }
+    let expected = if is_variadic {
+        format!("at least {required_parameter_count}")
+    } else if required_parameter_count < parameter_count {
+        format!("between {required_parameter_count} and {parameter_count}")
+    } else {
+        parameter_count.to_string()
+    };
+    Err(format!("Expected {expected} arguments, but received {argument_count}").into())
+}
+
 impl Clone for Box<dyn UntypedYarnFn> {
     fn clone(&self) -> Self {
         self.clone_box()
@@ -70,21 +121,38 @@ impl<Marker, F> UntypedYarnFn for YarnFnWrapper<Marker, F>
 where
     Marker: 'static,
     F: YarnFn<Marker> + 'static + Clone,
-    F::Out: IntoYarnValueFromNonYarnValue + 'static + Clone,
+    F::Out: YarnFnReturn + 'static,
 {
-    fn call(&self, input: Vec<YarnValue>) -> YarnValue {
-        let output = self.function.call(input);
-        output.into_yarn_value()
+    fn call(
+        &self,
+        input: Vec<YarnValue>,
+        context: &dyn YarnFnContext,
+    ) -> Result<YarnValue, YarnFnError> {
+        check_argument_count(self, input.len())?;
+        let output = self.function.call(input, context);
+        output.into_yarn_fn_result()
     }
 
     fn clone_box(&self) -> Box<dyn UntypedYarnFn> {
         Box::new(self.clone())
     }
 
+    fn function_type_id(&self) -> TypeId {
+        TypeId::of::<F>()
+    }
+
     fn parameter_types(&self) -> Vec<TypeId> {
         self.function.parameter_types()
     }
 
+    fn optional_parameter_count(&self) -> usize {
+        self.function.optional_parameter_count()
+    }
+
+    fn variadic_parameter_type(&self) -> Option<TypeId> {
+        self.function.variadic_parameter_type()
+    }
+
     fn return_type(&self) -> TypeId {
         self.function.return_type()
     }
@@ -163,12 +231,17 @@ impl Eq for Box<dyn UntypedYarnFn> {}
 /// This is useful when registering functions in a [`Library`] with [`Library::add_function`].
 #[macro_export]
 macro_rules! yarn_fn_type {
-    (impl Fn($($param:ty),+) -> $ret:ty) => {
-        impl $crate::prelude::YarnFn<fn($($param),+) -> $ret, Out = $ret>
+    (impl Fn($($param:ty),*) -> $ret:ty) => {
+        impl $crate::prelude::YarnFn<fn($($param),*) -> $ret, Out = $ret>
     };
 }
 pub use yarn_fn_type;
 
+/// Marker used to distinguish [`YarnFn`]s taking a [`YarnFnContext`] as their first parameter from those that don't.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct WithYarnFnContext;
+
 /// Adapted from <https://github.com/bevyengine/bevy/blob/fe852fd0adbce6856f5886d66d20d62cfc936287/crates/bevy_ecs/src/system/system_param.rs#L1370>
 macro_rules! impl_yarn_fn_tuple {
     ($($param: ident),*) => {
@@ -179,13 +252,13 @@ macro_rules! impl_yarn_fn_tuple {
                 Send + Sync + Clone +
                 Fn($($param,)*) -> O +
                 Fn($(<$param as YarnFnParam>::Item<'a>,)*) -> O,
-            O: IntoYarnValueFromNonYarnValue + 'static,
+            O: YarnFnReturn + 'static,
             $($param: YarnFnParam + 'static,)*
             ($(<$param as YarnFnParam>::Optionality,)*): AllowedOptionalityChain,
             {
                 type Out = O;
                 #[allow(non_snake_case)]
-                fn call(&self, input: Vec<YarnValue>) -> Self::Out {
+                fn call(&self, input: Vec<YarnValue>, _context: &dyn YarnFnContext) -> Self::Out {
                     let mut params: Vec<_> = input.into_iter().map(YarnValueWrapper::from).collect();
 
                     #[allow(unused_variables, unused_mut)] // for n = 0 tuples
@@ -195,6 +268,7 @@ macro_rules! impl_yarn_fn_tuple {
                     let input = (
                         $($param::retrieve(&mut iter),)*
                     );
+                    // `UntypedYarnFn::call` returns an error for the wrong number of arguments before getting here.
                     assert!(iter.next().is_none(), "Passed too many arguments to YarnFn");
 
                     let ($($param,)*) = input;
@@ -202,7 +276,57 @@ macro_rules! impl_yarn_fn_tuple {
                 }
 
                 fn parameter_types(&self) -> Vec<TypeId> {
-                    vec![$(TypeId::of::<$param>()),*]
+                    YarnFnParameterTypes::of::<($($param,)*)>().types
+                }
+
+                fn optional_parameter_count(&self) -> usize {
+                    YarnFnParameterTypes::of::<($($param,)*)>().optional_count()
+                }
+
+                fn variadic_parameter_type(&self) -> Option<TypeId> {
+                    YarnFnParameterTypes::of::<($($param,)*)>().variadic
+                }
+            }
+
+        #[allow(non_snake_case)]
+        impl<F, O, $($param,)*> YarnFn<(WithYarnFnContext, fn($($param,)*) -> O)> for F
+            where
+            for<'a, 'c> F:
+                Send + Sync + Clone +
+                Fn(&'c dyn YarnFnContext, $($param,)*) -> O +
+                Fn(&'c dyn YarnFnContext, $(<$param as YarnFnParam>::Item<'a>,)*) -> O,
+            O: YarnFnReturn + 'static,
+            $($param: YarnFnParam + 'static,)*
+            ($(<$param as YarnFnParam>::Optionality,)*): AllowedOptionalityChain,
+            {
+                type Out = O;
+                #[allow(non_snake_case)]
+                fn call(&self, input: Vec<YarnValue>, context: &dyn YarnFnContext) -> Self::Out {
+                    let mut params: Vec<_> = input.into_iter().map(YarnValueWrapper::from).collect();
+
+                    #[allow(unused_variables, unused_mut)] // for n = 0 tuples
+                    let mut iter = params.iter_mut().peekable();
+
+                    let input = (
+                        $($param::retrieve(&mut iter),)*
+                    );
+                    // `UntypedYarnFn::call` returns an error for the wrong number of arguments before getting here.
+                    assert!(iter.next().is_none(), "Passed too many arguments to YarnFn");
+
+                    let ($($param,)*) = input;
+                    self(context, $($param,)*)
+                }
+
+                fn parameter_types(&self) -> Vec<TypeId> {
+                    YarnFnParameterTypes::of::<($($param,)*)>().types
+                }
+
+                fn optional_parameter_count(&self) -> usize {
+                    YarnFnParameterTypes::of::<($($param,)*)>().optional_count()
+                }
+
+                fn variadic_parameter_type(&self) -> Option<TypeId> {
+                    YarnFnParameterTypes::of::<($($param,)*)>().variadic
                 }
             }
     };
@@ -357,13 +481,87 @@ mod tests {
         accept_yarn_fn(f);
     }
 
+    #[test]
+    fn accepts_context_as_first_param() {
+        fn f(_: &dyn YarnFnContext, _: &str, _: Option<usize>) -> bool {
+            true
+        }
+        accept_yarn_fn(f);
+    }
+
+    #[test]
+    fn passes_context_without_counting_it_as_param() {
+        struct TestContext(Vec<LineId>);
+        impl YarnFnContext for TestContext {
+            fn variable(&self, name: &str) -> Option<YarnValue> {
+                (name == "$gold").then(|| 10.into())
+            }
+
+            fn current_node(&self) -> Option<&str> {
+                Some("Start")
+            }
+
+            fn language_code(&self) -> Option<String> {
+                None
+            }
+
+            fn line_history(&self) -> &[LineId] {
+                &self.0
+            }
+        }
+
+        let f = |context: &dyn YarnFnContext, name: &str| {
+            format!(
+                "{} {:?} {}",
+                context.current_node().unwrap(),
+                context.variable(name),
+                context.line_history().len()
+            )
+        };
+        assert_eq!(1, YarnFn::parameter_types(&f).len());
+
+        let context = TestContext(vec!["line:a".into()]);
+        let result = YarnFn::call(&f, vec!["$gold".into()], &context);
+        assert_eq!("Start Some(Number(10.0)) 1", result);
+    }
+
+    #[test]
+    fn reports_optional_and_variadic_parameters() {
+        fn f(_: &str, _: (f32, bool), _: Option<String>, _: Variadic<f32>) -> bool {
+            true
+        }
+        assert_eq!(
+            vec![
+                TypeId::of::<&str>(),
+                TypeId::of::<f32>(),
+                TypeId::of::<bool>(),
+                TypeId::of::<String>()
+            ],
+            f.parameter_types()
+        );
+        assert_eq!(1, f.optional_parameter_count());
+        assert_eq!(Some(TypeId::of::<f32>()), f.variadic_parameter_type());
+    }
+
+    #[test]
+    fn collects_variadic_arguments() {
+        fn f(first: usize, rest: Variadic<usize>) -> usize {
+            first * 100 + rest.iter().sum::<usize>()
+        }
+        assert_eq!(100, apply_yarn_fn(f, vec![1.into()]));
+        assert_eq!(
+            109,
+            apply_yarn_fn(f, (1..=4).map(YarnValue::from).collect())
+        );
+    }
+
     fn accept_yarn_fn<Marker>(_: impl YarnFn<Marker>) {}
 
     fn apply_yarn_fn<T, Marker>(f: T, input: Vec<YarnValue>) -> T::Out
     where
         T: YarnFn<Marker>,
     {
-        f.call(input)
+        f.call(input, &())
     }
 
     mod optionality {
@@ -392,6 +590,11 @@ mod tests {
         assert_is_yarn_fn! { (Option<()>, Option<()>, Option<()>, Option<()>) -> bool }
         assert_is_not_yarn_fn! { (Option<()>, Option<()>, Option<()>, ()) -> bool }
 
+        assert_is_yarn_fn! { (Variadic<f32>) -> bool }
+        assert_is_yarn_fn! { ((), Option<()>, Variadic<f32>) -> bool }
+        assert_is_not_yarn_fn! { (Variadic<f32>, ()) -> bool }
+        assert_is_not_yarn_fn! { (Variadic<f32>, Option<()>) -> bool }
+
         assert_is_yarn_fn! { (((), (), ()), ((), Option<()>), (Option<()>, Option<()>)) -> bool }
         assert_is_yarn_fn! { ((), ((), ((), ((), Option<()>)))) -> bool }
         assert_is_not_yarn_fn! { ((), ((), ((), ((), Option<()>))), ()) -> bool }
@@ -2,13 +2,14 @@
 //!
 //! Inspired by <https://promethia-27.github.io/dependency_injection_like_bevy_from_scratch/chapter2/passing_references.html>
 
-use super::optionality::{AllowedOptionalityChain, Optional, Optionality, Required};
+use super::optionality::{self, AllowedOptionalityChain, Optional, Optionality, Required};
 use crate::prelude::*;
-use std::any::Any;
+use std::any::{Any, TypeId};
 use std::borrow::Borrow;
 use std::fmt::{Debug, Display};
 use std::iter::Peekable;
 use std::marker::PhantomData;
+use std::ops::Deref;
 use std::slice::IterMut;
 use yarnspinner_macros::all_tuples;
 
@@ -52,6 +53,8 @@ impl YarnValueWrapper {
 /// - [`String`] (for a reference, [`&str`] may be used instead of `&String`)
 /// - [`YarnValue`], which means that a parameter may be any of the above types
 /// - Tuples of the above types.
+/// - [`Option`]s of the above types, which makes the parameter optional. Optional parameters may only be followed by other optional parameters.
+/// - [`Variadic`] of the above non-reference types, which accepts any number of trailing arguments. It must be the last parameter.
 pub trait YarnFnParam {
     /// The item type returned when constructing this [`YarnFn`] param. The value of this associated type should be `Self`, instantiated with a new lifetime.
     /// You could think of `YarnFnParam::Item<'new>` as being an operation that changes the lifetime bound to `Self`.
@@ -65,6 +68,39 @@ pub trait YarnFnParam {
 
     #[doc(hidden)]
     fn retrieve<'a>(iter: &mut YarnValueWrapperIter<'a>) -> Self::Item<'a>;
+
+    #[doc(hidden)]
+    fn collect_parameter_types(signature: &mut YarnFnParameterTypes)
+    where
+        Self: Sized + 'static,
+    {
+        signature.types.push(TypeId::of::<Self>());
+    }
+}
+
+/// The parameters of a [`YarnFn`] as seen from Yarn, built up by [`YarnFnParam::collect_parameter_types`].
+#[doc(hidden)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct YarnFnParameterTypes {
+    /// The types of all parameters, with tuples flattened and [`Option`]s unwrapped. Does not include a [`Variadic`] parameter.
+    pub types: Vec<TypeId>,
+    /// The index in `types` from which on all parameters are optional.
+    pub first_optional: Option<usize>,
+    /// The type of the values accepted by a [`Variadic`] parameter.
+    pub variadic: Option<TypeId>,
+}
+
+impl YarnFnParameterTypes {
+    pub(crate) fn of<T: YarnFnParam + 'static>() -> Self {
+        let mut signature = Self::default();
+        T::collect_parameter_types(&mut signature);
+        signature
+    }
+
+    pub(crate) fn optional_count(&self) -> usize {
+        self.first_optional
+            .map_or(0, |first_optional| self.types.len() - first_optional)
+    }
 }
 
 /// Shorthand way of accessing the associated type [`YarnFnParam::Item`] for a given [`YarnFnParam`].
@@ -81,6 +117,76 @@ impl<T: YarnFnParam> YarnFnParam for Option<T> {
             None
         }
     }
+
+    fn collect_parameter_types(signature: &mut YarnFnParameterTypes)
+    where
+        Self: Sized + 'static,
+    {
+        let first_optional = signature.types.len();
+        T::collect_parameter_types(signature);
+        signature.first_optional.get_or_insert(first_optional);
+    }
+}
+
+/// A [`YarnFnParam`] that collects all remaining arguments of a call, so that a [`YarnFn`] can be called with any number of them.
+/// Must be the last parameter of the function.
+///
+/// ## Examples
+/// ```rust
+/// # use yarnspinner_core::prelude::*;
+/// fn sum(first: f32, rest: Variadic<f32>) -> f32 {
+///     first + rest.iter().sum::<f32>()
+/// }
+///
+/// let mut library = Library::new();
+/// library.add_function("sum", sum);
+/// ```
+/// Which may be called from Yarn as follows:
+/// ```text
+/// The total is {sum(1, 2, 3, 4)}.
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Variadic<T>(pub Vec<T>);
+
+impl<T> Deref for Variadic<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> IntoIterator for Variadic<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T> YarnFnParam for Variadic<T>
+where
+    T: TryFrom<YarnValue> + 'static,
+    <T as TryFrom<YarnValue>>::Error: Display,
+{
+    type Item<'new> = Variadic<T>;
+    type Optionality = optionality::Variadic;
+
+    fn retrieve<'a>(iter: &mut YarnValueWrapperIter<'a>) -> Self::Item<'a> {
+        let mut values = Vec::new();
+        while iter.peek().is_some() {
+            values.push(ResOwned::<T>::retrieve(iter).value);
+        }
+        Variadic(values)
+    }
+
+    fn collect_parameter_types(signature: &mut YarnFnParameterTypes)
+    where
+        Self: Sized + 'static,
+    {
+        signature.variadic = Some(TypeId::of::<T>());
+    }
 }
 
 macro_rules! impl_yarn_fn_param_tuple {
@@ -97,6 +203,14 @@ macro_rules! impl_yarn_fn_param_tuple {
             fn retrieve<'a>(iter: &mut YarnValueWrapperIter<'a>) -> Self::Item<'a> {
                ($($param::retrieve(iter),)*)
             }
+
+            #[allow(unused_variables)] // for n = 0 tuples
+            fn collect_parameter_types(signature: &mut YarnFnParameterTypes)
+            where
+                Self: Sized + 'static,
+            {
+                $($param::collect_parameter_types(signature);)*
+            }
         }
     };
 }
@@ -0,0 +1,50 @@
+use crate::prelude::*;
+use std::error::Error;
+
+/// The error returned by a fallible [`YarnFn`], i.e. a function returning a [`Result`].
+pub type YarnFnError = Box<dyn Error + Send + Sync>;
+
+/// Trait implemented by all types that are allowed to be returned by a [`YarnFn`].
+/// These are all [`IntoYarnValueFromNonYarnValue`]s as well as [`Result`]s wrapping them,
+/// as long as the error can be converted into a [`YarnFnError`].
+///
+/// A function returning an [`Err`] will stop the dialogue with an error
+/// that contains the name of the function and the location of the call.
+///
+/// ## Examples
+/// ```rust
+/// # use yarnspinner_core::prelude::*;
+/// let mut library = Library::new();
+/// library.add_function("parse_int", |text: &str| text.trim().parse::<i32>());
+/// ```
+pub trait YarnFnReturn {
+    /// The type that will be turned into a [`YarnValue`] on success. Determines the return type of the function as seen by Yarn.
+    type Value: IntoYarnValueFromNonYarnValue + 'static;
+
+    #[doc(hidden)]
+    fn into_yarn_fn_result(self) -> Result<YarnValue, YarnFnError>;
+}
+
+impl<T> YarnFnReturn for T
+where
+    T: IntoYarnValueFromNonYarnValue + 'static,
+{
+    type Value = T;
+
+    fn into_yarn_fn_result(self) -> Result<YarnValue, YarnFnError> {
+        Ok(self.into_yarn_value())
+    }
+}
+
+impl<T, E> YarnFnReturn for Result<T, E>
+where
+    T: IntoYarnValueFromNonYarnValue + 'static,
+    E: Into<YarnFnError>,
+{
+    type Value = T;
+
+    fn into_yarn_fn_result(self) -> Result<YarnValue, YarnFnError> {
+        self.map(IntoYarnValueFromNonYarnValue::into_yarn_value)
+            .map_err(Into::into)
+    }
+}
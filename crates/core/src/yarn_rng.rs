@@ -0,0 +1,186 @@
+//! The seedable random number generator used by the random functions of the standard library.
+//! The original implementation uses a global `System.Random` instead.
+
+#[cfg(feature = "serde")]
+use crate::prelude::*;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// A seedable source of randomness that is shared by all functions drawing from it,
+/// such as `random`, `random_range` and `dice` of [`Library::standard_library`].
+///
+/// Cloning a [`YarnRng`] creates a shallow clone, i.e. a clone that shares the same underlying state.
+/// This way, a [`Dialogue`](https://docs.rs/yarnspinner_runtime/latest/yarnspinner_runtime/prelude/struct.Dialogue.html)
+/// and all functions registered to its [`Library`] draw from the same sequence of random numbers.
+///
+/// The generated sequence only depends on the seed, so two [`YarnRng`]s with the same seed
+/// will produce the same numbers on every platform. The entire state of the generator is a single [`u64`]
+/// that can be read with [`YarnRng::state`] and restored with [`YarnRng::set_state`] when saving and loading a game.
+///
+/// ## Examples
+///
+/// Registering a custom function that draws from the same randomness as the standard library:
+/// ```
+/// # use yarnspinner_core::prelude::*;
+/// let rng = YarnRng::from_seed(42);
+/// let mut library = Library::standard_library_with_rng(rng.clone());
+/// library.add_function("coin_flip", move || rng.gen_bool());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "u64", into = "u64"))]
+pub struct YarnRng(Arc<Mutex<u64>>);
+
+impl Default for YarnRng {
+    fn default() -> Self {
+        Self::from_entropy()
+    }
+}
+
+impl YarnRng {
+    /// Creates a new [`YarnRng`] that will always produce the same sequence of numbers for the same `seed`.
+    pub fn from_seed(seed: u64) -> Self {
+        Self(Arc::new(Mutex::new(seed)))
+    }
+
+    /// Creates a new [`YarnRng`] seeded with a value that is different for every call.
+    pub fn from_entropy() -> Self {
+        Self::from_seed(entropy())
+    }
+
+    /// Resets the generator to the beginning of the sequence determined by `seed`.
+    /// All shallow clones of this [`YarnRng`] are affected as well.
+    pub fn seed(&self, seed: u64) {
+        self.set_state(seed);
+    }
+
+    /// Returns the current internal state of the generator. Pass it to [`YarnRng::set_state`] to continue the sequence from this point later.
+    pub fn state(&self) -> u64 {
+        *self.0.lock().unwrap()
+    }
+
+    /// Restores an internal state previously returned by [`YarnRng::state`].
+    /// All shallow clones of this [`YarnRng`] are affected as well.
+    pub fn set_state(&self, state: u64) {
+        *self.0.lock().unwrap() = state;
+    }
+
+    /// Returns the next random [`u64`] of the sequence.
+    ///
+    /// ## Implementation notes
+    ///
+    /// Uses SplitMix64, which is simple, fast, has a state of a single [`u64`] and is fully deterministic across platforms.
+    pub fn next_u64(&self) -> u64 {
+        let mut state = self.0.lock().unwrap();
+        *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = *state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a random number in the range `[0, 1)`.
+    pub fn gen_f32(&self) -> f32 {
+        // Use the upper 24 bits, as that is the precision of an f32 mantissa.
+        (self.next_u64() >> 40) as f32 / (1_u32 << 24) as f32
+    }
+
+    /// Returns `true` or `false` with equal probability.
+    pub fn gen_bool(&self) -> bool {
+        self.next_u64() >> 63 == 1
+    }
+
+    /// Returns a random number in the range `[min, max)`. Returns `min` if the range is empty.
+    pub fn gen_range_f32(&self, min: f32, max: f32) -> f32 {
+        if min >= max {
+            return min;
+        }
+        let value = min + (max - min) * self.gen_f32();
+        // Rounding can push the value onto the exclusive upper bound.
+        if value < max {
+            value
+        } else {
+            min
+        }
+    }
+
+    /// Returns a random integer in the range `[min, max_inclusive]`. Returns `min` if the range is empty.
+    pub fn gen_range_inclusive(&self, min: i64, max_inclusive: i64) -> i64 {
+        if min >= max_inclusive {
+            return min;
+        }
+        let span = (max_inclusive as i128 - min as i128 + 1) as u128;
+        let offset = (self.next_u64() as u128 * span) >> 64;
+        (min as i128 + offset as i128) as i64
+    }
+}
+
+impl From<u64> for YarnRng {
+    fn from(state: u64) -> Self {
+        Self::from_seed(state)
+    }
+}
+
+impl From<YarnRng> for u64 {
+    fn from(rng: YarnRng) -> Self {
+        rng.state()
+    }
+}
+
+fn entropy() -> u64 {
+    // `RandomState` is seeded randomly per process; the counter makes sure that
+    // subsequent calls within the same process differ as well.
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(COUNTER.fetch_add(1, Ordering::Relaxed));
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_produces_same_sequence() {
+        let a = YarnRng::from_seed(1234);
+        let b = YarnRng::from_seed(1234);
+        let a: Vec<_> = (0..10).map(|_| a.next_u64()).collect();
+        let b: Vec<_> = (0..10).map(|_| b.next_u64()).collect();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn clones_share_state() {
+        let a = YarnRng::from_seed(1234);
+        let b = a.clone();
+        let reference = YarnRng::from_seed(1234);
+        assert_eq!(reference.next_u64(), a.next_u64());
+        assert_eq!(reference.next_u64(), b.next_u64());
+    }
+
+    #[test]
+    fn restoring_state_continues_sequence() {
+        let rng = YarnRng::from_seed(7);
+        rng.next_u64();
+        let state = rng.state();
+        let expected: Vec<_> = (0..5).map(|_| rng.next_u64()).collect();
+        rng.set_state(state);
+        let actual: Vec<_> = (0..5).map(|_| rng.next_u64()).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn ranges_are_respected() {
+        let rng = YarnRng::from_seed(0);
+        for _ in 0..1000 {
+            let float = rng.gen_range_f32(-2.0, 3.0);
+            assert!((-2.0..3.0).contains(&float));
+            let int = rng.gen_range_inclusive(1, 6);
+            assert!((1..=6).contains(&int));
+        }
+        assert_eq!(5, rng.gen_range_inclusive(5, 5));
+        assert_eq!(1.0, rng.gen_range_f32(1.0, 1.0));
+    }
+}
@@ -0,0 +1,94 @@
+//! Conversions between the types of the compiler and the ones of the language server protocol.
+//!
+//! The compiler counts the characters of a line in Unicode code points, while the protocol counts them in UTF-16 code units,
+//! so converting a position requires the text of the document.
+
+use std::ops::Range;
+use yarnspinner::compiler::{Diagnostic, DiagnosticSeverity};
+use yarnspinner::core::Position;
+
+pub(crate) fn to_lsp_position(text: &str, position: Position) -> lsp_types::Position {
+    let character = text
+        .lines()
+        .nth(position.line)
+        .map_or(position.character, |line| {
+            line.chars()
+                .take(position.character)
+                .map(char::len_utf16)
+                .sum()
+        });
+    lsp_types::Position {
+        line: position.line as u32,
+        character: character as u32,
+    }
+}
+
+pub(crate) fn from_lsp_position(text: &str, position: lsp_types::Position) -> Position {
+    let target = position.character as usize;
+    let character = text
+        .lines()
+        .nth(position.line as usize)
+        .map_or(target, |line| {
+            let mut code_units = 0;
+            line.chars()
+                .take_while(|c| {
+                    code_units += c.len_utf16();
+                    code_units <= target
+                })
+                .count()
+        });
+    Position {
+        line: position.line as usize,
+        character,
+    }
+}
+
+pub(crate) fn to_lsp_range(text: &str, range: &Range<Position>) -> lsp_types::Range {
+    lsp_types::Range {
+        start: to_lsp_position(text, range.start),
+        end: to_lsp_position(text, range.end),
+    }
+}
+
+pub(crate) fn to_lsp_diagnostic(text: &str, diagnostic: &Diagnostic) -> lsp_types::Diagnostic {
+    let range = diagnostic
+        .range
+        .as_ref()
+        .map(|range| to_lsp_range(text, range))
+        .unwrap_or_default();
+    let severity = match diagnostic.severity {
+        DiagnosticSeverity::Error => lsp_types::DiagnosticSeverity::ERROR,
+        DiagnosticSeverity::Warning => lsp_types::DiagnosticSeverity::WARNING,
+    };
+    lsp_types::Diagnostic {
+        range,
+        severity: Some(severity),
+        code: Some(lsp_types::NumberOrString::String(
+            diagnostic.code.as_str().to_owned(),
+        )),
+        source: Some("yarn".to_owned()),
+        message: diagnostic.message.clone(),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_positions_to_utf16() {
+        let text = "title: Start\n---\nSmile 😀 and wave\n===";
+        let position = Position {
+            line: 2,
+            character: 8,
+        };
+        let lsp_position = lsp_types::Position {
+            line: 2,
+            character: 9,
+        };
+
+        assert_eq!(lsp_position, to_lsp_position(text, position));
+        assert_eq!(position, from_lsp_position(text, lsp_position));
+    }
+}
@@ -0,0 +1,29 @@
+//! A [language server](https://microsoft.github.io/language-server-protocol/) for Yarn Spinner, providing diagnostics,
+//! go to definition, hover information, completion and document symbols for `.yarn` files in any editor that supports the protocol.
+//!
+//! The original Yarn Spinner has its own language server written in C#,
+//! while this one is built on the Rust compiler, so that it reports the same problems as the compiler a game uses.
+//!
+//! Editors start the `yarn-language-server` binary and talk to it over stdio.
+//! Games that register their own functions or variables can embed a [`LanguageServer`] in a binary of their own instead:
+//!
+//! ```no_run
+//! use yarnspinner::compiler::Compiler;
+//! use yarnspinner_language_server::{Connection, LanguageServer};
+//!
+//! let mut compiler = Compiler::new();
+//! compiler.library.add_function("is_raining", || false);
+//! let (connection, io_threads) = Connection::stdio();
+//! LanguageServer::with_compiler(compiler).run(&connection).unwrap();
+//! drop(connection);
+//! io_threads.join().unwrap();
+//! ```
+#![warn(missing_docs, missing_debug_implementations)]
+
+mod convert;
+mod outline;
+mod server;
+mod workspace;
+
+pub use lsp_server::Connection;
+pub use server::{LanguageServer, ServerError};
@@ -0,0 +1,12 @@
+//! The `yarn-language-server` binary, which serves a single editor over stdio.
+
+use yarnspinner_language_server::{Connection, LanguageServer, ServerError};
+
+fn main() -> Result<(), ServerError> {
+    let (connection, io_threads) = Connection::stdio();
+    LanguageServer::new().run(&connection)?;
+    // The I/O threads only stop once the connection is gone.
+    drop(connection);
+    io_threads.join()?;
+    Ok(())
+}
@@ -0,0 +1,375 @@
+//! The structure of a Yarn document as needed by the editor features: where its nodes are, and what it refers to.
+//!
+//! Both are read from the syntax tree of the document. While the document has syntax errors, the nodes are the ones the
+//! compiler found without errors, see [`Compilation::nodes`], and the references are read from the syntax tree of each of
+//! them on its own, so that a syntax error in one node only hides the references of that node.
+
+use std::iter;
+use std::ops::Range;
+use yarnspinner::compiler::ast::{self, *};
+use yarnspinner::compiler::{File, NodeInfo};
+use yarnspinner::core::Position;
+
+/// The nodes of a document and the names it refers to.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Outline {
+    pub(crate) nodes: Vec<NodeOutline>,
+    /// Names of nodes used in `<<jump>>`, `visited` and `visited_count`.
+    pub(crate) node_references: Vec<Reference>,
+    /// Variables used anywhere in a node body, including their `$`.
+    pub(crate) variable_references: Vec<Reference>,
+}
+
+/// A node of a document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct NodeOutline {
+    pub(crate) title: String,
+    /// The range of the value of the `title` header.
+    pub(crate) title_range: Range<Position>,
+    /// The range from the first header to the end of the `===` line.
+    pub(crate) range: Range<Position>,
+}
+
+/// A name used at a position in a document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Reference {
+    pub(crate) name: String,
+    pub(crate) range: Range<Position>,
+}
+
+impl Reference {
+    pub(crate) fn contains(&self, position: Position) -> bool {
+        self.range.start <= position && position <= self.range.end
+    }
+}
+
+impl Outline {
+    /// The outline of `source`, given the nodes the compiler found in it and the outline of the text before.
+    pub(crate) fn new<'a>(
+        source: &str,
+        nodes: impl IntoIterator<Item = &'a NodeInfo>,
+        previous: &Outline,
+    ) -> Self {
+        // The compiler does not count the BOM either.
+        let source = source.strip_prefix('\u{feff}').unwrap_or(source);
+        let lines: Vec<_> = source.lines().collect();
+        let mut outline = Self::default();
+
+        // A partial compilation leaves out the nodes with errors, so they are taken from the syntax tree if there is one.
+        if let Ok(dialogue) = parse(source) {
+            for node in &dialogue.nodes {
+                let title_header = node.headers.iter().find(|header| header.key == "title");
+                if let Some(header) = title_header {
+                    let line = header.range.start.line;
+                    outline.add_node(&lines, &header.value, node.range.clone(), line..line + 1);
+                }
+            }
+            ReferenceCollector {
+                outline: &mut outline,
+                first_line: 0,
+            }
+            .visit_dialogue(&dialogue);
+            return outline;
+        }
+
+        // Otherwise, the nodes without errors are parsed on their own.
+        let line_starts: Vec<_> = iter::once(0)
+            .chain(source.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+        for node in nodes {
+            let first_line = node.range.start.line;
+            let Some(&start) = line_starts.get(first_line) else {
+                continue;
+            };
+            let end = line_starts
+                .get(node.range.end.line + 1)
+                .copied()
+                .unwrap_or(source.len());
+            let header_lines = first_line..node.body_range.start.line;
+            outline.add_node(&lines, &node.title, node.range.clone(), header_lines);
+            if let Ok(dialogue) = parse(&source[start..end]) {
+                ReferenceCollector {
+                    outline: &mut outline,
+                    first_line,
+                }
+                .visit_dialogue(&dialogue);
+            }
+        }
+        // Like the variables of the workspace, the nodes with errors are kept from before so that they can still be found.
+        let missing_nodes: Vec<_> = previous
+            .nodes
+            .iter()
+            .filter(|previous| {
+                outline
+                    .nodes
+                    .iter()
+                    .all(|node| node.title != previous.title)
+            })
+            .cloned()
+            .collect();
+        outline.nodes.extend(missing_nodes);
+        outline
+    }
+
+    /// Adds the node named `title`, whose `title` header is on one of the `header_lines`.
+    fn add_node(
+        &mut self,
+        lines: &[&str],
+        title: &str,
+        range: Range<Position>,
+        header_lines: Range<usize>,
+    ) {
+        let title_range = header_lines
+            .filter_map(|index| Some((index, *lines.get(index)?)))
+            .find_map(|(index, line)| title_range(index, line, title))
+            .unwrap_or(range.start..range.start);
+        self.nodes.push(NodeOutline {
+            title: title.to_owned(),
+            title_range,
+            range,
+        });
+    }
+}
+
+fn parse(source: &str) -> yarnspinner::compiler::Result<Dialogue> {
+    ast::parse(&File {
+        file_name: String::new(),
+        source: source.to_owned(),
+    })
+}
+
+/// The range of the value of `line` if it is the `title` header of the node named `title`.
+fn title_range(line_index: usize, line: &str, title: &str) -> Option<Range<Position>> {
+    let (key, value) = line.split_once(':')?;
+    if key.trim() != "title" || value.trim() != title.trim() {
+        return None;
+    }
+    let start = key.chars().count() + 1 + leading_whitespace(value);
+    Some(
+        Position {
+            line: line_index,
+            character: start,
+        }..Position {
+            line: line_index,
+            character: start + value.trim().chars().count(),
+        },
+    )
+}
+
+/// Adds the references in the syntax tree of a single node to an [`Outline`].
+struct ReferenceCollector<'a> {
+    outline: &'a mut Outline,
+    /// The line of the document the node starts at, as the syntax tree counts from the start of the node.
+    first_line: usize,
+}
+
+impl ReferenceCollector<'_> {
+    fn reference(&self, name: &str, range: &Range<Position>) -> Reference {
+        let in_document = |position: &Position| Position {
+            line: self.first_line + position.line,
+            character: position.character,
+        };
+        Reference {
+            name: name.to_owned(),
+            range: in_document(&range.start)..in_document(&range.end),
+        }
+    }
+}
+
+impl Visitor for ReferenceCollector<'_> {
+    fn visit_jump(&mut self, jump: &JumpStatement) {
+        if let JumpDestination::Node { name, range } = &jump.destination {
+            let reference = self.reference(name, range);
+            self.outline.node_references.push(reference);
+        }
+        walk_jump(self, jump);
+    }
+
+    fn visit_function_call(&mut self, function_call: &FunctionCall) {
+        if let ("visited" | "visited_count", [argument]) = (
+            function_call.name.as_str(),
+            function_call.arguments.as_slice(),
+        ) {
+            if let ExpressionKind::String(name) = &argument.kind {
+                // Without the quotes
+                let mut range = argument.range.clone();
+                range.start.character += 1;
+                range.end.character = range.end.character.saturating_sub(1);
+                let reference = self.reference(name, &range);
+                self.outline.node_references.push(reference);
+            }
+        }
+        walk_function_call(self, function_call);
+    }
+
+    fn visit_variable(&mut self, variable: &Variable) {
+        let reference = self.reference(&variable.name, &variable.range);
+        self.outline.variable_references.push(reference);
+    }
+}
+
+/// The identifier around `position` in `source`, and whether it is called as a function, i.e. followed by an opening parenthesis.
+pub(crate) fn identifier_at(source: &str, position: Position) -> Option<(String, bool)> {
+    let line: Vec<char> = source.lines().nth(position.line)?.chars().collect();
+    let is_identifier = |c: &&char| c.is_alphanumeric() || **c == '_';
+    let cursor = position.character.min(line.len());
+    let start = cursor
+        - line[..cursor]
+            .iter()
+            .rev()
+            .take_while(is_identifier)
+            .count();
+    let end = cursor + line[cursor..].iter().take_while(is_identifier).count();
+    if start == end {
+        return None;
+    }
+    let is_call = line[end..].iter().find(|c| !c.is_whitespace()) == Some(&'(');
+    Some((line[start..end].iter().collect(), is_call))
+}
+
+fn leading_whitespace(text: &str) -> usize {
+    text.chars().take_while(|c| c.is_whitespace()).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yarnspinner::compiler::{CompilationType, Compiler};
+
+    fn position(line: usize, character: usize) -> Position {
+        Position { line, character }
+    }
+
+    fn outline(source: &str) -> Outline {
+        let mut compiler = Compiler::new();
+        compiler.compilation_type = CompilationType::DeclarationsOnly;
+        compiler.partial_compilation = true;
+        compiler.add_file(File {
+            file_name: "test.yarn".to_owned(),
+            source: source.to_owned(),
+        });
+        let compilation = compiler.compile().unwrap();
+        Outline::new(source, &compilation.nodes, &Outline::default())
+    }
+
+    #[test]
+    fn finds_nodes_and_references() {
+        let outline = outline(
+            "# file_tag
+title: Start
+tags: intro
+---
+Hello, {$name}!
+<<jump  Other>>
+===
+title:  Other
+---
+<<if visited(\"Start\")>>
+<<endif>>
+===
+",
+        );
+
+        assert_eq!(
+            outline.nodes,
+            vec![
+                NodeOutline {
+                    title: "Start".to_owned(),
+                    title_range: position(1, 7)..position(1, 12),
+                    range: position(1, 0)..position(6, 3),
+                },
+                NodeOutline {
+                    title: "Other".to_owned(),
+                    title_range: position(7, 8)..position(7, 13),
+                    range: position(7, 0)..position(11, 3),
+                },
+            ]
+        );
+        assert_eq!(
+            outline.node_references,
+            vec![
+                Reference {
+                    name: "Other".to_owned(),
+                    range: position(5, 8)..position(5, 13),
+                },
+                Reference {
+                    name: "Start".to_owned(),
+                    range: position(9, 14)..position(9, 19),
+                },
+            ]
+        );
+        assert_eq!(
+            outline.variable_references,
+            vec![Reference {
+                name: "$name".to_owned(),
+                range: position(4, 8)..position(4, 13),
+            }]
+        );
+    }
+
+    #[test]
+    fn ignores_names_in_comments_strings_and_text() {
+        let outline = outline(
+            "title: Start
+---
+// Uses $commented and <<jump Commented>>
+Costs $5 ===
+<<set $set to \"$quoted\">>
+---
+===
+",
+        );
+
+        assert_eq!(1, outline.nodes.len());
+        assert_eq!(position(0, 0)..position(6, 3), outline.nodes[0].range);
+        assert_eq!(Vec::<Reference>::new(), outline.node_references);
+        assert_eq!(
+            vec![Reference {
+                name: "$set".to_owned(),
+                range: position(4, 6)..position(4, 10),
+            }],
+            outline.variable_references
+        );
+    }
+
+    #[test]
+    fn keeps_the_references_of_nodes_without_errors() {
+        let outline = outline(
+            "title: Start
+---
+<<jump Other>>
+===
+title: Other
+---
+<<if $broken>>
+===
+",
+        );
+
+        let titles: Vec<_> = outline.nodes.iter().map(|node| &node.title).collect();
+        assert_eq!(vec!["Start"], titles);
+        assert_eq!(
+            vec![Reference {
+                name: "Other".to_owned(),
+                range: position(2, 7)..position(2, 12),
+            }],
+            outline.node_references
+        );
+        assert_eq!(Vec::<Reference>::new(), outline.variable_references);
+    }
+
+    #[test]
+    fn finds_identifiers() {
+        let source = "{round_places ($pi, 2)}";
+
+        assert_eq!(
+            Some(("round_places".to_owned(), true)),
+            identifier_at(source, position(0, 13))
+        );
+        assert_eq!(
+            Some(("pi".to_owned(), false)),
+            identifier_at(source, position(0, 17))
+        );
+        assert_eq!(None, identifier_at(source, position(0, 14)));
+    }
+}
@@ -0,0 +1,378 @@
+use crate::convert::*;
+use crate::outline::identifier_at;
+use crate::workspace::Workspace;
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+    Notification as LspNotification, PublishDiagnostics,
+};
+use lsp_types::request::{
+    Completion, DocumentSymbolRequest, GotoDefinition, HoverRequest, Request as LspRequest,
+};
+use lsp_types::*;
+use regex::Regex;
+use std::error::Error;
+use std::sync::OnceLock;
+use yarnspinner::compiler::{Compiler, Declaration};
+use yarnspinner::core::Position as YarnPosition;
+
+/// The error returned by [`LanguageServer::run`] when the connection to the client breaks down.
+pub type ServerError = Box<dyn Error + Send + Sync>;
+
+/// A language server for Yarn files.
+///
+/// All `.yarn` files in the workspace folders are compiled together, with the documents open in the editor taking precedence over the files on disk.
+/// The server offers:
+/// - Diagnostics, updated on every change.
+/// - Go to definition for the nodes used in `<<jump>>`, `<<detour>>` and `visited`, and for variables.
+/// - The types of variables and functions on hover.
+/// - Completion of node names, variables and functions.
+/// - A document symbol for each node.
+///
+/// ## Implementation notes
+///
+/// The files are compiled with [`CompilationType::DeclarationsOnly`](yarnspinner::compiler::CompilationType::DeclarationsOnly)
+/// in a [`CompilerSession`](yarnspinner::compiler::CompilerSession), so only the files affected by an edit are analysed again.
+/// Nodes and the names used in them are found by scanning the text, so that they are available while a file does not parse.
+#[derive(Debug)]
+pub struct LanguageServer {
+    workspace: Workspace,
+}
+
+impl Default for LanguageServer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LanguageServer {
+    /// Creates a server that knows about the functions of [`Library::standard_library`](yarnspinner::core::Library::standard_library).
+    pub fn new() -> Self {
+        Self::with_compiler(Compiler::new())
+    }
+
+    /// Creates a server that compiles the workspace with the [`Compiler::library`] and [`Compiler::variable_declarations`] of `compiler`,
+    /// e.g. to know about the functions and variables a game provides. The files of `compiler` are ignored.
+    pub fn with_compiler(compiler: Compiler) -> Self {
+        Self {
+            workspace: Workspace::new(compiler),
+        }
+    }
+
+    /// The capabilities announced to the client.
+    pub fn capabilities() -> ServerCapabilities {
+        ServerCapabilities {
+            text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+            definition_provider: Some(OneOf::Left(true)),
+            hover_provider: Some(HoverProviderCapability::Simple(true)),
+            completion_provider: Some(CompletionOptions {
+                trigger_characters: Some(vec!["$".to_owned(), " ".to_owned()]),
+                ..Default::default()
+            }),
+            document_symbol_provider: Some(OneOf::Left(true)),
+            ..Default::default()
+        }
+    }
+
+    /// Serves the client on the other end of `connection` until it shuts the server down.
+    /// Use [`Connection::stdio`] to talk to an editor, or [`Connection::memory`] to talk to a client in the same process.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the connection is closed before the client shut the server down properly.
+    pub fn run(&mut self, connection: &Connection) -> Result<(), ServerError> {
+        let capabilities = serde_json::to_value(Self::capabilities())?;
+        let params: InitializeParams =
+            serde_json::from_value(connection.initialize(capabilities)?)?;
+        for folder in params.workspace_folders.unwrap_or_default() {
+            if let Ok(path) = folder.uri.to_file_path() {
+                self.workspace.add_directory(&path);
+            }
+        }
+        self.workspace.compile();
+        self.publish_diagnostics(connection, None)?;
+
+        for message in &connection.receiver {
+            match message {
+                Message::Request(request) => {
+                    if connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+                    let response = self.handle_request(request);
+                    connection.sender.send(response.into())?;
+                }
+                Message::Notification(notification) => {
+                    self.handle_notification(connection, notification)?;
+                }
+                Message::Response(_) => {}
+            }
+        }
+        Err("The connection was closed before the server was shut down".into())
+    }
+
+    fn handle_request(&self, request: Request) -> Response {
+        match request.method.as_str() {
+            GotoDefinition::METHOD => respond::<GotoDefinition>(request, |params| {
+                self.definition(params.text_document_position_params)
+            }),
+            HoverRequest::METHOD => respond::<HoverRequest>(request, |params| {
+                self.hover(params.text_document_position_params)
+            }),
+            Completion::METHOD => respond::<Completion>(request, |params| {
+                self.completion(params.text_document_position)
+            }),
+            DocumentSymbolRequest::METHOD => respond::<DocumentSymbolRequest>(request, |params| {
+                self.document_symbols(&params.text_document.uri)
+            }),
+            _ => Response::new_err(
+                request.id,
+                ErrorCode::MethodNotFound as i32,
+                format!("Unsupported request: {}", request.method),
+            ),
+        }
+    }
+
+    fn handle_notification(
+        &mut self,
+        connection: &Connection,
+        notification: Notification,
+    ) -> Result<(), ServerError> {
+        let mut removed_document = None;
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let Some(params) = extract::<DidOpenTextDocument>(notification) else {
+                    return Ok(());
+                };
+                let document = params.text_document;
+                self.workspace.open(document.uri, document.text);
+            }
+            DidChangeTextDocument::METHOD => {
+                let Some(params) = extract::<DidChangeTextDocument>(notification) else {
+                    return Ok(());
+                };
+                // With full synchronization, the last change contains the whole text.
+                let Some(change) = params.content_changes.into_iter().last() else {
+                    return Ok(());
+                };
+                self.workspace.open(params.text_document.uri, change.text);
+            }
+            DidCloseTextDocument::METHOD => {
+                let Some(params) = extract::<DidCloseTextDocument>(notification) else {
+                    return Ok(());
+                };
+                let uri = params.text_document.uri;
+                if self.workspace.close(&uri) {
+                    removed_document = Some(uri);
+                }
+            }
+            _ => return Ok(()),
+        }
+        self.workspace.compile();
+        self.publish_diagnostics(connection, removed_document)
+    }
+
+    /// Sends the diagnostics of every document, and an empty list for the `removed_document` to clear its diagnostics.
+    fn publish_diagnostics(
+        &self,
+        connection: &Connection,
+        removed_document: Option<Url>,
+    ) -> Result<(), ServerError> {
+        let mut all_diagnostics: Vec<_> = self
+            .workspace
+            .documents()
+            .map(|(uri, document)| {
+                let diagnostics = self
+                    .workspace
+                    .diagnostics(uri)
+                    .iter()
+                    .map(|diagnostic| to_lsp_diagnostic(&document.text, diagnostic))
+                    .collect();
+                PublishDiagnosticsParams::new(uri.clone(), diagnostics, None)
+            })
+            .collect();
+        all_diagnostics.extend(
+            removed_document.map(|uri| PublishDiagnosticsParams::new(uri, Vec::new(), None)),
+        );
+        for params in all_diagnostics {
+            let notification = Notification::new(PublishDiagnostics::METHOD.to_owned(), params);
+            connection.sender.send(notification.into())?;
+        }
+        Ok(())
+    }
+
+    fn definition(&self, params: TextDocumentPositionParams) -> Option<GotoDefinitionResponse> {
+        let document = self.workspace.document(&params.text_document.uri)?;
+        let position = from_lsp_position(&document.text, params.position);
+        let outline = &document.outline;
+        let location = if let Some(reference) = outline
+            .node_references
+            .iter()
+            .find(|reference| reference.contains(position))
+        {
+            let (uri, node) = self.workspace.node(&reference.name)?;
+            let text = &self.workspace.document(uri)?.text;
+            Location {
+                uri: uri.clone(),
+                range: to_lsp_range(text, &node.title_range),
+            }
+        } else {
+            let reference = outline
+                .variable_references
+                .iter()
+                .find(|reference| reference.contains(position))?;
+            let declaration = self.workspace.variable(&reference.name)?;
+            self.workspace.location(declaration)?
+        };
+        Some(GotoDefinitionResponse::Scalar(location))
+    }
+
+    fn hover(&self, params: TextDocumentPositionParams) -> Option<Hover> {
+        let document = self.workspace.document(&params.text_document.uri)?;
+        let position = from_lsp_position(&document.text, params.position);
+        let declaration = if let Some(reference) = document
+            .outline
+            .variable_references
+            .iter()
+            .find(|reference| reference.contains(position))
+        {
+            self.workspace.variable(&reference.name)?
+        } else {
+            let (name, is_call) = identifier_at(&document.text, position)?;
+            if !is_call {
+                return None;
+            }
+            self.workspace.function(&name)?
+        };
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: describe(declaration),
+            }),
+            range: None,
+        })
+    }
+
+    fn completion(&self, params: TextDocumentPositionParams) -> Option<CompletionResponse> {
+        static VARIABLE_PREFIX: OnceLock<Regex> = OnceLock::new();
+        static NODE_PREFIX: OnceLock<Regex> = OnceLock::new();
+        static WORD_PREFIX: OnceLock<Regex> = OnceLock::new();
+        let variable_prefix = VARIABLE_PREFIX.get_or_init(|| Regex::new(r"\$\w*$").unwrap());
+        let node_prefix =
+            NODE_PREFIX.get_or_init(|| Regex::new(r"<<\s*(?:jump|detour)\s+[\w.]*$").unwrap());
+        let word_prefix = WORD_PREFIX.get_or_init(|| Regex::new(r"\$?[\w.]*$").unwrap());
+
+        let document = self.workspace.document(&params.text_document.uri)?;
+        let position = from_lsp_position(&document.text, params.position);
+        let line = document.text.lines().nth(position.line).unwrap_or_default();
+        let line_before_cursor: String = line.chars().take(position.character).collect();
+
+        let variables = self
+            .workspace
+            .variables()
+            .map(|declaration| CompletionItem {
+                label: declaration.name.clone(),
+                kind: Some(CompletionItemKind::VARIABLE),
+                detail: Some(declaration.r#type.to_string()),
+                documentation: declaration.description.clone().map(Documentation::String),
+                ..Default::default()
+            });
+        let nodes = self.workspace.nodes().map(|node| CompletionItem {
+            label: node.title.clone(),
+            kind: Some(CompletionItemKind::MODULE),
+            ..Default::default()
+        });
+        let word = word_prefix
+            .find(&line_before_cursor)
+            .map_or_else(String::new, |word| word.as_str().to_lowercase());
+        let items: Vec<_> = if let Some(prefix) = variable_prefix.find(&line_before_cursor) {
+            // Editors usually do not consider the `$` part of the word, so replace it explicitly.
+            let start = YarnPosition {
+                line: position.line,
+                character: line_before_cursor[..prefix.start()].chars().count(),
+            };
+            let range = to_lsp_range(&document.text, &(start..position));
+            variables
+                .map(|item| CompletionItem {
+                    text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                        range,
+                        new_text: item.label.clone(),
+                    })),
+                    ..item
+                })
+                .collect()
+        } else if node_prefix.is_match(&line_before_cursor) {
+            nodes.collect()
+        } else {
+            let functions = self
+                .workspace
+                .functions()
+                .iter()
+                .map(|declaration| CompletionItem {
+                    label: declaration.name.clone(),
+                    kind: Some(CompletionItemKind::FUNCTION),
+                    detail: Some(declaration.r#type.to_string()),
+                    ..Default::default()
+                });
+            nodes.chain(variables).chain(functions).collect()
+        };
+        // Only offer what the word being typed can still become, ignoring case.
+        let items = items
+            .into_iter()
+            .filter(|item| item.label.to_lowercase().starts_with(&word))
+            .collect();
+        Some(CompletionResponse::Array(items))
+    }
+
+    fn document_symbols(&self, uri: &Url) -> Option<DocumentSymbolResponse> {
+        let document = self.workspace.document(uri)?;
+        let symbols = document
+            .outline
+            .nodes
+            .iter()
+            .map(|node| {
+                #[allow(deprecated)]
+                // `DocumentSymbol::deprecated` has to be set, even though it is deprecated
+                DocumentSymbol {
+                    name: node.title.clone(),
+                    detail: None,
+                    kind: SymbolKind::MODULE,
+                    tags: None,
+                    deprecated: None,
+                    range: to_lsp_range(&document.text, &node.range),
+                    selection_range: to_lsp_range(&document.text, &node.title_range),
+                    children: None,
+                }
+            })
+            .collect();
+        Some(DocumentSymbolResponse::Nested(symbols))
+    }
+}
+
+/// Markdown showing the name and type of a declaration, its description and, for variables, its default value.
+fn describe(declaration: &Declaration) -> String {
+    let mut description = format!("```\n{}: {}\n```", declaration.name, declaration.r#type);
+    if let Some(text) = &declaration.description {
+        description.push_str("\n\n");
+        description.push_str(text);
+    }
+    if let Some(default_value) = &declaration.default_value {
+        description.push_str(&format!("\n\nDefault value: `{default_value}`"));
+    }
+    description
+}
+
+fn respond<R: LspRequest>(
+    request: Request,
+    handler: impl FnOnce(R::Params) -> R::Result,
+) -> Response {
+    let id = request.id.clone();
+    match request.extract::<R::Params>(R::METHOD) {
+        Ok((id, params)) => Response::new_ok(id, handler(params)),
+        Err(error) => Response::new_err(id, ErrorCode::InvalidParams as i32, error.to_string()),
+    }
+}
+
+/// The parameters of the notification, or [`None`] if the client sent invalid ones, which are ignored.
+fn extract<N: LspNotification>(notification: Notification) -> Option<N::Params> {
+    notification.extract::<N::Params>(N::METHOD).ok()
+}
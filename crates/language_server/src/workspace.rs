@@ -0,0 +1,242 @@
+//! The documents the language server knows about and what the compiler found out about them.
+
+use crate::convert::to_lsp_range;
+use crate::outline::{NodeOutline, Outline};
+use lsp_types::{Location, Url};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use yarnspinner::compiler::*;
+
+/// The `.yarn` files of the workspace, with the text of the open ones as it is in the editor.
+#[derive(Debug)]
+pub(crate) struct Workspace {
+    session: CompilerSession,
+    /// Sorted by URI, so that the files are always compiled in the same order.
+    documents: BTreeMap<Url, Document>,
+    /// The variables found by the last compilation. While a file has errors, the variables of the nodes with errors
+    /// are kept from the compilations before, so that the editor features keep working.
+    variables: Vec<Declaration>,
+    functions: Vec<Declaration>,
+    /// The diagnostics of the last compilation, by the URI of their file.
+    diagnostics: HashMap<String, Vec<Diagnostic>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Document {
+    pub(crate) text: String,
+    /// Built from the nodes of the last compilation after the text changed.
+    pub(crate) outline: Outline,
+    is_outline_current: bool,
+}
+
+impl Document {
+    fn new(text: String) -> Self {
+        Self {
+            text,
+            outline: Outline::default(),
+            is_outline_current: false,
+        }
+    }
+
+    /// Replaces the text, keeping the outline until the next compilation.
+    fn set_text(&mut self, text: String) {
+        self.text = text;
+        self.is_outline_current = false;
+    }
+}
+
+impl Workspace {
+    pub(crate) fn new(mut compiler: Compiler) -> Self {
+        compiler.compilation_type = CompilationType::DeclarationsOnly;
+        compiler.partial_compilation = true;
+        compiler.files.clear();
+        let functions = compiler.function_declarations();
+        Self {
+            session: CompilerSession::new(compiler),
+            documents: Default::default(),
+            variables: Default::default(),
+            functions,
+            diagnostics: Default::default(),
+        }
+    }
+
+    /// Adds all `.yarn` files in `directory` and its subdirectories that are not open yet.
+    pub(crate) fn add_directory(&mut self, directory: &Path) {
+        let Ok(entries) = std::fs::read_dir(directory) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let is_hidden = entry.file_name().to_string_lossy().starts_with('.');
+            if path.is_dir() && !is_hidden {
+                self.add_directory(&path);
+            } else if path
+                .extension()
+                .is_some_and(|extension| extension == "yarn")
+            {
+                let (Ok(uri), Ok(text)) =
+                    (Url::from_file_path(&path), std::fs::read_to_string(&path))
+                else {
+                    continue;
+                };
+                self.documents
+                    .entry(uri)
+                    .or_insert_with(|| Document::new(text));
+            }
+        }
+    }
+
+    pub(crate) fn open(&mut self, uri: Url, text: String) {
+        match self.documents.get_mut(&uri) {
+            Some(document) => document.set_text(text),
+            None => {
+                self.documents.insert(uri, Document::new(text));
+            }
+        }
+    }
+
+    /// Returns whether the document was removed from the workspace, which is the case if it does not exist on disk.
+    pub(crate) fn close(&mut self, uri: &Url) -> bool {
+        let text_on_disk = uri
+            .to_file_path()
+            .ok()
+            .and_then(|path| std::fs::read_to_string(path).ok());
+        match text_on_disk {
+            Some(text) => {
+                self.documents.insert(uri.clone(), Document::new(text));
+                false
+            }
+            None => self.documents.remove(uri).is_some(),
+        }
+    }
+
+    pub(crate) fn document(&self, uri: &Url) -> Option<&Document> {
+        self.documents.get(uri)
+    }
+
+    pub(crate) fn documents(&self) -> impl Iterator<Item = (&Url, &Document)> {
+        self.documents.iter()
+    }
+
+    /// Compiles all documents, updating the diagnostics, the variables and the outlines of the changed documents.
+    pub(crate) fn compile(&mut self) {
+        self.session.compiler.files = self
+            .documents
+            .iter()
+            .map(|(uri, document)| File {
+                file_name: uri.to_string(),
+                source: document.text.clone(),
+            })
+            .collect();
+        let diagnostics = match self.session.compile() {
+            Ok(compilation) => {
+                for (uri, document) in &mut self.documents {
+                    if !document.is_outline_current {
+                        let nodes = compilation
+                            .nodes
+                            .iter()
+                            .filter(|node| node.file_name == uri.as_str());
+                        document.outline = Outline::new(&document.text, nodes, &document.outline);
+                        document.is_outline_current = true;
+                    }
+                }
+                // The declarations also contain the functions used by the scripts.
+                // The variables the compiler generates for tracking visits are not meant to be used in scripts.
+                let mut variables: Vec<_> = compilation
+                    .declarations
+                    .into_iter()
+                    .filter(|declaration| {
+                        declaration.name.starts_with('$')
+                            && !declaration.name.starts_with("$Yarn.Internal.")
+                    })
+                    .collect();
+                if !compilation.errors.is_empty() {
+                    // The declarations in nodes with errors are missing from a partial compilation.
+                    let previous_variables = std::mem::take(&mut self.variables)
+                        .into_iter()
+                        .filter(|previous| {
+                            variables
+                                .iter()
+                                .all(|declaration| declaration.name != previous.name)
+                        })
+                        .collect::<Vec<_>>();
+                    variables.extend(previous_variables);
+                }
+                self.variables = variables;
+                compilation
+                    .warnings
+                    .into_iter()
+                    .chain(compilation.errors)
+                    .collect()
+            }
+            Err(error) => error.0,
+        };
+        self.diagnostics.clear();
+        for diagnostic in diagnostics {
+            if let Some(file_name) = diagnostic.file_name.clone() {
+                self.diagnostics
+                    .entry(file_name)
+                    .or_default()
+                    .push(diagnostic);
+            }
+        }
+    }
+
+    pub(crate) fn diagnostics(&self, uri: &Url) -> &[Diagnostic] {
+        self.diagnostics
+            .get(uri.as_str())
+            .map_or(&[], |diagnostics| diagnostics.as_slice())
+    }
+
+    /// The node with the given title, in whichever document it is.
+    pub(crate) fn node(&self, title: &str) -> Option<(&Url, &NodeOutline)> {
+        self.documents.iter().find_map(|(uri, document)| {
+            let node = document
+                .outline
+                .nodes
+                .iter()
+                .find(|node| node.title == title)?;
+            Some((uri, node))
+        })
+    }
+
+    pub(crate) fn nodes(&self) -> impl Iterator<Item = &NodeOutline> {
+        self.documents
+            .values()
+            .flat_map(|document| document.outline.nodes.iter())
+    }
+
+    /// The variables declared in the documents or given to the compiler, including the ones whose type was inferred.
+    pub(crate) fn variables(&self) -> impl Iterator<Item = &Declaration> {
+        self.session
+            .compiler
+            .variable_declarations
+            .iter()
+            .chain(self.variables.iter())
+    }
+
+    pub(crate) fn variable(&self, name: &str) -> Option<&Declaration> {
+        self.variables()
+            .find(|declaration| declaration.name == name)
+    }
+
+    pub(crate) fn functions(&self) -> &[Declaration] {
+        &self.functions
+    }
+
+    pub(crate) fn function(&self, name: &str) -> Option<&Declaration> {
+        self.functions
+            .iter()
+            .find(|declaration| declaration.name == name)
+    }
+
+    /// Where the declaration was written, if it was written in one of the documents.
+    pub(crate) fn location(&self, declaration: &Declaration) -> Option<Location> {
+        let DeclarationSource::File(file_name) = &declaration.source_file_name else {
+            return None;
+        };
+        let uri = Url::parse(file_name).ok()?;
+        let range = to_lsp_range(&self.document(&uri)?.text, declaration.range.as_ref()?);
+        Some(Location { uri, range })
+    }
+}
@@ -0,0 +1,357 @@
+//! Talks to a [`LanguageServer`] running on another thread, the way an editor would.
+
+use lsp_server::{Message, Notification as ServerNotification, Request as ServerRequest};
+use lsp_types::notification::*;
+use lsp_types::request::*;
+use lsp_types::*;
+use std::path::PathBuf;
+use std::thread::JoinHandle;
+use std::time::Duration;
+use yarnspinner::compiler::Compiler;
+use yarnspinner_language_server::{Connection, LanguageServer};
+
+const START: &str = "title: Start
+---
+<<declare $gold = 5>>
+Hello! You have {$gold} gold.
+<<jump Other>>
+===
+";
+
+const OTHER: &str = "title: Other
+---
+<<if visited(\"Start\")>>
+    Welcome back, {round_places($gold, 1)} gold.
+<<endif>>
+===
+";
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+struct Client {
+    connection: Connection,
+    server: Option<JoinHandle<()>>,
+    notifications: Vec<ServerNotification>,
+    next_id: i32,
+}
+
+impl Client {
+    fn start(server: LanguageServer, workspace_folder: Option<PathBuf>) -> Self {
+        let (server_connection, connection) = Connection::memory();
+        let mut server = server;
+        let server = std::thread::spawn(move || server.run(&server_connection).unwrap());
+        let mut client = Self {
+            connection,
+            server: Some(server),
+            notifications: Vec::new(),
+            next_id: 0,
+        };
+        let workspace_folders = workspace_folder.map(|path| {
+            vec![WorkspaceFolder {
+                uri: Url::from_directory_path(path).unwrap(),
+                name: "project".to_owned(),
+            }]
+        });
+        client.request::<Initialize>(InitializeParams {
+            workspace_folders,
+            ..Default::default()
+        });
+        client.notify::<Initialized>(InitializedParams {});
+        client
+    }
+
+    fn request<R: Request>(&mut self, params: R::Params) -> R::Result {
+        self.next_id += 1;
+        let request = ServerRequest::new(self.next_id.into(), R::METHOD.to_owned(), params);
+        self.connection.sender.send(request.into()).unwrap();
+        loop {
+            match self.receive() {
+                Message::Response(response) if response.id == self.next_id.into() => {
+                    return serde_json::from_value(response.result.unwrap_or_default()).unwrap();
+                }
+                Message::Notification(notification) => self.notifications.push(notification),
+                _ => {}
+            }
+        }
+    }
+
+    fn notify<N: Notification>(&self, params: N::Params) {
+        let notification = ServerNotification::new(N::METHOD.to_owned(), params);
+        self.connection.sender.send(notification.into()).unwrap();
+    }
+
+    fn receive(&self) -> Message {
+        self.connection.receiver.recv_timeout(TIMEOUT).unwrap()
+    }
+
+    /// Waits for the next diagnostics published for `uri`.
+    fn diagnostics(&mut self, uri: &Url) -> Vec<Diagnostic> {
+        loop {
+            let notification = match self
+                .notifications
+                .iter()
+                .position(|notification| notification.method == PublishDiagnostics::METHOD)
+            {
+                Some(index) => self.notifications.remove(index),
+                None => match self.receive() {
+                    Message::Notification(notification) => notification,
+                    _ => continue,
+                },
+            };
+            let params: PublishDiagnosticsParams =
+                serde_json::from_value(notification.params).unwrap();
+            if &params.uri == uri {
+                return params.diagnostics;
+            }
+        }
+    }
+
+    fn open(&mut self, uri: &Url, text: &str) -> Vec<Diagnostic> {
+        self.notify::<DidOpenTextDocument>(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem::new(
+                uri.clone(),
+                "yarn".to_owned(),
+                1,
+                text.to_owned(),
+            ),
+        });
+        self.diagnostics(uri)
+    }
+
+    fn change(&mut self, uri: &Url, text: &str) -> Vec<Diagnostic> {
+        self.notify::<DidChangeTextDocument>(DidChangeTextDocumentParams {
+            text_document: VersionedTextDocumentIdentifier::new(uri.clone(), 2),
+            content_changes: vec![TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: text.to_owned(),
+            }],
+        });
+        self.diagnostics(uri)
+    }
+
+    fn definition(&mut self, uri: &Url, line: u32, character: u32) -> Option<Location> {
+        let response = self.request::<GotoDefinition>(GotoDefinitionParams {
+            text_document_position_params: position(uri, line, character),
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        });
+        match response? {
+            GotoDefinitionResponse::Scalar(location) => Some(location),
+            response => panic!("Expected a single location, got {response:?}"),
+        }
+    }
+
+    fn hover(&mut self, uri: &Url, line: u32, character: u32) -> Option<String> {
+        let hover = self.request::<HoverRequest>(HoverParams {
+            text_document_position_params: position(uri, line, character),
+            work_done_progress_params: Default::default(),
+        })?;
+        match hover.contents {
+            HoverContents::Markup(content) => Some(content.value),
+            contents => panic!("Expected markup, got {contents:?}"),
+        }
+    }
+
+    fn completion(&mut self, uri: &Url, line: u32, character: u32) -> Vec<String> {
+        let response = self.request::<Completion>(CompletionParams {
+            text_document_position: position(uri, line, character),
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+            context: None,
+        });
+        let Some(CompletionResponse::Array(items)) = response else {
+            panic!("Expected a list of completions, got {response:?}");
+        };
+        let mut labels: Vec<_> = items.into_iter().map(|item| item.label).collect();
+        labels.sort();
+        labels
+    }
+}
+
+impl Drop for Client {
+    fn drop(&mut self) {
+        if std::thread::panicking() {
+            return;
+        }
+        self.request::<Shutdown>(());
+        self.notify::<Exit>(());
+        self.server.take().unwrap().join().unwrap();
+    }
+}
+
+fn uri(file_name: &str) -> Url {
+    Url::parse(&format!("file:///project/{file_name}")).unwrap()
+}
+
+fn position(uri: &Url, line: u32, character: u32) -> TextDocumentPositionParams {
+    TextDocumentPositionParams::new(
+        TextDocumentIdentifier::new(uri.clone()),
+        Position::new(line, character),
+    )
+}
+
+fn range(line: u32, start: u32, end: u32) -> Range {
+    Range::new(Position::new(line, start), Position::new(line, end))
+}
+
+#[test]
+fn publishes_diagnostics_while_editing() {
+    let mut client = Client::start(LanguageServer::new(), None);
+    let uri = uri("other.yarn");
+    // Declares the variable used by the other file
+    client.open(&self::uri("start.yarn"), START);
+
+    let diagnostics = client.open(&uri, &OTHER.replace("<<endif>>", ""));
+    assert!(!diagnostics.is_empty());
+    assert!(diagnostics
+        .iter()
+        .all(|diagnostic| diagnostic.severity == Some(DiagnosticSeverity::ERROR)));
+
+    let diagnostics = client.change(&uri, OTHER);
+    assert_eq!(Vec::<Diagnostic>::new(), diagnostics);
+}
+
+#[test]
+fn goes_to_the_definition_of_nodes() {
+    let mut client = Client::start(LanguageServer::new(), None);
+    let (start, other) = (uri("start.yarn"), uri("other.yarn"));
+    client.open(&start, START);
+    client.open(&other, OTHER);
+
+    let jump = client.definition(&start, 4, 9);
+    assert_eq!(Some(Location::new(other.clone(), range(0, 7, 12))), jump);
+
+    let visited = client.definition(&other, 2, 15);
+    assert_eq!(Some(Location::new(start.clone(), range(0, 7, 12))), visited);
+
+    assert_eq!(None, client.definition(&start, 3, 2));
+}
+
+#[test]
+fn goes_to_the_declaration_of_variables() {
+    let mut client = Client::start(LanguageServer::new(), None);
+    let (start, other) = (uri("start.yarn"), uri("other.yarn"));
+    client.open(&start, START);
+    client.open(&other, OTHER);
+
+    let location = client.definition(&other, 3, 34).unwrap();
+    assert_eq!(start, location.uri);
+    assert_eq!(2, location.range.start.line);
+}
+
+#[test]
+fn shows_types_on_hover() {
+    let mut client = Client::start(LanguageServer::new(), None);
+    let (start, other) = (uri("start.yarn"), uri("other.yarn"));
+    client.open(&start, START);
+    client.open(&other, OTHER);
+
+    let variable = client.hover(&other, 3, 34).unwrap();
+    assert!(variable.contains("$gold: Number"), "{variable}");
+    assert!(variable.contains("Default value: `5`"), "{variable}");
+
+    let function = client.hover(&other, 3, 22).unwrap();
+    assert!(
+        function.contains("round_places: Fn(Number, Number) -> Number"),
+        "{function}"
+    );
+
+    assert_eq!(None, client.hover(&other, 3, 8));
+}
+
+#[test]
+fn completes_nodes_variables_and_functions() {
+    let mut client = Client::start(LanguageServer::new(), None);
+    let (start, other) = (uri("start.yarn"), uri("other.yarn"));
+    client.open(&start, START);
+    client.open(&other, OTHER);
+    client.change(
+        &start,
+        &START.replace("<<jump Other>>", "<<jump O\n{$g}{ro}"),
+    );
+
+    assert_eq!(["Other"], client.completion(&start, 4, 8).as_slice());
+    assert_eq!(
+        ["Other", "Start"],
+        client.completion(&start, 4, 7).as_slice()
+    );
+    assert_eq!(["$gold"], client.completion(&start, 5, 3).as_slice());
+    let functions = client.completion(&start, 5, 7);
+    assert!(functions.iter().any(|item| item == "round_places"));
+    assert!(functions.iter().all(|item| item.starts_with("ro")));
+
+    let everything = client.completion(&start, 3, 0);
+    for label in ["Other", "Start", "$gold", "dice", "round_places"] {
+        assert!(everything.iter().any(|item| item == label), "{label}");
+    }
+}
+
+#[test]
+fn completes_functions_of_the_game() {
+    let mut compiler = Compiler::new();
+    compiler.library.add_function("is_raining", || false);
+    let mut client = Client::start(LanguageServer::with_compiler(compiler), None);
+    let start = uri("start.yarn");
+    client.open(&start, START);
+
+    let completions = client.completion(&start, 3, 0);
+    assert!(completions.iter().any(|item| item == "is_raining"));
+}
+
+#[test]
+fn lists_nodes_as_document_symbols() {
+    let mut client = Client::start(LanguageServer::new(), None);
+    let start = uri("start.yarn");
+    client.open(
+        &start,
+        &format!("{START}{}", OTHER.replace("Other", "Second")),
+    );
+
+    let response = client.request::<DocumentSymbolRequest>(DocumentSymbolParams {
+        text_document: TextDocumentIdentifier::new(start),
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    });
+    let Some(DocumentSymbolResponse::Nested(symbols)) = response else {
+        panic!("Expected nested symbols, got {response:?}");
+    };
+    let symbols: Vec<_> = symbols
+        .into_iter()
+        .map(|symbol| (symbol.name, symbol.range, symbol.selection_range))
+        .collect();
+    assert_eq!(
+        vec![
+            (
+                "Start".to_owned(),
+                Range::new(Position::new(0, 0), Position::new(5, 3)),
+                range(0, 7, 12)
+            ),
+            (
+                "Second".to_owned(),
+                Range::new(Position::new(6, 0), Position::new(11, 3)),
+                range(6, 7, 13)
+            ),
+        ],
+        symbols
+    );
+}
+
+#[test]
+fn analyses_files_of_the_workspace_that_are_not_open() {
+    let directory =
+        std::env::temp_dir().join(format!("yarn_language_server_test_{}", std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+    std::fs::write(directory.join("other.yarn"), OTHER).unwrap();
+
+    let mut client = Client::start(LanguageServer::new(), Some(directory.clone()));
+    let start = Url::from_file_path(directory.join("start.yarn")).unwrap();
+    let other = Url::from_file_path(directory.join("other.yarn")).unwrap();
+    client.open(&start, START);
+
+    let jump = client.definition(&start, 4, 9);
+    assert_eq!(Some(Location::new(other, range(0, 7, 12))), jump);
+
+    drop(client);
+    std::fs::remove_dir_all(directory).unwrap();
+}
@@ -15,6 +15,7 @@ use yarnspinner_core::prelude::*;
 pub struct Dialogue {
     vm: VirtualMachine,
     language_code: Option<Language>,
+    rng: YarnRng,
 }
 
 #[allow(missing_docs)]
@@ -44,6 +45,22 @@ pub enum DialogueError {
         function_name: String,
         library: Library,
     },
+    /// A fallible [`YarnFn`] returned an error, or a function was called with the wrong number of arguments.
+    ///
+    /// The call is identified by the node it was made in and the index of the instruction within that node.
+    /// A [`Program`] does not know which source its instructions were compiled from, so `location` is only set
+    /// if the compiler's debug info for the node was registered with [`Dialogue::add_source_positions`].
+    /// The Bevy plugin does this when it loads a compilation.
+    FunctionFailed {
+        function_name: String,
+        arguments: Vec<YarnValue>,
+        node_name: String,
+        instruction_index: usize,
+        location: Option<Box<SourceLocation>>,
+        error: YarnFnError,
+    },
+    /// Returned by [`Dialogue::try_add_program`] when the program failed [`verify_program`].
+    InvalidProgram(VerificationReport),
 }
 
 impl Error for DialogueError {
@@ -52,6 +69,7 @@ impl Error for DialogueError {
         match self {
             MarkupParseError(e) => e.source(),
             VariableStorageError(e) => e.source(),
+            FunctionFailed { error, .. } => Some(error.as_ref()),
             _ => None,
         }
     }
@@ -71,10 +89,34 @@ impl Display for DialogueError {
             InvalidNode { node_name } => write!(f, "No node named \"{node_name}\" has been loaded."),
             VariableStorageError(e) => Display::fmt(e, f),
             FunctionNotFound { function_name, library } => write!(f, "Function \"{function_name}\" not found in library: {library}"),
+            FunctionFailed { function_name, arguments, node_name, instruction_index, location: Some(location), error } => write!(f, "Function \"{function_name}\" failed when called with arguments {arguments:?} in node \"{node_name}\" at instruction {instruction_index} ({location}): {error}"),
+            FunctionFailed { function_name, arguments, node_name, instruction_index, location: None, error } => write!(f, "Function \"{function_name}\" failed when called with arguments {arguments:?} in node \"{node_name}\" at instruction {instruction_index}: {error}"),
+            InvalidProgram(report) => Display::fmt(report, f),
         }
     }
 }
 
+/// A place in a Yarn file that an instruction was compiled from. See [`Dialogue::add_source_positions`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SourceLocation {
+    /// The name of the file containing the instruction's node.
+    pub file_name: String,
+    /// The zero-indexed position of the instruction within that file.
+    pub position: Position,
+}
+
+impl Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}",
+            self.file_name,
+            self.position.line + 1,
+            self.position.character + 1
+        )
+    }
+}
+
 impl From<MarkupParseError> for DialogueError {
     fn from(source: MarkupParseError) -> Self {
         DialogueError::MarkupParseError(source)
@@ -88,6 +130,9 @@ impl From<VariableStorageError> for DialogueError {
 }
 
 impl Dialogue {
+    /// The default for [`Dialogue::line_history_limit`].
+    pub const DEFAULT_LINE_HISTORY_LIMIT: usize = 1000;
+
     /// Creates a new [`Dialogue`] instance with the given [`VariableStorage`] and [`TextProvider`].
     /// - The [`TextProvider`] is used to retrieve the text of lines and options.
     /// - The [`VariableStorage`] is used to store and retrieve variables.
@@ -98,10 +143,11 @@ impl Dialogue {
         variable_storage: Box<dyn VariableStorage>,
         text_provider: Box<dyn TextProvider>,
     ) -> Self {
-        let mut library = Library::standard_library();
+        let rng = YarnRng::from_entropy();
+        let mut library = Library::standard_library_with_rng(rng.clone());
         library
-            .add_function("visited", visited(variable_storage.clone()))
-            .add_function("visited_count", visited_count(variable_storage.clone()));
+            .add_function("visited", visited)
+            .add_function("visited_count", visited_count);
 
         let dialogue_text_processor = Box::new(DialogueTextProcessor::new());
         let line_parser = LineParser::new()
@@ -112,29 +158,21 @@ impl Dialogue {
         Self {
             vm: VirtualMachine::new(library, variable_storage, line_parser, text_provider),
             language_code: Default::default(),
+            rng,
         }
     }
 }
 
-fn visited(storage: Box<dyn VariableStorage>) -> yarn_fn_type! { impl Fn(String) -> bool } {
-    move |node: String| -> bool {
-        let name = Library::generate_unique_visited_variable_for_node(&node);
-        if let Ok(YarnValue::Number(count)) = storage.get(&name) {
-            count > 0.0
-        } else {
-            false
-        }
-    }
+fn visited(context: &dyn YarnFnContext, node: &str) -> bool {
+    visited_count(context, node) > 0.0
 }
 
-fn visited_count(storage: Box<dyn VariableStorage>) -> yarn_fn_type! { impl Fn(String) -> f32 } {
-    move |node: String| {
-        let name = Library::generate_unique_visited_variable_for_node(&node);
-        if let Ok(YarnValue::Number(count)) = storage.get(&name) {
-            count
-        } else {
-            0.0
-        }
+fn visited_count(context: &dyn YarnFnContext, node: &str) -> f32 {
+    let name = Library::generate_unique_visited_variable_for_node(node);
+    if let Some(YarnValue::Number(count)) = context.variable(&name) {
+        count
+    } else {
+        0.0
     }
 }
 
@@ -192,6 +230,23 @@ impl Dialogue {
         &mut self.vm.library
     }
 
+    /// Gets the [`YarnRng`] that the random functions of the [`Library`] draw from, such as `random`, `random_range` and `dice`.
+    ///
+    /// Clone it to register your own functions that should draw from the same sequence of random numbers.
+    /// Read its [`YarnRng::state`] when saving a game and pass it to [`YarnRng::set_state`] when loading it
+    /// to continue the same sequence.
+    #[must_use]
+    pub fn rng(&self) -> &YarnRng {
+        &self.rng
+    }
+
+    /// Seeds the [`YarnRng`] returned by [`Dialogue::rng`], making all random functions deterministic.
+    /// Two dialogues seeded with the same value will produce the same random results when running the same program.
+    pub fn set_random_seed(&mut self, seed: u64) -> &mut Self {
+        self.rng.seed(seed);
+        self
+    }
+
     /// Gets whether [`Dialogue::next`] is able able to return [`DialogueEvent::LineHints`] events.
     /// The default is `false`.
     #[must_use]
@@ -225,6 +280,44 @@ impl Dialogue {
     pub fn variable_storage_mut(&mut self) -> &mut dyn VariableStorage {
         self.vm.variable_storage_mut()
     }
+
+    /// The IDs of the lines delivered by this [`Dialogue`] so far, in the order they were delivered.
+    /// Only the most recent [`Dialogue::line_history_limit`] lines are kept.
+    /// Functions taking a [`YarnFnContext`] can read this via [`YarnFnContext::line_history`].
+    #[must_use]
+    pub fn line_history(&self) -> &[LineId] {
+        self.vm.line_history()
+    }
+
+    /// Replaces the line history, e.g. to restore it from a save game alongside the [`VariableStorage`].
+    /// If there are more lines than [`Dialogue::line_history_limit`], the oldest ones are dropped.
+    pub fn set_line_history(
+        &mut self,
+        line_history: impl IntoIterator<Item = LineId>,
+    ) -> &mut Self {
+        self.vm.set_line_history(line_history.into_iter().collect());
+        self
+    }
+
+    /// Forgets all lines delivered so far.
+    pub fn clear_line_history(&mut self) -> &mut Self {
+        self.vm.set_line_history(Vec::new());
+        self
+    }
+
+    /// Gets the maximum number of lines kept in [`Dialogue::line_history`].
+    /// The default is [`Dialogue::DEFAULT_LINE_HISTORY_LIMIT`].
+    #[must_use]
+    pub fn line_history_limit(&self) -> usize {
+        self.vm.line_history_limit()
+    }
+
+    /// Mutable gets the maximum number of lines kept in [`Dialogue::line_history`].
+    /// Lowering the limit immediately drops the oldest lines.
+    pub fn set_line_history_limit(&mut self, limit: usize) -> &mut Self {
+        self.vm.set_line_history_limit(limit);
+        self
+    }
 }
 
 // VM proxy
@@ -276,6 +369,8 @@ impl Dialogue {
     }
 
     /// Merges the currently set [`Program`] with the given one. If there is no program set, the given one is set.
+    ///
+    /// The program is not checked before it is run. Use [`Dialogue::try_add_program`] for programs that do not come straight from the compiler.
     pub fn add_program(&mut self, program: Program) -> &mut Self {
         if let Some(existing_program) = self.vm.program.as_mut() {
             *existing_program =
@@ -289,6 +384,82 @@ impl Dialogue {
         self
     }
 
+    /// Registers where the instructions of the node `node_name` were compiled from,
+    /// so that errors such as [`DialogueError::FunctionFailed`] can point at the Yarn source.
+    ///
+    /// The positions are usually taken from the `debug_info` of a
+    /// [`Compilation`](https://docs.rs/yarnspinner_compiler/latest/yarnspinner_compiler/prelude/struct.Compilation.html):
+    /// ```ignore
+    /// for (node_name, debug_info) in &compilation.debug_info {
+    ///     dialogue.add_source_positions(
+    ///         node_name,
+    ///         &debug_info.file_name,
+    ///         debug_info.line_positions.iter().filter_map(|(&index, &position)| Some((index, position?))),
+    ///     );
+    /// }
+    /// ```
+    /// Positions registered earlier for the same node are replaced.
+    pub fn add_source_positions(
+        &mut self,
+        node_name: impl Into<String>,
+        file_name: impl Into<String>,
+        positions: impl IntoIterator<Item = (usize, Position)>,
+    ) -> &mut Self {
+        self.vm.source_positions.insert(
+            node_name.into(),
+            NodeSourcePositions {
+                file_name: file_name.into(),
+                positions: positions.into_iter().collect(),
+            },
+        );
+        self
+    }
+
+    /// Like [`Dialogue::add_program`], but first checks the result of merging the given [`Program`] into the current one
+    /// with [`Dialogue::verify_program`]. Nodes that have already been loaded are reported as [`VerificationErrorKind::NodeAlreadyExists`].
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`DialogueError::InvalidProgram`] if any problems were found. In that case, the [`Dialogue`] is left unchanged.
+    pub fn try_add_program(&mut self, program: Program) -> Result<&mut Self> {
+        let mut report = VerificationReport::default();
+        let merged_program = match self.vm.program.as_ref() {
+            Some(existing_program) => {
+                let mut merged_program = existing_program.clone();
+                let mut node_names: Vec<_> = program.nodes.keys().collect();
+                node_names.sort();
+                for node_name in node_names {
+                    if merged_program.nodes.contains_key(node_name) {
+                        report.errors.push(VerificationError {
+                            node_name: node_name.clone(),
+                            instruction_index: None,
+                            kind: VerificationErrorKind::NodeAlreadyExists,
+                        });
+                    } else {
+                        merged_program
+                            .nodes
+                            .insert(node_name.clone(), program.nodes[node_name].clone());
+                    }
+                }
+                merged_program
+            }
+            None => program.clone(),
+        };
+        report
+            .errors
+            .extend(self.verify_program(&merged_program).errors);
+        if !report.is_valid() {
+            return Err(DialogueError::InvalidProgram(report));
+        }
+        Ok(self.add_program(program))
+    }
+
+    /// Checks the given [`Program`] against this [`Dialogue`]'s [`Library`] without loading it. See [`verify_program`] for what is checked.
+    #[must_use]
+    pub fn verify_program(&self, program: &Program) -> VerificationReport {
+        verify_program(program, self.library())
+    }
+
     /// Prepares the [`Dialogue`] that the user intends to start running a node.
     ///
     /// After this method is called, you call [`Dialogue::next`] to start executing it.
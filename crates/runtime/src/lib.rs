@@ -17,6 +17,7 @@ pub mod markup;
 mod pluralization;
 mod text_provider;
 mod variable_storage;
+mod verifier;
 mod virtual_machine;
 
 pub use dialogue::Result;
@@ -26,7 +27,7 @@ pub mod prelude {
     pub use crate::{
         analyser::*,
         command::*,
-        dialogue::{Dialogue, DialogueError},
+        dialogue::{Dialogue, DialogueError, SourceLocation},
         dialogue_option::*,
         events::*,
         language::*,
@@ -34,6 +35,7 @@ pub mod prelude {
         markup::MarkupParseError,
         text_provider::*,
         variable_storage::*,
+        verifier::*,
     };
     pub(crate) use crate::{pluralization::*, virtual_machine::*};
     pub(crate) use yarnspinner_core::prelude::*;
@@ -4,7 +4,8 @@
 //! Introduced `LineId` newtype for better type safety
 
 use crate::markup::{
-    MarkupAttribute, MarkupValue, CHARACTER_ATTRIBUTE, CHARACTER_ATTRIBUTE_NAME_PROPERTY,
+    LineParser, MarkupAttribute, MarkupValue, CHARACTER_ATTRIBUTE,
+    CHARACTER_ATTRIBUTE_NAME_PROPERTY,
 };
 use crate::prelude::*;
 
@@ -41,6 +42,26 @@ pub struct Line {
 }
 
 impl Line {
+    /// Parses the markup of `text` the way the [`Dialogue`] does before sending a line,
+    /// including the implicit `character` attribute, so that e.g. [`Line::character_name`] can be used on the text of a string table.
+    ///
+    /// Since no values are substituted into `text`, the `select`, `plural` and `ordinal` markers are kept as attributes instead of being replaced.
+    ///
+    /// ```rust
+    /// # use yarnspinner_runtime::prelude::*;
+    /// let line = Line::parse("line:hello".into(), "Alice: [wave]Hello![/wave]").unwrap();
+    /// assert_eq!(Some("Alice"), line.character_name());
+    /// assert_eq!("Hello!", line.text_without_character_name());
+    /// ```
+    pub fn parse(id: LineId, text: &str) -> crate::markup::Result<Self> {
+        let markup = LineParser::new().parse_markup(text)?;
+        Ok(Self {
+            id,
+            text: markup.text,
+            attributes: markup.attributes,
+        })
+    }
+
     /// Gets the first attribute with the specified name, if present.
     ///
     /// ## Implementation note
@@ -0,0 +1,966 @@
+//! The virtual machine trusts the [`Program`] it runs and panics or errors as soon as it encounters an instruction that does not make sense.
+//! This is fine for programs fresh out of the compiler, but programs loaded from disk or merged with [`Program::combine`]
+//! deserve to be checked as a whole before a single instruction is run.
+
+use crate::prelude::*;
+use std::fmt::{self, Display, Formatter};
+use std::ops::Range;
+
+/// Checks a whole [`Program`] for problems that would otherwise only surface while running it.
+///
+/// For every node, this verifies that
+/// - all opcodes are valid and supported,
+/// - every instruction has the number and types of operands its [`OpCode`] requires,
+/// - all labels that are jumped to exist in the node's `labels`, and point to an instruction inside the node,
+/// - no instruction pops more values off the stack than are on it on any path through the node,
+/// - all functions that are called exist in the given [`Library`] and accept the number of arguments they are called with,
+/// - all nodes that are run with [`OpCode::RunNode`] exist in the program, if their name is known before running.
+///
+/// Usually, you'll want to call [`Dialogue::verify_program`] or [`Dialogue::try_add_program`] instead,
+/// which use the [`Library`] of the [`Dialogue`].
+pub fn verify_program(program: &Program, library: &Library) -> VerificationReport {
+    let mut node_names: Vec<_> = program.nodes.keys().collect();
+    node_names.sort();
+    let errors = node_names
+        .into_iter()
+        .flat_map(|node_name| {
+            NodeVerifier::new(program, library, &program.nodes[node_name]).verify()
+        })
+        .collect();
+    VerificationReport { errors }
+}
+
+/// The result of [`verify_program`]. A program is valid if the report contains no errors.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct VerificationReport {
+    /// All problems found in the program, ordered by node name and then by instruction.
+    pub errors: Vec<VerificationError>,
+}
+
+impl VerificationReport {
+    /// Returns `true` if no problems were found.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Iterates over the problems found in the node with the given name.
+    pub fn errors_in_node<'a>(
+        &'a self,
+        node_name: &'a str,
+    ) -> impl Iterator<Item = &'a VerificationError> {
+        self.errors
+            .iter()
+            .filter(move |error| error.node_name == node_name)
+    }
+}
+
+impl Display for VerificationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.is_valid() {
+            return f.write_str("The program is valid.");
+        }
+        writeln!(f, "The program is invalid:")?;
+        for error in &self.errors {
+            writeln!(f, "- {error}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A single problem found by [`verify_program`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerificationError {
+    /// The name of the node containing the problem.
+    pub node_name: String,
+    /// The index of the offending instruction within the node, if the problem is caused by a specific instruction.
+    pub instruction_index: Option<usize>,
+    /// What exactly is wrong.
+    pub kind: VerificationErrorKind,
+}
+
+impl Display for VerificationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.instruction_index {
+            Some(index) => write!(
+                f,
+                "Node \"{}\", instruction {index}: {}",
+                self.node_name, self.kind
+            ),
+            None => write!(f, "Node \"{}\": {}", self.node_name, self.kind),
+        }
+    }
+}
+
+/// The kinds of problems [`verify_program`] can find.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+#[allow(missing_docs)]
+pub enum VerificationErrorKind {
+    /// The instruction's opcode does not correspond to any [`OpCode`].
+    InvalidOpCode(i32),
+    /// The opcode is no longer supported by the runtime, e.g. [`OpCode::PushNull`].
+    UnsupportedOpCode(OpCode),
+    WrongOperandCount {
+        opcode: OpCode,
+        expected: usize,
+        actual: usize,
+    },
+    WrongOperandType {
+        opcode: OpCode,
+        operand_index: usize,
+        expected: OperandType,
+        /// [`None`] if the operand has no value.
+        actual: Option<OperandType>,
+    },
+    /// An operand that counts values on the stack is negative or not a whole number.
+    InvalidCount {
+        opcode: OpCode,
+        operand_index: usize,
+        value: f32,
+    },
+    /// A label is jumped to, but not part of the node's `labels`.
+    LabelNotFound {
+        label: String,
+    },
+    /// A label in the node's `labels` points before the first or after the last instruction.
+    LabelOutOfRange {
+        label: String,
+        position: i32,
+        instruction_count: usize,
+    },
+    StackUnderflow {
+        opcode: OpCode,
+        required: usize,
+        available: usize,
+    },
+    /// The number of arguments of a function call is not a constant pushed right before it.
+    UnknownArgumentCount {
+        function_name: String,
+    },
+    FunctionNotFound {
+        function_name: String,
+    },
+    WrongArgumentCount {
+        function_name: String,
+        min: usize,
+        /// [`None`] if the function is variadic.
+        max: Option<usize>,
+        actual: usize,
+    },
+    NodeNotFound {
+        node_name: String,
+    },
+    /// Returned by [`Dialogue::try_add_program`] when the dialogue already has a node with the same name.
+    NodeAlreadyExists,
+}
+
+impl Display for VerificationErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        use VerificationErrorKind::*;
+        match self {
+            InvalidOpCode(opcode) => write!(f, "{opcode} is not a valid opcode"),
+            UnsupportedOpCode(opcode) => write!(f, "{opcode:?} is no longer supported. To fix this error, re-compile the original source code"),
+            WrongOperandCount { opcode, expected, actual } => write!(f, "{opcode:?} expects {expected} operand(s), but has {actual}"),
+            WrongOperandType { opcode, operand_index, expected, actual: Some(actual) } => write!(f, "Operand {operand_index} of {opcode:?} should be a {expected}, but is a {actual}"),
+            WrongOperandType { opcode, operand_index, expected, actual: None } => write!(f, "Operand {operand_index} of {opcode:?} should be a {expected}, but is empty"),
+            InvalidCount { opcode, operand_index, value } => write!(f, "Operand {operand_index} of {opcode:?} should be a non-negative whole number, but is {value}"),
+            LabelNotFound { label } => write!(f, "Label \"{label}\" does not exist in this node"),
+            LabelOutOfRange { label, position, instruction_count } => write!(f, "Label \"{label}\" points to instruction {position}, but the node has {instruction_count} instructions"),
+            StackUnderflow { opcode, required, available } => write!(f, "{opcode:?} needs {required} value(s) on the stack, but only {available} are available"),
+            UnknownArgumentCount { function_name } => write!(f, "The number of arguments passed to function \"{function_name}\" cannot be determined"),
+            FunctionNotFound { function_name } => write!(f, "Function \"{function_name}\" does not exist in the library"),
+            WrongArgumentCount { function_name, min, max: Some(max), actual } if min == max => write!(f, "Function \"{function_name}\" expects {min} argument(s), but is called with {actual}"),
+            WrongArgumentCount { function_name, min, max: Some(max), actual } => write!(f, "Function \"{function_name}\" expects between {min} and {max} arguments, but is called with {actual}"),
+            WrongArgumentCount { function_name, min, max: None, actual } => write!(f, "Function \"{function_name}\" expects at least {min} argument(s), but is called with {actual}"),
+            NodeNotFound { node_name } => write!(f, "There is no node named \"{node_name}\" to run"),
+            NodeAlreadyExists => f.write_str("A node with this name has already been loaded"),
+        }
+    }
+}
+
+/// The type of an [`Operand`], as expected by an [`OpCode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum OperandType {
+    String,
+    Float,
+    Bool,
+}
+
+impl OperandType {
+    fn of(operand: &Operand) -> Option<Self> {
+        match operand.value.as_ref()? {
+            OperandValue::StringValue(_) => Some(Self::String),
+            OperandValue::FloatValue(_) => Some(Self::Float),
+            OperandValue::BoolValue(_) => Some(Self::Bool),
+        }
+    }
+}
+
+impl Display for OperandType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::String => f.write_str("string"),
+            Self::Float => f.write_str("float"),
+            Self::Bool => f.write_str("bool"),
+        }
+    }
+}
+
+fn operand_types(opcode: OpCode) -> &'static [OperandType] {
+    use OperandType::*;
+    match opcode {
+        OpCode::JumpTo
+        | OpCode::PushString
+        | OpCode::JumpIfFalse
+        | OpCode::CallFunc
+        | OpCode::PushVariable
+        | OpCode::StoreVariable => &[String],
+        OpCode::RunLine | OpCode::RunCommand => &[String, Float],
+        OpCode::AddOption => &[String, String, Float, Bool],
+        OpCode::PushFloat => &[Float],
+        OpCode::PushBool => &[Bool],
+        OpCode::Jump
+        | OpCode::ShowOptions
+        | OpCode::PushNull
+        | OpCode::Pop
+        | OpCode::Stop
+        | OpCode::RunNode => &[],
+    }
+}
+
+/// What is known about a value on the stack before running the node.
+#[derive(Debug, Clone, PartialEq)]
+enum StackValue {
+    String(String),
+    Number(f32),
+    /// The destination of the option selected at the `ShowOptions` instruction with this index.
+    SelectedOption(usize),
+    Unknown,
+}
+
+impl StackValue {
+    fn merge(&mut self, other: &Self) -> bool {
+        if self == other || *self == Self::Unknown {
+            return false;
+        }
+        *self = Self::Unknown;
+        true
+    }
+}
+
+struct NodeVerifier<'a> {
+    program: &'a Program,
+    library: &'a Library,
+    node: &'a Node,
+    /// The opcodes of all instructions that passed the static checks. Verification does not continue past the others.
+    opcodes: Vec<Option<OpCode>>,
+    errors: Vec<VerificationError>,
+}
+
+impl<'a> NodeVerifier<'a> {
+    fn new(program: &'a Program, library: &'a Library, node: &'a Node) -> Self {
+        Self {
+            program,
+            library,
+            node,
+            opcodes: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    fn verify(mut self) -> Vec<VerificationError> {
+        self.check_labels();
+        self.opcodes = (0..self.node.instructions.len())
+            .map(|index| self.check_instruction(index))
+            .collect();
+        self.check_control_flow();
+        self.errors
+            .sort_by_key(|error| error.instruction_index.map_or(0, |index| index + 1));
+        self.errors
+    }
+
+    fn report(&mut self, instruction_index: Option<usize>, kind: VerificationErrorKind) {
+        let error = VerificationError {
+            node_name: self.node.name.clone(),
+            instruction_index,
+            kind,
+        };
+        if !self.errors.contains(&error) {
+            self.errors.push(error);
+        }
+    }
+
+    fn check_labels(&mut self) {
+        let instruction_count = self.node.instructions.len();
+        let mut labels: Vec<_> = self.node.labels.iter().collect();
+        labels.sort();
+        for (label, &position) in labels {
+            if usize::try_from(position).map_or(true, |position| position > instruction_count) {
+                self.report(
+                    None,
+                    VerificationErrorKind::LabelOutOfRange {
+                        label: label.clone(),
+                        position,
+                        instruction_count,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Checks everything about an instruction that does not depend on the instructions run before it.
+    fn check_instruction(&mut self, index: usize) -> Option<OpCode> {
+        let node = self.node;
+        let instruction = &node.instructions[index];
+        let Ok(opcode) = OpCode::try_from(instruction.opcode) else {
+            self.report(
+                Some(index),
+                VerificationErrorKind::InvalidOpCode(instruction.opcode),
+            );
+            return None;
+        };
+        if opcode == OpCode::PushNull {
+            self.report(
+                Some(index),
+                VerificationErrorKind::UnsupportedOpCode(opcode),
+            );
+            return None;
+        }
+
+        let expected_types = operand_types(opcode);
+        if instruction.operands.len() != expected_types.len() {
+            self.report(
+                Some(index),
+                VerificationErrorKind::WrongOperandCount {
+                    opcode,
+                    expected: expected_types.len(),
+                    actual: instruction.operands.len(),
+                },
+            );
+            return None;
+        }
+        let mut is_valid = true;
+        for (operand_index, (operand, &expected)) in
+            instruction.operands.iter().zip(expected_types).enumerate()
+        {
+            let actual = OperandType::of(operand);
+            if actual != Some(expected) {
+                self.report(
+                    Some(index),
+                    VerificationErrorKind::WrongOperandType {
+                        opcode,
+                        operand_index,
+                        expected,
+                        actual,
+                    },
+                );
+                is_valid = false;
+            }
+        }
+        if !is_valid {
+            return None;
+        }
+
+        let count_operand_index = match opcode {
+            OpCode::RunLine | OpCode::RunCommand => Some(1),
+            OpCode::AddOption => Some(2),
+            _ => None,
+        };
+        if let Some(operand_index) = count_operand_index {
+            let value: f32 = instruction.read_operand(operand_index);
+            if as_count(value).is_none() {
+                self.report(
+                    Some(index),
+                    VerificationErrorKind::InvalidCount {
+                        opcode,
+                        operand_index,
+                        value,
+                    },
+                );
+                is_valid = false;
+            }
+        }
+
+        let label_operand_index = match opcode {
+            OpCode::JumpTo | OpCode::JumpIfFalse => Some(0),
+            OpCode::AddOption => Some(1),
+            _ => None,
+        };
+        if let Some(operand_index) = label_operand_index {
+            let label: String = instruction.read_operand(operand_index);
+            if !self.node.labels.contains_key(&label) {
+                self.report(Some(index), VerificationErrorKind::LabelNotFound { label });
+                is_valid = false;
+            }
+        }
+
+        is_valid.then_some(opcode)
+    }
+
+    /// Follows every path through the node, keeping track of what is on the stack at each instruction.
+    ///
+    /// Paths may join with a different number of values on the stack. For example, the compiler emits `if` statements
+    /// such that the condition is only popped when it is false, just like the original implementation.
+    /// Since such leftover values are never read again, we only track the values that are on the stack on every path,
+    /// counted from the top.
+    fn check_control_flow(&mut self) {
+        let instruction_count = self.node.instructions.len();
+        if instruction_count == 0 {
+            return;
+        }
+        let mut stacks: Vec<Option<Vec<StackValue>>> = vec![None; instruction_count];
+        stacks[0] = Some(Vec::new());
+        let mut pending = vec![0];
+
+        while let Some(index) = pending.pop() {
+            let mut stack = stacks[index].clone().unwrap();
+            for successor in self.step(index, &mut stack) {
+                if successor >= instruction_count {
+                    // Running past the last instruction simply completes the node
+                    continue;
+                }
+                let Some(known_stack) = &mut stacks[successor] else {
+                    stacks[successor] = Some(stack.clone());
+                    pending.push(successor);
+                    continue;
+                };
+                let mut changed = false;
+                if known_stack.len() > stack.len() {
+                    known_stack.drain(..known_stack.len() - stack.len());
+                    changed = true;
+                }
+                let shared_values = &stack[stack.len() - known_stack.len()..];
+                for (known_value, value) in known_stack.iter_mut().zip(shared_values) {
+                    changed |= known_value.merge(value);
+                }
+                if changed {
+                    pending.push(successor);
+                }
+            }
+        }
+    }
+
+    /// Simulates the effect of the instruction at `index` on the stack and returns the indices of the instructions that may run next.
+    fn step(&mut self, index: usize, stack: &mut Vec<StackValue>) -> Vec<usize> {
+        let Some(opcode) = self.opcodes[index] else {
+            return Vec::new();
+        };
+        let node = self.node;
+        let instruction = &node.instructions[index];
+        let next = vec![index + 1];
+
+        let required = match opcode {
+            OpCode::RunLine | OpCode::RunCommand => as_count(instruction.read_operand(1)).unwrap(),
+            OpCode::AddOption => {
+                let condition_count = usize::from(instruction.read_operand::<bool>(3));
+                as_count(instruction.read_operand(2)).unwrap() + condition_count
+            }
+            OpCode::Jump
+            | OpCode::JumpIfFalse
+            | OpCode::Pop
+            | OpCode::CallFunc
+            | OpCode::StoreVariable
+            | OpCode::RunNode => 1,
+            _ => 0,
+        };
+        if stack.len() < required {
+            self.report(
+                Some(index),
+                VerificationErrorKind::StackUnderflow {
+                    opcode,
+                    required,
+                    available: stack.len(),
+                },
+            );
+            return Vec::new();
+        }
+
+        match opcode {
+            OpCode::JumpTo => self.label_target(instruction.read_operand(0)),
+            OpCode::Jump => match stack.last().unwrap() {
+                StackValue::String(label) => {
+                    let label = label.clone();
+                    if !self.node.labels.contains_key(&label) {
+                        self.report(Some(index), VerificationErrorKind::LabelNotFound { label });
+                        return Vec::new();
+                    }
+                    self.label_target(label)
+                }
+                // The options shown there are the ones added since the previous `ShowOptions`.
+                // Nested option groups come after their parent's `ShowOptions`, so they are not mixed up.
+                StackValue::SelectedOption(show_options_index) => {
+                    let first_option_index = self.opcodes[..*show_options_index]
+                        .iter()
+                        .rposition(|opcode| *opcode == Some(OpCode::ShowOptions))
+                        .map_or(0, |previous_index| previous_index + 1);
+                    self.option_targets(first_option_index..*show_options_index)
+                }
+                // This is the destination of some selected option,
+                // so we may continue at any option that was added in this node.
+                _ => self.option_targets(0..self.opcodes.len()),
+            },
+            OpCode::RunLine | OpCode::RunCommand | OpCode::AddOption => {
+                stack.truncate(stack.len() - required);
+                next
+            }
+            OpCode::ShowOptions => {
+                stack.push(StackValue::SelectedOption(index));
+                next
+            }
+            OpCode::PushVariable => {
+                stack.push(StackValue::Unknown);
+                next
+            }
+            OpCode::PushString => {
+                stack.push(StackValue::String(instruction.read_operand(0)));
+                next
+            }
+            OpCode::PushFloat => {
+                stack.push(StackValue::Number(instruction.read_operand(0)));
+                next
+            }
+            OpCode::PushBool => {
+                stack.push(StackValue::Unknown);
+                next
+            }
+            OpCode::JumpIfFalse => {
+                let mut successors = self.label_target(instruction.read_operand(0));
+                successors.push(index + 1);
+                successors
+            }
+            OpCode::Pop => {
+                stack.pop();
+                next
+            }
+            OpCode::CallFunc => self.call_function(index, stack),
+            OpCode::StoreVariable => next,
+            OpCode::RunNode => {
+                if let Some(StackValue::String(node_name)) = stack.pop() {
+                    if !self.program.nodes.contains_key(&node_name) {
+                        self.report(
+                            Some(index),
+                            VerificationErrorKind::NodeNotFound { node_name },
+                        );
+                    }
+                }
+                Vec::new()
+            }
+            OpCode::Stop | OpCode::PushNull => Vec::new(),
+        }
+    }
+
+    fn call_function(&mut self, index: usize, stack: &mut Vec<StackValue>) -> Vec<usize> {
+        let function_name: String = self.node.instructions[index].read_operand(0);
+        let argument_count = match stack.pop() {
+            Some(StackValue::Number(count)) => as_count(count),
+            _ => None,
+        };
+        let Some(argument_count) = argument_count else {
+            self.report(
+                Some(index),
+                VerificationErrorKind::UnknownArgumentCount { function_name },
+            );
+            return Vec::new();
+        };
+
+        if let Some(function) = self.library.get(&function_name) {
+            let max = function.parameter_types().len();
+            let min = max - function.optional_parameter_count();
+            let max = function.variadic_parameter_type().is_none().then_some(max);
+            if argument_count < min || max.is_some_and(|max| argument_count > max) {
+                let kind = VerificationErrorKind::WrongArgumentCount {
+                    function_name,
+                    min,
+                    max,
+                    actual: argument_count,
+                };
+                self.report(Some(index), kind);
+            }
+        } else {
+            self.report(
+                Some(index),
+                VerificationErrorKind::FunctionNotFound { function_name },
+            );
+        }
+
+        if stack.len() < argument_count {
+            self.report(
+                Some(index),
+                VerificationErrorKind::StackUnderflow {
+                    opcode: OpCode::CallFunc,
+                    required: argument_count + 1,
+                    available: stack.len() + 1,
+                },
+            );
+            return Vec::new();
+        }
+        stack.truncate(stack.len() - argument_count);
+        stack.push(StackValue::Unknown);
+        vec![index + 1]
+    }
+
+    fn label_target(&self, label: String) -> Vec<usize> {
+        self.node
+            .labels
+            .get(&label)
+            .and_then(|&position| usize::try_from(position).ok())
+            .into_iter()
+            .collect()
+    }
+
+    /// The destinations of all options added by the instructions in `range`.
+    fn option_targets(&self, range: Range<usize>) -> Vec<usize> {
+        self.node.instructions[range.clone()]
+            .iter()
+            .zip(&self.opcodes[range])
+            .filter(|(_, opcode)| **opcode == Some(OpCode::AddOption))
+            .flat_map(|(instruction, _)| self.label_target(instruction.read_operand(1)))
+            .collect()
+    }
+}
+
+fn as_count(value: f32) -> Option<usize> {
+    (value >= 0.0 && value.fract() == 0.0).then_some(value as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instruction(opcode: OpCode, operands: impl IntoIterator<Item = Operand>) -> Instruction {
+        Instruction {
+            opcode: opcode.into(),
+            operands: operands.into_iter().collect(),
+        }
+    }
+
+    fn program(nodes: impl IntoIterator<Item = Node>) -> Program {
+        Program {
+            nodes: nodes
+                .into_iter()
+                .map(|node| (node.name.clone(), node))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn node(name: &str, instructions: impl IntoIterator<Item = Instruction>) -> Node {
+        Node {
+            name: name.to_owned(),
+            instructions: instructions.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    fn kinds(report: &VerificationReport) -> Vec<(Option<usize>, VerificationErrorKind)> {
+        report
+            .errors
+            .iter()
+            .map(|error| (error.instruction_index, error.kind.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn accepts_valid_program() {
+        let mut start = node(
+            "Start",
+            [
+                instruction(OpCode::PushFloat, [1.0.into()]),
+                instruction(OpCode::PushFloat, [2.0.into()]),
+                instruction(OpCode::PushFloat, [2.0.into()]),
+                instruction(OpCode::CallFunc, ["Number.Add".to_owned().into()]),
+                instruction(OpCode::StoreVariable, ["$x".to_owned().into()]),
+                instruction(OpCode::Pop, []),
+                instruction(
+                    OpCode::AddOption,
+                    [
+                        "line:1".to_owned().into(),
+                        "L0".to_owned().into(),
+                        0.0.into(),
+                        false.into(),
+                    ],
+                ),
+                instruction(OpCode::ShowOptions, []),
+                instruction(OpCode::Jump, []),
+                instruction(OpCode::JumpTo, ["end".to_owned().into()]),
+                instruction(OpCode::Pop, []),
+                instruction(OpCode::PushString, ["Other".to_owned().into()]),
+                instruction(OpCode::RunNode, []),
+            ],
+        );
+        start.labels.insert("L0".to_owned(), 9);
+        start.labels.insert("end".to_owned(), 10);
+        // Leaves the condition on the stack if it is true, like the compiler does for `<<if>>`
+        let mut other = node(
+            "Other",
+            [
+                instruction(OpCode::PushBool, [true.into()]),
+                instruction(OpCode::JumpIfFalse, ["skip".to_owned().into()]),
+                instruction(OpCode::JumpTo, ["end".to_owned().into()]),
+                instruction(OpCode::Pop, []),
+                instruction(OpCode::Stop, []),
+            ],
+        );
+        other.labels.insert("skip".to_owned(), 3);
+        other.labels.insert("end".to_owned(), 4);
+
+        let report = verify_program(&program([start, other]), &Library::standard_library());
+
+        assert!(report.is_valid(), "{report}");
+    }
+
+    #[test]
+    fn accepts_nested_options() {
+        let add_option = |line: &str, label: &str| {
+            instruction(
+                OpCode::AddOption,
+                [
+                    line.to_owned().into(),
+                    label.to_owned().into(),
+                    0.0.into(),
+                    false.into(),
+                ],
+            )
+        };
+        let mut start = node(
+            "Start",
+            [
+                add_option("line:1", "L1"),
+                add_option("line:2", "L2"),
+                instruction(OpCode::ShowOptions, []),
+                instruction(OpCode::Jump, []),
+                add_option("line:3", "L3"),
+                instruction(OpCode::ShowOptions, []),
+                instruction(OpCode::Jump, []),
+                instruction(OpCode::JumpTo, ["inner_end".to_owned().into()]),
+                instruction(OpCode::Pop, []),
+                instruction(OpCode::JumpTo, ["outer_end".to_owned().into()]),
+                instruction(OpCode::JumpTo, ["outer_end".to_owned().into()]),
+                instruction(OpCode::Pop, []),
+                instruction(OpCode::Stop, []),
+            ],
+        );
+        for (label, position) in [
+            ("L1", 4),
+            ("L3", 7),
+            ("inner_end", 8),
+            ("L2", 10),
+            ("outer_end", 11),
+        ] {
+            start.labels.insert(label.to_owned(), position);
+        }
+
+        let report = verify_program(&program([start]), &Library::new());
+
+        assert!(report.is_valid(), "{report}");
+    }
+
+    #[test]
+    fn reports_invalid_instructions() {
+        let start = node(
+            "Start",
+            [
+                Instruction {
+                    opcode: 42,
+                    operands: vec![],
+                },
+                instruction(OpCode::PushNull, []),
+                instruction(OpCode::RunLine, ["line:1".to_owned().into()]),
+                instruction(OpCode::PushFloat, [true.into()]),
+                instruction(OpCode::JumpTo, ["nowhere".to_owned().into()]),
+                instruction(OpCode::RunCommand, ["cmd".to_owned().into(), 1.5.into()]),
+            ],
+        );
+
+        let report = verify_program(&program([start]), &Library::new());
+
+        assert_eq!(
+            vec![
+                (Some(0), VerificationErrorKind::InvalidOpCode(42)),
+                (
+                    Some(1),
+                    VerificationErrorKind::UnsupportedOpCode(OpCode::PushNull)
+                ),
+                (
+                    Some(2),
+                    VerificationErrorKind::WrongOperandCount {
+                        opcode: OpCode::RunLine,
+                        expected: 2,
+                        actual: 1
+                    }
+                ),
+                (
+                    Some(3),
+                    VerificationErrorKind::WrongOperandType {
+                        opcode: OpCode::PushFloat,
+                        operand_index: 0,
+                        expected: OperandType::Float,
+                        actual: Some(OperandType::Bool)
+                    }
+                ),
+                (
+                    Some(4),
+                    VerificationErrorKind::LabelNotFound {
+                        label: "nowhere".to_owned()
+                    }
+                ),
+                (
+                    Some(5),
+                    VerificationErrorKind::InvalidCount {
+                        opcode: OpCode::RunCommand,
+                        operand_index: 1,
+                        value: 1.5
+                    }
+                ),
+            ],
+            kinds(&report)
+        );
+    }
+
+    #[test]
+    fn reports_stack_underflow_on_any_path() {
+        let mut start = node(
+            "Start",
+            [
+                instruction(OpCode::PushBool, [true.into()]),
+                instruction(OpCode::JumpIfFalse, ["skip".to_owned().into()]),
+                instruction(OpCode::Pop, []),
+                instruction(OpCode::Pop, []),
+                instruction(OpCode::Stop, []),
+            ],
+        );
+        start.labels.insert("skip".to_owned(), 3);
+        let other = node(
+            "Other",
+            [
+                instruction(OpCode::PushString, ["line".to_owned().into()]),
+                instruction(OpCode::RunLine, ["line:1".to_owned().into(), 2.0.into()]),
+            ],
+        );
+
+        let report = verify_program(&program([start, other]), &Library::new());
+
+        assert_eq!(
+            vec![
+                VerificationError {
+                    node_name: "Other".to_owned(),
+                    instruction_index: Some(1),
+                    kind: VerificationErrorKind::StackUnderflow {
+                        opcode: OpCode::RunLine,
+                        required: 2,
+                        available: 1
+                    }
+                },
+                VerificationError {
+                    node_name: "Start".to_owned(),
+                    instruction_index: Some(3),
+                    kind: VerificationErrorKind::StackUnderflow {
+                        opcode: OpCode::Pop,
+                        required: 1,
+                        available: 0
+                    }
+                },
+            ],
+            report.errors
+        );
+    }
+
+    #[test]
+    fn reports_functions_and_nodes_that_do_not_exist() {
+        let start = node(
+            "Start",
+            [
+                instruction(OpCode::PushFloat, [0.0.into()]),
+                instruction(OpCode::CallFunc, ["missing".to_owned().into()]),
+                instruction(OpCode::PushFloat, [1.0.into()]),
+                instruction(OpCode::PushFloat, [1.0.into()]),
+                instruction(OpCode::CallFunc, ["Number.Add".to_owned().into()]),
+                instruction(OpCode::PushVariable, ["$count".to_owned().into()]),
+                instruction(OpCode::CallFunc, ["floor".to_owned().into()]),
+                instruction(OpCode::PushString, ["Nowhere".to_owned().into()]),
+                instruction(OpCode::RunNode, []),
+            ],
+        );
+
+        let report = verify_program(&program([start]), &Library::standard_library());
+
+        assert_eq!(
+            vec![
+                (
+                    Some(1),
+                    VerificationErrorKind::FunctionNotFound {
+                        function_name: "missing".to_owned()
+                    }
+                ),
+                (
+                    Some(4),
+                    VerificationErrorKind::WrongArgumentCount {
+                        function_name: "Number.Add".to_owned(),
+                        min: 2,
+                        max: Some(2),
+                        actual: 1
+                    }
+                ),
+                (
+                    Some(6),
+                    VerificationErrorKind::UnknownArgumentCount {
+                        function_name: "floor".to_owned()
+                    }
+                ),
+            ],
+            kinds(&report)
+        );
+
+        let start = node(
+            "Start",
+            [
+                instruction(OpCode::PushString, ["Nowhere".to_owned().into()]),
+                instruction(OpCode::RunNode, []),
+            ],
+        );
+        let report = verify_program(&program([start]), &Library::standard_library());
+        assert_eq!(
+            vec![(
+                Some(1),
+                VerificationErrorKind::NodeNotFound {
+                    node_name: "Nowhere".to_owned()
+                }
+            )],
+            kinds(&report)
+        );
+    }
+
+    #[test]
+    fn reports_labels_out_of_range() {
+        let mut start = node("Start", [instruction(OpCode::Stop, [])]);
+        start.labels.insert("before".to_owned(), -1);
+        start.labels.insert("end".to_owned(), 1);
+        start.labels.insert("after".to_owned(), 2);
+
+        let report = verify_program(&program([start]), &Library::new());
+
+        assert_eq!(
+            vec![
+                (
+                    None,
+                    VerificationErrorKind::LabelOutOfRange {
+                        label: "after".to_owned(),
+                        position: 2,
+                        instruction_count: 1
+                    }
+                ),
+                (
+                    None,
+                    VerificationErrorKind::LabelOutOfRange {
+                        label: "before".to_owned(),
+                        position: -1,
+                        instruction_count: 1
+                    }
+                ),
+            ],
+            kinds(&report)
+        );
+    }
+}
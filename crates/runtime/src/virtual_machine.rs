@@ -8,6 +8,7 @@ use crate::markup::{LineParser, ParsedMarkup};
 use crate::prelude::*;
 use crate::Result;
 use log::*;
+use std::collections::HashMap;
 use std::fmt::Debug;
 use yarnspinner_core::prelude::OpCode;
 use yarnspinner_core::prelude::*;
@@ -29,6 +30,16 @@ pub(crate) struct VirtualMachine {
     line_parser: LineParser,
     text_provider: Box<dyn TextProvider>,
     language_code: Option<Language>,
+    line_history: Vec<LineId>,
+    line_history_limit: usize,
+    pub(crate) source_positions: HashMap<String, NodeSourcePositions>,
+}
+
+/// The source positions of a node's instructions. See [`Dialogue::add_source_positions`].
+#[derive(Debug, Clone, Default)]
+pub(crate) struct NodeSourcePositions {
+    pub(crate) file_name: String,
+    pub(crate) positions: HashMap<usize, Position>,
 }
 
 impl Iterator for VirtualMachine {
@@ -54,6 +65,9 @@ impl VirtualMachine {
             line_parser,
             text_provider,
             language_code: Default::default(),
+            line_history: Default::default(),
+            line_history_limit: Dialogue::DEFAULT_LINE_HISTORY_LIMIT,
+            source_positions: Default::default(),
             program: Default::default(),
             current_node_name: Default::default(),
             state: Default::default(),
@@ -80,6 +94,64 @@ impl VirtualMachine {
         self.variable_storage.as_mut()
     }
 
+    pub(crate) fn line_history(&self) -> &[LineId] {
+        &self.line_history
+    }
+
+    pub(crate) fn line_history_limit(&self) -> usize {
+        self.line_history_limit
+    }
+
+    pub(crate) fn set_line_history_limit(&mut self, limit: usize) {
+        self.line_history_limit = limit;
+        self.trim_line_history();
+    }
+
+    pub(crate) fn set_line_history(&mut self, line_history: Vec<LineId>) {
+        self.line_history = line_history;
+        self.trim_line_history();
+    }
+
+    fn push_line_history(&mut self, line_id: LineId) {
+        self.line_history.push(line_id);
+        self.trim_line_history();
+    }
+
+    /// Drops the oldest lines until the history fits into its limit.
+    fn trim_line_history(&mut self) {
+        let overflow = self
+            .line_history
+            .len()
+            .saturating_sub(self.line_history_limit);
+        self.line_history.drain(..overflow);
+    }
+
+    fn function_failed(
+        &self,
+        function_name: String,
+        arguments: Vec<YarnValue>,
+        error: YarnFnError,
+    ) -> DialogueError {
+        let node_name = self.current_node_name.clone().unwrap_or_default();
+        let instruction_index = self.state.program_counter;
+        let location = self.source_positions.get(&node_name).and_then(|node| {
+            node.positions.get(&instruction_index).map(|&position| {
+                Box::new(SourceLocation {
+                    file_name: node.file_name.clone(),
+                    position,
+                })
+            })
+        });
+        DialogueError::FunctionFailed {
+            function_name,
+            arguments,
+            node_name,
+            instruction_index,
+            location,
+            error,
+        }
+    }
+
     pub(crate) fn set_language_code(&mut self, language_code: impl Into<Option<Language>>) {
         let language_code = language_code.into();
         self.language_code.clone_from(&language_code);
@@ -310,8 +382,9 @@ impl VirtualMachine {
                 assert_up_to_date_compiler(instruction.operands.len() >= 2);
 
                 let substitutions = self.pop_substitutions_with_count_at_operand(instruction, 1);
-                let line = self.prepare_line(string_id, &substitutions)?;
+                let line = self.prepare_line(string_id.clone(), &substitutions)?;
 
+                self.push_line_history(string_id);
                 self.batched_events.push(DialogueEvent::Line(line));
 
                 // Implementation note:
@@ -464,17 +537,20 @@ impl VirtualMachine {
                             library: self.library.clone(),
                         })?;
 
-                // Expect the compiler to have placed the number of parameters
-                // actually passed at the top of the stack.
-                let expected_parameter_count = function.parameter_types().len();
-
-                assert_eq!(
-                    expected_parameter_count, actual_parameter_count,
-                    "Function {function_name} expected {expected_parameter_count} parameters, but received {actual_parameter_count}",
-                );
-
-                // Invoke the function
-                let return_value = function.call(parameters);
+                // Invoke the function. The function consumes its arguments, so they are kept around for the error.
+                // Functions called with the wrong number of arguments fail as well.
+                let context = FunctionContext {
+                    variable_storage: self.variable_storage.as_ref(),
+                    current_node_name: self.current_node_name.as_deref(),
+                    language_code: self.language_code.as_ref(),
+                    line_history: &self.line_history,
+                };
+                let return_value =
+                    function
+                        .call(parameters.clone(), &context)
+                        .map_err(|error| {
+                            self.function_failed(function_name.clone(), parameters, error)
+                        })?;
                 let return_type = function
                     .return_type()
                     .try_into()
@@ -637,3 +713,29 @@ fn expand_substitutions(text: &str, substitutions: &[String]) -> String {
             text.replace(&format!("{{{i}}}",), substitution)
         })
 }
+
+/// The [`YarnFnContext`] handed to functions called by the [`VirtualMachine`].
+struct FunctionContext<'a> {
+    variable_storage: &'a dyn VariableStorage,
+    current_node_name: Option<&'a str>,
+    language_code: Option<&'a Language>,
+    line_history: &'a [LineId],
+}
+
+impl YarnFnContext for FunctionContext<'_> {
+    fn variable(&self, name: &str) -> Option<YarnValue> {
+        self.variable_storage.get(name).ok()
+    }
+
+    fn current_node(&self) -> Option<&str> {
+        self.current_node_name
+    }
+
+    fn language_code(&self) -> Option<String> {
+        self.language_code.map(ToString::to_string)
+    }
+
+    fn line_history(&self) -> &[LineId] {
+        self.line_history
+    }
+}
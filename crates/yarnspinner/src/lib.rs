@@ -10,12 +10,13 @@ pub use log;
 pub mod prelude {
     //! Everything you need to get started using Yarn Spinner.
     pub use crate::compiler::{
-        Compilation, CompilationType, Compiler as YarnCompiler, CompilerError, File as YarnFile,
-        LineInfo, Result as YarnCompilerResult, StringInfo,
+        Compilation, CompilationType, Compiler as YarnCompiler, CompilerError,
+        CompilerSession as YarnCompilerSession, File as YarnFile, LineInfo,
+        Result as YarnCompilerResult, StringInfo,
     };
     pub use crate::core::{
         yarn_library, IntoYarnValueFromNonYarnValue, Library as YarnLibrary, LineId,
-        Program as YarnProgram, YarnFn, YarnValue,
+        Program as YarnProgram, Variadic, YarnFn, YarnFnContext, YarnFnReturn, YarnRng, YarnValue,
     };
     pub use crate::runtime::{
         Command as YarnCommand, CompiledProgramAnalyser as YarnAnalyser,
@@ -28,15 +29,23 @@ pub mod prelude {
 pub mod core {
     //! Core types and traits that are used by both the compiler and runtime.
     pub use yarnspinner_core::prelude::{
-        optionality, yarn_fn_type, yarn_library, Header, Instruction,
-        IntoYarnValueFromNonYarnValue, InvalidOpCodeError, Library, LineId, Node, Position,
-        Program, Type, UntypedYarnFn, YarnFn, YarnFnParam, YarnFnParamItem, YarnValue,
+        optionality, yarn_fn_type, yarn_library, AssemblyError, Header, Instruction,
+        IntoYarnValueFromNonYarnValue, InvalidOpCodeError, Library, LibraryError, LineEntry,
+        LineId, LineMetadataEntry, Node, Operator, Position, Program, ProgramFile,
+        ProgramFileError, Type, UntypedYarnFn, UpstreamFormatError, Variadic, YarnFn,
+        YarnFnContext, YarnFnError, YarnFnParam, YarnFnParamItem, YarnFnReturn, YarnRng, YarnValue,
         YarnValueCastError, YarnValueWrapper, YarnValueWrapperIter,
     };
 }
 pub mod compiler {
     //! Types and traits used by the compiler, in particular the [`Compiler`] struct.
+    pub use yarnspinner_compiler::ast;
+    pub use yarnspinner_compiler::format;
+    pub use yarnspinner_compiler::graph;
+    pub use yarnspinner_compiler::line_tagging;
     pub use yarnspinner_compiler::prelude::*;
+    #[cfg(feature = "serde")]
+    pub use yarnspinner_compiler::sarif;
     pub use yarnspinner_compiler::Result;
 }
 
@@ -49,3 +58,5 @@ pub mod runtime {
     pub use yarnspinner_runtime::prelude::*;
     pub use yarnspinner_runtime::Result;
 }
+
+pub mod statistics;
@@ -0,0 +1,261 @@
+//! Counts the words, lines and options of a story to budget voice-over and translation.
+//! [`ScriptStatistics`] reads the string table of a [`Compilation`] and totals the counts per character, node and file,
+//! and per language if translations are added with [`ScriptStatistics::with_translation`].
+//! The report can be exported with [`ScriptStatistics::to_csv`], and with `ScriptStatistics::to_json` if the `serde` feature is enabled.
+//!
+//! ```rust
+//! # use yarnspinner::prelude::*;
+//! # use yarnspinner::statistics::*;
+//! let compilation = YarnCompiler::new()
+//!     .add_file(YarnFile {
+//!         file_name: "intro.yarn".to_owned(),
+//!         source: "title: Start\n---\nAlice: Hello there! #line:hello\n-> Bob: Hi. #line:hi\n===\n".to_owned(),
+//!     })
+//!     .compile()
+//!     .unwrap();
+//! let statistics = ScriptStatistics::from_compilation(&compilation);
+//!
+//! assert_eq!(3, statistics.total.words);
+//! let alice = &statistics.characters[0];
+//! assert_eq!(("Alice", 1, 0, 2), (alice.name.as_str(), alice.lines, alice.options, alice.words));
+//! assert!(statistics.to_csv().contains("character,Bob,0,1,1\r\n"));
+//! ```
+//!
+//! ## Implementation notes
+//!
+//! The character of a line is found the same way as the `character` attribute of a [`YarnLine`](crate::prelude::YarnLine), see [`Line::parse`].
+//! Its name is not counted as words of the line. Markup is not counted either, and lines whose markup is malformed are counted as they are written.
+//! Words are the whitespace-separated parts of a line that contain a letter or digit, so a substitution like `{0}` counts as one word.
+//!
+//! The string table does not say which strings are options, so they are taken from the `AddOption` instructions of [`Compilation::program`].
+//! Without a program, e.g. for a [`CompilationType::StringsOnly`](crate::prelude::CompilationType) compilation, options are counted as lines.
+
+use crate::compiler::{Compilation, StringInfo};
+use crate::core::{Instruction, LineId};
+use crate::runtime::{Language, Line};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use yarnspinner_core::prelude::OpCode;
+
+/// The name of the base language in [`ScriptStatistics::languages`] until one is set with [`ScriptStatistics::with_base_language`].
+pub const DEFAULT_BASE_LANGUAGE_NAME: &str = "base";
+
+/// Word, line and option counts of a story. See the [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ScriptStatistics {
+    /// The counts of the whole story in the base language.
+    pub total: WordCounts,
+
+    /// The counts per language, starting with the base language. Empty unless translations were added.
+    /// A translation only counts the lines it contains.
+    pub languages: Vec<WordCounts>,
+
+    /// The counts per file, in the order of their names.
+    pub files: Vec<WordCounts>,
+
+    /// The counts per node, in the order they appear in the files.
+    pub nodes: Vec<WordCounts>,
+
+    /// The counts per character, in the order they first appear in the files.
+    /// Lines without a character are counted under an empty name.
+    pub characters: Vec<WordCounts>,
+
+    /// The IDs of the strings that are options. Also used to count the options of translations.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub option_ids: HashSet<LineId>,
+}
+
+/// The number of lines, options and words of a group of strings in [`ScriptStatistics`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WordCounts {
+    /// The name of the language, file, node or character. Empty for [`ScriptStatistics::total`].
+    pub name: String,
+
+    /// The number of lines, not including options.
+    pub lines: usize,
+
+    /// The number of options.
+    pub options: usize,
+
+    /// The number of words in both the lines and options.
+    pub words: usize,
+}
+
+impl WordCounts {
+    fn named(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    fn add(&mut self, is_option: bool, words: usize) {
+        if is_option {
+            self.options += 1;
+        } else {
+            self.lines += 1;
+        }
+        self.words += words;
+    }
+}
+
+impl ScriptStatistics {
+    /// Counts the strings in [`Compilation::string_table`].
+    pub fn from_compilation(compilation: &Compilation) -> Self {
+        let option_ids = compilation
+            .program
+            .iter()
+            .flat_map(|program| program.nodes.values())
+            .flat_map(|node| &node.instructions)
+            .filter_map(option_id)
+            .collect();
+        let mut statistics = Self {
+            option_ids,
+            ..Default::default()
+        };
+
+        let mut strings: Vec<(&LineId, &StringInfo)> = compilation.string_table.iter().collect();
+        strings.sort_by(|(a_id, a), (b_id, b)| {
+            (&a.file_name, a.line_number, &a_id.0).cmp(&(&b.file_name, b.line_number, &b_id.0))
+        });
+        for (line_id, string_info) in strings {
+            let (character, words) = count_words(line_id, &string_info.text);
+            let is_option = statistics.option_ids.contains(line_id);
+            statistics.total.add(is_option, words);
+            for (groups, name) in [
+                (&mut statistics.files, &string_info.file_name),
+                (&mut statistics.nodes, &string_info.node_name),
+                (&mut statistics.characters, &character.unwrap_or_default()),
+            ] {
+                group(groups, name).add(is_option, words);
+            }
+        }
+        statistics
+    }
+
+    /// Sets the name of the base language in [`ScriptStatistics::languages`]. Defaults to [`DEFAULT_BASE_LANGUAGE_NAME`].
+    #[must_use]
+    pub fn with_base_language(mut self, language: impl Into<Language>) -> Self {
+        let name = language.into().to_string();
+        match self.languages.first_mut() {
+            Some(base) => base.name = name,
+            None => self.languages.push(WordCounts {
+                name,
+                ..self.total.clone()
+            }),
+        }
+        self
+    }
+
+    /// Adds the counts of a translation of the string table, e.g. one loaded for a [`StringTableTextProvider`](crate::runtime::StringTableTextProvider), to [`ScriptStatistics::languages`].
+    #[must_use]
+    pub fn with_translation(
+        mut self,
+        language: impl Into<Language>,
+        translation: &HashMap<LineId, String>,
+    ) -> Self {
+        if self.languages.is_empty() {
+            self.languages.push(WordCounts {
+                name: DEFAULT_BASE_LANGUAGE_NAME.to_owned(),
+                ..self.total.clone()
+            });
+        }
+        let mut counts = WordCounts::named(language.into().to_string());
+        for (line_id, text) in translation {
+            let (_, words) = count_words(line_id, text);
+            counts.add(self.option_ids.contains(line_id), words);
+        }
+        self.languages.push(counts);
+        self
+    }
+
+    /// Writes the report as CSV with the columns `group,name,lines,options,words`.
+    /// The group is one of `total`, `language`, `file`, `node` and `character`, in that order.
+    /// The file is written like the `<name>-Lines.csv` file, see [`LineEntry::write_csv`](crate::core::LineEntry::write_csv).
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("group,name,lines,options,words\r\n");
+        for (group, counts) in self.groups() {
+            for counts in counts {
+                let record = [
+                    group.to_owned(),
+                    csv_field(&counts.name),
+                    counts.lines.to_string(),
+                    counts.options.to_string(),
+                    counts.words.to_string(),
+                ];
+                csv.push_str(&record.join(","));
+                csv.push_str("\r\n");
+            }
+        }
+        csv
+    }
+
+    /// Writes the report as a JSON object with the keys `total`, `languages`, `files`, `nodes` and `characters`.
+    /// Each count is an object with the keys `name`, `lines`, `options` and `words`.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Serializing statistics cannot fail")
+    }
+
+    fn groups(&self) -> impl Iterator<Item = (&'static str, &[WordCounts])> {
+        [
+            ("total", std::slice::from_ref(&self.total)),
+            ("language", self.languages.as_slice()),
+            ("file", self.files.as_slice()),
+            ("node", self.nodes.as_slice()),
+            ("character", self.characters.as_slice()),
+        ]
+        .into_iter()
+    }
+}
+
+/// The ID of the option added by `instruction`, if it adds one.
+fn option_id(instruction: &Instruction) -> Option<LineId> {
+    if OpCode::try_from(instruction.opcode).ok()? != OpCode::AddOption {
+        return None;
+    }
+    let operand = instruction.operands.first()?.clone();
+    String::try_from(operand).ok().map(LineId)
+}
+
+/// The counts named `name` in `groups`, added at the end if there are none yet.
+fn group<'a>(groups: &'a mut Vec<WordCounts>, name: &str) -> &'a mut WordCounts {
+    let index = match groups.iter().position(|counts| counts.name == name) {
+        Some(index) => index,
+        None => {
+            groups.push(WordCounts::named(name));
+            groups.len() - 1
+        }
+    };
+    &mut groups[index]
+}
+
+/// The character speaking `text` and the number of words they say.
+fn count_words(line_id: &LineId, text: &str) -> (Option<String>, usize) {
+    let (character, text) = match Line::parse(line_id.clone(), text) {
+        Ok(line) => (
+            line.character_name().map(ToOwned::to_owned),
+            line.text_without_character_name(),
+        ),
+        Err(_) => (None, text.to_owned()),
+    };
+    let words = text
+        .split_whitespace()
+        .filter(|word| word.chars().any(char::is_alphanumeric))
+        .count();
+    (character, words)
+}
+
+fn csv_field(field: &str) -> String {
+    let needs_quotes = field.contains([',', '"', '\r', '\n'])
+        || field.starts_with(char::is_whitespace)
+        || field.ends_with(char::is_whitespace);
+    if needs_quotes {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
@@ -0,0 +1,115 @@
+//! Tests the syntax tree created by `ast::parse`.
+
+use yarnspinner::compiler::ast::*;
+use yarnspinner::compiler::File;
+use yarnspinner::core::{LineId, Operator};
+
+const SOURCE: &str = "title: Start
+---
+<<declare $gold = 5 as Number>>
+Sally: Hi, {$name}! #line:greeting #happy
+<<if $gold >= 5>>
+    -> Buy a sword <<if visited(\"Shop\")>> #line:sword
+        <<set $gold -= 3>>
+    -> Leave
+<<elseif not $rich>>
+    <<walk Sally {$gold * 2}>>
+<<else>>
+    <<jump Shop>>
+<<endif>>
+===
+";
+
+fn parse_source() -> Dialogue {
+    parse(&File {
+        file_name: "test.yarn".to_owned(),
+        source: SOURCE.to_owned(),
+    })
+    .unwrap()
+}
+
+#[test]
+fn parses_lines_with_expressions_and_hashtags() {
+    let dialogue = parse_source();
+    let Statement::Line(line) = &dialogue.nodes[0].body[1] else {
+        panic!("expected a line");
+    };
+
+    assert_eq!(TextSegment::Text("Sally: Hi, ".to_owned()), line.text[0]);
+    assert!(matches!(
+        &line.text[1],
+        TextSegment::Expression(Expression { kind: ExpressionKind::Variable(variable), .. }) if variable.name == "$name"
+    ));
+    assert_eq!(TextSegment::Text("!".to_owned()), line.text[2]);
+    assert_eq!(Some(LineId::from("line:greeting")), line.line_id());
+    assert_eq!("happy", line.hashtags[1].text);
+    assert_eq!(3, line.range.start.line);
+}
+
+#[test]
+fn parses_if_statements_with_options_and_commands() {
+    let dialogue = parse_source();
+    let Statement::If(if_statement) = &dialogue.nodes[0].body[2] else {
+        panic!("expected an if statement");
+    };
+    assert_eq!(3, if_statement.clauses.len());
+    assert!(if_statement.clauses[2].condition.is_none());
+
+    let Statement::Options(options) = &if_statement.clauses[0].statements[0] else {
+        panic!("expected options");
+    };
+    let buy = &options.options[0];
+    assert!(buy.line.condition.is_some());
+    let Statement::Set(set) = &buy.statements[0] else {
+        panic!("expected a set statement");
+    };
+    assert_eq!(Some(Operator::Subtract), set.operator);
+    assert!(options.options[1].statements.is_empty());
+
+    let Statement::Command(command) = &if_statement.clauses[1].statements[0] else {
+        panic!("expected a command");
+    };
+    assert_eq!(Some("walk"), command.name());
+    assert_eq!(2, command.text.len());
+
+    let Statement::Jump(jump) = &if_statement.clauses[2].statements[0] else {
+        panic!("expected a jump");
+    };
+    assert!(matches!(&jump.destination, JumpDestination::Node { name, .. } if name == "Shop"));
+}
+
+#[test]
+fn visits_all_variables_and_function_calls() {
+    #[derive(Default)]
+    struct Collector {
+        variables: Vec<String>,
+        functions: Vec<String>,
+        lines: usize,
+    }
+
+    impl Visitor for Collector {
+        fn visit_line(&mut self, line: &Line) {
+            self.lines += 1;
+            walk_line(self, line);
+        }
+
+        fn visit_variable(&mut self, variable: &Variable) {
+            self.variables.push(variable.name.clone());
+        }
+
+        fn visit_function_call(&mut self, function_call: &FunctionCall) {
+            self.functions.push(function_call.name.clone());
+            walk_function_call(self, function_call);
+        }
+    }
+
+    let mut collector = Collector::default();
+    collector.visit_dialogue(&parse_source());
+
+    assert_eq!(
+        vec!["$gold", "$name", "$gold", "$gold", "$rich", "$gold"],
+        collector.variables
+    );
+    assert_eq!(vec!["visited"], collector.functions);
+    assert_eq!(3, collector.lines);
+}
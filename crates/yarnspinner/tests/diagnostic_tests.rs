@@ -0,0 +1,114 @@
+//! Tests the codes and suggestions of diagnostics.
+
+use crate::test_base::*;
+use test_base::prelude::*;
+use yarnspinner::compiler::*;
+use yarnspinner::core::Position;
+
+mod test_base;
+
+#[test]
+fn reports_stable_codes() {
+    let result = Compiler::from_test_source("<<if true>>")
+        .compile()
+        .unwrap_err();
+
+    let codes: Vec<_> = result.0.iter().map(|d| d.code).collect();
+    assert!(codes.contains(&DiagnosticCode::SyntaxError), "{result}");
+    assert_eq!("YS0002", DiagnosticCode::SyntaxError.as_str());
+    assert_eq!(
+        Some(DiagnosticCode::UndeclaredVariable),
+        DiagnosticCode::from_code("YS0001")
+    );
+    assert!(result.to_string().contains("error[YS0002]"), "{result}");
+}
+
+#[test]
+fn suggests_the_closest_declared_variable() {
+    let result = Compiler::from_test_source("<<declare $gold = 0>>\nYou have {$glod} gold.")
+        .compile()
+        .unwrap_err();
+
+    let diagnostic = &result.0[0];
+    assert_eq!(DiagnosticCode::UndeclaredVariable, diagnostic.code);
+    let suggestion = &diagnostic.suggestions[0];
+    assert_eq!("$gold", suggestion.replacement);
+    assert_eq!(diagnostic.range.clone().unwrap(), suggestion.range);
+    assert!(
+        result.to_string().contains("did you mean `$gold`?"),
+        "{result}"
+    );
+}
+
+#[test]
+fn does_not_suggest_unrelated_variables() {
+    let result = Compiler::from_test_source("<<declare $gold = 0>>\nYou are {$name}.")
+        .compile()
+        .unwrap_err();
+
+    assert_eq!(DiagnosticCode::UndeclaredVariable, result.0[0].code);
+    assert!(result.0[0].suggestions.is_empty());
+}
+
+#[test]
+fn warns_about_jumps_to_unknown_nodes() {
+    let result = Compiler::new()
+        .add_files([
+            File {
+                file_name: "start.yarn".to_owned(),
+                source: "title: Start\n---\n<<jump Shpo>>\n<<jump {(\"Shop\")}>>\n<<jump {\"Shpo\"}>>\n===\n"
+                    .to_owned(),
+            },
+            File {
+                file_name: "shop.yarn".to_owned(),
+                source: "title: Shop\n---\n<<jump Nowhere>>\n===\n".to_owned(),
+            },
+        ])
+        .compile()
+        .unwrap();
+
+    let warnings: Vec<_> = result
+        .warnings
+        .iter()
+        .filter(|warning| warning.code == DiagnosticCode::UnknownNode)
+        .collect();
+    assert_eq!(3, warnings.len(), "{:?}", result.warnings);
+
+    let suggestion = &warnings[0].suggestions[0];
+    assert_eq!(Some("start.yarn"), warnings[0].file_name.as_deref());
+    assert_eq!("Shop", suggestion.replacement);
+    assert_eq!(
+        Position {
+            line: 2,
+            character: 7
+        }..Position {
+            line: 2,
+            character: 11
+        },
+        suggestion.range
+    );
+    assert_eq!("\"Shop\"", warnings[1].suggestions[0].replacement);
+    assert_eq!(Some("shop.yarn"), warnings[2].file_name.as_deref());
+    assert!(warnings[2].suggestions.is_empty());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn converts_diagnostics_to_sarif() {
+    use yarnspinner::compiler::sarif::SarifLog;
+
+    let result = Compiler::from_test_source("<<declare $gold = 0>>\nYou have {$glod} gold.")
+        .compile()
+        .unwrap_err();
+
+    let log = SarifLog::from_diagnostics(&result.0);
+
+    let sarif_result = &log.runs[0].results[0];
+    assert_eq!("YS0001", sarif_result.rule_id);
+    assert_eq!(
+        "$gold",
+        sarif_result.fixes[0].artifact_changes[0].replacements[0]
+            .inserted_content
+            .text
+    );
+}
@@ -6,6 +6,7 @@
 use std::collections::HashMap;
 use test_base::prelude::*;
 use yarnspinner::compiler::*;
+use yarnspinner::core::*;
 use yarnspinner::runtime::*;
 
 mod test_base;
@@ -267,6 +268,238 @@ fn test_function_argument_type_inference() {
     assert!(!bool_value);
 }
 
+#[test]
+fn test_failing_function_returns_error() {
+    let mut test_base = TestBase::new().extend_library(|library| {
+        library.add_function("parse_int", |text: &str| text.parse::<i32>());
+    });
+
+    let result = Compiler::from_test_source("{parse_int(\"12\")}\n{parse_int(\"twelve\")}")
+        .extend_library(test_base.dialogue.library().clone())
+        .compile()
+        .unwrap();
+    let debug_info = result.debug_info.clone();
+    test_base = test_base.with_compilation(result);
+    for (node_name, node_debug_info) in &debug_info {
+        test_base.dialogue.add_source_positions(
+            node_name,
+            &node_debug_info.file_name,
+            node_debug_info
+                .line_positions
+                .iter()
+                .filter_map(|(&index, &position)| Some((index, position?))),
+        );
+    }
+    test_base.dialogue.set_node("Start").unwrap();
+
+    let events = test_base.dialogue.continue_().unwrap();
+    assert!(events
+        .iter()
+        .any(|event| matches!(event, DialogueEvent::Line(line) if line.text == "12")));
+
+    let error = test_base.dialogue.continue_().unwrap_err();
+    let DialogueError::FunctionFailed {
+        function_name,
+        arguments,
+        node_name,
+        instruction_index,
+        location,
+        ..
+    } = error
+    else {
+        panic!("Expected a FunctionFailed error, got {error:?}");
+    };
+    assert_eq!("parse_int", function_name);
+    assert_eq!(vec![YarnValue::from("twelve")], arguments);
+    assert_eq!("Start", node_name);
+
+    let line_info = debug_info[&node_name]
+        .try_get_line_info(instruction_index)
+        .unwrap();
+    let location = location.unwrap();
+    assert_eq!(line_info.file_name, location.file_name);
+    // Zero-indexed and counting the node header
+    assert_eq!(3, location.position.line);
+}
+
+#[test]
+fn test_calling_function_with_wrong_argument_count_returns_error() {
+    let mut test_base = TestBase::new().extend_library(|library| {
+        library.add_function("greet", |name: &str| format!("Hi {name}"));
+    });
+
+    // Compiled against a different signature than the one the dialogue runs with
+    let mut compile_library = Library::new();
+    compile_library.add_function("greet", |name: &str, title: &str| {
+        format!("Hi {title} {name}")
+    });
+    let result = Compiler::from_test_source("{greet(\"Ada\", \"Dr.\")}")
+        .extend_library(compile_library)
+        .compile()
+        .unwrap();
+    test_base = test_base.with_compilation(result);
+    test_base.dialogue.set_node("Start").unwrap();
+
+    let error = test_base.dialogue.continue_().unwrap_err();
+    let DialogueError::FunctionFailed {
+        function_name,
+        arguments,
+        error,
+        ..
+    } = error
+    else {
+        panic!("Expected a FunctionFailed error, got {error:?}");
+    };
+    assert_eq!("greet", function_name);
+    assert_eq!(
+        vec![YarnValue::from("Ada"), YarnValue::from("Dr.")],
+        arguments
+    );
+    assert_eq!("Expected 1 arguments, but received 2", error.to_string());
+}
+
+#[test]
+fn test_functions_can_read_dialogue_context() {
+    let test_base = TestBase::new().extend_library(|library| {
+        library.add_function(
+            "describe_context",
+            |context: &dyn YarnFnContext, variable: &str| {
+                format!(
+                    "{} {:?} {} {}",
+                    context.current_node().unwrap_or_default(),
+                    context.variable(variable),
+                    context.language_code().unwrap_or_default(),
+                    context.line_history().len(),
+                )
+            },
+        );
+    });
+
+    let result =
+        Compiler::from_test_source("<<declare $gold = 5>>\nHello\n{describe_context(\"$gold\")}")
+            .extend_library(test_base.dialogue.library().clone())
+            .compile()
+            .unwrap();
+
+    let mut dialogue = test_base.with_compilation(result).dialogue;
+    dialogue.set_node("Start").unwrap();
+    let lines: Vec<_> = dialogue
+        .by_ref()
+        .flatten()
+        .filter_map(|event| match event {
+            DialogueEvent::Line(line) => Some(line.text),
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(
+        vec![
+            "Hello".to_string(),
+            "Start Some(Number(5.0)) en-US 1".to_string()
+        ],
+        lines
+    );
+    assert_eq!(2, dialogue.line_history().len());
+}
+
+#[test]
+fn test_line_history_is_bounded_and_restorable() {
+    let result = Compiler::from_test_source("One\nTwo\nThree")
+        .compile()
+        .unwrap();
+    let mut dialogue = TestBase::new().with_compilation(result).dialogue;
+    dialogue.set_line_history_limit(2);
+    dialogue.set_node("Start").unwrap();
+    dialogue.by_ref().for_each(drop);
+
+    let saved = dialogue.line_history().to_vec();
+    assert_eq!(2, saved.len());
+    let three = saved[1].clone();
+
+    dialogue.clear_line_history();
+    assert!(dialogue.line_history().is_empty());
+
+    dialogue.set_line_history(saved.clone());
+    assert_eq!(saved, dialogue.line_history());
+
+    dialogue.set_line_history_limit(1);
+    assert_eq!(vec![three], dialogue.line_history());
+}
+
+#[test]
+fn test_namespaced_functions_can_be_called() {
+    let test_base = TestBase::new().extend_library(|library| {
+        library
+            .import_module(
+                "Inventory",
+                yarn_library! { "count" => |item: &str| item.len(), },
+            )
+            .unwrap()
+            .import_module("Quests", yarn_library! { "count" => || 2, })
+            .unwrap();
+    });
+
+    let result = Compiler::from_test_source(
+        "{Inventory.count(\"sword\")} {Quests.count()}\n<<if Inventory.count(\"axe\") == 3>>\nThree\n<<endif>>",
+    )
+    .extend_library(test_base.dialogue.library().clone())
+    .compile()
+    .unwrap();
+
+    let mut dialogue = test_base.with_compilation(result).dialogue;
+    dialogue.set_node("Start").unwrap();
+    let lines: Vec<_> = dialogue
+        .flatten()
+        .filter_map(|event| match event {
+            DialogueEvent::Line(line) => Some(line.text),
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(vec!["5 2".to_string(), "Three".to_string()], lines);
+}
+
+#[test]
+fn test_compiled_programs_pass_verification() {
+    let result = Compiler::from_test_source(
+        "<<declare $gold = 5>>
+<<if $gold > 3>>
+    Rich {round($gold)}
+<<elseif $gold > 1>>
+    Okay
+<<else>>
+    Poor
+<<endif>>
+-> Buy {$gold} <<if $gold >= 2>>
+    <<set $gold -= 2>>
+    -> Again
+        Nested
+    -> Stop
+-> Leave
+<<jump Start>>",
+    )
+    .compile()
+    .unwrap();
+    let program = result.program.unwrap();
+
+    let mut dialogue = TestBase::new().dialogue;
+    let report = dialogue.verify_program(&program);
+    assert!(report.is_valid(), "{report}");
+
+    dialogue.try_add_program(program.clone()).unwrap();
+    let Err(DialogueError::InvalidProgram(report)) = dialogue.try_add_program(program) else {
+        panic!("Expected adding the same program twice to fail");
+    };
+    assert_eq!(
+        vec![VerificationError {
+            node_name: "Start".to_owned(),
+            instruction_index: None,
+            kind: VerificationErrorKind::NodeAlreadyExists,
+        }],
+        report.errors
+    );
+}
+
 #[test]
 fn test_selecting_option_from_inside_option_callback() {
     let result = Compiler::from_test_source("-> option 1\n->option 2\nfinal line\n")
@@ -327,3 +560,29 @@ fn test_selecting_option_from_inside_option_callback() {
         }
     }
 }
+
+#[test]
+fn test_seeded_random_functions_are_deterministic() {
+    let result = Compiler::from_test_source(
+        "{random()}\n{random_range(1, 1000)}\n{dice(20)}\n<<jump Start>>",
+    )
+    .compile()
+    .unwrap();
+
+    let run = |seed: u64| -> Vec<String> {
+        let mut dialogue = TestBase::new().with_compilation(result.clone()).dialogue;
+        dialogue.set_random_seed(seed);
+        dialogue.set_node("Start").unwrap();
+        dialogue
+            .flatten()
+            .filter_map(|event| match event {
+                DialogueEvent::Line(line) => Some(line.text),
+                _ => None,
+            })
+            .take(30)
+            .collect()
+    };
+
+    assert_eq!(run(42), run(42));
+    assert_ne!(run(42), run(43));
+}
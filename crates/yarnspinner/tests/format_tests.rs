@@ -0,0 +1,186 @@
+//! Tests that formatting a file keeps what it compiles to.
+
+use std::collections::HashMap;
+use std::path::Path;
+use test_base::prelude::*;
+use yarnspinner::compiler::*;
+use yarnspinner::core::LineId;
+
+mod test_base;
+
+const UNFORMATTED: &str = "#file_tag
+// Where it all begins
+tags:  intro   tutorial
+title:Start
+---
+
+<<declare $name=\"Traveler\">>
+// Greet the player
+Hello {$name}!   You have {  $gold+1 } gold. #line:0a1b #greeting
+<<declare $gold=5 as Number>>
+<<if $gold>=5&&visited( \"Start\" )>>
+-> Buy a sword<<if $gold > 3>>#line:sword
+   <<set $gold-=3>>
+   Sold!   // the shopkeeper is happy
+-> Leave
+<<else>>
+  <<walk  Sally   {$gold*2}>>
+<<endif>>
+
+
+
+-> Option after a blank line
+  Nested
+      -> Deeper
+           Deepest
+===
+
+
+
+title: Other
+---
+   <<jump Start>>
+===
+";
+
+fn format_source(source: &str) -> String {
+    format::format_file(&File {
+        file_name: "<input>".to_owned(),
+        source: source.to_owned(),
+    })
+    .unwrap()
+}
+
+fn compile(source: &str) -> Result<Compilation> {
+    let test_base = TestBase::default().extend_library(|library| {
+        library
+            .add_function("add_three_operands", |a: i32, b: i32, c: i32| a + b + c)
+            .add_function("dummy_bool", || true)
+            .add_function("dummy_number", || 1)
+            .add_function("dummy_string", || "string".to_owned());
+    });
+    Compiler::new()
+        .add_file(File {
+            file_name: "<input>".to_owned(),
+            source: source.to_owned(),
+        })
+        .extend_library(test_base.dialogue.library().clone())
+        .compile()
+}
+
+fn strings(compilation: &Compilation) -> HashMap<LineId, (String, Vec<String>)> {
+    compilation
+        .string_table
+        .iter()
+        .map(|(id, info)| (id.clone(), (info.text.clone(), info.metadata.clone())))
+        .collect()
+}
+
+fn assert_formatting_keeps_program(name: &str, source: &str) {
+    let Ok(original) = compile(source) else {
+        println!("INFO: Skipping {name}, which does not compile");
+        return;
+    };
+    let formatted_source = format_source(source);
+    let formatted = compile(&formatted_source)
+        .unwrap_or_else(|error| panic!("{name} does not compile after formatting: {error}"));
+
+    assert_eq!(original.program, formatted.program, "{name}");
+    assert_eq!(strings(&original), strings(&formatted), "{name}");
+    assert_eq!(formatted_source, format_source(&formatted_source), "{name}");
+}
+
+#[test]
+fn formats_canonically() {
+    let expected = "#file_tag
+
+// Where it all begins
+tags: intro   tutorial
+title: Start
+---
+<<declare $name = \"Traveler\">>
+// Greet the player
+Hello {$name}!   You have {$gold + 1} gold. #line:0a1b #greeting
+<<declare $gold = 5 as Number>>
+<<if $gold >= 5 && visited(\"Start\")>>
+    -> Buy a sword <<if $gold > 3>> #line:sword
+        <<set $gold -= 3>>
+        Sold! // the shopkeeper is happy
+    -> Leave
+<<else>>
+    <<walk  Sally   {$gold * 2}>>
+<<endif>>
+
+-> Option after a blank line
+    Nested
+    -> Deeper
+        Deepest
+===
+
+title: Other
+---
+<<jump Start>>
+===
+";
+
+    assert_eq!(expected, format_source(UNFORMATTED));
+}
+
+#[test]
+fn formatting_keeps_the_program() {
+    assert!(compile(UNFORMATTED).is_ok());
+    assert_formatting_keeps_program("UNFORMATTED", UNFORMATTED);
+}
+
+#[test]
+fn formatting_keeps_comments_and_line_tags() {
+    let formatted = format_source(UNFORMATTED);
+
+    for kept in [
+        "// Where it all begins",
+        "// Greet the player",
+        "// the shopkeeper is happy",
+        "#line:0a1b",
+        "#line:sword",
+    ] {
+        assert!(formatted.contains(kept), "{kept} is missing in {formatted}");
+    }
+}
+
+#[test]
+fn formatting_is_idempotent() {
+    let formatted = format_source(UNFORMATTED);
+
+    assert_eq!(formatted, format_source(&formatted));
+}
+
+#[test]
+fn does_not_format_files_with_errors() {
+    let result = format::format_file(&File {
+        file_name: "broken.yarn".to_owned(),
+        source: "title: Start\n---\n<<if true\n===\n".to_owned(),
+    });
+
+    let error = result.unwrap_err();
+    assert!(!error.0.is_empty());
+    assert!(error
+        .0
+        .iter()
+        .all(|diagnostic| diagnostic.file_name.as_deref() == Some("broken.yarn")));
+}
+
+#[test]
+fn formatting_keeps_the_programs_of_the_test_cases() {
+    let files = TestBase::file_sources("TestCases")
+        .chain(TestBase::file_sources("Projects/Space"))
+        .map(|file| test_data_path().join(file))
+        .chain([project_root_path().join("../../demo/assets/dialogue/story.yarn")]);
+    for path in files {
+        let source = std::fs::read_to_string(&path).unwrap();
+        assert_formatting_keeps_program(&display_name(&path), &source);
+    }
+}
+
+fn display_name(path: &Path) -> String {
+    path.file_name().unwrap().to_string_lossy().into_owned()
+}
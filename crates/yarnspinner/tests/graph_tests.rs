@@ -0,0 +1,168 @@
+//! Tests the export of dialogue graphs in [`graph`].
+
+use yarnspinner::compiler::graph::*;
+use yarnspinner::compiler::*;
+
+const SOURCE: &str = "title: Start
+tags: intro
+---
+<<declare $gold = 0>>
+Welcome!
+-> Go shopping #line:shop
+    <<jump Shop>>
+-> Look around
+    -> At the \"sign\"
+        <<jump Sign>>
+    -> At the sky
+<<if $gold > 5>>
+    <<jump Rich>>
+<<endif>>
+<<jump {\"End\"}>>
+===
+title: Shop
+---
+<<set $gold to $gold + 1>>
+<<if visited(\"Start\")>>
+    Welcome back.
+<<endif>>
+<<jump Start>>
+===
+title: End
+---
+===
+";
+
+fn compile() -> Compilation {
+    Compiler::new()
+        .add_file(File {
+            file_name: "test.yarn".to_owned(),
+            source: SOURCE.to_owned(),
+        })
+        .compile()
+        .unwrap()
+}
+
+fn edge(from: &str, to: &str, label: Option<&str>) -> GraphEdge {
+    GraphEdge {
+        from: from.to_owned(),
+        to: to.to_owned(),
+        kind: if label.is_some() {
+            EdgeKind::Option
+        } else {
+            EdgeKind::Jump
+        },
+        label: label.map(ToOwned::to_owned),
+    }
+}
+
+#[test]
+fn builds_graph_from_compilation() {
+    let graph = DialogueGraph::from_compilation(&compile()).unwrap();
+
+    let names: Vec<_> = graph.nodes.iter().map(|node| node.name.as_str()).collect();
+    assert_eq!(vec!["Start", "Shop", "End"], names);
+    assert_eq!(
+        vec![
+            edge("Start", "Shop", Some("Go shopping")),
+            edge("Start", "Sign", Some("At the \"sign\"")),
+            edge("Start", "Rich", None),
+            edge("Start", "End", None),
+            edge("Shop", "Start", None),
+        ],
+        graph.edges
+    );
+}
+
+#[test]
+fn annotates_nodes_with_tags_and_variables() {
+    let graph = DialogueGraph::from_compilation(&compile()).unwrap();
+
+    let start = &graph.nodes[0];
+    assert_eq!(vec!["intro"], start.tags);
+    assert_eq!(vec!["$gold"], start.reads);
+    assert!(start.writes.is_empty());
+
+    let shop = &graph.nodes[1];
+    assert!(shop.tags.is_empty());
+    assert_eq!(vec!["$gold"], shop.reads);
+    assert_eq!(vec!["$gold"], shop.writes);
+}
+
+#[test]
+fn labels_options_with_line_ids_without_string_table() {
+    let compilation = compile();
+    let graph = DialogueGraph::from_program(compilation.program.as_ref().unwrap());
+
+    let names: Vec<_> = graph.nodes.iter().map(|node| node.name.as_str()).collect();
+    assert_eq!(vec!["End", "Shop", "Start"], names);
+    assert!(graph
+        .edges
+        .contains(&edge("Start", "Shop", Some("line:shop"))));
+}
+
+#[test]
+fn has_no_graph_without_program() {
+    let compilation = Compiler::new()
+        .add_file(File {
+            file_name: "test.yarn".to_owned(),
+            source: SOURCE.to_owned(),
+        })
+        .with_compilation_type(CompilationType::StringsOnly)
+        .compile()
+        .unwrap();
+
+    assert!(DialogueGraph::from_compilation(&compilation).is_none());
+}
+
+#[test]
+fn exports_dot() {
+    let dot = DialogueGraph::from_compilation(&compile())
+        .unwrap()
+        .to_dot();
+
+    assert!(dot.starts_with("digraph dialogue {\n"));
+    assert!(dot.contains("    \"Start\" [label=\"Start\\ntags: intro\\nreads: $gold\"];\n"));
+    assert!(dot.contains("    \"Start\" -> \"Sign\" [label=\"At the \\\"sign\\\"\"];\n"));
+    assert!(dot.contains("    \"Shop\" -> \"Start\";\n"));
+    assert!(dot.ends_with("}\n"));
+}
+
+#[test]
+fn exports_mermaid() {
+    let mermaid = DialogueGraph::from_compilation(&compile())
+        .unwrap()
+        .to_mermaid();
+
+    assert!(mermaid.starts_with("flowchart TD\n"));
+    assert!(mermaid.contains("    n0[\"Start<br/>tags: intro<br/>reads: $gold\"]\n"));
+    // Nodes that don't exist are added so that the jumps to them can be drawn.
+    assert!(mermaid.contains("    n3[\"Sign\"]\n"));
+    assert!(mermaid.contains("    n0 -->|\"At the #quot;sign#quot;\"| n3\n"));
+    assert!(mermaid.contains("    n1 --> n0\n"));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn exports_json() {
+    let graph = DialogueGraph {
+        nodes: vec![GraphNode {
+            name: "Start".to_owned(),
+            tags: vec!["intro".to_owned()],
+            reads: vec!["$gold".to_owned()],
+            writes: Vec::new(),
+        }],
+        edges: vec![
+            edge("Start", "Shop", Some("Say \"hi\"\n")),
+            edge("Start", "End", None),
+        ],
+    };
+
+    assert_eq!(
+        concat!(
+            r#"{"nodes":[{"name":"Start","tags":["intro"],"reads":["$gold"],"writes":[]}],"#,
+            r#""edges":[{"from":"Start","to":"Shop","kind":"option","label":"Say \"hi\"\n"},"#,
+            r#"{"from":"Start","to":"End","kind":"jump","label":null}]}"#
+        ),
+        graph.to_json()
+    );
+}
@@ -210,6 +210,76 @@ fn test_sources() {
     }
 }
 
+#[test]
+fn test_optimized_sources() {
+    for file in TestBase::file_sources("TestCases") {
+        let path = test_data_path().join(&file);
+        let test_plan = path.with_extension("testplan");
+        if !test_plan.exists() {
+            continue;
+        }
+        println!("INFO: Loading file {}", file.display());
+
+        let test_base = TestBase::default().extend_library(|library| {
+            library
+                .add_function("add_three_operands", |a: i32, b: i32, c: i32| a + b + c)
+                .add_function("dummy_bool", || true)
+                .add_function("dummy_number", || 1)
+                .add_function("dummy_string", || "string".to_owned());
+        });
+        let compile = |optimize| {
+            Compiler::default()
+                .read_file(&path)
+                .extend_library(test_base.dialogue.library().clone())
+                .with_optimization(optimize)
+                .compile()
+                .unwrap()
+        };
+        let instruction_count = |compilation: &Compilation| -> usize {
+            let program = compilation.program.as_ref().unwrap();
+            program
+                .nodes
+                .values()
+                .map(|node| node.instructions.len())
+                .sum()
+        };
+        let unoptimized = compile(false);
+        let optimized = compile(true);
+
+        assert!(instruction_count(&optimized) <= instruction_count(&unoptimized));
+        let report = test_base
+            .dialogue
+            .verify_program(optimized.program.as_ref().unwrap());
+        assert!(report.is_valid(), "{}: {report}", file.display());
+
+        let mut test_base = test_base
+            .read_test_plan(test_plan)
+            .with_compilation(optimized);
+        if test_base.dialogue.node_exists("Start") {
+            test_base.run_standard_testcase();
+        }
+    }
+}
+
+#[test]
+fn test_optimization_keeps_calls_to_replaced_standard_functions() {
+    let mut test_base = TestBase::new().extend_library(|library| {
+        library.add_function("upper", |string: &str| format!("{string}!"));
+    });
+    let result = Compiler::from_test_source("{upper(\"a\")} {round_places(1.5, 12)}")
+        .extend_library(test_base.dialogue.library().clone())
+        .with_optimization(true)
+        .compile()
+        .unwrap();
+    test_base = test_base.with_compilation(result);
+    test_base.dialogue.set_node("Start").unwrap();
+
+    let events = test_base.dialogue.continue_().unwrap();
+    assert!(events
+        .iter()
+        .any(|event| matches!(event, DialogueEvent::Line(line) if line.text == "a! 1.5")));
+}
+
 #[test]
 #[should_panic]
 fn crashes_on_command_expression_evaluating_whitespace() {
@@ -0,0 +1,248 @@
+//! Tests tagging the lines of several files with a [`LineTagger`].
+
+use std::collections::HashSet;
+use yarnspinner::compiler::line_tagging::*;
+use yarnspinner::compiler::*;
+use yarnspinner::core::LineId;
+
+fn file(file_name: &str, source: &str) -> File {
+    File {
+        file_name: file_name.to_owned(),
+        source: source.to_owned(),
+    }
+}
+
+fn files() -> Vec<File> {
+    vec![
+        file(
+            "start.yarn",
+            "title: Start\n---\nHello! #line:Other-1\n-> Hi\n-> Bye #line:bye\n===\n",
+        ),
+        file(
+            "other.yarn",
+            "title: Other\n---\nThe same line.\nThe same line.\n<<jump Start>>\n===\n",
+        ),
+    ]
+}
+
+fn tagged_sources(tagged_files: &[TaggedFile]) -> Vec<File> {
+    tagged_files
+        .iter()
+        .zip(files())
+        .map(|(tagged_file, original)| File {
+            source: tagged_file.source.clone().unwrap_or(original.source),
+            ..original
+        })
+        .collect()
+}
+
+fn string_table_ids(files: Vec<File>) -> Vec<LineId> {
+    let mut ids: Vec<_> = Compiler::new()
+        .add_files(files)
+        .with_compilation_type(CompilationType::StringsOnly)
+        .compile()
+        .unwrap()
+        .string_table
+        .into_iter()
+        .filter(|(_, string_info)| !string_info.is_implicit_tag)
+        .map(|(line_id, _)| line_id)
+        .collect();
+    ids.sort_by(|a, b| a.0.cmp(&b.0));
+    ids
+}
+
+#[test]
+fn tags_every_line_with_a_unique_id() {
+    let tagged_files = LineTagger::new()
+        .with_id_generator(RandomLineIds::from_seed(0))
+        .tag_files(&files());
+
+    assert!(tagged_files
+        .iter()
+        .all(|tagged_file| tagged_file.diagnostics.is_empty()));
+    let added_ids: Vec<_> = tagged_files
+        .iter()
+        .flat_map(|tagged_file| &tagged_file.added_line_ids)
+        .map(|added| added.line_id.clone())
+        .collect();
+    assert_eq!(3, added_ids.len());
+
+    let ids = string_table_ids(tagged_sources(&tagged_files));
+    assert_eq!(5, ids.len());
+    assert_eq!(5, ids.iter().collect::<HashSet<_>>().len());
+    assert!(added_ids.iter().all(|id| ids.contains(id)));
+}
+
+#[test]
+fn reports_the_added_ids_per_file() {
+    let tagged_files = LineTagger::new()
+        .with_id_generator(SequentialLineIds::default())
+        .tag_files(&files());
+
+    assert_eq!("start.yarn", tagged_files[0].file_name);
+    assert_eq!(
+        vec![AddedLineId {
+            line_id: "line:Start-1".into(),
+            node_name: "Start".to_owned(),
+            line_number: 4,
+        }],
+        tagged_files[0].added_line_ids
+    );
+
+    // `line:Other-1` is already used in the other file.
+    assert_eq!("other.yarn", tagged_files[1].file_name);
+    assert_eq!(
+        Some("title: Other\n---\nThe same line. #line:Other-2 \nThe same line. #line:Other-3 \n<<jump Start>>\n===\n"),
+        tagged_files[1].source.as_deref()
+    );
+    assert_eq!(
+        vec![3, 4],
+        tagged_files[1]
+            .added_line_ids
+            .iter()
+            .map(|added| added.line_number)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn content_hash_ids_do_not_depend_on_previous_runs() {
+    let first = LineTagger::new()
+        .with_id_generator(ContentHashLineIds)
+        .tag_files(&files());
+    let second = LineTagger::new()
+        .with_id_generator(ContentHashLineIds)
+        .tag_files(&files());
+
+    assert_eq!(first, second);
+    let other_ids: Vec<_> = first[1]
+        .added_line_ids
+        .iter()
+        .map(|added| &added.line_id)
+        .collect();
+    assert_ne!(other_ids[0], other_ids[1]);
+}
+
+#[test]
+fn uses_custom_generators_and_skips_ids_in_use() {
+    let tagged_files = LineTagger::new()
+        .with_existing_line_tags(["line:vendor-Other-2".into()])
+        .with_id_generator(|line: &UntaggedLine| {
+            LineId(format!(
+                "line:vendor-{}-{}",
+                line.node_name,
+                line.line_number - 1 + line.attempt
+            ))
+        })
+        .tag_files(&files());
+
+    let ids: Vec<_> = tagged_files
+        .iter()
+        .flat_map(|tagged_file| &tagged_file.added_line_ids)
+        .map(|added| added.line_id.0.as_str())
+        .collect();
+    assert_eq!(
+        vec![
+            "line:vendor-Start-3",
+            "line:vendor-Other-3",
+            "line:vendor-Other-4"
+        ],
+        ids
+    );
+}
+
+#[test]
+fn tags_only_the_healthy_nodes_of_files_with_errors() {
+    let files = [
+        file(
+            "broken.yarn",
+            "title: Start\n---\nHi #line:Other-1\n<<if true\nHello\n===\ntitle: Healthy\n---\nHey\n===\n",
+        ),
+        file("other.yarn", "title: Other\n---\nBye\n===\n"),
+    ];
+
+    let tagged_files = LineTagger::new()
+        .with_id_generator(SequentialLineIds::default())
+        .tag_files(&files);
+
+    assert!(tagged_files[0].diagnostics.has_errors());
+    assert!(tagged_files[0]
+        .diagnostics
+        .iter()
+        .all(|diagnostic| diagnostic.file_name.as_deref() == Some("broken.yarn")));
+    assert_eq!(
+        Some("title: Start\n---\nHi #line:Other-1\n<<if true\nHello\n===\ntitle: Healthy\n---\nHey #line:Healthy-1 \n===\n"),
+        tagged_files[0].source.as_deref()
+    );
+    assert_eq!(1, tagged_files[0].added_line_ids.len());
+
+    assert!(tagged_files[1].diagnostics.is_empty());
+    assert_eq!(
+        Some("title: Other\n---\nBye #line:Other-2 \n===\n"),
+        tagged_files[1].source.as_deref()
+    );
+}
+
+#[test]
+fn does_not_change_files_that_are_already_tagged() {
+    let files = [file("done.yarn", "title: Start\n---\nHi #line:hi\n===\n")];
+
+    let tagged_files = LineTagger::new().tag_files(&files);
+
+    assert_eq!(None, tagged_files[0].source);
+    assert!(tagged_files[0].added_line_ids.is_empty());
+    assert!(tagged_files[0].diagnostics.is_empty());
+}
+
+#[test]
+fn does_not_tag_files_with_errors_at_their_end() {
+    let files = [file(
+        "unclosed.yarn",
+        "title: Start\n---\nHi\n===\ntitle: Other\n---\nBye\n",
+    )];
+
+    let tagged_files = LineTagger::new().tag_files(&files);
+
+    assert!(tagged_files[0].diagnostics.has_errors());
+    assert_eq!(None, tagged_files[0].source);
+    assert!(tagged_files[0].added_line_ids.is_empty());
+}
+
+#[test]
+fn does_not_tag_nodes_with_hashtags_without_text() {
+    for line in ["B#", "    B#"] {
+        let files = [file(
+            "hashtag.yarn",
+            &format!("title: Start\n---\n{line}\n===\ntitle: Other\n---\nBye\n===\n"),
+        )];
+
+        let tagged_files = LineTagger::new()
+            .with_id_generator(SequentialLineIds::default())
+            .tag_files(&files);
+
+        assert!(tagged_files[0].diagnostics.has_errors());
+        assert_eq!(
+            Some(format!(
+                "title: Start\n---\n{line}\n===\ntitle: Other\n---\nBye #line:Other-1 \n===\n"
+            )),
+            tagged_files[0].source
+        );
+    }
+}
+
+#[test]
+fn inserts_tags_after_lines_with_multibyte_characters() {
+    let files = [file(
+        "umlauts.yarn",
+        "title: Start\n---\n    Grüße {1}\n-> Schön #hübsch\n===\n",
+    )];
+
+    let tagged_files = LineTagger::new()
+        .with_id_generator(SequentialLineIds::default())
+        .tag_files(&files);
+
+    assert_eq!(
+        Some("title: Start\n---\n    Grüße {1} #line:Start-1 \n-> Schön #hübsch #line:Start-2 \n===\n"),
+        tagged_files[0].source.as_deref()
+    );
+}
@@ -0,0 +1,136 @@
+//! Tests the node metadata in [`Compilation::nodes`].
+
+use yarnspinner::compiler::*;
+use yarnspinner::core::*;
+
+const START: &str = "title: Start
+tags: intro tutorial
+color: red
+color: blue
+---
+Hello! #line:hello
+-> Shop
+    <<jump Shop>>
+-> Leave #line:leave
+<<jump {\"Leave\"}>>
+===
+title: Leave
+---
+===
+";
+
+const SHOP: &str = "title: Shop
+---
+Buy something?
+<<jump Start>>
+===
+";
+
+fn compile(compilation_type: CompilationType) -> Compilation {
+    Compiler::new()
+        .add_file(File {
+            file_name: "start.yarn".to_owned(),
+            source: START.to_owned(),
+        })
+        .add_file(File {
+            file_name: "shop.yarn".to_owned(),
+            source: SHOP.to_owned(),
+        })
+        .with_compilation_type(compilation_type)
+        .compile()
+        .unwrap()
+}
+
+#[test]
+fn lists_nodes_in_source_order() {
+    let compilation = compile(CompilationType::FullCompilation);
+
+    let titles: Vec<_> = compilation
+        .nodes
+        .iter()
+        .map(|node| (node.file_name.as_str(), node.title.as_str()))
+        .collect();
+    assert_eq!(
+        vec![
+            ("start.yarn", "Start"),
+            ("start.yarn", "Leave"),
+            ("shop.yarn", "Shop")
+        ],
+        titles
+    );
+}
+
+#[test]
+fn collects_headers_tags_jumps_and_lines() {
+    let compilation = compile(CompilationType::FullCompilation);
+    let start = compilation.node("Start").unwrap();
+
+    let headers: Vec<_> = start
+        .headers
+        .iter()
+        .map(|header| (header.key.as_str(), header.value.as_str()))
+        .collect();
+    assert_eq!(
+        vec![
+            ("title", "Start"),
+            ("tags", "intro tutorial"),
+            ("color", "red"),
+            ("color", "blue")
+        ],
+        headers
+    );
+    assert_eq!(
+        vec!["red", "blue"],
+        start.header_values("color").collect::<Vec<_>>()
+    );
+    assert_eq!(vec!["intro", "tutorial"], start.tags);
+    assert_eq!(vec!["Shop", "Leave"], start.jumps);
+    assert_eq!(LineId::from("line:hello"), start.line_ids[0]);
+    assert_eq!(3, start.line_ids.len());
+    assert_eq!(LineId::from("line:leave"), start.line_ids[2]);
+
+    let shop = compilation.node("Shop").unwrap();
+    assert_eq!(vec!["Start"], shop.jumps);
+    assert_eq!(1, shop.line_ids.len());
+    assert!(shop.tags.is_empty());
+}
+
+#[test]
+fn records_node_and_body_ranges() {
+    let compilation = compile(CompilationType::FullCompilation);
+
+    let start = compilation.node("Start").unwrap();
+    assert_eq!(
+        Position {
+            line: 0,
+            character: 0
+        }..Position {
+            line: 10,
+            character: 3
+        },
+        start.range
+    );
+    assert_eq!(
+        Position {
+            line: 5,
+            character: 0
+        }..Position {
+            line: 10,
+            character: 0
+        },
+        start.body_range
+    );
+
+    let leave = compilation.node("Leave").unwrap();
+    assert_eq!(leave.body_range.start, leave.body_range.end);
+    assert_eq!(13, leave.body_range.start.line);
+}
+
+#[test]
+fn lists_nodes_without_compiling_them() {
+    let compilation = compile(CompilationType::StringsOnly);
+
+    assert!(compilation.program.is_none());
+    assert_eq!(3, compilation.nodes.len());
+    assert_eq!(vec!["Start"], compilation.node("Shop").unwrap().jumps);
+}
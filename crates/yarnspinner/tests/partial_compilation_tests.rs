@@ -0,0 +1,275 @@
+//! Tests the recovery from syntax errors and partial compilations.
+
+use test_base::prelude::*;
+use yarnspinner::compiler::*;
+use yarnspinner::core::*;
+
+mod test_base;
+
+const HEALTHY_FILE: &str = "title: Healthy
+---
+<<declare $gold = 10>>
+You have {$gold} gold. #line:healthy
+===
+";
+
+const BROKEN_FILE: &str = "title: Broken
+---
+<<declare $broken = true>>
+<<set $broken to >>
+This line is fine. #line:broken
+<<if >>
+<<endif>>
+===
+";
+
+fn compiler(files: &[(&str, &str)]) -> Compiler {
+    let mut compiler = Compiler::new();
+    for (file_name, source) in files {
+        compiler.add_file(File {
+            file_name: file_name.to_string(),
+            source: source.to_string(),
+        });
+    }
+    compiler
+}
+
+#[test]
+fn reports_all_syntax_errors_in_all_files() {
+    let other_broken_file = "title: AlsoBroken
+---
+<<jump >>
+===
+";
+    let result = compiler(&[
+        ("broken.yarn", BROKEN_FILE),
+        ("other.yarn", other_broken_file),
+    ])
+    .compile()
+    .unwrap_err();
+
+    let syntax_errors: Vec<_> = result
+        .0
+        .iter()
+        .filter(|diagnostic| diagnostic.code == DiagnosticCode::SyntaxError)
+        .collect();
+    let lines_in_broken_file: Vec<_> = syntax_errors
+        .iter()
+        .filter(|diagnostic| diagnostic.file_name.as_deref() == Some("broken.yarn"))
+        .map(|diagnostic| diagnostic.range.as_ref().unwrap().start.line)
+        .collect();
+    assert!(lines_in_broken_file.contains(&3), "{result}");
+    assert!(lines_in_broken_file.contains(&5), "{result}");
+    assert!(
+        syntax_errors
+            .iter()
+            .any(|diagnostic| diagnostic.file_name.as_deref() == Some("other.yarn")),
+        "{result}"
+    );
+}
+
+#[test]
+fn fails_without_partial_compilation() {
+    let result =
+        compiler(&[("healthy.yarn", HEALTHY_FILE), ("broken.yarn", BROKEN_FILE)]).compile();
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn returns_the_healthy_nodes_with_partial_compilation() {
+    let compilation = compiler(&[("healthy.yarn", HEALTHY_FILE), ("broken.yarn", BROKEN_FILE)])
+        .with_partial_compilation(true)
+        .compile()
+        .unwrap();
+
+    assert!(!compilation.errors.is_empty());
+    assert!(compilation
+        .errors
+        .iter()
+        .all(|diagnostic| diagnostic.severity == DiagnosticSeverity::Error));
+
+    assert!(compilation.node("Healthy").is_some());
+    assert!(compilation.node("Broken").is_none());
+
+    let program = compilation.program.unwrap();
+    assert!(program.nodes.contains_key("Healthy"));
+    assert!(!program.nodes.contains_key("Broken"));
+
+    assert!(compilation
+        .string_table
+        .contains_key(&LineId::from("line:healthy")));
+    assert!(!compilation
+        .string_table
+        .contains_key(&LineId::from("line:broken")));
+
+    let declarations: Vec<_> = compilation
+        .declarations
+        .iter()
+        .map(|declaration| declaration.name.as_str())
+        .collect();
+    assert!(declarations.contains(&"$gold"));
+    assert!(!declarations.contains(&"$broken"));
+}
+
+#[test]
+fn partial_compilation_of_healthy_files_has_no_errors() {
+    let compilation = compiler(&[("healthy.yarn", HEALTHY_FILE)])
+        .with_partial_compilation(true)
+        .compile()
+        .unwrap();
+
+    assert!(compilation.errors.is_empty());
+    assert!(compilation.program.unwrap().nodes.contains_key("Healthy"));
+}
+
+#[test]
+fn unterminated_commands_end_at_their_line() {
+    for command in ["<<jump Other", "<<set $a = \"x"] {
+        let source = format!(
+            "title: Start
+---
+{command}
+Hello #line:hello
+===
+title: Other
+---
+<<declare $a = \"y\">>
+Hi #line:hi
+===
+"
+        );
+        let compilation = compiler(&[("unterminated.yarn", &source)])
+            .with_partial_compilation(true)
+            .compile()
+            .unwrap();
+
+        let newline_in_command = compilation
+            .errors
+            .iter()
+            .find(|diagnostic| diagnostic.code == DiagnosticCode::NewlineInCommand)
+            .unwrap_or_else(|| panic!("{}", CompilerError(compilation.errors.clone())));
+        assert_eq!(Some(command), newline_in_command.context.as_deref());
+        assert!(
+            compilation.errors.iter().all(|diagnostic| diagnostic
+                .range
+                .as_ref()
+                .unwrap()
+                .start
+                .line
+                < 3),
+            "{}",
+            CompilerError(compilation.errors.clone())
+        );
+        assert!(compilation.node("Other").is_some());
+        assert!(compilation
+            .string_table
+            .contains_key(&LineId::from("line:hi")));
+
+        // Rendering the diagnostics must not panic
+        let rendered = CompilerError(compilation.errors.clone()).to_string();
+        assert!(rendered.contains(command), "{rendered}");
+    }
+}
+
+#[test]
+fn whitespace_in_commands_is_not_reported() {
+    let compilation = compiler(&[(
+        "whitespace.yarn",
+        "title: Start\n---\n<<jump   Start>>\n===\n",
+    )])
+    .compile()
+    .unwrap();
+
+    assert!(
+        compilation.warnings.is_empty(),
+        "{}",
+        CompilerError(compilation.warnings.clone())
+    );
+}
+
+#[test]
+fn unrecognized_characters_are_reported_with_their_line() {
+    let compilation = compiler(&[("stray.yarn", "title: Start\n---\nHello\n===\n@@\n")])
+        .compile()
+        .unwrap();
+
+    let warning = compilation
+        .warnings
+        .iter()
+        .find(|diagnostic| diagnostic.message.contains("token recognition error"))
+        .unwrap();
+    assert_eq!(Some("@@"), warning.context.as_deref());
+    assert_eq!(4, warning.start_line);
+}
+
+#[test]
+fn syntax_errors_at_the_end_of_the_file_are_reported() {
+    let result = compiler(&[("eof.yarn", "title: Start\n---\nHello\n===\nstray\n")])
+        .compile()
+        .unwrap_err();
+
+    assert!(
+        result
+            .0
+            .iter()
+            .any(|diagnostic| diagnostic.message.contains("<EOF>")),
+        "{result}"
+    );
+}
+
+#[test]
+fn partial_compilation_leaves_out_nodes_without_a_title() {
+    for broken_node in [
+        "tile: S\n---\n<<set $x + 1>>\n===\n",
+        "titl: S\n---\n<<if $g > 3>    R\n<<endif>>\n===\n",
+        "title: S\n---\n<<set $x + 1>>\n===\n",
+        "title: S\n---\n<<if $g > 3>    R\n<<endif>>\n===\n",
+    ] {
+        let source = format!("{broken_node}{HEALTHY_FILE}");
+        let compilation = compiler(&[("untitled.yarn", &source)])
+            .with_partial_compilation(true)
+            .compile()
+            .unwrap();
+
+        assert!(compilation.errors.has_errors(), "{broken_node}");
+        let program = compilation.program.unwrap();
+        assert_eq!(
+            vec!["Healthy"],
+            program.nodes.keys().collect::<Vec<_>>(),
+            "{broken_node}"
+        );
+    }
+}
+
+#[test]
+fn incomplete_statements_and_headers_are_reported() {
+    for (source, error_line) in [
+        ("title: Start\n---\n<<declare $x>>\n===\n", 2),
+        ("title\n---\nHi\n===\n", 1),
+        ("title:\n---\nHi\n===\n", 0),
+        ("tile: S\n---\n<<set $x to 1>>\n===\n", 0),
+        ("title: Start\n---\n<<set $x to visited()>>\n===\n", 0),
+    ] {
+        let errors = compiler(&[("incomplete.yarn", source)])
+            .compile()
+            .unwrap_err()
+            .0;
+
+        assert_eq!(error_line, errors[0].start_line, "{source:?}: {errors:?}");
+    }
+}
+
+#[test]
+fn hashtags_without_text_end_at_their_line() {
+    let source = format!("title: Start\n---\nHi #\n===\n{HEALTHY_FILE}");
+    let compilation = compiler(&[("hashtag.yarn", &source)])
+        .with_partial_compilation(true)
+        .compile()
+        .unwrap();
+
+    assert_eq!(1, compilation.errors.len(), "{:?}", compilation.errors);
+    assert_eq!(2, compilation.errors[0].start_line);
+    assert!(compilation.node("Start").is_none());
+    assert!(compilation.node("Healthy").is_some());
+}
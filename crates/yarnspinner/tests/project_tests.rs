@@ -292,3 +292,51 @@ fn test_debug_output_is_produced() {
     assert_eq!(2, first_line_info.position.unwrap().line);
     assert_eq!(0, first_line_info.position.unwrap().character);
 }
+
+#[test]
+fn test_disassembly_includes_debug_output() {
+    let file = File {
+        file_name: "input".to_owned(),
+        source: create_test_node_with_name("This is a test node.", "DebugTesting"),
+    };
+    let result = Compiler::new().add_file(file).compile().unwrap();
+
+    let listing = result.disassemble().unwrap();
+
+    assert!(listing.contains("node \"DebugTesting\"\n"), "{listing}");
+    // The first instruction of the only node begins on the third line
+    let first_instruction = listing
+        .lines()
+        .find(|line| line.trim_start().starts_with("0 "))
+        .unwrap();
+    assert!(first_instruction.ends_with("; input:3:1"), "{listing}");
+}
+
+#[test]
+fn test_disassembled_programs_can_be_assembled() {
+    let path = test_data_path().join("Projects/Basic/Test.yarn");
+    let result = Compiler::new().read_file(path).compile().unwrap();
+    let program = result.program.as_ref().unwrap();
+
+    let assembled = Program::assemble(&result.disassemble().unwrap()).unwrap();
+
+    assert_eq!(program, &assembled);
+    assert_eq!(program.disassemble(), assembled.disassemble());
+}
+
+#[test]
+fn test_compiled_programs_can_be_saved_and_loaded() {
+    let result = Compiler::from_test_source("Hello! #line:hello #greeting\nBye. #line:bye")
+        .compile()
+        .unwrap();
+
+    let file = ProgramFile::from_bytes(&result.to_bytes().unwrap()).unwrap();
+
+    assert_eq!(&file.program, result.program.as_ref().unwrap());
+    assert_eq!(file.string_table[&LineId::from("line:hello")], "Hello!");
+    assert_eq!(file.string_table[&LineId::from("line:bye")], "Bye.");
+    assert!(file.line_metadata[&LineId::from("line:hello")].contains(&"greeting".to_owned()));
+    for (line_id, string_info) in &result.string_table {
+        assert_eq!(file.line_metadata[line_id], string_info.metadata);
+    }
+}
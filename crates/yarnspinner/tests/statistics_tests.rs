@@ -0,0 +1,135 @@
+//! Tests the reports of [`statistics`].
+
+use std::collections::HashMap;
+use yarnspinner::compiler::*;
+use yarnspinner::core::LineId;
+use yarnspinner::statistics::*;
+
+const START: &str = "title: Start
+---
+Alice: Hello, [wave]traveller[/wave]! #line:hello
+The wind howls. #line:wind
+-> Bob: Who are you? #line:who
+    Alice: A friend. #line:friend
+-> Leave #line:leave
+<<jump Shop>>
+===
+";
+
+const SHOP: &str = "title: Shop
+---
+[character name=\"Clerk\"]Shopkeeper:[/character] What'll it be? #line:shop
+Bob: Nothing, \"thanks\". #line:nothing
+===
+";
+
+fn compile(compilation_type: CompilationType) -> Compilation {
+    Compiler::new()
+        .add_file(File {
+            file_name: "start.yarn".to_owned(),
+            source: START.to_owned(),
+        })
+        .add_file(File {
+            file_name: "shop.yarn".to_owned(),
+            source: SHOP.to_owned(),
+        })
+        .with_compilation_type(compilation_type)
+        .compile()
+        .unwrap()
+}
+
+fn counts(name: &str, lines: usize, options: usize, words: usize) -> WordCounts {
+    WordCounts {
+        name: name.to_owned(),
+        lines,
+        options,
+        words,
+    }
+}
+
+#[test]
+fn counts_words_per_character_node_and_file() {
+    let statistics = ScriptStatistics::from_compilation(&compile(CompilationType::FullCompilation));
+
+    assert_eq!(counts("", 5, 2, 16), statistics.total);
+    assert_eq!(
+        vec![counts("shop.yarn", 2, 0, 5), counts("start.yarn", 3, 2, 11)],
+        statistics.files
+    );
+    assert_eq!(
+        vec![counts("Shop", 2, 0, 5), counts("Start", 3, 2, 11)],
+        statistics.nodes
+    );
+    assert_eq!(
+        vec![
+            counts("Clerk", 1, 0, 3),
+            counts("Bob", 1, 1, 5),
+            counts("Alice", 2, 0, 4),
+            counts("", 1, 1, 4),
+        ],
+        statistics.characters
+    );
+    assert!(statistics.languages.is_empty());
+}
+
+#[test]
+fn counts_options_as_lines_without_program() {
+    let statistics = ScriptStatistics::from_compilation(&compile(CompilationType::StringsOnly));
+
+    assert_eq!(counts("", 7, 0, 16), statistics.total);
+}
+
+#[test]
+fn totals_translations_by_language() {
+    let translation = HashMap::from([
+        (
+            LineId::from("line:hello"),
+            "Alice: Hallo, Reisende!".to_owned(),
+        ),
+        (LineId::from("line:who"), "Bob: Wer bist du?".to_owned()),
+    ]);
+    let statistics = ScriptStatistics::from_compilation(&compile(CompilationType::FullCompilation))
+        .with_translation("de-CH", &translation)
+        .with_base_language("en-US");
+
+    assert_eq!(
+        vec![counts("en-US", 5, 2, 16), counts("de-CH", 1, 1, 5)],
+        statistics.languages
+    );
+}
+
+#[test]
+fn exports_csv() {
+    let statistics = ScriptStatistics::from_compilation(&compile(CompilationType::FullCompilation))
+        .with_translation("de-CH", &HashMap::new());
+
+    let csv = statistics.to_csv();
+    assert!(csv.starts_with(
+        "group,name,lines,options,words\r\n\
+         total,,5,2,16\r\n\
+         language,base,5,2,16\r\n\
+         language,de-CH,0,0,0\r\n\
+         file,shop.yarn,2,0,5\r\n"
+    ));
+    assert!(csv.ends_with("character,Alice,2,0,4\r\ncharacter,,1,1,4\r\n"));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn exports_json() {
+    let statistics = ScriptStatistics {
+        total: counts("", 1, 0, 2),
+        nodes: vec![counts("Start", 1, 0, 2)],
+        characters: vec![counts("\"Boss\"", 1, 0, 2)],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        concat!(
+            r#"{"total":{"name":"","lines":1,"options":0,"words":2},"languages":[],"files":[],"#,
+            r#""nodes":[{"name":"Start","lines":1,"options":0,"words":2}],"#,
+            r#""characters":[{"name":"\"Boss\"","lines":1,"options":0,"words":2}]}"#
+        ),
+        statistics.to_json()
+    );
+}
@@ -21,3 +21,7 @@ pub fn test_data_path() -> PathBuf {
 pub fn space_demo_scripts_path() -> PathBuf {
     test_data_path().join("Projects/Space")
 }
+
+pub fn golden_data_path() -> PathBuf {
+    project_root_path().join("tests/golden")
+}
@@ -297,6 +297,41 @@ fn test_function_signatures() {
             .any(|d| d.name == "$bool" && d.r#type == Type::Boolean));
     }
 }
+
+#[test]
+fn test_optional_and_variadic_function_signatures() {
+    let mut test_base = TestBase::default();
+    test_base
+        .dialogue
+        .library_mut()
+        .add_function("func_int_optional_int_bool", |_i: i32, _j: Option<i32>| {
+            true
+        })
+        .add_function(
+            "func_int_variadic_int_bool",
+            |_i: i32, _rest: Variadic<i32>| true,
+        );
+
+    for source in [
+        "<<set $bool = func_int_optional_int_bool(1)>>",
+        "<<set $bool = func_int_optional_int_bool(1, 2)>>",
+        "<<set $bool = func_int_variadic_int_bool(1)>>",
+        "<<set $bool = func_int_variadic_int_bool(1, 2, 3, 4)>>",
+        "<<set $bool = max(1, 2, 3, 4) == 4>>",
+        "<<set $bool = format(\"{0} and {1}\", 1, \"two\") == \"1 and two\">>",
+    ] {
+        let result = Compiler::from_test_source(source)
+            .extend_library(test_base.dialogue.library().clone())
+            .compile()
+            .unwrap();
+
+        assert!(result
+            .declarations
+            .iter()
+            .any(|d| d.name == "$bool" && d.r#type == Type::Boolean));
+    }
+}
+
 #[test]
 fn test_operators_are_type_checked() {
     let test_base = TestBase::default();
@@ -333,7 +368,14 @@ fn test_failing_function_signatures() {
         .add_function("func_void_bool", || true)
         .add_function("func_int_bool", |_i: i32| true)
         .add_function("func_int_int_bool", |_i: i32, _j: i32| true)
-        .add_function("func_string_string_bool", |_i: &str, _j: &str| true);
+        .add_function("func_string_string_bool", |_i: &str, _j: &str| true)
+        .add_function("func_int_optional_int_bool", |_i: i32, _j: Option<i32>| {
+            true
+        })
+        .add_function(
+            "func_int_variadic_int_bool",
+            |_i: i32, _rest: Variadic<i32>| true,
+        );
 
     for (source, expected_exception_message) in [
         (
@@ -356,6 +398,22 @@ fn test_failing_function_signatures() {
             "<<set $int = func_void_bool()>>",
             "$int (Number) cannot be assigned a Bool",
         ),
+        (
+            "<<set $bool = func_int_optional_int_bool()>>",
+            "expects between 1 and 2 parameters, but received 0",
+        ),
+        (
+            "<<set $bool = func_int_optional_int_bool(1, 2, 3)>>",
+            "expects between 1 and 2 parameters, but received 3",
+        ),
+        (
+            "<<set $bool = func_int_variadic_int_bool()>>",
+            "expects at least 1 parameter, but received 0",
+        ),
+        (
+            "<<set $bool = func_int_variadic_int_bool(1, 2, true)>>",
+            "parameter 3 expects a Number, not a Bool",
+        ),
     ] {
         let failing_source = format!("<<declare $bool = false>>\n<<declare $int = 1>>\n{source}",);
 
@@ -595,7 +653,7 @@ fn test_type_conversion() {
 }
 
 #[test]
-#[should_panic = "Failed to convert a Yarn value to a number: ParseFloatError(ParseFloatError { kind: Invalid })"]
+#[should_panic = "Function \"number\" failed in node \"Start\" at instruction 2: Cannot convert \"hello\" to a number"]
 fn test_type_conversion_failure_to_number() {
     let source = "{number(\"hello\")}";
     let test_base =
@@ -608,7 +666,7 @@ fn test_type_conversion_failure_to_number() {
 }
 
 #[test]
-#[should_panic = "Failed to convert a Yarn value to a bool: ParseBoolError(ParseBoolError"]
+#[should_panic = "Function \"bool\" failed in node \"Start\" at instruction 2: Cannot convert \"hello\" to a bool"]
 fn test_type_conversion_failure_to_bool() {
     let source = "{bool(\"hello\")}";
     let test_base =
@@ -0,0 +1,193 @@
+//! Checks reading and writing the files of `ysc compile` of the original implementation.
+//!
+//! The files in `tests/golden/upstream_format` were written by this crate from `tests/golden/upstream/Example.yarn`
+//! in the upstream file formats. They guard against regressions in reading and writing those formats,
+//! but cannot show that the formats match upstream:
+//! - `Example.yarnc` is the compiled program, whose listing is in `Example.yarnasm`.
+//! - `Example-Lines.csv` and `Example-Metadata.csv` are the string table and line metadata.
+//!
+//! Checking against the real upstream compiler needs its output in `tests/golden/upstream`, see the `README.md` there.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use test_base::prelude::*;
+use yarnspinner::compiler::*;
+use yarnspinner::core::*;
+use yarnspinner::runtime::*;
+
+mod test_base;
+
+/// Source and output of the upstream compiler.
+fn upstream_path() -> PathBuf {
+    golden_data_path().join("upstream")
+}
+
+/// Output of this crate in the upstream file formats.
+fn upstream_format_path() -> PathBuf {
+    golden_data_path().join("upstream_format")
+}
+
+fn read_golden(file_name: &str) -> String {
+    fs::read_to_string(upstream_format_path().join(file_name)).unwrap()
+}
+
+fn read_program_file(path: PathBuf) -> ProgramFile {
+    let read = |file_name: &str| fs::read_to_string(path.join(file_name)).unwrap();
+    let program = fs::read(path.join("Example.yarnc")).unwrap();
+    ProgramFile::from_upstream(
+        &program,
+        &read("Example-Lines.csv"),
+        Some(&read("Example-Metadata.csv")),
+    )
+    .unwrap()
+}
+
+/// Runs the node `Start` to completion, always selecting the first option, and returns the text of everything that was shown.
+fn run_to_completion(program: Program, string_table: HashMap<LineId, String>) -> Vec<String> {
+    let mut text_provider = StringTableTextProvider::new();
+    text_provider.extend_base_language(string_table);
+    let mut dialogue = Dialogue::new(
+        Box::new(MemoryVariableStorage::new()),
+        Box::new(text_provider),
+    );
+    dialogue.add_program(program);
+    dialogue.set_node("Start").unwrap();
+
+    let mut transcript = Vec::new();
+    loop {
+        for event in dialogue.continue_().unwrap() {
+            match event {
+                DialogueEvent::Line(line) => transcript.push(format!("{}: {}", line.id, line.text)),
+                DialogueEvent::Options(options) => {
+                    for option in &options {
+                        transcript.push(format!("-> {}: {}", option.line.id, option.line.text));
+                    }
+                    dialogue.set_selected_option(options[0].id).unwrap();
+                }
+                DialogueEvent::DialogueComplete => return transcript,
+                _ => {}
+            }
+        }
+    }
+}
+
+fn expected_transcript() -> Vec<String> {
+    [
+        "line:hello: Narrator: Hello there.",
+        "line:rich: Narrator: You are rich!",
+        "-> line:buy: Buy",
+        "-> line:leave: Leave",
+        "line:thanks: Narrator: Thanks.",
+        "line:bye: Narrator: Bye, then.",
+    ]
+    .map(String::from)
+    .to_vec()
+}
+
+#[test]
+fn test_upstream_programs_round_trip() {
+    let bytes = fs::read(upstream_format_path().join("Example.yarnc")).unwrap();
+
+    let program = Program::from_upstream_bytes(&bytes).unwrap();
+
+    // Labels and headers are stored in maps, so their encoded order is not stable and the bytes cannot be compared directly.
+    assert_eq!(
+        Ok(program.clone()),
+        Program::from_upstream_bytes(&program.to_upstream_bytes())
+    );
+    assert_eq!(read_golden("Example.yarnasm"), program.disassemble());
+    let report = TestBase::new().dialogue.verify_program(&program);
+    assert!(report.is_valid(), "{report}");
+}
+
+#[test]
+fn test_upstream_programs_are_rejected_by_program_file() {
+    let bytes = fs::read(upstream_format_path().join("Example.yarnc")).unwrap();
+
+    assert_eq!(
+        Err(ProgramFileError::NotAProgramFile),
+        ProgramFile::from_bytes(&bytes)
+    );
+}
+
+#[test]
+fn test_upstream_csv_files_round_trip() {
+    let lines_csv = read_golden("Example-Lines.csv");
+    let metadata_csv = read_golden("Example-Metadata.csv");
+
+    let lines = LineEntry::read_csv(&lines_csv).unwrap();
+    let metadata = LineMetadataEntry::read_csv(&metadata_csv).unwrap();
+
+    assert_eq!(6, lines.len());
+    assert_eq!("Narrator: Bye, then.", lines[5].text);
+    assert_eq!(11, lines[5].line_number);
+    assert_eq!(vec!["farewell", "shout"], metadata[1].tags);
+    assert_eq!(lines_csv, LineEntry::write_csv(&lines));
+    assert_eq!(metadata_csv, LineMetadataEntry::write_csv(&metadata));
+}
+
+#[test]
+fn test_upstream_program_files_can_be_run() {
+    let file = read_program_file(upstream_format_path());
+
+    assert_eq!(
+        vec!["mood:happy".to_owned()],
+        file.line_metadata[&LineId::from("line:rich")]
+    );
+    assert!(!file.line_metadata.contains_key(&LineId::from("line:hello")));
+    assert_eq!(
+        expected_transcript(),
+        run_to_completion(file.program, file.string_table)
+    );
+}
+
+#[test]
+fn test_compiled_programs_match_golden_upstream_format_files() {
+    let result = Compiler::new()
+        .read_file(upstream_path().join("Example.yarn"))
+        .compile()
+        .unwrap();
+    let golden_lines = LineEntry::read_csv(&read_golden("Example-Lines.csv")).unwrap();
+
+    // The file column depends on the path the compiler was given, so it is left out.
+    let without_file = |entries: Vec<LineEntry>| -> Vec<_> {
+        entries
+            .into_iter()
+            .map(|entry| (entry.id, entry.text, entry.node, entry.line_number))
+            .collect()
+    };
+    assert_eq!(
+        without_file(golden_lines),
+        without_file(result.line_entries())
+    );
+
+    let string_table = result
+        .string_table
+        .iter()
+        .map(|(line_id, string_info)| (line_id.clone(), string_info.text.clone()))
+        .collect();
+    assert_eq!(
+        expected_transcript(),
+        run_to_completion(result.program.unwrap(), string_table)
+    );
+}
+
+#[test]
+#[ignore = "needs the output of the upstream compiler, see tests/golden/upstream/README.md"]
+fn test_programs_compiled_by_upstream_ysc_can_be_run() {
+    let version = fs::read_to_string(upstream_path().join("VERSION")).unwrap();
+    assert!(!version.trim().is_empty());
+    let file = read_program_file(upstream_path());
+
+    assert_eq!(
+        vec!["mood:happy".to_owned()],
+        file.line_metadata[&LineId::from("line:rich")]
+    );
+    let report = TestBase::new().dialogue.verify_program(&file.program);
+    assert!(report.is_valid(), "{report}");
+    assert_eq!(
+        expected_transcript(),
+        run_to_completion(file.program, file.string_table)
+    );
+}
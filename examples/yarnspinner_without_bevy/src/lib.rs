@@ -15,7 +15,7 @@ use crossterm::event::KeyCode;
 use ratatui::layout::{Constraint, Layout};
 use ratatui::style::{Color, Stylize};
 use yarnspinner::compiler::Compiler;
-use yarnspinner::core::{IntoYarnValueFromNonYarnValue, LineId, YarnFn, YarnValue};
+use yarnspinner::core::{LineId, YarnFn, YarnFnReturn, YarnValue};
 use yarnspinner::runtime::{
     Dialogue, DialogueEvent, Line, MemoryVariableStorage, StringTableTextProvider,
 };
@@ -308,7 +308,7 @@ impl TuiDialogueRunner {
     where
         Marker: 'static,
         F: YarnFn<Marker> + 'static + Clone,
-        F::Out: IntoYarnValueFromNonYarnValue + 'static + Clone,
+        F::Out: YarnFnReturn + 'static,
     {
         self.dialogue.library_mut().add_function(name, function);
     }